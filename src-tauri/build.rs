@@ -1,3 +1,6 @@
 fn main() {
-    tauri_build::build()
+    // The CLI-only build (`--no-default-features`) has no Tauri context to generate.
+    if std::env::var_os("CARGO_FEATURE_DESKTOP").is_some() {
+        tauri_build::build()
+    }
 }
@@ -1,24 +1,51 @@
 use std::path::Path;
 
+#[cfg(feature = "desktop")]
+use crate::{
+    application::source_watch_service::SourceWatchService, infra::WatchTarget,
+    interface::contracts::watch::WatchSourcesRequest,
+};
 use crate::{
     application::{
+        command::{
+            listing_service::CommandListingService, mutation_service::CommandMutationService,
+            replication_service::CommandReplicationService,
+        },
         detection::detection_service::DetectionService,
+        instruction::{
+            listing_service::InstructionListingService,
+            mutation_service::InstructionMutationService,
+            replication_service::InstructionReplicationService,
+        },
         mcp::{
-            listing_service::McpListingService, mutation_service::McpMutationService,
-            replication_service::McpReplicationService,
+            config_repair_service::McpConfigRepairService, listing_service::McpListingService,
+            mutation_service::McpMutationService, replication_service::McpReplicationService,
+            secret_externalization_service::McpSecretExternalizationService,
         },
         project_context_resolver::ProjectContextResolver,
+        security_audit_service::SecurityAuditService,
+        setting::{
+            listing_service::SettingListingService, mutation_service::SettingMutationService,
+        },
         skill::{listing_service::SkillListingService, mutation_service::SkillMutationService},
         subagent::listing_service::SubagentListingService,
     },
-    infra::{AdapterRegistry, DetectorRegistry, MutationTestHooks, SafeFileMutator},
+    infra::{
+        AdapterRegistry, DetectorRegistry, MutationTestHooks, ParkedServerStore, SafeFileMutator,
+    },
     interface::contracts::{
         command::CommandError,
-        common::ResourceKind,
+        common::{ClientKind, ResourceKind},
         detect::{DetectClientsRequest, DetectClientsResponse},
         list::{ListResourcesRequest, ListResourcesResponse},
         mutate::{MutateResourceRequest, MutateResourceResponse},
+        repair::{
+            DiagnoseConfigRequest, DiagnoseConfigResponse, RepairConfigRequest,
+            RepairConfigResponse,
+        },
         replicate::{ReplicateResourceRequest, ReplicateResourceResponse},
+        secrets::{ExternalizeSecretsRequest, ExternalizeSecretsResponse, SecretReferenceKind},
+        security_audit::{SecurityAuditRequest, SecurityAuditResponse},
     },
 };
 
@@ -65,6 +92,8 @@ impl<'a> AdapterService<'a> {
                 view_mode: request.view_mode,
                 items: result.items,
                 warning: result.warning,
+                diagnostics: result.diagnostics,
+                source_revisions: result.source_revisions,
             });
         }
 
@@ -99,6 +128,73 @@ impl<'a> AdapterService<'a> {
                 view_mode: request.view_mode,
                 items: result.items,
                 warning: result.warning,
+                diagnostics: Vec::new(),
+                source_revisions: Vec::new(),
+            });
+        }
+
+        if matches!(request.resource_kind, ResourceKind::Command) {
+            let command_listing_service = CommandListingService::new();
+            let result = command_listing_service.list(
+                client,
+                request.project_root.as_deref(),
+                request.enabled,
+                request.view_mode,
+                request.scope_filter.as_deref(),
+            );
+
+            return Ok(ListResourcesResponse {
+                client: Some(client),
+                resource_kind: request.resource_kind,
+                project_root: request.project_root,
+                view_mode: request.view_mode,
+                items: result.items,
+                warning: result.warning,
+                diagnostics: Vec::new(),
+                source_revisions: Vec::new(),
+            });
+        }
+
+        if matches!(request.resource_kind, ResourceKind::Instruction) {
+            let instruction_listing_service = InstructionListingService::new();
+            let result = instruction_listing_service.list(
+                client,
+                request.project_root.as_deref(),
+                request.enabled,
+                request.scope_filter.as_deref(),
+            );
+
+            return Ok(ListResourcesResponse {
+                client: Some(client),
+                resource_kind: request.resource_kind,
+                project_root: request.project_root,
+                view_mode: request.view_mode,
+                items: result.items,
+                warning: result.warning,
+                diagnostics: Vec::new(),
+                source_revisions: result.source_revisions,
+            });
+        }
+
+        if matches!(request.resource_kind, ResourceKind::Setting) {
+            let setting_listing_service = SettingListingService::new();
+            let result = setting_listing_service.list(
+                client,
+                request.project_root.as_deref(),
+                request.enabled,
+                request.view_mode,
+                request.scope_filter.as_deref(),
+            );
+
+            return Ok(ListResourcesResponse {
+                client: Some(client),
+                resource_kind: request.resource_kind,
+                project_root: request.project_root,
+                view_mode: request.view_mode,
+                items: result.items,
+                warning: result.warning,
+                diagnostics: Vec::new(),
+                source_revisions: result.source_revisions,
             });
         }
 
@@ -113,6 +209,8 @@ impl<'a> AdapterService<'a> {
             view_mode: request.view_mode,
             items: result.items,
             warning: result.warning,
+            diagnostics: Vec::new(),
+            source_revisions: Vec::new(),
         })
     }
 
@@ -136,7 +234,9 @@ impl<'a> AdapterService<'a> {
             let new_content = file_mutation_payload.content.as_bytes();
             let mutator = SafeFileMutator::new();
 
-            let outcome = if file_mutation_payload.fail_after_write {
+            let outcome = if let Some(expected_revision) = request.expected_revision.as_deref() {
+                mutator.replace_file_if_unchanged(target_path, new_content, Some(expected_revision))
+            } else if file_mutation_payload.fail_after_write {
                 let hooks = MutationTestHooks {
                     fail_after_backup: false,
                     fail_after_write: true,
@@ -146,12 +246,7 @@ impl<'a> AdapterService<'a> {
             } else {
                 mutator.replace_file(target_path, new_content)
             }
-            .map_err(|failure| {
-                CommandError::internal(format!(
-                    "[stage={:?}] {} (rollback_succeeded={})",
-                    failure.stage, failure.message, failure.rollback_succeeded
-                ))
-            })?;
+            .map_err(|failure| CommandError::from_mutation_failure(&failure, target_path))?;
 
             let mut message = format!(
                 "Applied safe mutation to '{}'.",
@@ -168,18 +263,23 @@ impl<'a> AdapterService<'a> {
                 message,
                 source_path: Some(file_mutation_payload.target_path),
                 target_source_id: request.target_source_id.clone(),
+                warning: None,
             });
         }
 
         if matches!(request.resource_kind, ResourceKind::Mcp) {
-            let mutation_service = McpMutationService::new(self.detector_registry);
-            let outcome = mutation_service.mutate(
+            let mutation_service = McpMutationService::new(
+                self.detector_registry,
+                ParkedServerStore::default_location(),
+            );
+            let outcome = mutation_service.mutate_at_revision(
                 request.client,
                 request.action,
                 target_id,
                 project_root.as_deref(),
                 request.target_source_id.as_deref(),
                 request.payload.as_ref(),
+                request.expected_revision.as_deref(),
             )?;
 
             return Ok(MutateResourceResponse {
@@ -189,9 +289,81 @@ impl<'a> AdapterService<'a> {
                 message: outcome.message,
                 source_path: Some(outcome.source_path),
                 target_source_id: Some(outcome.target_source_id),
+                warning: outcome.warning,
             });
         }
 
+        if matches!(request.resource_kind, ResourceKind::Setting) {
+            let outcome = SettingMutationService::new().mutate(
+                request.client,
+                request.action,
+                target_id,
+                project_root.as_deref(),
+                request.target_source_id.as_deref(),
+                request.expected_revision.as_deref(),
+            )?;
+
+            return Ok(MutateResourceResponse {
+                accepted: true,
+                action: request.action,
+                target_id: target_id.to_string(),
+                message: outcome.message,
+                source_path: Some(outcome.source_path),
+                target_source_id: Some(outcome.target_source_id),
+                warning: None,
+            });
+        }
+
+        if matches!(request.resource_kind, ResourceKind::Command) {
+            let outcome = CommandMutationService::new().mutate(
+                request.client,
+                request.action,
+                target_id,
+                project_root.as_deref(),
+                request.target_source_id.as_deref(),
+                request.payload.as_ref(),
+                request.expected_revision.as_deref(),
+            )?;
+
+            return Ok(MutateResourceResponse {
+                accepted: true,
+                action: request.action,
+                target_id: target_id.to_string(),
+                message: outcome.message,
+                source_path: Some(outcome.source_path),
+                target_source_id: Some(outcome.target_source_id),
+                warning: None,
+            });
+        }
+
+        if matches!(request.resource_kind, ResourceKind::Instruction) {
+            let outcome = InstructionMutationService::new().mutate(
+                request.client,
+                request.action,
+                target_id,
+                project_root.as_deref(),
+                request.target_source_id.as_deref(),
+                request.payload.as_ref(),
+                request.expected_revision.as_deref(),
+            )?;
+
+            return Ok(MutateResourceResponse {
+                accepted: true,
+                action: request.action,
+                target_id: target_id.to_string(),
+                message: outcome.message,
+                source_path: Some(outcome.source_path),
+                target_source_id: Some(outcome.target_source_id),
+                warning: None,
+            });
+        }
+
+        if request.expected_revision.is_some() {
+            return Err(CommandError::validation(
+                "expected_revision is supported only for MCP, setting, command, instruction and file mutations.",
+            ));
+        }
+
         if matches!(request.resource_kind, ResourceKind::Skill) {
             let mutation_service = SkillMutationService::new();
             let outcome = mutation_service.mutate(
@@ -208,6 +380,7 @@ impl<'a> AdapterService<'a> {
                 message: outcome.message,
                 source_path: Some(outcome.source_path),
                 target_source_id: request.target_source_id.clone(),
+                warning: None,
             });
         }
 
@@ -233,6 +406,7 @@ impl<'a> AdapterService<'a> {
             message: result.message,
             source_path: None,
             target_source_id: request.target_source_id.clone(),
+            warning: None,
         })
     }
 
@@ -240,9 +414,12 @@ impl<'a> AdapterService<'a> {
         &self,
         request: ReplicateResourceRequest,
     ) -> Result<ReplicateResourceResponse, CommandError> {
-        if !matches!(request.resource_kind, ResourceKind::Mcp) {
+        if !matches!(
+            request.resource_kind,
+            ResourceKind::Mcp | ResourceKind::Command | ResourceKind::Instruction
+        ) {
             return Err(CommandError::not_implemented(
-                "Replication is currently supported only for MCP resources.",
+                "Replication is currently supported only for MCP, command and instruction resources.",
             ));
         }
 
@@ -269,26 +446,71 @@ impl<'a> AdapterService<'a> {
             .destination_target_id
             .as_deref()
             .map(str::trim)
-            .filter(|value| !value.is_empty())
-            .map(str::to_string)
-            .unwrap_or_else(|| source_target_id.to_string());
+            .filter(|value| !value.is_empty());
         let destination_source_id = request
             .destination_source_id
             .as_deref()
             .map(str::trim)
             .filter(|value| !value.is_empty());
 
-        let outcome = McpReplicationService::new(self.detector_registry).replicate(
-            request.source_client,
-            source_target_id,
-            source_source_id,
-            source_project_root.as_deref(),
-            request.destination_client,
-            Some(destination_target_id.as_str()),
-            destination_source_id,
-            destination_project_root.as_deref(),
-            request.overwrite,
-        )?;
+        let outcome = if matches!(request.resource_kind, ResourceKind::Instruction) {
+            let outcome = InstructionReplicationService::new().replicate(
+                request.source_client,
+                source_target_id,
+                source_source_id,
+                source_project_root.as_deref(),
+                request.destination_client,
+                destination_target_id,
+                destination_source_id,
+                destination_project_root.as_deref(),
+                request.overwrite,
+            )?;
+            (
+                outcome.destination_target_id,
+                outcome.destination_source_id,
+                outcome.message,
+            )
+        } else if matches!(request.resource_kind, ResourceKind::Command) {
+            let outcome = CommandReplicationService::new().replicate(
+                request.source_client,
+                source_target_id,
+                source_source_id,
+                source_project_root.as_deref(),
+                request.destination_client,
+                destination_target_id,
+                destination_source_id,
+                destination_project_root.as_deref(),
+                request.overwrite,
+            )?;
+            (
+                outcome.destination_target_id,
+                outcome.destination_source_id,
+                outcome.message,
+            )
+        } else {
+            let outcome = McpReplicationService::new(
+                self.detector_registry,
+                ParkedServerStore::default_location(),
+            )
+            .replicate(
+                request.source_client,
+                source_target_id,
+                source_source_id,
+                source_project_root.as_deref(),
+                request.destination_client,
+                destination_target_id,
+                destination_source_id,
+                destination_project_root.as_deref(),
+                request.overwrite,
+            )?;
+            (
+                outcome.destination_target_id,
+                outcome.destination_source_id,
+                outcome.message,
+            )
+        };
+
+        let (replicated_target_id, replicated_source_id, message) = outcome;
 
         Ok(ReplicateResourceResponse {
             accepted: true,
@@ -296,11 +518,200 @@ impl<'a> AdapterService<'a> {
             source_client: request.source_client,
             source_target_id: source_target_id.to_string(),
             destination_client: request.destination_client,
-            destination_target_id: outcome.destination_target_id,
-            destination_source_id: outcome.destination_source_id,
-            message: outcome.message,
+            destination_target_id: replicated_target_id,
+            destination_source_id: replicated_source_id,
+            message,
+        })
+    }
+
+    pub fn diagnose_config(
+        &self,
+        request: DiagnoseConfigRequest,
+    ) -> Result<DiagnoseConfigResponse, CommandError> {
+        let project_root =
+            ProjectContextResolver::new().resolve(request.project_root.as_deref())?;
+        let target_source_id = request
+            .target_source_id
+            .as_deref()
+            .map(str::trim)
+            .filter(|value| !value.is_empty());
+
+        let diagnosis = McpConfigRepairService::new(self.detector_registry).diagnose(
+            request.client,
+            project_root.as_deref(),
+            target_source_id,
+        )?;
+
+        Ok(DiagnoseConfigResponse {
+            client: request.client,
+            project_root,
+            checked_sources: diagnosis.checked_sources,
+            proposals: diagnosis.proposals,
         })
     }
+
+    pub fn repair_config(
+        &self,
+        request: RepairConfigRequest,
+    ) -> Result<RepairConfigResponse, CommandError> {
+        let target_source_id = request.target_source_id.trim();
+        if target_source_id.is_empty() {
+            return Err(CommandError::validation(
+                "target_source_id must not be empty for repair commands.",
+            ));
+        }
+
+        let project_root =
+            ProjectContextResolver::new().resolve(request.project_root.as_deref())?;
+        let result = McpConfigRepairService::new(self.detector_registry).repair(
+            request.client,
+            project_root.as_deref(),
+            target_source_id,
+            &request.accepted_diff,
+        )?;
+
+        let mut message = format!(
+            "Repaired {} issue(s) in '{}'.",
+            result.repaired_issues.len(),
+            result.source_path
+        );
+        if let Some(backup_path) = result.backup_path.as_deref() {
+            message.push_str(&format!(" Backup: {}.", backup_path));
+        }
+
+        Ok(RepairConfigResponse {
+            client: request.client,
+            source_id: result.source_id,
+            source_path: result.source_path,
+            repaired_issues: result.repaired_issues,
+            backup_path: result.backup_path,
+            message,
+        })
+    }
+
+    pub fn externalize_secrets(
+        &self,
+        request: ExternalizeSecretsRequest,
+    ) -> Result<ExternalizeSecretsResponse, CommandError> {
+        let project_root =
+            ProjectContextResolver::new().resolve(request.project_root.as_deref())?;
+        let target_source_id = request
+            .target_source_id
+            .as_deref()
+            .map(str::trim)
+            .filter(|value| !value.is_empty());
+
+        let result = McpSecretExternalizationService::new(self.detector_registry).externalize(
+            request.client,
+            project_root.as_deref(),
+            target_source_id,
+            request.dry_run,
+        )?;
+
+        let mut message = if result.secrets.is_empty() {
+            format!(
+                "No inline MCP secrets found for '{}'.",
+                request.client.as_str()
+            )
+        } else if request.dry_run {
+            format!(
+                "Found {} inline secret(s) in {} source(s); nothing was changed.",
+                result.secrets.len(),
+                result.updated_sources.len()
+            )
+        } else {
+            format!(
+                "Moved {} secret(s) from {} source(s) into '{}'.",
+                result.secrets.len(),
+                result.updated_sources.len(),
+                result.store_location
+            )
+        };
+        if result
+            .secrets
+            .iter()
+            .any(|secret| secret.reference == SecretReferenceKind::EnvInterpolation)
+        {
+            message.push_str(
+                " Secrets referenced through env interpolation are read from the environment; start the client with `ai-manager-cli run-with-secrets --all -- <client>`.",
+            );
+        }
+        for warning in &result.warnings {
+            message.push(' ');
+            message.push_str(warning);
+        }
+
+        Ok(ExternalizeSecretsResponse {
+            client: request.client,
+            project_root,
+            dry_run: request.dry_run,
+            secrets: result.secrets,
+            updated_sources: result.updated_sources,
+            store_location: result.store_location,
+            message,
+        })
+    }
+
+    pub fn security_audit(
+        &self,
+        request: SecurityAuditRequest,
+    ) -> Result<SecurityAuditResponse, CommandError> {
+        let project_root =
+            ProjectContextResolver::new().resolve(request.project_root.as_deref())?;
+        let clients = match request.client {
+            Some(client) => vec![client],
+            None => vec![
+                ClientKind::ClaudeCode,
+                ClientKind::Codex,
+                ClientKind::Cursor,
+            ],
+        };
+
+        let audit = SecurityAuditService::new(self.detector_registry)
+            .audit(&clients, project_root.as_deref());
+
+        let message = if audit.findings.is_empty() {
+            format!(
+                "Scanned {} file(s); no likely secrets found.",
+                audit.scanned_files
+            )
+        } else if audit.high_risk_files.is_empty() {
+            format!(
+                "Scanned {} file(s); found {} likely secret(s).",
+                audit.scanned_files,
+                audit.findings.len()
+            )
+        } else {
+            format!(
+                "Scanned {} file(s); found {} likely secret(s), {} file(s) committed to Git.",
+                audit.scanned_files,
+                audit.findings.len(),
+                audit.high_risk_files.len()
+            )
+        };
+
+        Ok(SecurityAuditResponse {
+            project_root,
+            scanned_files: audit.scanned_files,
+            findings: audit.findings,
+            high_risk_files: audit.high_risk_files,
+            warnings: audit.warnings,
+            message,
+        })
+    }
+
+    #[cfg(feature = "desktop")]
+    pub fn watch_targets(
+        &self,
+        request: &WatchSourcesRequest,
+    ) -> Result<(Option<String>, Vec<WatchTarget>), CommandError> {
+        let project_root =
+            ProjectContextResolver::new().resolve(request.project_root.as_deref())?;
+        let targets =
+            SourceWatchService::new(self.detector_registry).watch_targets(project_root.as_deref());
+
+        Ok((project_root, targets))
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -375,7 +786,7 @@ mod tests {
     #[test]
     fn detect_clients_uses_every_registered_detector() {
         let adapter_registry = AdapterRegistry::with_default_adapters();
-        let detector_registry = DetectorRegistry::with_default_detectors();
+        let detector_registry = DetectorRegistry::with_installed_version("1.0.0");
         let service = AdapterService::new(&adapter_registry, &detector_registry);
 
         let response = service.detect_clients(DetectClientsRequest {
@@ -398,7 +809,7 @@ mod tests {
     #[test]
     fn list_resources_routes_by_requested_client() {
         let adapter_registry = AdapterRegistry::with_default_adapters();
-        let detector_registry = DetectorRegistry::with_default_detectors();
+        let detector_registry = DetectorRegistry::with_installed_version("1.0.0");
         let service = AdapterService::new(&adapter_registry, &detector_registry);
         let temp_dir = std::env::temp_dir().join(format!(
             "ai-manager-list-project-context-{}",
@@ -437,7 +848,7 @@ mod tests {
     #[test]
     fn list_resources_for_skill_requires_client_filter() {
         let adapter_registry = AdapterRegistry::with_default_adapters();
-        let detector_registry = DetectorRegistry::with_default_detectors();
+        let detector_registry = DetectorRegistry::with_installed_version("1.0.0");
         let service = AdapterService::new(&adapter_registry, &detector_registry);
 
         let error = service
@@ -457,7 +868,7 @@ mod tests {
     #[test]
     fn list_resources_routes_subagent_requests_to_native_claude_listing() {
         let adapter_registry = AdapterRegistry::with_default_adapters();
-        let detector_registry = DetectorRegistry::with_default_detectors();
+        let detector_registry = DetectorRegistry::with_installed_version("1.0.0");
         let service = AdapterService::new(&adapter_registry, &detector_registry);
         let project_root = std::env::temp_dir().join(format!(
             "ai-manager-subagent-project-context-{}",
@@ -501,7 +912,7 @@ description: Reviews pull requests.
     #[test]
     fn mutate_resource_validates_target_id_before_adapter_call() {
         let adapter_registry = AdapterRegistry::with_default_adapters();
-        let detector_registry = DetectorRegistry::with_default_detectors();
+        let detector_registry = DetectorRegistry::with_installed_version("1.0.0");
         let service = AdapterService::new(&adapter_registry, &detector_registry);
 
         let error = service
@@ -513,6 +924,7 @@ description: Reviews pull requests.
                 project_root: None,
                 target_source_id: None,
                 payload: None,
+                expected_revision: None,
             })
             .expect_err("blank target_id should fail validation");
 
@@ -522,7 +934,7 @@ description: Reviews pull requests.
     #[test]
     fn list_resources_rejects_missing_project_root() {
         let adapter_registry = AdapterRegistry::with_default_adapters();
-        let detector_registry = DetectorRegistry::with_default_detectors();
+        let detector_registry = DetectorRegistry::with_installed_version("1.0.0");
         let service = AdapterService::new(&adapter_registry, &detector_registry);
         let missing_root = std::env::temp_dir().join(format!(
             "ai-manager-missing-project-root-{}",
@@ -546,7 +958,7 @@ description: Reviews pull requests.
     #[test]
     fn mutate_resource_rejects_missing_project_root() {
         let adapter_registry = AdapterRegistry::with_default_adapters();
-        let detector_registry = DetectorRegistry::with_default_detectors();
+        let detector_registry = DetectorRegistry::with_installed_version("1.0.0");
         let service = AdapterService::new(&adapter_registry, &detector_registry);
         let missing_root = std::env::temp_dir().join(format!(
             "ai-manager-missing-project-root-mutate-{}",
@@ -562,6 +974,7 @@ description: Reviews pull requests.
                 project_root: Some(missing_root.display().to_string()),
                 target_source_id: None,
                 payload: None,
+                expected_revision: None,
             })
             .expect_err("missing project root should fail validation");
 
@@ -571,7 +984,7 @@ description: Reviews pull requests.
     #[test]
     fn mutate_resource_applies_safe_file_mutation_when_payload_matches_contract() {
         let adapter_registry = AdapterRegistry::with_default_adapters();
-        let detector_registry = DetectorRegistry::with_default_detectors();
+        let detector_registry = DetectorRegistry::with_installed_version("1.0.0");
         let service = AdapterService::new(&adapter_registry, &detector_registry);
 
         let temp_dir = std::env::temp_dir().join(format!(
@@ -594,6 +1007,7 @@ description: Reviews pull requests.
                     "target_path": target.display().to_string(),
                     "content": "{\"before\":false}"
                 })),
+                expected_revision: None,
             })
             .expect("safe file mutation payload should succeed");
 
@@ -608,7 +1022,7 @@ description: Reviews pull requests.
     #[test]
     fn mutate_resource_rolls_back_file_when_post_write_failure_is_requested() {
         let adapter_registry = AdapterRegistry::with_default_adapters();
-        let detector_registry = DetectorRegistry::with_default_detectors();
+        let detector_registry = DetectorRegistry::with_installed_version("1.0.0");
         let service = AdapterService::new(&adapter_registry, &detector_registry);
 
         let temp_dir = std::env::temp_dir().join(format!(
@@ -632,6 +1046,7 @@ description: Reviews pull requests.
                     "content": "{\"before\":false}",
                     "fail_after_write": true
                 })),
+                expected_revision: None,
             })
             .expect_err("post-write failure should surface command error");
 
@@ -645,7 +1060,7 @@ description: Reviews pull requests.
     #[test]
     fn mutate_resource_adds_mcp_entry_with_actionable_metadata() {
         let adapter_registry = AdapterRegistry::with_default_adapters();
-        let detector_registry = DetectorRegistry::with_default_detectors();
+        let detector_registry = DetectorRegistry::with_installed_version("1.0.0");
         let service = AdapterService::new(&adapter_registry, &detector_registry);
 
         let temp_dir =
@@ -670,6 +1085,7 @@ description: Reviews pull requests.
                     "transport": { "command": "npx", "args": ["-y", "server"] },
                     "enabled": true
                 })),
+                expected_revision: None,
             })
             .expect("MCP add should succeed");
 
@@ -695,7 +1111,7 @@ description: Reviews pull requests.
     #[test]
     fn mutate_resource_adds_skill_entry_with_actionable_metadata() {
         let adapter_registry = AdapterRegistry::with_default_adapters();
-        let detector_registry = DetectorRegistry::with_default_detectors();
+        let detector_registry = DetectorRegistry::with_installed_version("1.0.0");
         let service = AdapterService::new(&adapter_registry, &detector_registry);
 
         let temp_dir = std::env::temp_dir().join(format!(
@@ -716,6 +1132,7 @@ description: Reviews pull requests.
                     "skills_dir": temp_dir.display().to_string(),
                     "manifest": "# Python Refactor\n\nRefactor Python code safely.\n"
                 })),
+                expected_revision: None,
             })
             .expect("Skill add should succeed");
 
@@ -736,7 +1153,7 @@ description: Reviews pull requests.
     #[test]
     fn mutate_resource_rejects_subagent_mutations_until_supported() {
         let adapter_registry = AdapterRegistry::with_default_adapters();
-        let detector_registry = DetectorRegistry::with_default_detectors();
+        let detector_registry = DetectorRegistry::with_installed_version("1.0.0");
         let service = AdapterService::new(&adapter_registry, &detector_registry);
 
         let error = service
@@ -748,6 +1165,7 @@ description: Reviews pull requests.
                 project_root: None,
                 target_source_id: None,
                 payload: None,
+                expected_revision: None,
             })
             .expect_err("subagent mutations should remain unimplemented");
 
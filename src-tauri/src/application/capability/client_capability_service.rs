@@ -1,7 +1,80 @@
-use crate::domain::{
-    ClientKind, ClientProfile, ResourceKind, ResourceSourceScope, profile_for_client,
+use crate::{
+    domain::{
+        ClientKind, ClientProfile, ClientVersion, ResourceKind, ResourceSourceScope,
+        profile_for_client,
+    },
+    interface::contracts::command::CommandError,
 };
 
+/// Config fields whose support depends on the installed client version.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClientFeature {
+    McpRemoteTransport,
+    McpProjectScope,
+    McpEnabledToggle,
+}
+
+impl ClientFeature {
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::McpRemoteTransport => "url-based MCP servers",
+            Self::McpProjectScope => "project-scoped MCP config",
+            Self::McpEnabledToggle => "the MCP `enabled` field",
+        }
+    }
+}
+
+struct VersionGate {
+    client: ClientKind,
+    feature: ClientFeature,
+    minimum: ClientVersion,
+}
+
+const VERSION_GATES: &[VersionGate] = &[
+    // Claude Code CHANGELOG.md, 0.2.54: SSE MCP servers (`--transport sse`).
+    VersionGate {
+        client: ClientKind::ClaudeCode,
+        feature: ClientFeature::McpRemoteTransport,
+        minimum: ClientVersion::new(0, 2, 54),
+    },
+    // Claude Code CHANGELOG.md, 0.2.50: `project` MCP scope backed by `.mcp.json`.
+    VersionGate {
+        client: ClientKind::ClaudeCode,
+        feature: ClientFeature::McpProjectScope,
+        minimum: ClientVersion::new(0, 2, 50),
+    },
+    // openai/codex release rust-v0.46.0: streamable HTTP (`url`) MCP servers.
+    VersionGate {
+        client: ClientKind::Codex,
+        feature: ClientFeature::McpRemoteTransport,
+        minimum: ClientVersion::new(0, 46, 0),
+    },
+    // openai/codex release rust-v0.31.0: `enabled` on `[mcp_servers.*]` entries.
+    VersionGate {
+        client: ClientKind::Codex,
+        feature: ClientFeature::McpEnabledToggle,
+        minimum: ClientVersion::new(0, 31, 0),
+    },
+    // Cursor changelog 0.45: MCP servers, including SSE transport.
+    VersionGate {
+        client: ClientKind::Cursor,
+        feature: ClientFeature::McpRemoteTransport,
+        minimum: ClientVersion::new(0, 45, 0),
+    },
+    // Cursor changelog 0.45: project MCP config in `.cursor/mcp.json`.
+    VersionGate {
+        client: ClientKind::Cursor,
+        feature: ClientFeature::McpProjectScope,
+        minimum: ClientVersion::new(0, 45, 0),
+    },
+    // Cursor changelog 0.47: per-server MCP enable toggle persisted as `enabled`.
+    VersionGate {
+        client: ClientKind::Cursor,
+        feature: ClientFeature::McpEnabledToggle,
+        minimum: ClientVersion::new(0, 47, 0),
+    },
+];
+
 pub struct ClientCapabilityService;
 
 impl ClientCapabilityService {
@@ -54,6 +127,54 @@ impl ClientCapabilityService {
             .capabilities
             .supports_destination(resource_kind, scope)
     }
+
+    /// Minimum client version that reads `feature`, or `None` when every version does.
+    pub fn minimum_version_for(
+        &self,
+        client: ClientKind,
+        feature: ClientFeature,
+    ) -> Option<ClientVersion> {
+        VERSION_GATES
+            .iter()
+            .find(|gate| gate.client == client && gate.feature == feature)
+            .map(|gate| gate.minimum)
+    }
+
+    /// An unknown installed version is treated as current so undetected clients stay writable.
+    pub fn supports_feature(
+        &self,
+        client: ClientKind,
+        feature: ClientFeature,
+        installed: Option<ClientVersion>,
+    ) -> bool {
+        match (self.minimum_version_for(client, feature), installed) {
+            (Some(minimum), Some(installed)) => installed >= minimum,
+            _ => true,
+        }
+    }
+
+    pub fn ensure_feature(
+        &self,
+        client: ClientKind,
+        feature: ClientFeature,
+        installed: Option<ClientVersion>,
+    ) -> Result<(), CommandError> {
+        if self.supports_feature(client, feature, installed) {
+            return Ok(());
+        }
+
+        Err(CommandError::validation(format!(
+            "Installed {} {} cannot read {}; version {} or newer is required.",
+            self.profile(client).display_name,
+            installed
+                .map(|version| version.to_string())
+                .unwrap_or_default(),
+            feature.label(),
+            self.minimum_version_for(client, feature)
+                .map(|version| version.to_string())
+                .unwrap_or_default(),
+        )))
+    }
 }
 
 impl Default for ClientCapabilityService {
@@ -64,8 +185,8 @@ impl Default for ClientCapabilityService {
 
 #[cfg(test)]
 mod tests {
-    use super::ClientCapabilityService;
-    use crate::domain::{ClientKind, ResourceKind, ResourceSourceScope};
+    use super::{ClientCapabilityService, ClientFeature, VERSION_GATES};
+    use crate::domain::{ClientKind, ClientVersion, ResourceKind, ResourceSourceScope};
 
     #[test]
     fn service_exposes_distinct_mcp_scope_support_by_client() {
@@ -125,4 +246,76 @@ mod tests {
             ResourceSourceScope::ProjectShared
         ));
     }
+
+    #[test]
+    fn version_gates_refuse_features_older_clients_cannot_read() {
+        let service = ClientCapabilityService::new();
+
+        assert!(!service.supports_feature(
+            ClientKind::Codex,
+            ClientFeature::McpRemoteTransport,
+            Some(ClientVersion::new(0, 45, 2))
+        ));
+        assert!(service.supports_feature(
+            ClientKind::Codex,
+            ClientFeature::McpRemoteTransport,
+            Some(ClientVersion::new(0, 46, 0))
+        ));
+        assert!(service.supports_feature(
+            ClientKind::Codex,
+            ClientFeature::McpRemoteTransport,
+            None
+        ));
+        assert!(service.supports_feature(
+            ClientKind::ClaudeCode,
+            ClientFeature::McpEnabledToggle,
+            Some(ClientVersion::new(0, 1, 0))
+        ));
+
+        let error = service
+            .ensure_feature(
+                ClientKind::Cursor,
+                ClientFeature::McpProjectScope,
+                Some(ClientVersion::new(0, 44, 1)),
+            )
+            .expect_err("old Cursor should not get project MCP config");
+        assert!(error.message.contains("0.44.1"));
+        assert!(error.message.contains("0.45.0 or newer"));
+    }
+
+    #[test]
+    fn version_gates_admit_the_minimum_and_refuse_the_release_before_it() {
+        let service = ClientCapabilityService::new();
+
+        assert!(service.supports_feature(
+            ClientKind::ClaudeCode,
+            ClientFeature::McpRemoteTransport,
+            Some(ClientVersion::new(0, 2, 54))
+        ));
+        assert!(!service.supports_feature(
+            ClientKind::ClaudeCode,
+            ClientFeature::McpRemoteTransport,
+            Some(ClientVersion::new(0, 2, 53))
+        ));
+
+        for gate in VERSION_GATES {
+            let minimum = gate.minimum;
+            let previous = match minimum.patch {
+                0 => ClientVersion::new(minimum.major, minimum.minor - 1, u64::MAX),
+                patch => ClientVersion::new(minimum.major, minimum.minor, patch - 1),
+            };
+            assert!(
+                service.supports_feature(gate.client, gate.feature, Some(minimum)),
+                "{:?} {:?}",
+                gate.client,
+                gate.feature
+            );
+            assert!(
+                !service.supports_feature(gate.client, gate.feature, Some(previous)),
+                "{:?} {:?}",
+                gate.client,
+                gate.feature
+            );
+        }
+    }
 }
@@ -0,0 +1,263 @@
+use std::{fs, path::Path};
+
+use crate::{
+    application::subagent::listing_service::apply_effective_precedence,
+    domain::{ClientKind, ResourceSourceMetadata, ResourceSourceScope},
+    interface::contracts::list::{ResourceRecord, ResourceViewMode},
+};
+
+use super::{
+    metadata_parser::parse_command_metadata,
+    source_catalog_service::{CommandSourceCatalogService, CommandSourceDescriptor},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommandListResult {
+    pub items: Vec<ResourceRecord>,
+    pub warning: Option<String>,
+}
+
+pub struct CommandListingService;
+
+impl CommandListingService {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn list(
+        &self,
+        client: ClientKind,
+        project_root: Option<&str>,
+        enabled_filter: Option<bool>,
+        view_mode: ResourceViewMode,
+        scope_filter: Option<&[ResourceSourceScope]>,
+    ) -> CommandListResult {
+        let descriptors = CommandSourceCatalogService::new().list_sources(client, project_root);
+        collect_from_descriptors(descriptors, enabled_filter, view_mode, scope_filter)
+    }
+}
+
+impl Default for CommandListingService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn collect_from_descriptors<I>(
+    descriptors: I,
+    enabled_filter: Option<bool>,
+    view_mode: ResourceViewMode,
+    scope_filter: Option<&[ResourceSourceScope]>,
+) -> CommandListResult
+where
+    I: IntoIterator<Item = CommandSourceDescriptor>,
+{
+    let mut items: Vec<ResourceRecord> = Vec::new();
+    let mut warnings: Vec<String> = Vec::new();
+
+    for descriptor in descriptors {
+        if scope_filter.is_some_and(|scopes| !scopes.contains(&descriptor.source_scope)) {
+            continue;
+        }
+        if enabled_filter == Some(false) || !descriptor.directory_path.exists() {
+            continue;
+        }
+
+        collect_commands_from_directory(&descriptor, &mut items, &mut warnings);
+    }
+
+    apply_effective_precedence(&mut items);
+    if !matches!(view_mode, ResourceViewMode::AllSources) {
+        items.retain(|item| item.is_effective);
+    }
+
+    items.sort_by(|left, right| {
+        (
+            left.client.as_str(),
+            left.logical_id.as_str(),
+            left.id.as_str(),
+        )
+            .cmp(&(
+                right.client.as_str(),
+                right.logical_id.as_str(),
+                right.id.as_str(),
+            ))
+    });
+
+    CommandListResult {
+        items,
+        warning: (!warnings.is_empty()).then(|| warnings.join(" | ")),
+    }
+}
+
+fn collect_commands_from_directory(
+    descriptor: &CommandSourceDescriptor,
+    items: &mut Vec<ResourceRecord>,
+    warnings: &mut Vec<String>,
+) {
+    let mut candidate_paths = match fs::read_dir(&descriptor.directory_path) {
+        Ok(entries) => entries
+            .filter_map(Result::ok)
+            .map(|entry| entry.path())
+            .filter(|path| is_markdown_file(path))
+            .collect::<Vec<_>>(),
+        Err(error) => {
+            warnings.push(format!(
+                "[{}:COMMAND_DIR_READ_ERROR] failed to read '{}': {}",
+                descriptor.client.as_str(),
+                descriptor.directory_path.display(),
+                error
+            ));
+            return;
+        }
+    };
+
+    candidate_paths.sort_unstable_by(|left, right| left.as_os_str().cmp(right.as_os_str()));
+
+    for candidate_path in candidate_paths {
+        let manifest_source = match fs::read_to_string(&candidate_path) {
+            Ok(source) => source,
+            Err(error) => {
+                warnings.push(format!(
+                    "[{}:COMMAND_READ_ERROR] failed to read '{}': {}",
+                    descriptor.client.as_str(),
+                    candidate_path.display(),
+                    error
+                ));
+                continue;
+            }
+        };
+        let Some(logical_id) = candidate_path
+            .file_stem()
+            .map(|value| value.to_string_lossy().to_string())
+        else {
+            continue;
+        };
+        let metadata = parse_command_metadata(&manifest_source);
+
+        items.push(
+            ResourceRecord {
+                id: format!(
+                    "{}::command::{}::{}",
+                    descriptor.client.as_str(),
+                    descriptor.source_id,
+                    logical_id
+                ),
+                display_name: format!("/{}", logical_id),
+                logical_id,
+                client: descriptor.client,
+                enabled: true,
+                transport_kind: None,
+                transport_command: None,
+                transport_args: None,
+                transport_url: None,
+                source_path: Some(candidate_path.display().to_string()),
+                source_id: String::new(),
+                source_scope: descriptor.source_scope,
+                source_label: String::new(),
+                is_effective: true,
+                shadowed_by: None,
+                description: metadata.description,
+                install_kind: Some("file".to_string()),
+                manifest_content: Some(manifest_source),
+                approval: None,
+            }
+            .with_source_metadata(ResourceSourceMetadata {
+                source_id: descriptor.source_id.clone(),
+                source_scope: descriptor.source_scope,
+                source_label: descriptor.source_label.clone(),
+                is_effective: true,
+                shadowed_by: None,
+            }),
+        );
+    }
+}
+
+fn is_markdown_file(path: &Path) -> bool {
+    path.is_file()
+        && path
+            .extension()
+            .is_some_and(|extension| extension.eq_ignore_ascii_case("md"))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, path::Path};
+
+    use super::collect_from_descriptors;
+    use crate::{
+        domain::{ClientKind, ResourceSourceScope},
+        interface::contracts::list::ResourceViewMode,
+    };
+
+    use super::super::source_catalog_service::CommandSourceDescriptor;
+
+    fn descriptor(scope: ResourceSourceScope, directory: &Path) -> CommandSourceDescriptor {
+        CommandSourceDescriptor {
+            client: ClientKind::ClaudeCode,
+            source_id: format!(
+                "command::claude_code::{}::{}",
+                scope.as_str(),
+                directory.display()
+            ),
+            source_scope: scope,
+            source_label: scope.as_str().to_string(),
+            directory_path: directory.to_path_buf(),
+            project_root: None,
+        }
+    }
+
+    #[test]
+    fn project_command_shadows_personal_command_with_the_same_name() {
+        let temp_root =
+            std::env::temp_dir().join(format!("ai-manager-command-listing-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&temp_root);
+        let user_dir = temp_root.join("user-commands");
+        let project_dir = temp_root.join("project-commands");
+        fs::create_dir_all(&user_dir).expect("should create user dir");
+        fs::create_dir_all(&project_dir).expect("should create project dir");
+        fs::write(
+            user_dir.join("commit.md"),
+            "Commit everything: $ARGUMENTS\n",
+        )
+        .expect("should create user command");
+        fs::write(user_dir.join("notes.txt"), "not a command").expect("should create note");
+        fs::write(
+            project_dir.join("commit.md"),
+            "---\ndescription: Commit with the team template\n---\nUse $1 as the scope.\n",
+        )
+        .expect("should create project command");
+
+        let all_sources = collect_from_descriptors(
+            vec![
+                descriptor(ResourceSourceScope::User, &user_dir),
+                descriptor(ResourceSourceScope::ProjectShared, &project_dir),
+            ],
+            None,
+            ResourceViewMode::AllSources,
+            None,
+        );
+        let effective = collect_from_descriptors(
+            vec![
+                descriptor(ResourceSourceScope::User, &user_dir),
+                descriptor(ResourceSourceScope::ProjectShared, &project_dir),
+            ],
+            None,
+            ResourceViewMode::Effective,
+            None,
+        );
+        let _ = fs::remove_dir_all(&temp_root);
+
+        assert_eq!(all_sources.items.len(), 2);
+        assert_eq!(effective.items.len(), 1);
+        assert_eq!(effective.items[0].display_name, "/commit");
+        assert_eq!(
+            effective.items[0].source_scope,
+            ResourceSourceScope::ProjectShared
+        );
+        assert_eq!(
+            effective.items[0].description.as_deref(),
+            Some("Commit with the team template")
+        );
+    }
+}
@@ -0,0 +1,55 @@
+use crate::application::subagent::metadata_parser::{parse_frontmatter_scalar, split_frontmatter};
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct CommandMetadata {
+    pub description: Option<String>,
+    pub argument_hint: Option<String>,
+}
+
+/// Like the clients themselves, falls back to the first prompt line when the frontmatter has
+/// no `description`.
+pub fn parse_command_metadata(source: &str) -> CommandMetadata {
+    let (frontmatter, body) = split_frontmatter(source);
+
+    CommandMetadata {
+        description: frontmatter
+            .as_deref()
+            .and_then(|data| parse_frontmatter_scalar(data, "description"))
+            .or_else(|| {
+                body.lines()
+                    .map(|line| line.trim().trim_start_matches('#').trim())
+                    .find(|line| !line.is_empty())
+                    .map(str::to_string)
+            }),
+        argument_hint: frontmatter
+            .as_deref()
+            .and_then(|data| parse_frontmatter_scalar(data, "argument-hint")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_command_metadata;
+
+    #[test]
+    fn metadata_reads_frontmatter_and_falls_back_to_first_prompt_line() {
+        let with_frontmatter = parse_command_metadata(
+            r#"---
+description: Create a git commit
+argument-hint: [message]
+allowed-tools: Bash(git add:*)
+---
+Commit with message: $ARGUMENTS
+"#,
+        );
+        let plain = parse_command_metadata("\n# Review the staged diff\n\nFocus on $1.\n");
+
+        assert_eq!(
+            with_frontmatter.description.as_deref(),
+            Some("Create a git commit")
+        );
+        assert_eq!(with_frontmatter.argument_hint.as_deref(), Some("[message]"));
+        assert_eq!(plain.description.as_deref(), Some("Review the staged diff"));
+        assert_eq!(plain.argument_hint, None);
+    }
+}
@@ -0,0 +1,6 @@
+pub(super) mod listing_service;
+pub(super) mod metadata_parser;
+pub(super) mod mutation_service;
+pub(super) mod placeholders;
+pub(super) mod replication_service;
+pub(super) mod source_catalog_service;
@@ -0,0 +1,286 @@
+use std::{fs, io};
+
+use crate::{
+    domain::ClientKind,
+    infra::{SafeFileMutator, content_revision},
+    interface::contracts::{command::CommandError, mutate::MutationAction},
+};
+
+use super::source_catalog_service::{CommandSourceCatalogService, CommandSourceDescriptor};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommandMutationResult {
+    pub source_path: String,
+    pub target_source_id: String,
+    pub message: String,
+}
+
+pub struct CommandMutationService;
+
+impl CommandMutationService {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Writes or deletes `<target_id>.md` in the target commands directory; without
+    /// `target_source_id` the personal directory is used.
+    #[allow(clippy::too_many_arguments)]
+    pub fn mutate(
+        &self,
+        client: ClientKind,
+        action: MutationAction,
+        target_id: &str,
+        project_root: Option<&str>,
+        target_source_id: Option<&str>,
+        payload: Option<&serde_json::Value>,
+        expected_revision: Option<&str>,
+    ) -> Result<CommandMutationResult, CommandError> {
+        if matches!(action, MutationAction::Approve | MutationAction::Deny) {
+            return Err(CommandError::validation(format!(
+                "Commands do not support the '{}' action.",
+                action.as_str()
+            )));
+        }
+        validate_command_name(target_id)?;
+
+        let descriptor = resolve_target_source(client, project_root, target_source_id)?;
+        let command_path = descriptor.directory_path.join(format!("{}.md", target_id));
+        let command_path_label = command_path.display().to_string();
+
+        let current_revision = match fs::read(&command_path) {
+            Ok(content) => Some(content_revision(&content)),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => None,
+            Err(error) => {
+                return Err(CommandError::io(
+                    format!("Failed to read command '{}': {}", command_path_label, error),
+                    error.kind(),
+                )
+                .with_path(command_path_label)
+                .with_source_id(&descriptor.source_id));
+            }
+        };
+
+        if let Some(expected_revision) = expected_revision
+            && current_revision.as_deref() != Some(expected_revision)
+        {
+            return Err(CommandError::conflict(format!(
+                "Command '{}' changed since it was listed (expected revision {}, found {}). Refresh and retry.",
+                command_path_label,
+                expected_revision,
+                current_revision.as_deref().unwrap_or("<missing>")
+            ))
+            .with_path(command_path_label)
+            .with_source_id(&descriptor.source_id));
+        }
+
+        let exists = current_revision.is_some();
+        match action {
+            MutationAction::Add if exists => {
+                return Err(CommandError::validation(format!(
+                    "Command '/{}' already exists in {}.",
+                    target_id, descriptor.source_label
+                ))
+                .with_source_id(&descriptor.source_id));
+            }
+            MutationAction::Update | MutationAction::Remove if !exists => {
+                return Err(CommandError::validation(format!(
+                    "Command '/{}' does not exist in {}.",
+                    target_id, descriptor.source_label
+                ))
+                .with_source_id(&descriptor.source_id));
+            }
+            _ => {}
+        }
+
+        let verb = if matches!(action, MutationAction::Remove) {
+            fs::remove_file(&command_path).map_err(|error| {
+                CommandError::io(
+                    format!(
+                        "Failed to remove command '{}': {}",
+                        command_path_label, error
+                    ),
+                    error.kind(),
+                )
+                .with_path(command_path_label.clone())
+            })?;
+            "Removed"
+        } else {
+            let manifest = read_manifest(payload)?;
+            fs::create_dir_all(&descriptor.directory_path).map_err(|error| {
+                CommandError::io(
+                    format!(
+                        "Failed to create commands directory '{}': {}",
+                        descriptor.directory_path.display(),
+                        error
+                    ),
+                    error.kind(),
+                )
+                .with_path(descriptor.directory_path.display().to_string())
+            })?;
+            SafeFileMutator::new()
+                .replace_file_if_unchanged(
+                    &command_path,
+                    manifest.as_bytes(),
+                    current_revision.as_deref(),
+                )
+                .map_err(|failure| CommandError::from_mutation_failure(&failure, &command_path))?;
+            if exists { "Updated" } else { "Added" }
+        };
+
+        Ok(CommandMutationResult {
+            message: format!(
+                "{} command '/{}' for '{}' at '{}'.",
+                verb,
+                target_id,
+                client.as_str(),
+                command_path_label
+            ),
+            source_path: command_path_label,
+            target_source_id: descriptor.source_id,
+        })
+    }
+}
+
+impl Default for CommandMutationService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub fn resolve_target_source(
+    client: ClientKind,
+    project_root: Option<&str>,
+    target_source_id: Option<&str>,
+) -> Result<CommandSourceDescriptor, CommandError> {
+    let descriptors = CommandSourceCatalogService::new().list_sources(client, project_root);
+    if descriptors.is_empty() {
+        return Err(CommandError::validation(format!(
+            "'{}' does not support custom commands.",
+            client.as_str()
+        )));
+    }
+
+    match target_source_id {
+        Some(target_source_id) => descriptors
+            .into_iter()
+            .find(|descriptor| descriptor.source_id == target_source_id)
+            .ok_or_else(|| {
+                CommandError::validation(format!(
+                    "target_source_id '{}' is not a command source for '{}'.",
+                    target_source_id,
+                    client.as_str()
+                ))
+            }),
+        None => Ok(descriptors
+            .into_iter()
+            .next()
+            .expect("command catalogs always start with the personal source")),
+    }
+}
+
+fn validate_command_name(target_id: &str) -> Result<(), CommandError> {
+    let valid = !target_id.is_empty()
+        && target_id
+            .chars()
+            .all(|ch| ch.is_ascii_alphanumeric() || ch == '-' || ch == '_');
+    if valid {
+        Ok(())
+    } else {
+        Err(CommandError::validation(format!(
+            "Command name '{}' may only contain letters, digits, '-' and '_'.",
+            target_id
+        )))
+    }
+}
+
+fn read_manifest(payload: Option<&serde_json::Value>) -> Result<String, CommandError> {
+    payload
+        .and_then(|payload| payload.get("manifest"))
+        .and_then(serde_json::Value::as_str)
+        .filter(|manifest| !manifest.trim().is_empty())
+        .map(str::to_string)
+        .ok_or_else(|| {
+            CommandError::validation("payload.manifest is required for command add/update.")
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use serde_json::json;
+
+    use super::CommandMutationService;
+    use crate::{
+        domain::ClientKind,
+        infra::content_revision,
+        interface::contracts::{command::CommandErrorCode, mutate::MutationAction},
+    };
+
+    #[test]
+    fn project_commands_are_added_updated_and_removed_with_revision_checks() {
+        let project_root = std::env::temp_dir().join(format!(
+            "ai-manager-command-mutation-{}",
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&project_root);
+        let project_root_label = project_root.display().to_string();
+        let commands_dir = project_root.join(".claude").join("commands");
+        let source_id = format!(
+            "command::claude_code::project_shared::{}",
+            commands_dir.display()
+        );
+        let service = CommandMutationService::new();
+        let mutate = |action: MutationAction,
+                      target_id: &str,
+                      manifest: Option<&str>,
+                      expected_revision: Option<&str>| {
+            service.mutate(
+                ClientKind::ClaudeCode,
+                action,
+                target_id,
+                Some(&project_root_label),
+                Some(&source_id),
+                manifest
+                    .map(|manifest| json!({ "manifest": manifest }))
+                    .as_ref(),
+                expected_revision,
+            )
+        };
+
+        let added = mutate(MutationAction::Add, "review", Some("Review $1\n"), None)
+            .expect("add should create the command file");
+        let duplicate = mutate(MutationAction::Add, "review", Some("Again\n"), None)
+            .expect_err("existing commands cannot be added twice");
+        let stale = mutate(
+            MutationAction::Update,
+            "review",
+            Some("Stale\n"),
+            Some("not-the-revision"),
+        )
+        .expect_err("stale revisions should conflict");
+        let revision = content_revision(b"Review $1\n");
+        mutate(
+            MutationAction::Update,
+            "review",
+            Some("Review $ARGUMENTS\n"),
+            Some(&revision),
+        )
+        .expect("update should replace the command");
+        let updated =
+            fs::read_to_string(commands_dir.join("review.md")).expect("command file should exist");
+        mutate(MutationAction::Remove, "review", None, None).expect("remove should succeed");
+        let invalid = mutate(MutationAction::Add, "../escape", Some("x"), None)
+            .expect_err("names with path separators are rejected");
+        let removed = !commands_dir.join("review.md").exists();
+        let _ = fs::remove_dir_all(&project_root);
+
+        assert!(added.source_path.ends_with("review.md"));
+        assert_eq!(added.target_source_id, source_id);
+        assert!(duplicate.message.contains("already exists"));
+        assert_eq!(stale.code, CommandErrorCode::Conflict);
+        assert_eq!(updated, "Review $ARGUMENTS\n");
+        assert!(removed);
+        assert_eq!(invalid.code, CommandErrorCode::ValidationError);
+    }
+}
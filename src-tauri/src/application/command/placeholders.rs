@@ -0,0 +1,233 @@
+use std::collections::BTreeMap;
+
+use crate::{application::subagent::metadata_parser::split_frontmatter_block, domain::ClientKind};
+
+/// Frontmatter keys only Claude Code understands; Codex prompts accept `description` and
+/// `argument-hint` alone.
+const CLAUDE_ONLY_FRONTMATTER_KEYS: [&str; 3] =
+    ["allowed-tools", "model", "disable-model-invocation"];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommandTranslation {
+    pub content: String,
+    pub notes: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token<'a> {
+    Text(&'a str),
+    AllArguments,
+    Positional(u32),
+    Named(&'a str),
+    EscapedDollar,
+    Dollar,
+}
+
+/// Rewrites a command file from one client's conventions to another's. Both clients expand
+/// `$ARGUMENTS` and `$1`-`$9`; Codex also expands named `$UPPER_CASE` placeholders and reads
+/// `$$` as a literal dollar, while Claude leaves every other `$` untouched.
+pub fn translate_command(source: &str, from: ClientKind, to: ClientKind) -> CommandTranslation {
+    if from == to {
+        return CommandTranslation {
+            content: source.to_string(),
+            notes: Vec::new(),
+        };
+    }
+
+    let mut notes = Vec::new();
+    let (frontmatter, body) = split_frontmatter_block(source);
+    let frontmatter = match (frontmatter, to) {
+        (Some(frontmatter), ClientKind::Codex) => {
+            Some(drop_claude_only_keys(frontmatter, &mut notes))
+        }
+        (frontmatter, _) => frontmatter.map(str::to_string),
+    };
+
+    let tokens = tokenize(body, from == ClientKind::Codex);
+    let body = match to {
+        ClientKind::Codex => render_for_codex(&tokens),
+        _ => render_for_claude(&tokens, &mut notes),
+    };
+
+    CommandTranslation {
+        content: format!("{}{}", frontmatter.unwrap_or_default(), body),
+        notes,
+    }
+}
+
+fn drop_claude_only_keys(frontmatter: &str, notes: &mut Vec<String>) -> String {
+    let mut kept = String::new();
+    let mut skipping = false;
+
+    for line in frontmatter.split_inclusive('\n') {
+        let is_continuation = line.starts_with([' ', '\t']) || line.trim_start().starts_with("- ");
+        if skipping && is_continuation {
+            continue;
+        }
+        skipping = false;
+
+        if let Some(key) = CLAUDE_ONLY_FRONTMATTER_KEYS
+            .iter()
+            .find(|key| line.starts_with(&format!("{}:", key)))
+        {
+            notes.push(format!("Dropped Claude-only frontmatter '{}'.", key));
+            skipping = true;
+            continue;
+        }
+        kept.push_str(line);
+    }
+
+    kept
+}
+
+fn tokenize(body: &str, codex_syntax: bool) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut text_start = 0;
+    let mut index = 0;
+
+    while let Some(offset) = body[index..].find('$') {
+        let dollar = index + offset;
+        if dollar > text_start {
+            tokens.push(Token::Text(&body[text_start..dollar]));
+        }
+
+        let rest = &body[dollar + 1..];
+        let (token, length) = if rest.starts_with("ARGUMENTS") {
+            (Token::AllArguments, "ARGUMENTS".len())
+        } else if let Some(digit) = rest.chars().next().and_then(|ch| ch.to_digit(10))
+            && digit > 0
+        {
+            (Token::Positional(digit), 1)
+        } else if codex_syntax && rest.starts_with('$') {
+            (Token::EscapedDollar, 1)
+        } else if codex_syntax && rest.starts_with(|ch: char| ch.is_ascii_uppercase()) {
+            let length = rest
+                .find(|ch: char| !(ch.is_ascii_uppercase() || ch.is_ascii_digit() || ch == '_'))
+                .unwrap_or(rest.len());
+            (Token::Named(&rest[..length]), length)
+        } else {
+            (Token::Dollar, 0)
+        };
+
+        tokens.push(token);
+        index = dollar + 1 + length;
+        text_start = index;
+    }
+
+    if text_start < body.len() {
+        tokens.push(Token::Text(&body[text_start..]));
+    }
+    tokens
+}
+
+fn render_for_codex(tokens: &[Token<'_>]) -> String {
+    let mut rendered = String::new();
+    for (index, token) in tokens.iter().enumerate() {
+        match token {
+            Token::Text(text) => rendered.push_str(text),
+            Token::AllArguments => rendered.push_str("$ARGUMENTS"),
+            Token::Positional(position) => rendered.push_str(&format!("${}", position)),
+            Token::Named(name) => rendered.push_str(&format!("${}", name)),
+            Token::EscapedDollar => rendered.push_str("$$"),
+            Token::Dollar => {
+                // A literal dollar Codex would otherwise read as a placeholder or escape.
+                let ambiguous = match tokens.get(index + 1) {
+                    Some(Token::Text(next)) => {
+                        next.starts_with(|ch: char| ch.is_ascii_uppercase() || ch == '$')
+                    }
+                    Some(Token::Dollar) => true,
+                    _ => false,
+                };
+                rendered.push_str(if ambiguous { "$$" } else { "$" });
+            }
+        }
+    }
+    rendered
+}
+
+fn render_for_claude(tokens: &[Token<'_>], notes: &mut Vec<String>) -> String {
+    let highest_positional = tokens
+        .iter()
+        .filter_map(|token| match token {
+            Token::Positional(position) => Some(*position),
+            _ => None,
+        })
+        .max()
+        .unwrap_or(0);
+    let mut named_positions: BTreeMap<&str, u32> = BTreeMap::new();
+    for token in tokens {
+        if let Token::Named(name) = token
+            && !named_positions.contains_key(name)
+        {
+            let position = highest_positional + named_positions.len() as u32 + 1;
+            named_positions.insert(name, position);
+        }
+    }
+
+    let mut rendered = String::new();
+    for token in tokens {
+        match token {
+            Token::Text(text) => rendered.push_str(text),
+            Token::AllArguments => rendered.push_str("$ARGUMENTS"),
+            Token::Positional(position) => rendered.push_str(&format!("${}", position)),
+            Token::Named(name) => rendered.push_str(&format!("${}", named_positions[name])),
+            Token::EscapedDollar | Token::Dollar => rendered.push('$'),
+        }
+    }
+
+    let mut renamed: Vec<(&str, u32)> = named_positions.into_iter().collect();
+    renamed.sort_by_key(|(_, position)| *position);
+    notes.extend(renamed.into_iter().map(|(name, position)| {
+        format!(
+            "Named placeholder ${} became positional ${}.",
+            name, position
+        )
+    }));
+    rendered
+}
+
+#[cfg(test)]
+mod tests {
+    use super::translate_command;
+    use crate::domain::ClientKind;
+
+    #[test]
+    fn codex_named_placeholders_become_claude_positionals() {
+        let translation = translate_command(
+            "---\ndescription: Draft a PR\nargument-hint: FILE=<path> FOCUS=<area>\n---\nReview $FILE ($1) focusing on $FOCUS, then $FILE again. Price in $$USD. All: $ARGUMENTS\n",
+            ClientKind::Codex,
+            ClientKind::ClaudeCode,
+        );
+
+        assert_eq!(
+            translation.content,
+            "---\ndescription: Draft a PR\nargument-hint: FILE=<path> FOCUS=<area>\n---\nReview $2 ($1) focusing on $3, then $2 again. Price in $USD. All: $ARGUMENTS\n"
+        );
+        assert_eq!(
+            translation.notes,
+            vec![
+                "Named placeholder $FILE became positional $2.".to_string(),
+                "Named placeholder $FOCUS became positional $3.".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn claude_literals_are_escaped_and_claude_only_keys_dropped_for_codex() {
+        let translation = translate_command(
+            "---\ndescription: Commit\nallowed-tools:\n  - Bash(git add:*)\nmodel: haiku\n---\nRun `echo $HOME` and commit $ARGUMENTS for $1 at $$ cost.\n",
+            ClientKind::ClaudeCode,
+            ClientKind::Codex,
+        );
+
+        assert_eq!(
+            translation.content,
+            "---\ndescription: Commit\n---\nRun `echo $$HOME` and commit $ARGUMENTS for $1 at $$$ cost.\n"
+        );
+        assert_eq!(translation.notes.len(), 2);
+        assert_eq!(
+            translate_command("Keep $HOME", ClientKind::Codex, ClientKind::Codex).content,
+            "Keep $HOME"
+        );
+    }
+}
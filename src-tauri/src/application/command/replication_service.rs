@@ -0,0 +1,192 @@
+use serde_json::json;
+
+use crate::{
+    domain::ClientKind,
+    interface::contracts::{command::CommandError, list::ResourceViewMode, mutate::MutationAction},
+};
+
+use super::{
+    listing_service::CommandListingService,
+    mutation_service::{CommandMutationService, resolve_target_source},
+    placeholders::translate_command,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommandReplicationResult {
+    pub destination_target_id: String,
+    pub destination_source_id: String,
+    pub message: String,
+}
+
+pub struct CommandReplicationService;
+
+impl CommandReplicationService {
+    pub fn new() -> Self {
+        Self
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn replicate(
+        &self,
+        source_client: ClientKind,
+        source_target_id: &str,
+        source_source_id: &str,
+        source_project_root: Option<&str>,
+        destination_client: ClientKind,
+        destination_target_id: Option<&str>,
+        destination_source_id: Option<&str>,
+        destination_project_root: Option<&str>,
+        overwrite: bool,
+    ) -> Result<CommandReplicationResult, CommandError> {
+        let source_manifest = CommandListingService::new()
+            .list(
+                source_client,
+                source_project_root,
+                None,
+                ResourceViewMode::AllSources,
+                None,
+            )
+            .items
+            .into_iter()
+            .find(|record| {
+                record.source_id == source_source_id && record.logical_id == source_target_id
+            })
+            .and_then(|record| record.manifest_content)
+            .ok_or_else(|| {
+                CommandError::validation(format!(
+                    "Could not resolve source command '/{}' from source '{}'.",
+                    source_target_id, source_source_id
+                ))
+            })?;
+
+        let destination_target_id = destination_target_id
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .unwrap_or(source_target_id)
+            .to_string();
+        let destination = resolve_target_source(
+            destination_client,
+            destination_project_root,
+            destination_source_id,
+        )?;
+        let destination_exists = destination
+            .directory_path
+            .join(format!("{}.md", destination_target_id))
+            .is_file();
+
+        if destination_exists && !overwrite {
+            return Err(CommandError::validation(format!(
+                "Command '/{}' already exists in {} for '{}'. Set overwrite=true or choose a different destination_target_id.",
+                destination_target_id,
+                destination.source_label,
+                destination_client.as_str()
+            )));
+        }
+
+        let translation = translate_command(&source_manifest, source_client, destination_client);
+        let outcome = CommandMutationService::new().mutate(
+            destination_client,
+            if destination_exists {
+                MutationAction::Update
+            } else {
+                MutationAction::Add
+            },
+            &destination_target_id,
+            destination_project_root,
+            Some(&destination.source_id),
+            Some(&json!({ "manifest": translation.content })),
+            None,
+        )?;
+
+        let mut message = format!(
+            "Replicated command '/{}' from '{}' to '{}' in {}.",
+            source_target_id,
+            source_client.as_str(),
+            destination_client.as_str(),
+            destination.source_label
+        );
+        if destination_exists {
+            message.push_str(" Overwrote the existing command.");
+        }
+        for note in translation.notes {
+            message.push(' ');
+            message.push_str(&note);
+        }
+
+        Ok(CommandReplicationResult {
+            destination_target_id,
+            destination_source_id: outcome.target_source_id,
+            message,
+        })
+    }
+}
+
+impl Default for CommandReplicationService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::CommandReplicationService;
+    use crate::domain::ClientKind;
+
+    #[test]
+    fn replication_copies_commands_between_projects_and_requires_overwrite() {
+        let temp_root = std::env::temp_dir().join(format!(
+            "ai-manager-command-replication-{}",
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&temp_root);
+        let source_root = temp_root.join("source");
+        let destination_root = temp_root.join("destination");
+        let source_dir = source_root.join(".claude").join("commands");
+        fs::create_dir_all(&source_dir).expect("should create source commands");
+        fs::write(source_dir.join("triage.md"), "Triage issue $1\n")
+            .expect("should create source command");
+        let source_root_label = source_root.display().to_string();
+        let destination_root_label = destination_root.display().to_string();
+        let source_source_id = format!(
+            "command::claude_code::project_shared::{}",
+            source_dir.display()
+        );
+        let destination_source_id = format!(
+            "command::claude_code::project_shared::{}",
+            destination_root.join(".claude").join("commands").display()
+        );
+
+        let replicate = |overwrite: bool| {
+            CommandReplicationService::new().replicate(
+                ClientKind::ClaudeCode,
+                "triage",
+                &source_source_id,
+                Some(&source_root_label),
+                ClientKind::ClaudeCode,
+                None,
+                Some(&destination_source_id),
+                Some(&destination_root_label),
+                overwrite,
+            )
+        };
+
+        let first = replicate(false).expect("first replication should succeed");
+        let blocked = replicate(false).expect_err("existing destination requires overwrite");
+        let second = replicate(true).expect("overwrite should replace the destination");
+        let copied = fs::read_to_string(
+            destination_root
+                .join(".claude")
+                .join("commands")
+                .join("triage.md"),
+        )
+        .expect("destination command should exist");
+        let _ = fs::remove_dir_all(&temp_root);
+
+        assert_eq!(first.destination_source_id, destination_source_id);
+        assert!(blocked.message.contains("overwrite=true"));
+        assert!(second.message.contains("Overwrote"));
+        assert_eq!(copied, "Triage issue $1\n");
+    }
+}
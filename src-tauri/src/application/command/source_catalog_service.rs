@@ -0,0 +1,142 @@
+use std::{env, path::PathBuf};
+
+use crate::{
+    domain::{ClientKind, ResourceSourceScope},
+    infra::expand_user_path,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommandSourceDescriptor {
+    pub client: ClientKind,
+    pub source_id: String,
+    pub source_scope: ResourceSourceScope,
+    pub source_label: String,
+    pub directory_path: PathBuf,
+    pub project_root: Option<String>,
+}
+
+pub struct CommandSourceCatalogService;
+
+impl CommandSourceCatalogService {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn list_sources(
+        &self,
+        client: ClientKind,
+        project_root: Option<&str>,
+    ) -> Vec<CommandSourceDescriptor> {
+        match client {
+            ClientKind::ClaudeCode => {
+                let mut descriptors = vec![descriptor_for_scope(
+                    client,
+                    ResourceSourceScope::User,
+                    expand_user_path("~/.claude/commands"),
+                    None,
+                )];
+
+                if let Some(project_root) = project_root {
+                    descriptors.push(descriptor_for_scope(
+                        client,
+                        ResourceSourceScope::ProjectShared,
+                        PathBuf::from(project_root).join(".claude").join("commands"),
+                        Some(project_root.to_string()),
+                    ));
+                }
+
+                descriptors
+            }
+            ClientKind::Codex => vec![descriptor_for_scope(
+                client,
+                ResourceSourceScope::User,
+                codex_home().join("prompts"),
+                None,
+            )],
+            ClientKind::Cursor => Vec::new(),
+        }
+    }
+}
+
+impl Default for CommandSourceCatalogService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn descriptor_for_scope(
+    client: ClientKind,
+    source_scope: ResourceSourceScope,
+    directory_path: PathBuf,
+    project_root: Option<String>,
+) -> CommandSourceDescriptor {
+    let source_label = match (client, source_scope) {
+        (ClientKind::Codex, _) => "Personal prompts directory",
+        (_, ResourceSourceScope::User) => "Personal commands directory",
+        (_, ResourceSourceScope::ProjectShared) => "Project commands directory",
+        (_, ResourceSourceScope::ProjectPrivate) => "Project private commands directory",
+    };
+
+    let source_id = format!(
+        "command::{}::{}::{}",
+        client.as_str(),
+        source_scope.as_str(),
+        directory_path.display()
+    );
+
+    CommandSourceDescriptor {
+        client,
+        source_id,
+        source_scope,
+        source_label: source_label.to_string(),
+        directory_path,
+        project_root,
+    }
+}
+
+/// Codex keeps its state under `CODEX_HOME`, defaulting to `~/.codex`.
+fn codex_home() -> PathBuf {
+    env::var_os("CODEX_HOME")
+        .filter(|value| !value.is_empty())
+        .map(PathBuf::from)
+        .unwrap_or_else(|| expand_user_path("~/.codex"))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::CommandSourceCatalogService;
+    use crate::domain::{ClientKind, ResourceSourceScope};
+
+    #[test]
+    fn catalog_lists_claude_command_and_codex_prompt_directories() {
+        let service = CommandSourceCatalogService::new();
+        let claude = service.list_sources(ClientKind::ClaudeCode, Some("/Users/test/demo"));
+        let codex = service.list_sources(ClientKind::Codex, Some("/Users/test/demo"));
+
+        assert_eq!(
+            claude
+                .iter()
+                .map(|descriptor| descriptor.source_scope)
+                .collect::<Vec<_>>(),
+            vec![
+                ResourceSourceScope::User,
+                ResourceSourceScope::ProjectShared
+            ]
+        );
+        assert_eq!(
+            claude[1].directory_path,
+            PathBuf::from("/Users/test/demo")
+                .join(".claude")
+                .join("commands")
+        );
+        assert_eq!(codex.len(), 1);
+        assert!(codex[0].directory_path.ends_with("prompts"));
+        assert!(
+            service
+                .list_sources(ClientKind::Cursor, Some("/Users/test/demo"))
+                .is_empty()
+        );
+    }
+}
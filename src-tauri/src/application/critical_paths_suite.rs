@@ -11,8 +11,10 @@ use super::{
     mcp::mutation_service::McpMutationService, skill::mutation_service::SkillMutationService,
 };
 use crate::{
-    infra::DetectorRegistry,
-    infra::parsers::{ParseOutcome, ParserRegistry},
+    infra::{
+        DetectorRegistry, ParkedServerStore,
+        parsers::{ParseOutcome, ParserRegistry},
+    },
     interface::contracts::{common::ClientKind, mutate::MutationAction},
 };
 
@@ -106,8 +108,11 @@ fn mcp_mutation_round_trip_covers_success_and_failure_paths() {
     let config_path = root.join("mcp.json");
     fs::write(&config_path, r#"{"mcpServers": {}}"#).expect("seed config should be writable");
 
-    let detector_registry = DetectorRegistry::with_default_detectors();
-    let service = McpMutationService::new(&detector_registry);
+    let detector_registry = DetectorRegistry::with_installed_version("1.0.0");
+    let service = McpMutationService::new(
+        &detector_registry,
+        Some(ParkedServerStore::at(root.join("parked-mcp-servers.json"))),
+    );
 
     let add_result = service
         .mutate(
@@ -13,11 +13,16 @@ impl<'a> DetectionService<'a> {
     }
 
     pub fn detect_clients(&self, request: &DetectClientsRequest) -> DetectClientsResponse {
-        let clients = self
+        let clients: Vec<_> = self
             .detector_registry
             .all()
             .map(|detector| detector.detect(request))
             .collect();
+        if request.include_versions {
+            for detection in &clients {
+                self.detector_registry.remember_version(detection);
+            }
+        }
 
         DetectClientsResponse { clients }
     }
@@ -0,0 +1,188 @@
+use crate::application::subagent::metadata_parser::{
+    parse_frontmatter_scalar, split_frontmatter_block,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InstructionFormat {
+    /// Plain Markdown, as in `CLAUDE.md` and `AGENTS.md`.
+    Markdown,
+    /// A Cursor `.mdc` rule: Markdown behind `description`/`globs`/`alwaysApply` frontmatter.
+    CursorRule,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct InstructionMetadata {
+    pub description: Option<String>,
+    pub globs: Option<String>,
+    pub always_apply: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InstructionConversion {
+    pub content: String,
+    pub notes: Vec<String>,
+}
+
+/// Reads rule frontmatter when present and otherwise describes the file by its first line.
+/// Markdown instruction files always apply.
+pub fn parse_instruction_metadata(source: &str, format: InstructionFormat) -> InstructionMetadata {
+    let (frontmatter, body) = match format {
+        InstructionFormat::CursorRule => split_frontmatter_block(source),
+        InstructionFormat::Markdown => (None, source),
+    };
+    let field = |key: &str| frontmatter.and_then(|data| parse_frontmatter_scalar(data, key));
+
+    InstructionMetadata {
+        description: field("description").or_else(|| first_line(body)),
+        globs: field("globs"),
+        always_apply: frontmatter.is_none()
+            || field("alwaysApply").is_some_and(|value| value.eq_ignore_ascii_case("true")),
+    }
+}
+
+/// Converts between Markdown instruction files and Cursor rules. Markdown becomes an
+/// always-applied rule; a rule's scope has no Markdown equivalent, so it is kept as a sentence
+/// and reported in the notes.
+pub fn convert_instruction(
+    source: &str,
+    from: InstructionFormat,
+    to: InstructionFormat,
+    name: &str,
+) -> InstructionConversion {
+    match (from, to) {
+        (InstructionFormat::Markdown, InstructionFormat::CursorRule) => {
+            let description = first_line(source).unwrap_or_else(|| name.to_string());
+            InstructionConversion {
+                content: format!(
+                    "---\ndescription: {}\nglobs:\nalwaysApply: true\n---\n{}",
+                    yaml_quoted(&description),
+                    with_trailing_newline(source)
+                ),
+                notes: Vec::new(),
+            }
+        }
+        (InstructionFormat::CursorRule, InstructionFormat::Markdown) => {
+            let metadata = parse_instruction_metadata(source, from);
+            let body = split_frontmatter_block(source)
+                .1
+                .trim_start_matches(['\r', '\n']);
+            let mut notes = Vec::new();
+
+            let content = match (&metadata.globs, metadata.always_apply) {
+                (_, true) => body.to_string(),
+                (Some(globs), false) => {
+                    notes.push(format!(
+                        "Rule '{}' only applied to files matching '{}'; the scope was kept as a sentence because Markdown instructions always apply.",
+                        name, globs
+                    ));
+                    format!(
+                        "Apply the following only when working on files matching `{}`.\n\n{}",
+                        globs, body
+                    )
+                }
+                (None, false) => {
+                    notes.push(format!(
+                        "Rule '{}' was applied on request in Cursor; Markdown instructions always apply.",
+                        name
+                    ));
+                    body.to_string()
+                }
+            };
+
+            InstructionConversion {
+                content: with_trailing_newline(&content),
+                notes,
+            }
+        }
+        _ => InstructionConversion {
+            content: source.to_string(),
+            notes: Vec::new(),
+        },
+    }
+}
+
+fn first_line(body: &str) -> Option<String> {
+    body.lines()
+        .map(|line| line.trim().trim_start_matches('#').trim())
+        .find(|line| !line.is_empty())
+        .map(str::to_string)
+}
+
+/// A double-quoted YAML scalar, so headings with `: `, ` #` or leading indicators stay text.
+/// JSON string syntax is valid YAML double-quoted syntax.
+fn yaml_quoted(value: &str) -> String {
+    serde_json::Value::String(value.to_string()).to_string()
+}
+
+fn with_trailing_newline(content: &str) -> String {
+    if content.is_empty() || content.ends_with('\n') {
+        content.to_string()
+    } else {
+        format!("{}\n", content)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{InstructionFormat, convert_instruction, parse_instruction_metadata};
+
+    #[test]
+    fn markdown_becomes_an_always_applied_cursor_rule() {
+        let conversion = convert_instruction(
+            "# Team conventions\n\n- Use pnpm.\n",
+            InstructionFormat::Markdown,
+            InstructionFormat::CursorRule,
+            "claude",
+        );
+        let metadata =
+            parse_instruction_metadata(&conversion.content, InstructionFormat::CursorRule);
+
+        assert_eq!(
+            conversion.content,
+            "---\ndescription: \"Team conventions\"\nglobs:\nalwaysApply: true\n---\n# Team conventions\n\n- Use pnpm.\n"
+        );
+        assert!(conversion.notes.is_empty());
+        assert!(metadata.always_apply);
+        assert_eq!(metadata.globs, None);
+    }
+
+    #[test]
+    fn scoped_cursor_rules_keep_their_globs_as_prose() {
+        let scoped = convert_instruction(
+            "---\ndescription: React components\nglobs: src/**/*.tsx\nalwaysApply: false\n---\n\nPrefer function components.",
+            InstructionFormat::CursorRule,
+            InstructionFormat::Markdown,
+            "react",
+        );
+        let always = convert_instruction(
+            "---\ndescription: Everywhere\nglobs:\nalwaysApply: true\n---\nUse pnpm.\n",
+            InstructionFormat::CursorRule,
+            InstructionFormat::Markdown,
+            "base",
+        );
+
+        assert_eq!(
+            scoped.content,
+            "Apply the following only when working on files matching `src/**/*.tsx`.\n\nPrefer function components.\n"
+        );
+        assert_eq!(scoped.notes.len(), 1);
+        assert_eq!(always.content, "Use pnpm.\n");
+        assert!(always.notes.is_empty());
+    }
+
+    #[test]
+    fn cursor_rule_descriptions_are_quoted_yaml() {
+        let conversion = convert_instruction(
+            "# Note: use \"pnpm\" # always\n",
+            InstructionFormat::Markdown,
+            InstructionFormat::CursorRule,
+            "claude",
+        );
+
+        assert!(
+            conversion
+                .content
+                .starts_with("---\ndescription: \"Note: use \\\"pnpm\\\" # always\"\nglobs:\n")
+        );
+    }
+}
@@ -0,0 +1,268 @@
+use std::{fs, io, path::Path};
+
+use crate::{
+    domain::{ClientKind, ResourceSourceMetadata, ResourceSourceScope},
+    infra::content_revision,
+    interface::contracts::list::{ResourceRecord, SourceRevision},
+};
+
+use super::{
+    format_conversion::parse_instruction_metadata,
+    source_catalog_service::{
+        InstructionLocation, InstructionSourceCatalogService, InstructionSourceDescriptor,
+    },
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InstructionListResult {
+    pub items: Vec<ResourceRecord>,
+    pub warning: Option<String>,
+    pub source_revisions: Vec<SourceRevision>,
+}
+
+pub struct InstructionListingService;
+
+impl InstructionListingService {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Clients load every instruction file they find, so all records are effective.
+    pub fn list(
+        &self,
+        client: ClientKind,
+        project_root: Option<&str>,
+        enabled_filter: Option<bool>,
+        scope_filter: Option<&[ResourceSourceScope]>,
+    ) -> InstructionListResult {
+        let descriptors = InstructionSourceCatalogService::new().list_sources(client, project_root);
+        collect_from_descriptors(descriptors, enabled_filter, scope_filter)
+    }
+}
+
+impl Default for InstructionListingService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn collect_from_descriptors<I>(
+    descriptors: I,
+    enabled_filter: Option<bool>,
+    scope_filter: Option<&[ResourceSourceScope]>,
+) -> InstructionListResult
+where
+    I: IntoIterator<Item = InstructionSourceDescriptor>,
+{
+    let mut items: Vec<ResourceRecord> = Vec::new();
+    let mut warnings: Vec<String> = Vec::new();
+    let mut source_revisions: Vec<SourceRevision> = Vec::new();
+
+    for descriptor in descriptors {
+        if scope_filter.is_some_and(|scopes| !scopes.contains(&descriptor.source_scope)) {
+            continue;
+        }
+
+        let candidate_paths = match &descriptor.location {
+            InstructionLocation::File(path) => vec![path.clone()],
+            InstructionLocation::RuleDirectory(directory) => match fs::read_dir(directory) {
+                Ok(entries) => {
+                    let mut paths = entries
+                        .filter_map(Result::ok)
+                        .map(|entry| entry.path())
+                        .filter(|path| is_rule_file(path))
+                        .collect::<Vec<_>>();
+                    paths.sort_unstable_by(|left, right| left.as_os_str().cmp(right.as_os_str()));
+                    paths
+                }
+                Err(error) if error.kind() == io::ErrorKind::NotFound => Vec::new(),
+                Err(error) => {
+                    warnings.push(format!(
+                        "[{}:INSTRUCTION_DIR_READ_ERROR] failed to read '{}': {}",
+                        descriptor.client.as_str(),
+                        directory.display(),
+                        error
+                    ));
+                    continue;
+                }
+            },
+        };
+
+        for candidate_path in candidate_paths {
+            let source_path = candidate_path.display().to_string();
+            let source = match fs::read_to_string(&candidate_path) {
+                Ok(source) => source,
+                Err(error) if error.kind() == io::ErrorKind::NotFound => {
+                    source_revisions.push(SourceRevision {
+                        source_id: descriptor.source_id.clone(),
+                        source_path,
+                        revision: None,
+                    });
+                    continue;
+                }
+                Err(error) => {
+                    warnings.push(format!(
+                        "[{}:INSTRUCTION_READ_ERROR] failed to read '{}': {}",
+                        descriptor.client.as_str(),
+                        source_path,
+                        error
+                    ));
+                    continue;
+                }
+            };
+            source_revisions.push(SourceRevision {
+                source_id: descriptor.source_id.clone(),
+                source_path: source_path.clone(),
+                revision: Some(content_revision(source.as_bytes())),
+            });
+
+            if enabled_filter == Some(false) {
+                continue;
+            }
+
+            let logical_id = match &descriptor.location {
+                InstructionLocation::File(_) => candidate_path.file_name(),
+                InstructionLocation::RuleDirectory(_) => candidate_path.file_stem(),
+            }
+            .map(|value| value.to_string_lossy().to_string())
+            .unwrap_or_default();
+            let metadata = parse_instruction_metadata(&source, descriptor.location.format());
+            let description = match (metadata.description, metadata.globs) {
+                (Some(description), Some(globs)) if !metadata.always_apply => {
+                    Some(format!("{} ({})", description, globs))
+                }
+                (description, _) => description,
+            };
+
+            items.push(
+                ResourceRecord {
+                    id: format!(
+                        "{}::instruction::{}::{}",
+                        descriptor.client.as_str(),
+                        descriptor.source_id,
+                        logical_id
+                    ),
+                    display_name: logical_id.clone(),
+                    logical_id,
+                    client: descriptor.client,
+                    enabled: true,
+                    transport_kind: None,
+                    transport_command: None,
+                    transport_args: None,
+                    transport_url: None,
+                    source_path: Some(source_path),
+                    source_id: String::new(),
+                    source_scope: descriptor.source_scope,
+                    source_label: String::new(),
+                    is_effective: true,
+                    shadowed_by: None,
+                    description,
+                    install_kind: Some("file".to_string()),
+                    manifest_content: Some(source),
+                    approval: None,
+                }
+                .with_source_metadata(ResourceSourceMetadata {
+                    source_id: descriptor.source_id.clone(),
+                    source_scope: descriptor.source_scope,
+                    source_label: descriptor.source_label.clone(),
+                    is_effective: true,
+                    shadowed_by: None,
+                }),
+            );
+        }
+    }
+
+    InstructionListResult {
+        items,
+        warning: (!warnings.is_empty()).then(|| warnings.join(" | ")),
+        source_revisions,
+    }
+}
+
+fn is_rule_file(path: &Path) -> bool {
+    path.is_file()
+        && path
+            .extension()
+            .is_some_and(|extension| extension.eq_ignore_ascii_case("mdc"))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::collect_from_descriptors;
+    use crate::domain::{ClientKind, ResourceSourceScope};
+
+    use super::super::source_catalog_service::{InstructionLocation, InstructionSourceDescriptor};
+
+    #[test]
+    fn lists_markdown_files_and_each_cursor_rule_with_revisions() {
+        let temp_root = std::env::temp_dir().join(format!(
+            "ai-manager-instruction-listing-{}",
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&temp_root);
+        let rules_dir = temp_root.join(".cursor").join("rules");
+        fs::create_dir_all(&rules_dir).expect("should create rules dir");
+        fs::write(temp_root.join("CLAUDE.md"), "# Team conventions\n")
+            .expect("should create memory file");
+        fs::write(
+            rules_dir.join("react.mdc"),
+            "---\ndescription: React components\nglobs: src/**/*.tsx\nalwaysApply: false\n---\nPrefer hooks.\n",
+        )
+        .expect("should create rule");
+        fs::write(rules_dir.join("notes.md"), "ignored").expect("should create note");
+
+        let result = collect_from_descriptors(
+            vec![
+                InstructionSourceDescriptor {
+                    client: ClientKind::ClaudeCode,
+                    source_id: "claude-project".to_string(),
+                    source_scope: ResourceSourceScope::ProjectShared,
+                    source_label: "Project memory".to_string(),
+                    location: InstructionLocation::File(temp_root.join("CLAUDE.md")),
+                    project_root: None,
+                },
+                InstructionSourceDescriptor {
+                    client: ClientKind::ClaudeCode,
+                    source_id: "claude-local".to_string(),
+                    source_scope: ResourceSourceScope::ProjectPrivate,
+                    source_label: "Project local memory".to_string(),
+                    location: InstructionLocation::File(temp_root.join("CLAUDE.local.md")),
+                    project_root: None,
+                },
+                InstructionSourceDescriptor {
+                    client: ClientKind::Cursor,
+                    source_id: "cursor-rules".to_string(),
+                    source_scope: ResourceSourceScope::ProjectShared,
+                    source_label: "Project rules".to_string(),
+                    location: InstructionLocation::RuleDirectory(rules_dir.clone()),
+                    project_root: None,
+                },
+            ],
+            None,
+            None,
+        );
+        let _ = fs::remove_dir_all(&temp_root);
+
+        assert_eq!(
+            result
+                .items
+                .iter()
+                .map(|item| item.logical_id.as_str())
+                .collect::<Vec<_>>(),
+            vec!["CLAUDE.md", "react"]
+        );
+        assert_eq!(
+            result.items[0].description.as_deref(),
+            Some("Team conventions")
+        );
+        assert_eq!(
+            result.items[1].description.as_deref(),
+            Some("React components (src/**/*.tsx)")
+        );
+        assert_eq!(result.source_revisions.len(), 3);
+        assert!(result.source_revisions[1].revision.is_none());
+        assert!(result.warning.is_none());
+    }
+}
@@ -0,0 +1,5 @@
+pub(super) mod format_conversion;
+pub(super) mod listing_service;
+pub(super) mod mutation_service;
+pub(super) mod replication_service;
+pub(super) mod source_catalog_service;
@@ -0,0 +1,294 @@
+use std::{fs, io};
+
+use crate::{
+    domain::ClientKind,
+    infra::{SafeFileMutator, content_revision},
+    interface::contracts::{command::CommandError, mutate::MutationAction},
+};
+
+use super::source_catalog_service::{InstructionSourceCatalogService, InstructionSourceDescriptor};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InstructionMutationResult {
+    pub source_path: String,
+    pub target_source_id: String,
+    pub message: String,
+}
+
+pub struct InstructionMutationService;
+
+impl InstructionMutationService {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Writes or deletes one instruction file. `target_id` is the file name for Markdown
+    /// sources (`CLAUDE.md`, `AGENTS.md`) and the rule name for Cursor rules; without
+    /// `target_source_id` the first source that can hold it is used.
+    #[allow(clippy::too_many_arguments)]
+    pub fn mutate(
+        &self,
+        client: ClientKind,
+        action: MutationAction,
+        target_id: &str,
+        project_root: Option<&str>,
+        target_source_id: Option<&str>,
+        payload: Option<&serde_json::Value>,
+        expected_revision: Option<&str>,
+    ) -> Result<InstructionMutationResult, CommandError> {
+        if matches!(action, MutationAction::Approve | MutationAction::Deny) {
+            return Err(CommandError::validation(format!(
+                "Instructions do not support the '{}' action.",
+                action.as_str()
+            )));
+        }
+
+        let descriptor = resolve_target_source(client, target_id, project_root, target_source_id)?;
+        let instruction_path = descriptor.location.entry_path(target_id).ok_or_else(|| {
+            CommandError::validation(format!(
+                "'{}' is not an instruction file of {}.",
+                target_id, descriptor.source_label
+            ))
+            .with_source_id(&descriptor.source_id)
+        })?;
+        let instruction_path_label = instruction_path.display().to_string();
+
+        let current_revision = match fs::read(&instruction_path) {
+            Ok(content) => Some(content_revision(&content)),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => None,
+            Err(error) => {
+                return Err(CommandError::io(
+                    format!(
+                        "Failed to read instruction file '{}': {}",
+                        instruction_path_label, error
+                    ),
+                    error.kind(),
+                )
+                .with_path(instruction_path_label)
+                .with_source_id(&descriptor.source_id));
+            }
+        };
+
+        if let Some(expected_revision) = expected_revision
+            && current_revision.as_deref() != Some(expected_revision)
+        {
+            return Err(CommandError::conflict(format!(
+                "Instruction file '{}' changed since it was listed (expected revision {}, found {}). Refresh and retry.",
+                instruction_path_label,
+                expected_revision,
+                current_revision.as_deref().unwrap_or("<missing>")
+            ))
+            .with_path(instruction_path_label)
+            .with_source_id(&descriptor.source_id));
+        }
+
+        let exists = current_revision.is_some();
+        match action {
+            MutationAction::Add if exists => {
+                return Err(CommandError::validation(format!(
+                    "Instruction '{}' already exists in {}.",
+                    target_id, descriptor.source_label
+                ))
+                .with_source_id(&descriptor.source_id));
+            }
+            MutationAction::Update | MutationAction::Remove if !exists => {
+                return Err(CommandError::validation(format!(
+                    "Instruction '{}' does not exist in {}.",
+                    target_id, descriptor.source_label
+                ))
+                .with_source_id(&descriptor.source_id));
+            }
+            _ => {}
+        }
+
+        let verb = if matches!(action, MutationAction::Remove) {
+            fs::remove_file(&instruction_path).map_err(|error| {
+                CommandError::io(
+                    format!(
+                        "Failed to remove instruction file '{}': {}",
+                        instruction_path_label, error
+                    ),
+                    error.kind(),
+                )
+                .with_path(instruction_path_label.clone())
+            })?;
+            "Removed"
+        } else {
+            let manifest = read_manifest(payload)?;
+            if let Some(parent) = instruction_path.parent() {
+                fs::create_dir_all(parent).map_err(|error| {
+                    CommandError::io(
+                        format!(
+                            "Failed to create instruction directory '{}': {}",
+                            parent.display(),
+                            error
+                        ),
+                        error.kind(),
+                    )
+                    .with_path(parent.display().to_string())
+                })?;
+            }
+            SafeFileMutator::new()
+                .replace_file_if_unchanged(
+                    &instruction_path,
+                    manifest.as_bytes(),
+                    current_revision.as_deref(),
+                )
+                .map_err(|failure| {
+                    CommandError::from_mutation_failure(&failure, &instruction_path)
+                })?;
+            if exists { "Updated" } else { "Added" }
+        };
+
+        Ok(InstructionMutationResult {
+            message: format!(
+                "{} instruction '{}' for '{}' at '{}'.",
+                verb,
+                target_id,
+                client.as_str(),
+                instruction_path_label
+            ),
+            source_path: instruction_path_label,
+            target_source_id: descriptor.source_id,
+        })
+    }
+}
+
+impl Default for InstructionMutationService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub fn resolve_target_source(
+    client: ClientKind,
+    target_id: &str,
+    project_root: Option<&str>,
+    target_source_id: Option<&str>,
+) -> Result<InstructionSourceDescriptor, CommandError> {
+    let descriptors = InstructionSourceCatalogService::new().list_sources(client, project_root);
+    if descriptors.is_empty() {
+        return Err(CommandError::validation(format!(
+            "'{}' has no instruction sources{}.",
+            client.as_str(),
+            if project_root.is_some() {
+                ""
+            } else {
+                " without project_root"
+            }
+        )));
+    }
+
+    match target_source_id {
+        Some(target_source_id) => descriptors
+            .into_iter()
+            .find(|descriptor| descriptor.source_id == target_source_id)
+            .ok_or_else(|| {
+                CommandError::validation(format!(
+                    "target_source_id '{}' is not an instruction source for '{}'.",
+                    target_source_id,
+                    client.as_str()
+                ))
+            }),
+        None => descriptors
+            .into_iter()
+            .find(|descriptor| descriptor.location.entry_path(target_id).is_some())
+            .ok_or_else(|| {
+                CommandError::validation(format!(
+                    "'{}' is not an instruction file for '{}'.",
+                    target_id,
+                    client.as_str()
+                ))
+            }),
+    }
+}
+
+fn read_manifest(payload: Option<&serde_json::Value>) -> Result<String, CommandError> {
+    payload
+        .and_then(|payload| payload.get("manifest"))
+        .and_then(serde_json::Value::as_str)
+        .filter(|manifest| !manifest.trim().is_empty())
+        .map(str::to_string)
+        .ok_or_else(|| {
+            CommandError::validation("payload.manifest is required for instruction add/update.")
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use serde_json::json;
+
+    use super::InstructionMutationService;
+    use crate::{
+        domain::ClientKind,
+        infra::content_revision,
+        interface::contracts::{command::CommandErrorCode, mutate::MutationAction},
+    };
+
+    #[test]
+    fn memory_files_are_resolved_by_name_and_edited_with_revision_checks() {
+        let project_root = std::env::temp_dir().join(format!(
+            "ai-manager-instruction-mutation-{}",
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&project_root);
+        fs::create_dir_all(&project_root).expect("should create project root");
+        let project_root_label = project_root.display().to_string();
+        let service = InstructionMutationService::new();
+        let mutate = |action: MutationAction,
+                      target_id: &str,
+                      manifest: Option<&str>,
+                      expected_revision: Option<&str>| {
+            service.mutate(
+                ClientKind::ClaudeCode,
+                action,
+                target_id,
+                Some(&project_root_label),
+                None,
+                manifest
+                    .map(|manifest| json!({ "manifest": manifest }))
+                    .as_ref(),
+                expected_revision,
+            )
+        };
+
+        let added = mutate(
+            MutationAction::Add,
+            "CLAUDE.local.md",
+            Some("Use my fork.\n"),
+            None,
+        )
+        .expect("add should create the local memory file");
+        let stale = mutate(
+            MutationAction::Update,
+            "CLAUDE.local.md",
+            Some("Stale\n"),
+            Some("not-the-revision"),
+        )
+        .expect_err("stale revisions should conflict");
+        let revision = content_revision(b"Use my fork.\n");
+        mutate(
+            MutationAction::Update,
+            "CLAUDE.local.md",
+            Some("Use the upstream repo.\n"),
+            Some(&revision),
+        )
+        .expect("update should replace the memory file");
+        let updated = fs::read_to_string(project_root.join("CLAUDE.local.md"))
+            .expect("memory file should exist");
+        let unknown = mutate(MutationAction::Add, "README.md", Some("x"), None)
+            .expect_err("unrelated files are not instruction files");
+        mutate(MutationAction::Remove, "CLAUDE.local.md", None, None)
+            .expect("remove should succeed");
+        let removed = !project_root.join("CLAUDE.local.md").exists();
+        let _ = fs::remove_dir_all(&project_root);
+
+        assert!(added.target_source_id.contains("::project_private::"));
+        assert_eq!(stale.code, CommandErrorCode::Conflict);
+        assert_eq!(updated, "Use the upstream repo.\n");
+        assert_eq!(unknown.code, CommandErrorCode::ValidationError);
+        assert!(removed);
+    }
+}
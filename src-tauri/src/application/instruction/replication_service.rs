@@ -0,0 +1,252 @@
+use serde_json::json;
+
+use crate::{
+    domain::ClientKind,
+    interface::contracts::{command::CommandError, mutate::MutationAction},
+};
+
+use super::{
+    format_conversion::convert_instruction, listing_service::InstructionListingService,
+    mutation_service::InstructionMutationService,
+    source_catalog_service::InstructionSourceCatalogService,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InstructionReplicationResult {
+    pub destination_target_id: String,
+    pub destination_source_id: String,
+    pub message: String,
+}
+
+pub struct InstructionReplicationService;
+
+impl InstructionReplicationService {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Syncs one instruction file into another client's format. Without
+    /// `destination_source_id` the destination source with the same scope is used, and the
+    /// target id defaults to that source's file name (or a rule named after the source).
+    #[allow(clippy::too_many_arguments)]
+    pub fn replicate(
+        &self,
+        source_client: ClientKind,
+        source_target_id: &str,
+        source_source_id: &str,
+        source_project_root: Option<&str>,
+        destination_client: ClientKind,
+        destination_target_id: Option<&str>,
+        destination_source_id: Option<&str>,
+        destination_project_root: Option<&str>,
+        overwrite: bool,
+    ) -> Result<InstructionReplicationResult, CommandError> {
+        let source_record = InstructionListingService::new()
+            .list(source_client, source_project_root, None, None)
+            .items
+            .into_iter()
+            .find(|record| {
+                record.source_id == source_source_id && record.logical_id == source_target_id
+            })
+            .ok_or_else(|| {
+                CommandError::validation(format!(
+                    "Could not resolve source instruction '{}' from source '{}'.",
+                    source_target_id, source_source_id
+                ))
+            })?;
+        let source_format = InstructionSourceCatalogService::new()
+            .list_sources(source_client, source_project_root)
+            .into_iter()
+            .find(|descriptor| descriptor.source_id == source_source_id)
+            .map(|descriptor| descriptor.location.format())
+            .ok_or_else(|| {
+                CommandError::validation(format!(
+                    "source_source_id '{}' is not an instruction source for '{}'.",
+                    source_source_id,
+                    source_client.as_str()
+                ))
+            })?;
+
+        let destinations = InstructionSourceCatalogService::new()
+            .list_sources(destination_client, destination_project_root);
+        let destination = match destination_source_id {
+            Some(destination_source_id) => destinations
+                .into_iter()
+                .find(|descriptor| descriptor.source_id == destination_source_id),
+            None => destinations
+                .iter()
+                .find(|descriptor| descriptor.source_scope == source_record.source_scope)
+                .or(destinations.first())
+                .cloned(),
+        }
+        .ok_or_else(|| {
+            CommandError::validation(format!(
+                "No instruction destination for '{}'{}.",
+                destination_client.as_str(),
+                if destination_project_root.is_some() {
+                    ""
+                } else {
+                    " without destination_project_root"
+                }
+            ))
+        })?;
+
+        let destination_target_id = destination_target_id
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .map(str::to_string)
+            .unwrap_or_else(|| destination.location.default_logical_id(source_target_id));
+        let destination_exists = destination
+            .location
+            .entry_path(&destination_target_id)
+            .is_some_and(|path| path.is_file());
+
+        if destination_exists && !overwrite {
+            return Err(CommandError::validation(format!(
+                "Instruction '{}' already exists in {} for '{}'. Set overwrite=true or choose a different destination_target_id.",
+                destination_target_id,
+                destination.source_label,
+                destination_client.as_str()
+            )));
+        }
+
+        let conversion = convert_instruction(
+            source_record
+                .manifest_content
+                .as_deref()
+                .unwrap_or_default(),
+            source_format,
+            destination.location.format(),
+            source_target_id,
+        );
+        let outcome = InstructionMutationService::new().mutate(
+            destination_client,
+            if destination_exists {
+                MutationAction::Update
+            } else {
+                MutationAction::Add
+            },
+            &destination_target_id,
+            destination_project_root,
+            Some(&destination.source_id),
+            Some(&json!({ "manifest": conversion.content })),
+            None,
+        )?;
+
+        let mut message = format!(
+            "Synced instruction '{}' from '{}' to '{}' as '{}' in {}.",
+            source_target_id,
+            source_client.as_str(),
+            destination_client.as_str(),
+            destination_target_id,
+            destination.source_label
+        );
+        if destination_exists {
+            message.push_str(" Overwrote the existing instruction.");
+        }
+        for note in conversion.notes {
+            message.push(' ');
+            message.push_str(&note);
+        }
+
+        Ok(InstructionReplicationResult {
+            destination_target_id,
+            destination_source_id: outcome.target_source_id,
+            message,
+        })
+    }
+}
+
+impl Default for InstructionReplicationService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::InstructionReplicationService;
+    use crate::domain::ClientKind;
+
+    #[test]
+    fn sync_converts_claude_memory_to_cursor_rule_and_back_to_agents_md() {
+        let project_root = std::env::temp_dir().join(format!(
+            "ai-manager-instruction-replication-{}",
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&project_root);
+        fs::create_dir_all(&project_root).expect("should create project root");
+        fs::write(
+            project_root.join("CLAUDE.md"),
+            "# Team conventions\n\nUse pnpm.\n",
+        )
+        .expect("should create memory file");
+        let project_root_label = project_root.display().to_string();
+        let project = Some(project_root_label.as_str());
+        let claude_source_id = format!(
+            "instruction::claude_code::project_shared::{}",
+            project_root.join("CLAUDE.md").display()
+        );
+        let rules_source_id = format!(
+            "instruction::cursor::project_shared::{}",
+            project_root.join(".cursor").join("rules").display()
+        );
+        let service = InstructionReplicationService::new();
+
+        let to_cursor = service
+            .replicate(
+                ClientKind::ClaudeCode,
+                "CLAUDE.md",
+                &claude_source_id,
+                project,
+                ClientKind::Cursor,
+                None,
+                None,
+                project,
+                false,
+            )
+            .expect("sync into Cursor rules should succeed");
+        let rule = fs::read_to_string(project_root.join(".cursor/rules/claude.mdc"))
+            .expect("rule file should exist");
+        let to_codex = service
+            .replicate(
+                ClientKind::Cursor,
+                "claude",
+                &rules_source_id,
+                project,
+                ClientKind::Codex,
+                None,
+                None,
+                project,
+                false,
+            )
+            .expect("sync into AGENTS.md should succeed");
+        let agents =
+            fs::read_to_string(project_root.join("AGENTS.md")).expect("AGENTS.md should exist");
+        let blocked = service
+            .replicate(
+                ClientKind::Cursor,
+                "claude",
+                &rules_source_id,
+                project,
+                ClientKind::Codex,
+                None,
+                None,
+                project,
+                false,
+            )
+            .expect_err("existing destination requires overwrite");
+        let _ = fs::remove_dir_all(&project_root);
+
+        assert_eq!(to_cursor.destination_target_id, "claude");
+        assert_eq!(to_cursor.destination_source_id, rules_source_id);
+        assert!(rule.starts_with(
+            "---\ndescription: \"Team conventions\"\nglobs:\nalwaysApply: true\n---\n"
+        ));
+        assert_eq!(to_codex.destination_target_id, "AGENTS.md");
+        assert_eq!(agents, "# Team conventions\n\nUse pnpm.\n");
+        assert!(blocked.message.contains("overwrite=true"));
+    }
+}
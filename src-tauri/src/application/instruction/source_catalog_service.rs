@@ -0,0 +1,256 @@
+use std::{
+    env,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    domain::{ClientKind, ResourceSourceScope},
+    infra::expand_user_path,
+};
+
+use super::format_conversion::InstructionFormat;
+
+/// Where a client reads instructions from: one Markdown file loaded in full, or a directory of
+/// Cursor `.mdc` rules where every file is its own resource.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InstructionLocation {
+    File(PathBuf),
+    RuleDirectory(PathBuf),
+}
+
+impl InstructionLocation {
+    pub fn path(&self) -> &Path {
+        match self {
+            Self::File(path) | Self::RuleDirectory(path) => path,
+        }
+    }
+
+    pub fn format(&self) -> InstructionFormat {
+        match self {
+            Self::File(_) => InstructionFormat::Markdown,
+            Self::RuleDirectory(_) => InstructionFormat::CursorRule,
+        }
+    }
+
+    /// Resolves a logical id to the file backing it, or `None` when the id cannot live here.
+    pub fn entry_path(&self, logical_id: &str) -> Option<PathBuf> {
+        match self {
+            Self::File(path) => path
+                .file_name()
+                .is_some_and(|name| name.to_string_lossy() == logical_id)
+                .then(|| path.clone()),
+            Self::RuleDirectory(directory) => is_valid_rule_name(logical_id)
+                .then(|| directory.join(format!("{}.mdc", logical_id))),
+        }
+    }
+
+    /// The logical id a replicated instruction takes by default.
+    pub fn default_logical_id(&self, source_logical_id: &str) -> String {
+        match self {
+            Self::File(path) => path
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string())
+                .unwrap_or_default(),
+            Self::RuleDirectory(_) => {
+                let stem = source_logical_id
+                    .strip_suffix(".md")
+                    .unwrap_or(source_logical_id);
+                stem.chars()
+                    .map(|ch| match ch {
+                        ch if ch.is_ascii_alphanumeric() || ch == '_' => ch.to_ascii_lowercase(),
+                        _ => '-',
+                    })
+                    .collect()
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InstructionSourceDescriptor {
+    pub client: ClientKind,
+    pub source_id: String,
+    pub source_scope: ResourceSourceScope,
+    pub source_label: String,
+    pub location: InstructionLocation,
+    pub project_root: Option<String>,
+}
+
+pub struct InstructionSourceCatalogService;
+
+impl InstructionSourceCatalogService {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn list_sources(
+        &self,
+        client: ClientKind,
+        project_root: Option<&str>,
+    ) -> Vec<InstructionSourceDescriptor> {
+        let project = project_root.map(|root| (PathBuf::from(root), root.to_string()));
+
+        match client {
+            ClientKind::ClaudeCode => {
+                let mut descriptors = vec![descriptor_for_scope(
+                    client,
+                    ResourceSourceScope::User,
+                    "User memory",
+                    InstructionLocation::File(expand_user_path("~/.claude/CLAUDE.md")),
+                    None,
+                )];
+
+                if let Some((root, label)) = project {
+                    descriptors.push(descriptor_for_scope(
+                        client,
+                        ResourceSourceScope::ProjectShared,
+                        "Project memory",
+                        InstructionLocation::File(root.join("CLAUDE.md")),
+                        Some(label.clone()),
+                    ));
+                    descriptors.push(descriptor_for_scope(
+                        client,
+                        ResourceSourceScope::ProjectPrivate,
+                        "Project local memory",
+                        InstructionLocation::File(root.join("CLAUDE.local.md")),
+                        Some(label),
+                    ));
+                }
+
+                descriptors
+            }
+            ClientKind::Codex => {
+                let mut descriptors = vec![descriptor_for_scope(
+                    client,
+                    ResourceSourceScope::User,
+                    "Global AGENTS.md",
+                    InstructionLocation::File(codex_home().join("AGENTS.md")),
+                    None,
+                )];
+
+                if let Some((root, label)) = project {
+                    descriptors.push(descriptor_for_scope(
+                        client,
+                        ResourceSourceScope::ProjectShared,
+                        "Project AGENTS.md",
+                        InstructionLocation::File(root.join("AGENTS.md")),
+                        Some(label),
+                    ));
+                }
+
+                descriptors
+            }
+            ClientKind::Cursor => project
+                .map(|(root, label)| {
+                    vec![descriptor_for_scope(
+                        client,
+                        ResourceSourceScope::ProjectShared,
+                        "Project rules",
+                        InstructionLocation::RuleDirectory(root.join(".cursor").join("rules")),
+                        Some(label),
+                    )]
+                })
+                .unwrap_or_default(),
+        }
+    }
+}
+
+impl Default for InstructionSourceCatalogService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn descriptor_for_scope(
+    client: ClientKind,
+    source_scope: ResourceSourceScope,
+    source_label: &str,
+    location: InstructionLocation,
+    project_root: Option<String>,
+) -> InstructionSourceDescriptor {
+    let source_id = format!(
+        "instruction::{}::{}::{}",
+        client.as_str(),
+        source_scope.as_str(),
+        location.path().display()
+    );
+
+    InstructionSourceDescriptor {
+        client,
+        source_id,
+        source_scope,
+        source_label: source_label.to_string(),
+        location,
+        project_root,
+    }
+}
+
+fn is_valid_rule_name(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|ch| ch.is_ascii_alphanumeric() || ch == '-' || ch == '_')
+}
+
+/// Codex keeps its state under `CODEX_HOME`, defaulting to `~/.codex`.
+fn codex_home() -> PathBuf {
+    env::var_os("CODEX_HOME")
+        .filter(|value| !value.is_empty())
+        .map(PathBuf::from)
+        .unwrap_or_else(|| expand_user_path("~/.codex"))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::{InstructionLocation, InstructionSourceCatalogService};
+    use crate::domain::{ClientKind, ResourceSourceScope};
+
+    #[test]
+    fn catalog_lists_memory_files_and_cursor_rule_directory() {
+        let service = InstructionSourceCatalogService::new();
+        let root = PathBuf::from("/Users/test/demo");
+        let claude = service.list_sources(ClientKind::ClaudeCode, Some("/Users/test/demo"));
+        let codex = service.list_sources(ClientKind::Codex, Some("/Users/test/demo"));
+        let cursor = service.list_sources(ClientKind::Cursor, Some("/Users/test/demo"));
+
+        assert_eq!(
+            claude
+                .iter()
+                .map(|descriptor| descriptor.source_scope)
+                .collect::<Vec<_>>(),
+            vec![
+                ResourceSourceScope::User,
+                ResourceSourceScope::ProjectShared,
+                ResourceSourceScope::ProjectPrivate
+            ]
+        );
+        assert_eq!(
+            claude[2].location,
+            InstructionLocation::File(root.join("CLAUDE.local.md"))
+        );
+        assert_eq!(
+            codex[1].location,
+            InstructionLocation::File(root.join("AGENTS.md"))
+        );
+        assert_eq!(
+            cursor[0].location,
+            InstructionLocation::RuleDirectory(root.join(".cursor").join("rules"))
+        );
+        assert!(service.list_sources(ClientKind::Cursor, None).is_empty());
+        assert_eq!(
+            cursor[0].location.entry_path("../escape"),
+            None,
+            "rule names cannot leave the rules directory"
+        );
+        assert_eq!(
+            cursor[0].location.default_logical_id("CLAUDE.local.md"),
+            "claude-local"
+        );
+        assert_eq!(
+            codex[1].location.default_logical_id("typescript"),
+            "AGENTS.md"
+        );
+    }
+}
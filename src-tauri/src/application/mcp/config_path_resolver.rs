@@ -1,7 +1,10 @@
 use std::{env, path::PathBuf};
 
 use crate::{
-    infra::DetectorRegistry,
+    infra::{
+        DetectorRegistry, HostOs, PathCandidateKind, PathCandidateProfile, expand_user_path,
+        support_matrix_client,
+    },
     interface::contracts::{command::CommandError, common::ClientKind, mutate::MutationAction},
 };
 
@@ -42,45 +45,34 @@ pub(super) fn preferred_mcp_config_path(client: ClientKind) -> PathBuf {
 }
 
 fn existing_mcp_config_path(client: ClientKind) -> Option<PathBuf> {
-    if let Some(override_value) = read_first_env(override_env_names(client)) {
+    let profile = mcp_path_profile(client);
+    if let Some(override_value) = read_first_env(&profile.override_env_vars) {
         let expanded = expand_user_path(&override_value);
         return expanded.is_file().then_some(expanded);
     }
 
-    fallback_mcp_config_paths(client)
+    profile
+        .fallback_paths
         .iter()
         .map(|value| expand_user_path(value))
         .find(|path| path.is_file())
 }
 
 pub(super) fn default_mcp_config_path(client: ClientKind) -> PathBuf {
-    if let Some(override_value) = read_first_env(override_env_names(client)) {
+    let profile = mcp_path_profile(client);
+    if let Some(override_value) = read_first_env(&profile.override_env_vars) {
         return expand_user_path(&override_value);
     }
 
-    fallback_mcp_config_paths(client)
+    profile
+        .fallback_paths
         .first()
         .map(|value| expand_user_path(value))
         .unwrap_or_default()
 }
 
-fn override_env_names(client: ClientKind) -> &'static [&'static str] {
-    match client {
-        ClientKind::ClaudeCode => &["AI_MANAGER_CLAUDE_CODE_MCP_CONFIG"],
-        ClientKind::Codex => &["AI_MANAGER_CODEX_MCP_CONFIG"],
-        ClientKind::Cursor => &["AI_MANAGER_CURSOR_MCP_CONFIG"],
-    }
-}
-
-fn fallback_mcp_config_paths(client: ClientKind) -> &'static [&'static str] {
-    match client {
-        ClientKind::ClaudeCode => &["~/.claude.json", "~/.claude/claude_code_config.json"],
-        ClientKind::Codex => &["~/.codex/config.toml"],
-        ClientKind::Cursor => &[
-            "~/.cursor/mcp.json",
-            "~/Library/Application Support/Cursor/User/mcp.json",
-        ],
-    }
+fn mcp_path_profile(client: ClientKind) -> PathCandidateProfile {
+    support_matrix_client(client).path_profile(PathCandidateKind::McpConfig, HostOs::current())
 }
 
 fn read_first_env(names: &[&str]) -> Option<String> {
@@ -90,16 +82,6 @@ fn read_first_env(names: &[&str]) -> Option<String> {
         .filter(|value| !value.is_empty())
 }
 
-fn expand_user_path(value: &str) -> PathBuf {
-    if let Some(stripped) = value.strip_prefix("~/")
-        && let Some(home) = env::var_os("HOME")
-    {
-        return PathBuf::from(home).join(stripped);
-    }
-
-    PathBuf::from(value)
-}
-
 #[cfg(test)]
 mod tests {
     use std::{
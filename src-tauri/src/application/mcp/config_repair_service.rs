@@ -0,0 +1,844 @@
+use std::fs;
+
+use serde_json::{Map, Value};
+
+use crate::{
+    infra::parsers::{
+        SourcePosition, locate_jsonc_member, parse_jsonc, patch_jsonc, remove_duplicate_members,
+        strip_trailing_commas,
+    },
+    infra::security::redaction::redact_sensitive_text,
+    infra::{DetectorRegistry, SafeFileMutator, unified_diff},
+    interface::contracts::{
+        command::CommandError,
+        common::ClientKind,
+        repair::{ConfigIssue, ConfigIssueKind, ConfigRepairProposal},
+    },
+};
+
+use super::source_catalog_service::{
+    McpSourceCatalogService, McpSourceDescriptor, McpSourceStorageKind,
+};
+
+const TRANSPORT_URL_ALIASES: [&str; 2] = ["serverUrl", "httpUrl"];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct McpConfigDiagnosis {
+    pub checked_sources: usize,
+    pub proposals: Vec<ConfigRepairProposal>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct McpConfigRepairResult {
+    pub source_id: String,
+    pub source_path: String,
+    pub repaired_issues: Vec<ConfigIssue>,
+    pub backup_path: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct RepairPlan {
+    issues: Vec<ConfigIssue>,
+    repaired: Option<String>,
+}
+
+pub struct McpConfigRepairService<'a> {
+    detector_registry: &'a DetectorRegistry,
+}
+
+impl<'a> McpConfigRepairService<'a> {
+    pub fn new(detector_registry: &'a DetectorRegistry) -> Self {
+        Self { detector_registry }
+    }
+
+    pub fn diagnose(
+        &self,
+        client: ClientKind,
+        project_root: Option<&str>,
+        target_source_id: Option<&str>,
+    ) -> Result<McpConfigDiagnosis, CommandError> {
+        let descriptors = resolve_descriptors(
+            self.detector_registry,
+            client,
+            project_root,
+            target_source_id,
+        )?;
+        let mut checked_sources = 0;
+        let mut proposals = Vec::new();
+
+        for descriptor in descriptors {
+            let Some(source) = read_config(&descriptor)? else {
+                continue;
+            };
+            checked_sources += 1;
+
+            let plan = plan_repair(&descriptor, &source);
+            if plan.issues.is_empty() {
+                continue;
+            }
+
+            proposals.push(ConfigRepairProposal {
+                source_id: descriptor.source_id.clone(),
+                source_path: descriptor.container_path.display().to_string(),
+                diff: plan
+                    .repaired
+                    .as_deref()
+                    .map(|repaired| proposal_diff(&descriptor, &source, repaired))
+                    .unwrap_or_default(),
+                issues: plan.issues,
+            });
+        }
+
+        Ok(McpConfigDiagnosis {
+            checked_sources,
+            proposals,
+        })
+    }
+
+    pub fn repair(
+        &self,
+        client: ClientKind,
+        project_root: Option<&str>,
+        target_source_id: &str,
+        accepted_diff: &str,
+    ) -> Result<McpConfigRepairResult, CommandError> {
+        let Some(descriptor) = resolve_descriptors(
+            self.detector_registry,
+            client,
+            project_root,
+            Some(target_source_id),
+        )?
+        .into_iter()
+        .next() else {
+            return Err(CommandError::validation(format!(
+                "Unknown MCP source '{}'.",
+                target_source_id
+            )));
+        };
+
+        let Some(source) = read_config(&descriptor)? else {
+            return Err(CommandError::validation(format!(
+                "MCP config '{}' does not exist.",
+                descriptor.container_path.display()
+            )));
+        };
+
+        let plan = plan_repair(&descriptor, &source);
+        let Some(repaired) = plan.repaired else {
+            return Err(CommandError::validation(format!(
+                "No automatic repair is available for '{}'.",
+                descriptor.container_path.display()
+            )));
+        };
+
+        // Diagnose responses are redacted, so the accepted diff is compared in the
+        // same form. A mismatch means the file changed after it was diagnosed.
+        let current_diff = proposal_diff(&descriptor, &source, &repaired);
+        if redact_sensitive_text(&current_diff) != accepted_diff {
+            return Err(CommandError::conflict(format!(
+                "MCP config '{}' changed since it was diagnosed; run diagnose_config again.",
+                descriptor.container_path.display()
+            ))
+            .with_path(descriptor.container_path.display().to_string())
+            .with_source_id(&descriptor.source_id));
+        }
+
+        let write_result = SafeFileMutator::new()
+            .replace_file(&descriptor.container_path, repaired.as_bytes())
+            .map_err(|failure| {
+                CommandError::from_mutation_failure(&failure, &descriptor.container_path)
+                    .with_source_id(&descriptor.source_id)
+            })?;
+
+        Ok(McpConfigRepairResult {
+            source_id: descriptor.source_id,
+            source_path: descriptor.container_path.display().to_string(),
+            repaired_issues: plan
+                .issues
+                .into_iter()
+                .filter(|issue| issue.fixable)
+                .collect(),
+            backup_path: write_result.backup_path,
+        })
+    }
+}
+
+pub(super) fn resolve_descriptors(
+    detector_registry: &DetectorRegistry,
+    client: ClientKind,
+    project_root: Option<&str>,
+    target_source_id: Option<&str>,
+) -> Result<Vec<McpSourceDescriptor>, CommandError> {
+    let descriptors =
+        McpSourceCatalogService::new(detector_registry).list_sources(client, project_root);
+    let Some(target_source_id) = target_source_id else {
+        return Ok(descriptors);
+    };
+
+    let matching = descriptors
+        .into_iter()
+        .filter(|descriptor| descriptor.source_id == target_source_id)
+        .collect::<Vec<_>>();
+    if matching.is_empty() {
+        return Err(CommandError::validation(format!(
+            "target_source_id '{}' is not a known MCP source for '{}'.",
+            target_source_id,
+            client.as_str()
+        )));
+    }
+
+    Ok(matching)
+}
+
+pub(super) fn read_config(
+    descriptor: &McpSourceDescriptor,
+) -> Result<Option<String>, CommandError> {
+    match fs::read_to_string(&descriptor.container_path) {
+        Ok(source) => Ok(Some(source)),
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(error) => Err(CommandError::io(
+            format!(
+                "Failed to read MCP config '{}': {}",
+                descriptor.container_path.display(),
+                error
+            ),
+            error.kind(),
+        )
+        .with_path(descriptor.container_path.display().to_string())
+        .with_source_id(&descriptor.source_id)),
+    }
+}
+
+fn proposal_diff(descriptor: &McpSourceDescriptor, source: &str, repaired: &str) -> String {
+    unified_diff(
+        &descriptor.container_path.display().to_string(),
+        source,
+        repaired,
+    )
+}
+
+fn plan_repair(descriptor: &McpSourceDescriptor, source: &str) -> RepairPlan {
+    let mut issues = Vec::new();
+    let repaired = match descriptor.storage_kind {
+        McpSourceStorageKind::JsonSection => {
+            repair_json_source(source, &descriptor.selector, &mut issues)
+        }
+        McpSourceStorageKind::TomlTable => repair_toml_source(source, &mut issues),
+    };
+
+    RepairPlan {
+        repaired: repaired.filter(|repaired| repaired != source),
+        issues,
+    }
+}
+
+fn repair_json_source(
+    source: &str,
+    selector: &str,
+    issues: &mut Vec<ConfigIssue>,
+) -> Option<String> {
+    let tokens = selector
+        .split('/')
+        .skip(1)
+        .map(|token| token.replace("~1", "/").replace("~0", "~"))
+        .collect::<Vec<_>>();
+    let path = tokens.iter().map(String::as_str).collect::<Vec<_>>();
+
+    let (mut content, trailing_commas) = strip_trailing_commas(source);
+    for position in trailing_commas {
+        issues.push(issue(
+            ConfigIssueKind::TrailingComma,
+            "Trailing comma before a closing bracket; strict JSON readers reject it.".to_string(),
+            Some(position),
+            None,
+            true,
+        ));
+    }
+
+    let document = match parse_jsonc(&content) {
+        Ok(document) => document,
+        Err(error) => {
+            issues.push(issue(
+                ConfigIssueKind::Syntax,
+                format!("Invalid JSON payload: {error}"),
+                Some(SourcePosition {
+                    line: error.line(),
+                    column: error.column(),
+                }),
+                None,
+                false,
+            ));
+            return None;
+        }
+    };
+    let Some(section) = document.value.pointer(selector) else {
+        return Some(content);
+    };
+
+    if section.is_object()
+        && let Some((deduplicated, removed)) = remove_duplicate_members(&content, &path)
+    {
+        for (server_name, position) in removed {
+            issues.push(issue(
+                ConfigIssueKind::DuplicateServerKey,
+                format!(
+                    "Server `{server_name}` is defined more than once; only the last definition is loaded, earlier ones will be removed."
+                ),
+                Some(position),
+                Some(server_name),
+                true,
+            ));
+        }
+        content = deduplicated;
+    }
+
+    let mut value = match parse_jsonc(&content) {
+        Ok(document) => document.value,
+        Err(_) => return Some(content),
+    };
+    let Some(section) = value.pointer_mut(selector) else {
+        return Some(content);
+    };
+
+    if !section.is_object() {
+        let replacement = section_replacement(section);
+        issues.push(issue(
+            ConfigIssueKind::SectionNotObject,
+            match replacement {
+                Some(_) => format!(
+                    "MCP section is {} instead of an object map; it will be converted.",
+                    json_type_name(section)
+                ),
+                None => format!(
+                    "MCP section is {} instead of an object map and cannot be converted automatically.",
+                    json_type_name(section)
+                ),
+            },
+            locate_jsonc_member(source, &path),
+            None,
+            replacement.is_some(),
+        ));
+        let Some(replacement) = replacement else {
+            return Some(content);
+        };
+        *section = Value::Object(replacement);
+    }
+
+    let Some(servers) = section.as_object_mut() else {
+        return Some(content);
+    };
+    let mut removed_servers = Vec::new();
+    for (server_name, entry) in servers.iter_mut() {
+        let Some(entry) = entry.as_object_mut() else {
+            continue;
+        };
+        if has_transport(entry) {
+            continue;
+        }
+
+        let mut server_path = path.clone();
+        server_path.push(server_name);
+        let position = locate_jsonc_member(source, &server_path);
+        let alias = TRANSPORT_URL_ALIASES.into_iter().find(|alias| {
+            entry
+                .get(*alias)
+                .and_then(Value::as_str)
+                .is_some_and(|url| !url.trim().is_empty())
+        });
+
+        match alias {
+            Some(alias) => {
+                let url = entry.remove(alias).unwrap_or(Value::Null);
+                entry.insert("url".to_string(), url);
+                issues.push(issue(
+                    ConfigIssueKind::ServerTransportMissing,
+                    format!(
+                        "Server `{server_name}` uses `{alias}` instead of `url`; it will be renamed."
+                    ),
+                    position,
+                    Some(server_name.clone()),
+                    true,
+                ));
+            }
+            None => {
+                removed_servers.push(server_name.clone());
+                issues.push(issue(
+                    ConfigIssueKind::ServerTransportMissing,
+                    format!(
+                        "Server `{server_name}` has neither `command` nor `url`; the entry will be removed."
+                    ),
+                    position,
+                    Some(server_name.clone()),
+                    true,
+                ));
+            }
+        }
+    }
+    for server_name in removed_servers {
+        servers.remove(&server_name);
+    }
+
+    if parse_jsonc(&content).is_ok_and(|document| document.value == value) {
+        return Some(content);
+    }
+
+    patch_jsonc(&content, &value).or_else(|| {
+        serde_json::to_string_pretty(&value)
+            .ok()
+            .map(|serialized| format!("{serialized}\n"))
+    })
+}
+
+fn has_transport(entry: &Map<String, Value>) -> bool {
+    ["command", "url"].into_iter().any(|key| {
+        entry
+            .get(key)
+            .and_then(Value::as_str)
+            .is_some_and(|value| !value.trim().is_empty())
+    })
+}
+
+// `null` becomes an empty map and arrays of `{ "name": ... }` objects become a map
+// keyed by name; anything else needs a manual fix.
+fn section_replacement(section: &Value) -> Option<Map<String, Value>> {
+    match section {
+        Value::Null => Some(Map::new()),
+        Value::Array(entries) => {
+            let mut servers = Map::new();
+            for entry in entries {
+                let mut entry = entry.as_object()?.clone();
+                let Some(Value::String(name)) = entry.remove("name") else {
+                    return None;
+                };
+                if servers.insert(name, Value::Object(entry)).is_some() {
+                    return None;
+                }
+            }
+            Some(servers)
+        }
+        _ => None,
+    }
+}
+
+fn json_type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "a boolean",
+        Value::Number(_) => "a number",
+        Value::String(_) => "a string",
+        Value::Array(_) => "an array",
+        Value::Object(_) => "an object",
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct TomlServerBlock {
+    name: String,
+    start: usize,
+    end: usize,
+}
+
+fn repair_toml_source(source: &str, issues: &mut Vec<ConfigIssue>) -> Option<String> {
+    let blocks = toml_server_blocks(source);
+    let mut removed_ranges: Vec<(usize, usize)> = Vec::new();
+
+    for (index, block) in blocks.iter().enumerate() {
+        if blocks[index + 1..]
+            .iter()
+            .any(|later| later.name == block.name)
+        {
+            issues.push(issue(
+                ConfigIssueKind::DuplicateServerKey,
+                format!(
+                    "Server `{}` is defined more than once; earlier definitions will be removed.",
+                    block.name
+                ),
+                Some(SourcePosition::from_offset(source, block.start)),
+                Some(block.name.clone()),
+                true,
+            ));
+            removed_ranges.push((block.start, block.end));
+        }
+    }
+
+    let content = remove_ranges(source, &removed_ranges);
+    let table = match toml::from_str::<toml::Table>(&content) {
+        Ok(table) => table,
+        Err(error) => {
+            issues.push(issue(
+                ConfigIssueKind::Syntax,
+                format!("Invalid TOML payload: {error}"),
+                error
+                    .span()
+                    .map(|span| SourcePosition::from_offset(&content, span.start)),
+                None,
+                false,
+            ));
+            return None;
+        }
+    };
+
+    let Some(section) = table.get("mcp_servers") else {
+        return Some(content);
+    };
+    let Some(servers) = section.as_table() else {
+        issues.push(issue(
+            ConfigIssueKind::SectionNotObject,
+            "MCP section `mcp_servers` is not a table and cannot be converted automatically."
+                .to_string(),
+            None,
+            None,
+            false,
+        ));
+        return Some(content);
+    };
+
+    let blocks = toml_server_blocks(&content);
+    let mut removed_ranges = Vec::new();
+    for (server_name, entry) in servers {
+        let has_transport = entry.as_table().is_some_and(|entry| {
+            ["command", "url"].into_iter().any(|key| {
+                entry
+                    .get(key)
+                    .and_then(toml::Value::as_str)
+                    .is_some_and(|value| !value.trim().is_empty())
+            })
+        });
+        if has_transport || !entry.is_table() {
+            continue;
+        }
+
+        let block = blocks.iter().find(|block| block.name == *server_name);
+        issues.push(issue(
+            ConfigIssueKind::ServerTransportMissing,
+            match block {
+                Some(_) => format!(
+                    "Server `{server_name}` has neither `command` nor `url`; the entry will be removed."
+                ),
+                None => format!(
+                    "Server `{server_name}` has neither `command` nor `url` and is not a standalone table; fix it manually."
+                ),
+            },
+            block.map(|block| SourcePosition::from_offset(&content, block.start)),
+            Some(server_name.clone()),
+            block.is_some(),
+        ));
+        if let Some(block) = block {
+            removed_ranges.push((block.start, block.end));
+        }
+    }
+
+    Some(remove_ranges(&content, &removed_ranges))
+}
+
+// Each block spans a `[mcp_servers.<name>]` header, its nested sub-tables, and the
+// lines up to the next unrelated header.
+fn toml_server_blocks(source: &str) -> Vec<TomlServerBlock> {
+    let mut blocks: Vec<TomlServerBlock> = Vec::new();
+    let mut open_block: Option<TomlServerBlock> = None;
+    let mut offset = 0;
+
+    for line in source.split_inclusive('\n') {
+        let trimmed = line.trim();
+        if trimmed.starts_with('[') {
+            let header = parse_toml_server_header(trimmed);
+            let continues_open = match (&open_block, &header) {
+                (Some(block), Some((name, true))) => block.name == *name,
+                _ => false,
+            };
+            if !continues_open {
+                if let Some(mut block) = open_block.take() {
+                    block.end = offset;
+                    blocks.push(block);
+                }
+                if let Some((name, false)) = header {
+                    open_block = Some(TomlServerBlock {
+                        name,
+                        start: offset,
+                        end: source.len(),
+                    });
+                }
+            }
+        }
+        offset += line.len();
+    }
+
+    if let Some(block) = open_block {
+        blocks.push(block);
+    }
+
+    blocks
+}
+
+// Returns the server name and whether the header is a nested sub-table.
+fn parse_toml_server_header(header: &str) -> Option<(String, bool)> {
+    if header.starts_with("[[") {
+        return None;
+    }
+    let inner = header.strip_prefix('[')?.split(']').next()?.trim();
+    let rest = inner.strip_prefix("mcp_servers")?.trim_start();
+    let rest = rest.strip_prefix('.')?.trim_start();
+
+    let (name, remainder) = match rest.strip_prefix('"') {
+        Some(quoted) => {
+            let end = quoted.find('"')?;
+            (quoted[..end].to_string(), &quoted[end + 1..])
+        }
+        None => match rest.find('.') {
+            Some(index) => (rest[..index].trim().to_string(), &rest[index..]),
+            None => (rest.trim().to_string(), ""),
+        },
+    };
+
+    (!name.is_empty()).then(|| (name, !remainder.trim().is_empty()))
+}
+
+fn remove_ranges(source: &str, ranges: &[(usize, usize)]) -> String {
+    let mut content = source.to_string();
+    let mut ranges = ranges.to_vec();
+    ranges.sort();
+    for (start, end) in ranges.into_iter().rev() {
+        content.replace_range(start..end, "");
+    }
+    content
+}
+
+fn issue(
+    kind: ConfigIssueKind,
+    message: String,
+    position: Option<SourcePosition>,
+    server_name: Option<String>,
+    fixable: bool,
+) -> ConfigIssue {
+    ConfigIssue {
+        kind,
+        message,
+        line: position.map(|position| position.line),
+        column: position.map(|position| position.column),
+        server_name,
+        fixable,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, path::PathBuf};
+
+    use crate::{
+        domain::{ClientKind, ResourceSourceScope},
+        infra::DetectorRegistry,
+        infra::parsers::parse_jsonc,
+        infra::security::redaction::redact_sensitive_text,
+        interface::contracts::repair::ConfigIssueKind,
+    };
+
+    use super::{
+        super::source_catalog_service::{McpSourceDescriptor, McpSourceStorageKind},
+        McpConfigRepairService, plan_repair,
+    };
+
+    #[test]
+    fn json_plan_fixes_trailing_commas_duplicates_and_missing_transports() {
+        let source = r#"{
+  // keep me
+  "mcpServers": {
+    "docs": { "command": "old-docs" },
+    "docs": { "command": "docs-mcp" },
+    "remote": { "serverUrl": "https://mcp.example.com/sse" },
+    "empty": { "args": ["x"] },
+  },
+}
+"#;
+
+        let plan = plan_repair(&json_descriptor("/tmp/cursor.json"), source);
+        let kinds = plan
+            .issues
+            .iter()
+            .map(|issue| issue.kind)
+            .collect::<Vec<_>>();
+        let repaired = plan.repaired.expect("repair should be proposed");
+        let document = parse_jsonc(&repaired).expect("repaired config should parse");
+        let value = document.value["mcpServers"].clone();
+
+        assert_eq!(
+            kinds,
+            vec![
+                ConfigIssueKind::TrailingComma,
+                ConfigIssueKind::TrailingComma,
+                ConfigIssueKind::DuplicateServerKey,
+                ConfigIssueKind::ServerTransportMissing,
+                ConfigIssueKind::ServerTransportMissing,
+            ]
+        );
+        assert!(repaired.contains("// keep me"));
+        assert!(!document.has_trailing_commas);
+        assert_eq!(value["docs"]["command"], "docs-mcp");
+        assert_eq!(value["remote"]["url"], "https://mcp.example.com/sse");
+        assert!(value.get("empty").is_none());
+        assert_eq!(plan.issues[2].line, Some(4));
+    }
+
+    #[test]
+    fn json_plan_converts_named_array_section() {
+        let source = r#"{ "mcpServers": [{ "name": "fs", "command": "npx" }] }"#;
+
+        let plan = plan_repair(&json_descriptor("/tmp/cursor.json"), source);
+        let repaired = plan.repaired.expect("repair should be proposed");
+        let value: serde_json::Value =
+            serde_json::from_str(&repaired).expect("repaired config should parse");
+
+        assert_eq!(plan.issues[0].kind, ConfigIssueKind::SectionNotObject);
+        assert!(plan.issues[0].fixable);
+        assert_eq!(value["mcpServers"]["fs"]["command"], "npx");
+    }
+
+    #[test]
+    fn json_plan_reports_unfixable_syntax_errors() {
+        let plan = plan_repair(
+            &json_descriptor("/tmp/cursor.json"),
+            "{\n  \"mcpServers\": {\n    \"a\": \n}",
+        );
+
+        assert_eq!(plan.repaired, None);
+        assert_eq!(plan.issues[0].kind, ConfigIssueKind::Syntax);
+        assert!(!plan.issues[0].fixable);
+    }
+
+    #[test]
+    fn toml_plan_removes_duplicate_and_transportless_tables() {
+        let source = r#"model = "o3"
+
+[mcp_servers.docs]
+command = "old-docs"
+
+[mcp_servers.docs.env]
+TOKEN_FILE = "/tmp/old"
+
+[mcp_servers.docs]
+command = "docs-mcp"
+
+[mcp_servers.empty]
+args = ["x"]
+
+[profiles.default]
+model = "o3"
+"#;
+
+        let plan = plan_repair(&toml_descriptor(), source);
+        let repaired = plan.repaired.expect("repair should be proposed");
+
+        assert_eq!(
+            plan.issues
+                .iter()
+                .map(|issue| issue.kind)
+                .collect::<Vec<_>>(),
+            vec![
+                ConfigIssueKind::DuplicateServerKey,
+                ConfigIssueKind::ServerTransportMissing,
+            ]
+        );
+        assert_eq!(
+            repaired,
+            "model = \"o3\"\n\n[mcp_servers.docs]\ncommand = \"docs-mcp\"\n\n[profiles.default]\nmodel = \"o3\"\n"
+        );
+    }
+
+    #[test]
+    fn repair_applies_accepted_diff_and_rejects_stale_diff() {
+        let temp_dir = temp_root("apply");
+        let project_root = temp_dir.join("workspace");
+        let config_path = project_root.join(".cursor").join("mcp.json");
+        let _ = fs::create_dir_all(config_path.parent().expect("config parent"));
+        fs::write(
+            &config_path,
+            "{\n  \"mcpServers\": {\n    \"fs\": { \"command\": \"npx\" },\n  }\n}\n",
+        )
+        .expect("should write config");
+        let project_root_string = project_root.display().to_string();
+
+        let detector_registry = DetectorRegistry::with_installed_version("1.0.0");
+        let service = McpConfigRepairService::new(&detector_registry);
+        let source_id = format!(
+            "mcp::cursor::project_shared::{}::/mcpServers",
+            config_path.display()
+        );
+        let diagnosis = service
+            .diagnose(
+                ClientKind::Cursor,
+                Some(project_root_string.as_str()),
+                Some(source_id.as_str()),
+            )
+            .expect("diagnose should succeed");
+        let proposal = &diagnosis.proposals[0];
+        let accepted_diff = redact_sensitive_text(&proposal.diff);
+
+        let stale = service.repair(
+            ClientKind::Cursor,
+            Some(project_root_string.as_str()),
+            &source_id,
+            "--- stale\n",
+        );
+        let result = service
+            .repair(
+                ClientKind::Cursor,
+                Some(project_root_string.as_str()),
+                &source_id,
+                &accepted_diff,
+            )
+            .expect("repair should succeed");
+        let content = fs::read_to_string(&config_path).expect("should read repaired config");
+        let _ = fs::remove_dir_all(&temp_dir);
+
+        assert_eq!(diagnosis.checked_sources, 1);
+        assert!(
+            proposal
+                .diff
+                .contains("-    \"fs\": { \"command\": \"npx\" },")
+        );
+        assert!(
+            stale
+                .expect_err("stale diff should be rejected")
+                .message
+                .contains("changed since it was diagnosed")
+        );
+        assert_eq!(result.repaired_issues.len(), 1);
+        assert!(result.backup_path.is_some());
+        assert_eq!(
+            content,
+            "{\n  \"mcpServers\": {\n    \"fs\": { \"command\": \"npx\" }\n  }\n}\n"
+        );
+    }
+
+    fn json_descriptor(path: &str) -> McpSourceDescriptor {
+        McpSourceDescriptor {
+            client: ClientKind::Cursor,
+            source_id: format!("mcp::cursor::user::{path}::/mcpServers"),
+            source_scope: ResourceSourceScope::User,
+            source_label: "Personal config".to_string(),
+            container_path: path.into(),
+            selector: "/mcpServers".to_string(),
+            storage_kind: McpSourceStorageKind::JsonSection,
+            project_root: None,
+        }
+    }
+
+    fn toml_descriptor() -> McpSourceDescriptor {
+        McpSourceDescriptor {
+            client: ClientKind::Codex,
+            source_id: "mcp::codex::user::/tmp/config.toml::mcp_servers".to_string(),
+            source_scope: ResourceSourceScope::User,
+            source_label: "Personal config".to_string(),
+            container_path: "/tmp/config.toml".into(),
+            selector: "mcp_servers".to_string(),
+            storage_kind: McpSourceStorageKind::TomlTable,
+            project_root: None,
+        }
+    }
+
+    fn temp_root(suffix: &str) -> PathBuf {
+        std::env::temp_dir().join(format!(
+            "ai-manager-mcp-repair-{suffix}-{}",
+            std::process::id()
+        ))
+    }
+}
@@ -0,0 +1,234 @@
+use std::collections::BTreeMap;
+
+use crate::{
+    domain::ResourceRecord,
+    infra::{McpInventoryStore, content_revision},
+    interface::contracts::{
+        inventory::{
+            McpServerInventory, McpServerInventoryEntry, McpToolCollision, McpToolInventoryResponse,
+        },
+        probe::{McpProbeStatus, ProbeMcpServerResponse},
+    },
+};
+
+pub struct McpInventoryService<'a> {
+    store: &'a McpInventoryStore,
+}
+
+impl<'a> McpInventoryService<'a> {
+    pub fn new(store: &'a McpInventoryStore) -> Self {
+        Self { store }
+    }
+
+    pub fn record_probe(
+        &self,
+        resource: &ResourceRecord,
+        response: &ProbeMcpServerResponse,
+        probed_at_epoch_ms: u128,
+    ) {
+        if response.status != McpProbeStatus::Healthy || response.tool_count.is_none() {
+            return;
+        }
+
+        self.store.record(McpServerInventory {
+            resource_id: resource.id.clone(),
+            client: resource.client,
+            display_name: resource.display_name.clone(),
+            transport_fingerprint: transport_fingerprint(resource),
+            protocol_version: response.protocol_version.clone(),
+            server_name: response.server_name.clone(),
+            server_version: response.server_version.clone(),
+            tool_names: response.tool_names.clone(),
+            prompt_names: response.prompt_names.clone(),
+            resource_uris: response.resource_uris.clone(),
+            probed_at_epoch_ms,
+        });
+    }
+
+    /// Pairs each listed server with its cached inventory and flags tool names exposed by more
+    /// than one enabled, effective server of the same client.
+    pub fn report(
+        &self,
+        items: &[ResourceRecord],
+        project_root: Option<String>,
+        warning: Option<String>,
+    ) -> McpToolInventoryResponse {
+        let servers: Vec<McpServerInventoryEntry> = items
+            .iter()
+            .map(|resource| {
+                let inventory = self.store.get(&resource.id);
+                let stale = inventory.as_ref().is_some_and(|inventory| {
+                    inventory.transport_fingerprint != transport_fingerprint(resource)
+                });
+
+                McpServerInventoryEntry {
+                    client: resource.client,
+                    resource_id: resource.id.clone(),
+                    display_name: resource.display_name.clone(),
+                    source_label: resource.source_label.clone(),
+                    enabled: resource.enabled && resource.is_effective,
+                    inventory,
+                    stale,
+                }
+            })
+            .collect();
+
+        let collisions = find_collisions(&servers);
+
+        McpToolInventoryResponse {
+            project_root,
+            servers,
+            collisions,
+            warning,
+        }
+    }
+}
+
+fn transport_fingerprint(resource: &ResourceRecord) -> String {
+    let mut material = String::new();
+    for part in [
+        resource.transport_command.as_deref().unwrap_or_default(),
+        resource.transport_url.as_deref().unwrap_or_default(),
+    ]
+    .into_iter()
+    .chain(resource.transport_args.iter().flatten().map(String::as_str))
+    {
+        material.push_str(part);
+        material.push('\0');
+    }
+
+    content_revision(material.as_bytes())
+}
+
+fn find_collisions(servers: &[McpServerInventoryEntry]) -> Vec<McpToolCollision> {
+    let mut exposures: BTreeMap<(String, String), Vec<&McpServerInventoryEntry>> = BTreeMap::new();
+
+    for server in servers
+        .iter()
+        .filter(|server| server.enabled && !server.stale)
+    {
+        let Some(inventory) = server.inventory.as_ref() else {
+            continue;
+        };
+        for tool_name in &inventory.tool_names {
+            exposures
+                .entry((server.client.as_str().to_string(), tool_name.clone()))
+                .or_default()
+                .push(server);
+        }
+    }
+
+    exposures
+        .into_iter()
+        .filter(|(_, servers)| servers.len() > 1)
+        .map(|((_, tool_name), servers)| McpToolCollision {
+            client: servers[0].client,
+            tool_name,
+            resource_ids: servers
+                .iter()
+                .map(|server| server.resource_id.clone())
+                .collect(),
+            display_names: servers
+                .iter()
+                .map(|server| server.display_name.clone())
+                .collect(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::McpInventoryService;
+    use crate::{
+        domain::{ClientKind, ResourceRecord, ResourceSourceScope},
+        infra::McpInventoryStore,
+        interface::contracts::probe::{McpProbeStatus, ProbeMcpServerResponse},
+    };
+
+    fn record(client: ClientKind, name: &str, command: &str) -> ResourceRecord {
+        ResourceRecord {
+            id: format!("{}::mcp::{name}", client.as_str()),
+            logical_id: format!("mcp::{name}"),
+            client,
+            display_name: name.to_string(),
+            enabled: true,
+            transport_kind: Some("stdio".to_string()),
+            transport_command: Some(command.to_string()),
+            transport_args: None,
+            transport_url: None,
+            source_path: None,
+            source_id: format!("mcp::{}::user", client.as_str()),
+            source_scope: ResourceSourceScope::User,
+            source_label: "Personal".to_string(),
+            is_effective: true,
+            shadowed_by: None,
+            description: None,
+            install_kind: None,
+            manifest_content: None,
+            approval: None,
+        }
+    }
+
+    fn healthy(resource: &ResourceRecord, tools: &[&str]) -> ProbeMcpServerResponse {
+        ProbeMcpServerResponse {
+            client: resource.client,
+            target_id: resource.display_name.clone(),
+            status: McpProbeStatus::Healthy,
+            protocol_version: Some("2025-06-18".to_string()),
+            server_name: None,
+            server_version: None,
+            tool_count: Some(tools.len()),
+            tool_names: tools.iter().map(|tool| tool.to_string()).collect(),
+            prompt_names: Vec::new(),
+            resource_uris: Vec::new(),
+            remote_transport: None,
+            http_status: None,
+            resolved_addresses: Vec::new(),
+            elapsed_ms: 5,
+            stderr_tail: Vec::new(),
+            message: String::new(),
+        }
+    }
+
+    #[test]
+    fn duplicate_tool_names_collide_only_within_the_same_client() {
+        let store = McpInventoryStore::new();
+        let service = McpInventoryService::new(&store);
+        let github = record(ClientKind::Cursor, "github", "gh-mcp");
+        let gitlab = record(ClientKind::Cursor, "gitlab", "gl-mcp");
+        let mut disabled = record(ClientKind::Cursor, "mirror", "mirror-mcp");
+        disabled.enabled = false;
+        let other_client = record(ClientKind::Codex, "github", "gh-mcp");
+
+        service.record_probe(&github, &healthy(&github, &["search", "open_pr"]), 1);
+        service.record_probe(&gitlab, &healthy(&gitlab, &["search", "open_mr"]), 2);
+        service.record_probe(&disabled, &healthy(&disabled, &["open_pr"]), 3);
+        service.record_probe(&other_client, &healthy(&other_client, &["search"]), 4);
+
+        let report = service.report(&[github, gitlab, disabled, other_client], None, None);
+
+        assert_eq!(report.servers.len(), 4);
+        assert_eq!(report.collisions.len(), 1);
+        assert_eq!(report.collisions[0].client, ClientKind::Cursor);
+        assert_eq!(report.collisions[0].tool_name, "search");
+        assert_eq!(report.collisions[0].display_names, vec!["github", "gitlab"]);
+    }
+
+    #[test]
+    fn changed_transport_marks_inventory_stale_and_skips_it() {
+        let store = McpInventoryStore::new();
+        let service = McpInventoryService::new(&store);
+        let first = record(ClientKind::Cursor, "first", "first-mcp");
+        let second = record(ClientKind::Cursor, "second", "second-mcp");
+        service.record_probe(&first, &healthy(&first, &["search"]), 1);
+        service.record_probe(&second, &healthy(&second, &["search"]), 2);
+
+        let mut relaunched = first.clone();
+        relaunched.transport_args = Some(vec!["--beta".to_string()]);
+        let report = service.report(&[relaunched, second], None, None);
+
+        assert!(report.servers[0].stale);
+        assert!(!report.servers[1].stale);
+        assert!(report.collisions.is_empty());
+    }
+}
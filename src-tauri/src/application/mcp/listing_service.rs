@@ -1,30 +1,44 @@
-use std::{collections::HashMap, fs, io};
+use std::{
+    collections::{HashMap, HashSet},
+    fs, io,
+};
 
 use serde_json::{Value, json};
 
 use crate::{
-    domain::{ResourceSourceMetadata, ResourceSourceScope},
-    infra::DetectorRegistry,
-    infra::parsers::{ParseOutcome, ParserRegistry},
+    domain::{McpApprovalState, ResourceSourceMetadata, ResourceSourceScope},
+    infra::parsers::{
+        ParseOutcome, ParsedMcpServer, ParserRegistry, SourcePosition, locate_jsonc_member,
+        parse_jsonc,
+    },
+    infra::{DetectorRegistry, ParkedServer, ParkedServerStore, content_revision},
     interface::contracts::{
+        command::ErrorCategory,
         common::ClientKind,
-        list::{ListResourcesRequest, ResourceRecord},
+        list::{
+            DiagnosticSeverity, ListResourcesRequest, ResourceDiagnostic, ResourceRecord,
+            SourceRevision,
+        },
     },
 };
 
-use super::source_catalog_service::{
-    McpSourceCatalogService, McpSourceDescriptor, McpSourceStorageKind,
+use super::{
+    project_approvals::{ClaudeProjectApprovals, claude_settings_paths},
+    source_catalog_service::{McpSourceCatalogService, McpSourceDescriptor, McpSourceStorageKind},
 };
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct McpListResult {
     pub items: Vec<ResourceRecord>,
     pub warning: Option<String>,
+    pub diagnostics: Vec<ResourceDiagnostic>,
+    pub source_revisions: Vec<SourceRevision>,
 }
 
 pub struct McpListingService<'a> {
     detector_registry: &'a DetectorRegistry,
     parser_registry: ParserRegistry,
+    parked_store: Option<ParkedServerStore>,
 }
 
 impl<'a> McpListingService<'a> {
@@ -32,6 +46,7 @@ impl<'a> McpListingService<'a> {
         Self {
             detector_registry,
             parser_registry: ParserRegistry::new(),
+            parked_store: ParkedServerStore::default_location(),
         }
     }
 
@@ -44,9 +59,20 @@ impl<'a> McpListingService<'a> {
             })
             .collect::<Vec<_>>();
 
-        collect_from_descriptors(&self.parser_registry, descriptors, request, |path| {
-            fs::read_to_string(path)
-        })
+        let parked = self
+            .parked_store
+            .as_ref()
+            .and_then(|store| store.load().ok())
+            .map(|snapshot| snapshot.servers)
+            .unwrap_or_default();
+
+        collect_from_descriptors(
+            &self.parser_registry,
+            descriptors,
+            &parked,
+            request,
+            |path| fs::read_to_string(path),
+        )
     }
 }
 
@@ -66,6 +92,7 @@ fn requested_clients(
 fn collect_from_descriptors<I, F>(
     parser_registry: &ParserRegistry,
     descriptors: I,
+    parked: &[ParkedServer],
     request: &ListResourcesRequest,
     read_source: F,
 ) -> McpListResult
@@ -73,9 +100,12 @@ where
     I: IntoIterator<Item = McpSourceDescriptor>,
     F: Fn(&str) -> io::Result<String>,
 {
+    let descriptors: Vec<McpSourceDescriptor> = descriptors.into_iter().collect();
     let mut items: Vec<ResourceRecord> = Vec::new();
-    let mut warnings: Vec<String> = Vec::new();
-    for descriptor in descriptors {
+    let mut diagnostics: Vec<ResourceDiagnostic> = Vec::new();
+    let mut source_revisions: Vec<SourceRevision> = Vec::new();
+    let mut configured: HashSet<(String, String)> = HashSet::new();
+    for descriptor in &descriptors {
         if request
             .scope_filter
             .as_ref()
@@ -85,36 +115,57 @@ where
         }
 
         let source = match read_source(&descriptor.container_path.display().to_string()) {
-            Ok(source) => source,
+            Ok(source) => {
+                source_revisions.push(SourceRevision {
+                    source_id: descriptor.source_id.clone(),
+                    source_path: descriptor.container_path.display().to_string(),
+                    revision: Some(content_revision(source.as_bytes())),
+                });
+                source
+            }
             Err(error) => {
                 if error.kind() == io::ErrorKind::NotFound {
+                    source_revisions.push(SourceRevision {
+                        source_id: descriptor.source_id.clone(),
+                        source_path: descriptor.container_path.display().to_string(),
+                        revision: None,
+                    });
                     continue;
                 }
-                warnings.push(format!(
-                    "[{}:CONFIG_READ] failed to read '{}': {}",
-                    descriptor.client.as_str(),
-                    descriptor.container_path.display(),
-                    error
-                ));
+                diagnostics.push(
+                    source_diagnostic(
+                        descriptor,
+                        DiagnosticSeverity::Error,
+                        "CONFIG_READ",
+                        format!(
+                            "failed to read '{}': {}",
+                            descriptor.container_path.display(),
+                            error
+                        ),
+                        None,
+                        None,
+                    )
+                    .with_category(ErrorCategory::from_io_kind(error.kind())),
+                );
                 continue;
             }
         };
 
-        let Some(parse_input) = (match build_parse_input(&descriptor, &source) {
+        let Some(parse_input) = (match build_parse_input(descriptor, &source) {
             Ok(parse_input) => parse_input,
             Err(error) => {
-                let warning_code = if error.starts_with("Invalid JSON payload:") {
-                    "PARSER_JSON_SYNTAX"
-                } else {
-                    "CONFIG_SECTION_INVALID"
-                };
-                warnings.push(format!(
-                    "[{}:{}] failed to resolve '{}' in '{}': {}",
-                    descriptor.client.as_str(),
-                    warning_code,
-                    descriptor.selector,
-                    descriptor.container_path.display(),
-                    error
+                diagnostics.push(source_diagnostic(
+                    descriptor,
+                    DiagnosticSeverity::Error,
+                    error.code,
+                    format!(
+                        "failed to resolve '{}' in '{}': {}",
+                        descriptor.selector,
+                        descriptor.container_path.display(),
+                        error.message
+                    ),
+                    None,
+                    error.position,
                 ));
                 continue;
             }
@@ -122,75 +173,97 @@ where
             continue;
         };
 
-        let parse_outcome = parser_registry.parse_client_config(descriptor.client, &parse_input);
+        if parse_input.has_comments {
+            diagnostics.push(source_diagnostic(
+                descriptor,
+                DiagnosticSeverity::Info,
+                "PARSER_JSONC_COMMENTS",
+                format!(
+                    "'{}' contains comments (JSONC); edits keep existing comments in place.",
+                    descriptor.container_path.display()
+                ),
+                None,
+                None,
+            ));
+        }
+
+        let parse_outcome =
+            parser_registry.parse_client_config(descriptor.client, &parse_input.content);
         for warning in parse_outcome.warnings() {
-            warnings.push(format!(
-                "[{}:{}] {}",
-                descriptor.client.as_str(),
+            diagnostics.push(source_diagnostic(
+                descriptor,
+                DiagnosticSeverity::Warning,
                 warning.code,
-                warning.message
+                warning.message.clone(),
+                warning.server_name.clone(),
+                resolve_position(descriptor, &source, warning.server_name.as_deref())
+                    .or(warning.position),
             ));
         }
 
         match parse_outcome {
             ParseOutcome::Success { data, .. } => {
+                let approvals = claude_project_approvals(descriptor, &read_source);
                 for server in data.mcp_servers {
+                    configured.insert((descriptor.source_id.clone(), server.name.clone()));
+                    let approval = approvals
+                        .as_ref()
+                        .map(|approvals| approvals.state(&server.name));
+                    let mut record = server_record(descriptor, server);
+                    if let Some(approval) = approval {
+                        record.enabled &= approval == McpApprovalState::Approved;
+                        record.approval = Some(approval);
+                    }
                     if let Some(enabled_filter) = request.enabled
-                        && server.enabled != enabled_filter
+                        && record.enabled != enabled_filter
                     {
                         continue;
                     }
 
-                    let logical_id = server.name.clone();
-                    items.push(
-                        ResourceRecord {
-                            id: format!(
-                                "{}::mcp::{}::{}",
-                                descriptor.client.as_str(),
-                                descriptor.source_id,
-                                logical_id
-                            ),
-                            logical_id,
-                            client: descriptor.client,
-                            display_name: server.name,
-                            enabled: server.enabled,
-                            transport_kind: Some(server.transport_kind),
-                            transport_command: server.transport_command,
-                            transport_args: Some(server.transport_args),
-                            transport_url: server.transport_url,
-                            source_path: Some(descriptor.container_path.display().to_string()),
-                            source_id: String::new(),
-                            source_scope: descriptor.source_scope,
-                            source_label: String::new(),
-                            is_effective: true,
-                            shadowed_by: None,
-                            description: None,
-                            install_kind: None,
-                            manifest_content: None,
-                        }
-                        .with_source_metadata(ResourceSourceMetadata {
-                            source_id: descriptor.source_id.clone(),
-                            source_scope: descriptor.source_scope,
-                            source_label: descriptor.source_label.clone(),
-                            is_effective: true,
-                            shadowed_by: None,
-                        }),
-                    );
+                    items.push(record);
                 }
             }
             ParseOutcome::Failure { errors, .. } => {
                 for error in errors {
-                    warnings.push(format!(
-                        "[{}:{}] {}",
-                        descriptor.client.as_str(),
+                    diagnostics.push(source_diagnostic(
+                        descriptor,
+                        DiagnosticSeverity::Error,
                         error.code,
-                        error.message
+                        error.message,
+                        None,
+                        error.position,
                     ));
                 }
             }
         }
     }
 
+    if request.enabled != Some(true) {
+        for descriptor in &descriptors {
+            if request
+                .scope_filter
+                .as_ref()
+                .is_some_and(|scopes| !scopes.contains(&descriptor.source_scope))
+            {
+                continue;
+            }
+
+            for parked_server in parked
+                .iter()
+                .filter(|server| server.source_id == descriptor.source_id)
+            {
+                if !configured.contains(&(
+                    descriptor.source_id.clone(),
+                    parked_server.server_id.clone(),
+                )) && let Some(server) =
+                    parse_parked_server(parser_registry, descriptor, parked_server)
+                {
+                    items.push(server_record(descriptor, server));
+                }
+            }
+        }
+    }
+
     apply_effective_precedence(&mut items);
     if !matches!(
         request.view_mode,
@@ -214,35 +287,225 @@ where
 
     McpListResult {
         items,
-        warning: (!warnings.is_empty()).then(|| warnings.join(" | ")),
+        warning: (!diagnostics.is_empty()).then(|| {
+            diagnostics
+                .iter()
+                .map(ResourceDiagnostic::summary)
+                .collect::<Vec<_>>()
+                .join(" | ")
+        }),
+        diagnostics,
+        source_revisions,
+    }
+}
+
+/// Claude Code only activates `.mcp.json` servers approved in its settings files.
+fn claude_project_approvals<F>(
+    descriptor: &McpSourceDescriptor,
+    read_source: &F,
+) -> Option<ClaudeProjectApprovals>
+where
+    F: Fn(&str) -> io::Result<String>,
+{
+    if descriptor.client != ClientKind::ClaudeCode
+        || descriptor.source_scope != ResourceSourceScope::ProjectShared
+    {
+        return None;
+    }
+    let project_root = descriptor.project_root.as_deref()?;
+
+    Some(ClaudeProjectApprovals::load(
+        &claude_settings_paths(project_root),
+        read_source,
+    ))
+}
+
+fn server_record(descriptor: &McpSourceDescriptor, server: ParsedMcpServer) -> ResourceRecord {
+    let logical_id = server.name.clone();
+    ResourceRecord {
+        id: format!(
+            "{}::mcp::{}::{}",
+            descriptor.client.as_str(),
+            descriptor.source_id,
+            logical_id
+        ),
+        logical_id,
+        client: descriptor.client,
+        display_name: server.name,
+        enabled: server.enabled,
+        transport_kind: Some(server.transport_kind),
+        transport_command: server.transport_command,
+        transport_args: Some(server.transport_args),
+        transport_url: server.transport_url,
+        source_path: Some(descriptor.container_path.display().to_string()),
+        source_id: String::new(),
+        source_scope: descriptor.source_scope,
+        source_label: String::new(),
+        is_effective: true,
+        shadowed_by: None,
+        description: None,
+        install_kind: None,
+        manifest_content: None,
+        approval: None,
+    }
+    .with_source_metadata(ResourceSourceMetadata {
+        source_id: descriptor.source_id.clone(),
+        source_scope: descriptor.source_scope,
+        source_label: descriptor.source_label.clone(),
+        is_effective: true,
+        shadowed_by: None,
+    })
+}
+
+/// Parses a parked entry with the client's own parser so it lists like a disabled server.
+fn parse_parked_server(
+    parser_registry: &ParserRegistry,
+    descriptor: &McpSourceDescriptor,
+    parked: &ParkedServer,
+) -> Option<ParsedMcpServer> {
+    let content = match descriptor.storage_kind {
+        McpSourceStorageKind::JsonSection => {
+            json!({ "mcpServers": { &parked.server_id: &parked.entry } }).to_string()
+        }
+        McpSourceStorageKind::TomlTable => {
+            let entry = toml::Value::try_from(&parked.entry).ok()?;
+            let mut servers = toml::Table::new();
+            servers.insert(parked.server_id.clone(), entry);
+            let mut root = toml::Table::new();
+            root.insert("mcp_servers".to_string(), toml::Value::Table(servers));
+            toml::to_string(&root).ok()?
+        }
+    };
+
+    match parser_registry.parse_client_config(descriptor.client, &content) {
+        ParseOutcome::Success { data, .. } => data
+            .mcp_servers
+            .into_iter()
+            .find(|server| server.name == parked.server_id)
+            .map(|server| ParsedMcpServer {
+                enabled: false,
+                ..server
+            }),
+        ParseOutcome::Failure { .. } => None,
     }
 }
 
+fn source_diagnostic(
+    descriptor: &McpSourceDescriptor,
+    severity: DiagnosticSeverity,
+    code: &str,
+    message: String,
+    server_name: Option<String>,
+    position: Option<SourcePosition>,
+) -> ResourceDiagnostic {
+    let diagnostic = ResourceDiagnostic {
+        client: descriptor.client,
+        severity,
+        code: code.to_string(),
+        message,
+        source_id: Some(descriptor.source_id.clone()),
+        source_path: Some(descriptor.container_path.display().to_string()),
+        line: position.map(|position| position.line),
+        column: position.map(|position| position.column),
+        server_name,
+        category: None,
+        suggested_actions: Vec::new(),
+    };
+
+    // Everything past the read step comes from parsing or section resolution.
+    match severity {
+        DiagnosticSeverity::Info => diagnostic,
+        DiagnosticSeverity::Warning | DiagnosticSeverity::Error => {
+            diagnostic.with_category(ErrorCategory::Parse)
+        }
+    }
+}
+
+// JSON sections are parsed from a synthetic root, so parser positions are
+// re-resolved against the original file.
+fn resolve_position(
+    descriptor: &McpSourceDescriptor,
+    source: &str,
+    server_name: Option<&str>,
+) -> Option<SourcePosition> {
+    if !matches!(descriptor.storage_kind, McpSourceStorageKind::JsonSection) {
+        return None;
+    }
+
+    let server_name = server_name?;
+    let tokens = json_pointer_tokens(&descriptor.selector);
+    let mut path = tokens.iter().map(String::as_str).collect::<Vec<_>>();
+    path.push(server_name);
+    locate_jsonc_member(source, &path)
+}
+
+fn json_pointer_tokens(selector: &str) -> Vec<String> {
+    selector
+        .split('/')
+        .skip(1)
+        .map(|token| token.replace("~1", "/").replace("~0", "~"))
+        .collect()
+}
+
+struct McpParseInput {
+    content: String,
+    has_comments: bool,
+}
+
+struct McpParseInputError {
+    code: &'static str,
+    message: String,
+    position: Option<SourcePosition>,
+}
+
 fn build_parse_input(
     descriptor: &McpSourceDescriptor,
     source: &str,
-) -> Result<Option<String>, String> {
+) -> Result<Option<McpParseInput>, McpParseInputError> {
     match descriptor.storage_kind {
         McpSourceStorageKind::JsonSection => select_json_mcp_section(source, &descriptor.selector),
-        McpSourceStorageKind::TomlTable => Ok(Some(source.to_string())),
+        McpSourceStorageKind::TomlTable => Ok(Some(McpParseInput {
+            content: source.to_string(),
+            has_comments: false,
+        })),
     }
 }
 
-fn select_json_mcp_section(source: &str, selector: &str) -> Result<Option<String>, String> {
-    let parsed = serde_json::from_str::<Value>(source)
-        .map_err(|error| format!("Invalid JSON payload: {error}"))?;
-    let Some(section) = parsed.pointer(selector) else {
+fn select_json_mcp_section(
+    source: &str,
+    selector: &str,
+) -> Result<Option<McpParseInput>, McpParseInputError> {
+    let document = parse_jsonc(source).map_err(|error| McpParseInputError {
+        code: "PARSER_JSON_SYNTAX",
+        message: format!("Invalid JSON payload: {error}"),
+        position: Some(SourcePosition {
+            line: error.line(),
+            column: error.column(),
+        }),
+    })?;
+    let Some(section) = document.value.pointer(selector) else {
         return Ok(None);
     };
     let Some(section_object) = section.as_object() else {
-        return Err("Selected MCP section must be an object map.".to_string());
+        let tokens = json_pointer_tokens(selector);
+        return Err(McpParseInputError {
+            code: "CONFIG_SECTION_INVALID",
+            message: "Selected MCP section must be an object map.".to_string(),
+            position: locate_jsonc_member(
+                source,
+                &tokens.iter().map(String::as_str).collect::<Vec<_>>(),
+            ),
+        });
     };
 
     let synthetic_root = json!({
         "mcpServers": Value::Object(section_object.clone())
     });
 
-    Ok(Some(synthetic_root.to_string()))
+    Ok(Some(McpParseInput {
+        content: synthetic_root.to_string(),
+        has_comments: document.has_comments,
+    }))
 }
 
 fn apply_effective_precedence(items: &mut [ResourceRecord]) {
@@ -280,8 +543,10 @@ fn apply_effective_precedence(items: &mut [ResourceRecord]) {
     }
 }
 
-fn precedence_key(item: &ResourceRecord) -> (u8, &str, &str) {
+fn precedence_key(item: &ResourceRecord) -> (bool, u8, &str, &str) {
     (
+        item.approval
+            .is_some_and(|approval| approval != McpApprovalState::Approved),
         precedence_rank(item.client, item.source_scope),
         item.source_id.as_str(),
         item.id.as_str(),
@@ -314,12 +579,13 @@ mod tests {
     use crate::interface::contracts::{
         common::{ClientKind, ResourceKind},
         detect::{ClientDetection, DetectClientsRequest},
-        list::{ListResourcesRequest, ResourceViewMode},
+        list::{DiagnosticSeverity, ListResourcesRequest, ResourceViewMode},
     };
     use crate::{
-        domain::ResourceSourceScope,
-        infra::{DetectorRegistry, parsers::ParserRegistry},
+        domain::{McpApprovalState, ResourceSourceScope},
+        infra::{DetectorRegistry, ParkedServer, content_revision, parsers::ParserRegistry},
     };
+    use serde_json::json;
 
     use super::{collect_from_descriptors, requested_clients};
 
@@ -384,7 +650,7 @@ enabled = false
         };
 
         let result =
-            collect_from_descriptors(&ParserRegistry::new(), descriptors, &request, |path| {
+            collect_from_descriptors(&ParserRegistry::new(), descriptors, &[], &request, |path| {
                 match fixtures.get(path) {
                     Some(payload) => Ok((*payload).to_string()),
                     None => Err(io::Error::new(
@@ -417,6 +683,52 @@ enabled = false
         );
     }
 
+    #[test]
+    fn listing_reports_content_revision_per_source() {
+        let descriptors = vec![
+            descriptor(
+                ClientKind::Cursor,
+                ResourceSourceScope::User,
+                "/fixtures/cursor.json",
+            ),
+            descriptor(
+                ClientKind::Codex,
+                ResourceSourceScope::User,
+                "/fixtures/missing.toml",
+            ),
+        ];
+        let cursor_payload = r#"{ "mcpServers": {} }"#;
+
+        let request = ListResourcesRequest {
+            client: None,
+            resource_kind: ResourceKind::Mcp,
+            enabled: None,
+            project_root: None,
+            view_mode: ResourceViewMode::Effective,
+            scope_filter: None,
+        };
+
+        let result =
+            collect_from_descriptors(&ParserRegistry::new(), descriptors, &[], &request, |path| {
+                if path == "/fixtures/cursor.json" {
+                    Ok(cursor_payload.to_string())
+                } else {
+                    Err(io::Error::new(io::ErrorKind::NotFound, "missing"))
+                }
+            });
+
+        assert_eq!(result.source_revisions.len(), 2);
+        assert_eq!(
+            result.source_revisions[0].revision,
+            Some(content_revision(cursor_payload.as_bytes()))
+        );
+        assert_eq!(
+            result.source_revisions[1].source_path,
+            "/fixtures/missing.toml"
+        );
+        assert_eq!(result.source_revisions[1].revision, None);
+    }
+
     #[test]
     fn listing_filters_by_client_and_enabled_state() {
         let descriptors = vec![
@@ -464,7 +776,7 @@ enabled = false
         };
 
         let result =
-            collect_from_descriptors(&ParserRegistry::new(), descriptors, &request, |path| {
+            collect_from_descriptors(&ParserRegistry::new(), descriptors, &[], &request, |path| {
                 match fixtures.get(path) {
                     Some(payload) => Ok((*payload).to_string()),
                     None => Err(io::Error::new(
@@ -480,6 +792,186 @@ enabled = false
         assert!(!result.items[0].enabled);
     }
 
+    #[test]
+    fn parked_servers_are_listed_as_disabled_entries_of_their_source() {
+        let claude = descriptor(
+            ClientKind::ClaudeCode,
+            ResourceSourceScope::User,
+            "/fixtures/claude.json",
+        );
+        let codex = descriptor(
+            ClientKind::Codex,
+            ResourceSourceScope::User,
+            "/fixtures/codex.toml",
+        );
+        let parked = |source: &McpSourceDescriptor, server_id: &str, entry| ParkedServer {
+            client: source.client,
+            source_id: source.source_id.clone(),
+            container_path: source.container_path.display().to_string(),
+            server_id: server_id.to_string(),
+            entry,
+            parked_at_unix: 0,
+        };
+        let parked_servers = vec![
+            parked(
+                &claude,
+                "github",
+                json!({ "type": "sse", "url": "https://mcp.example.com/sse" }),
+            ),
+            parked(&claude, "context7", json!({ "command": "shadowed" })),
+            parked(
+                &codex,
+                "filesystem",
+                json!({ "command": "npx", "args": ["-y"] }),
+            ),
+        ];
+        let fixtures: HashMap<&str, &str> = HashMap::from([(
+            "/fixtures/claude.json",
+            r#"{ "mcpServers": { "context7": { "command": "context7" } } }"#,
+        )]);
+        let request = ListResourcesRequest {
+            client: None,
+            resource_kind: ResourceKind::Mcp,
+            enabled: Some(false),
+            project_root: None,
+            view_mode: ResourceViewMode::AllSources,
+            scope_filter: None,
+        };
+
+        let result = collect_from_descriptors(
+            &ParserRegistry::new(),
+            vec![claude, codex],
+            &parked_servers,
+            &request,
+            |path| match fixtures.get(path) {
+                Some(payload) => Ok((*payload).to_string()),
+                None => Err(io::Error::new(io::ErrorKind::NotFound, path.to_string())),
+            },
+        );
+
+        let listed: Vec<(ClientKind, &str, Option<&str>)> = result
+            .items
+            .iter()
+            .map(|item| {
+                (
+                    item.client,
+                    item.display_name.as_str(),
+                    item.transport_kind.as_deref(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            listed,
+            vec![
+                (ClientKind::ClaudeCode, "github", Some("sse")),
+                (ClientKind::Codex, "filesystem", Some("stdio")),
+            ]
+        );
+        assert!(result.items.iter().all(|item| !item.enabled));
+        assert_eq!(result.items[1].transport_args, Some(vec!["-y".to_string()]));
+    }
+
+    #[test]
+    fn claude_project_servers_follow_settings_approvals() {
+        let user = descriptor(
+            ClientKind::ClaudeCode,
+            ResourceSourceScope::User,
+            "/fixtures/claude.json",
+        );
+        let project = McpSourceDescriptor {
+            project_root: Some("/fixtures/repo".to_string()),
+            ..descriptor(
+                ClientKind::ClaudeCode,
+                ResourceSourceScope::ProjectShared,
+                "/fixtures/repo/.mcp.json",
+            )
+        };
+        let fixtures: HashMap<&str, &str> = HashMap::from([
+            (
+                "/fixtures/claude.json",
+                r#"{ "mcpServers": { "filesystem": { "command": "npx" } } }"#,
+            ),
+            (
+                "/fixtures/repo/.mcp.json",
+                r#"{ "mcpServers": {
+  "filesystem": { "command": "uvx" },
+  "github": { "url": "https://mcp.example.com/sse" },
+  "sentry": { "command": "sentry-mcp" }
+} }"#,
+            ),
+            (
+                "/fixtures/repo/.claude/settings.local.json",
+                r#"{ "enabledMcpjsonServers": ["github"], "disabledMcpjsonServers": ["sentry"] }"#,
+            ),
+        ]);
+        let request = ListResourcesRequest {
+            client: Some(ClientKind::ClaudeCode),
+            resource_kind: ResourceKind::Mcp,
+            enabled: None,
+            project_root: Some("/fixtures/repo".to_string()),
+            view_mode: ResourceViewMode::AllSources,
+            scope_filter: None,
+        };
+
+        let result = collect_from_descriptors(
+            &ParserRegistry::new(),
+            vec![user, project],
+            &[],
+            &request,
+            |path| match fixtures.get(path) {
+                Some(payload) => Ok((*payload).to_string()),
+                None => Err(io::Error::new(io::ErrorKind::NotFound, path.to_string())),
+            },
+        );
+
+        let states: Vec<(
+            &str,
+            ResourceSourceScope,
+            bool,
+            bool,
+            Option<McpApprovalState>,
+        )> = result
+            .items
+            .iter()
+            .map(|item| {
+                (
+                    item.display_name.as_str(),
+                    item.source_scope,
+                    item.enabled,
+                    item.is_effective,
+                    item.approval,
+                )
+            })
+            .collect();
+        assert_eq!(
+            states,
+            vec![
+                (
+                    "filesystem",
+                    ResourceSourceScope::ProjectShared,
+                    false,
+                    false,
+                    Some(McpApprovalState::Pending)
+                ),
+                ("filesystem", ResourceSourceScope::User, true, true, None),
+                (
+                    "github",
+                    ResourceSourceScope::ProjectShared,
+                    true,
+                    true,
+                    Some(McpApprovalState::Approved)
+                ),
+                (
+                    "sentry",
+                    ResourceSourceScope::ProjectShared,
+                    false,
+                    true,
+                    Some(McpApprovalState::Denied)
+                ),
+            ]
+        );
+    }
+
     #[test]
     fn parse_errors_do_not_fail_whole_listing() {
         let descriptors = vec![
@@ -524,7 +1016,7 @@ enabled = true
         };
 
         let result =
-            collect_from_descriptors(&ParserRegistry::new(), descriptors, &request, |path| {
+            collect_from_descriptors(&ParserRegistry::new(), descriptors, &[], &request, |path| {
                 match fixtures.get(path) {
                     Some(payload) => Ok((*payload).to_string()),
                     None => Err(io::Error::new(
@@ -544,6 +1036,115 @@ enabled = true
         );
     }
 
+    #[test]
+    fn diagnostics_point_at_source_positions() {
+        let descriptors = vec![
+            descriptor(
+                ClientKind::Cursor,
+                ResourceSourceScope::User,
+                "/fixtures/cursor.json",
+            ),
+            descriptor(
+                ClientKind::Codex,
+                ResourceSourceScope::User,
+                "/fixtures/broken.toml",
+            ),
+        ];
+        let fixtures: HashMap<&str, &str> = HashMap::from([
+            (
+                "/fixtures/cursor.json",
+                r#"{
+  "mcpServers": {
+    "ok": { "command": "npx" },
+    "broken": { "args": [] }
+  }
+}"#,
+            ),
+            ("/fixtures/broken.toml", "[mcp_servers.github]\nurl = \n"),
+        ]);
+        let request = ListResourcesRequest {
+            client: None,
+            resource_kind: ResourceKind::Mcp,
+            enabled: None,
+            project_root: None,
+            view_mode: ResourceViewMode::Effective,
+            scope_filter: None,
+        };
+
+        let result =
+            collect_from_descriptors(&ParserRegistry::new(), descriptors, &[], &request, |path| {
+                Ok(fixtures[path].to_string())
+            });
+
+        assert_eq!(result.items.len(), 1);
+        assert_eq!(result.diagnostics.len(), 2);
+
+        let transport = result
+            .diagnostics
+            .iter()
+            .find(|diagnostic| diagnostic.code == "PARSER_SERVER_TRANSPORT_MISSING")
+            .expect("missing transport should be reported");
+        assert_eq!(transport.severity, DiagnosticSeverity::Warning);
+        assert_eq!(transport.server_name.as_deref(), Some("broken"));
+        assert_eq!(
+            transport.source_path.as_deref(),
+            Some("/fixtures/cursor.json")
+        );
+        assert_eq!(
+            transport.source_id.as_deref(),
+            Some("mcp::cursor::user::/fixtures/cursor.json::/mcpServers")
+        );
+        assert_eq!((transport.line, transport.column), (Some(4), Some(5)));
+
+        let syntax = result
+            .diagnostics
+            .iter()
+            .find(|diagnostic| diagnostic.code == "PARSER_TOML_SYNTAX")
+            .expect("toml syntax error should be reported");
+        assert_eq!(syntax.severity, DiagnosticSeverity::Error);
+        assert_eq!(syntax.line, Some(2));
+        assert!(
+            result
+                .warning
+                .as_deref()
+                .is_some_and(|warning| warning.contains("[codex:PARSER_TOML_SYNTAX]"))
+        );
+    }
+
+    #[test]
+    fn jsonc_sources_are_listed_and_flagged() {
+        let descriptors = vec![descriptor(
+            ClientKind::Cursor,
+            ResourceSourceScope::User,
+            "/fixtures/cursor.json",
+        )];
+        let request = ListResourcesRequest {
+            client: None,
+            resource_kind: ResourceKind::Mcp,
+            enabled: None,
+            project_root: None,
+            view_mode: ResourceViewMode::Effective,
+            scope_filter: None,
+        };
+
+        let result =
+            collect_from_descriptors(&ParserRegistry::new(), descriptors, &[], &request, |_| {
+                Ok(r#"{
+  // hand-edited
+  "mcpServers": {
+    "filesystem": { "command": "npx", "args": ["-y", "fs"] }, /* local */
+  },
+}"#
+                .to_string())
+            });
+
+        assert_eq!(result.items.len(), 1);
+        assert_eq!(result.items[0].display_name, "filesystem");
+        let warning = result.warning.expect("comments should be flagged");
+        assert!(warning.contains("[cursor:PARSER_JSONC_COMMENTS]"));
+        assert!(warning.contains("/fixtures/cursor.json"));
+    }
+
     #[test]
     fn project_scoped_sources_affect_effective_listing_and_shadowing() {
         let project_root = "/fixtures/workspace";
@@ -609,7 +1210,7 @@ enabled = true
         };
 
         let result =
-            collect_from_descriptors(&ParserRegistry::new(), descriptors, &request, |path| {
+            collect_from_descriptors(&ParserRegistry::new(), descriptors, &[], &request, |path| {
                 match fixtures.get(path) {
                     Some(payload) => Ok((*payload).to_string()),
                     None => Err(io::Error::new(
@@ -667,6 +1268,7 @@ enabled = true
                     project_root: Some(project_root.to_string()),
                 },
             ],
+            &[],
             &ListResourcesRequest {
                 view_mode: ResourceViewMode::Effective,
                 ..request.clone()
@@ -715,6 +1317,7 @@ enabled = true
                     project_root: Some(project_root.to_string()),
                 },
             ],
+            &[],
             &ListResourcesRequest {
                 scope_filter: Some(vec![ResourceSourceScope::ProjectShared]),
                 ..request
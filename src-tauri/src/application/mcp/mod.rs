@@ -1,8 +1,13 @@
 pub(super) mod config_path_resolver;
+pub(super) mod config_repair_service;
+pub(super) mod inventory_service;
 pub(super) mod listing_service;
 pub(super) mod mutation_payload;
 pub(super) mod mutation_service;
 pub(super) mod mutation_target_resolver;
+pub(super) mod probe_service;
+pub(super) mod project_approvals;
 pub(super) mod replication_service;
+pub(super) mod secret_externalization_service;
 pub(super) mod source_catalog_service;
 pub(super) mod source_id;
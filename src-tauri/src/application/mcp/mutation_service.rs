@@ -1,15 +1,32 @@
-use std::fs;
+use std::{
+    fs,
+    path::PathBuf,
+    time::{SystemTime, UNIX_EPOCH},
+};
 
 use crate::{
-    infra::DetectorRegistry,
-    infra::SafeFileMutator,
+    application::{
+        ClientCapabilityService, ClientFeature, setting::settings_file::edit_settings_file,
+    },
+    domain::{ClientVersion, McpApprovalState, ResourceSourceScope},
+    infra::parsers::{parse_jsonc, patch_jsonc},
+    infra::{
+        BACKUP_DIR_NAME, SafeFileMutator, content_revision,
+        security::leak_scanner::scan_for_new_leaks,
+    },
+    infra::{DetectorRegistry, GitPathStatus, GitRepository, ParkedServer, ParkedServerStore},
     interface::contracts::{command::CommandError, common::ClientKind, mutate::MutationAction},
 };
 
 use super::{
     mutation_payload::{McpMutationPayload, McpTransportPayload, parse_mcp_mutation_payload},
     mutation_target_resolver::McpMutationTargetResolver,
-    source_catalog_service::{McpSourceDescriptor, McpSourceStorageKind},
+    project_approvals::{
+        ClaudeProjectApprovals, claude_local_settings_path, claude_settings_paths, record_approval,
+    },
+    source_catalog_service::{
+        McpSourceDescriptor, McpSourceStorageKind, descriptor_for_scope, selector_for_scope,
+    },
 };
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -17,15 +34,25 @@ pub struct McpMutationResult {
     pub source_path: String,
     pub target_source_id: String,
     pub message: String,
+    pub warning: Option<String>,
 }
 
 pub struct McpMutationService<'a> {
     detector_registry: &'a DetectorRegistry,
+    parked_store: Option<ParkedServerStore>,
 }
 
 impl<'a> McpMutationService<'a> {
-    pub fn new(detector_registry: &'a DetectorRegistry) -> Self {
-        Self { detector_registry }
+    /// `parked_store` is where disabled servers go on clients without an `enabled` field;
+    /// callers normally pass `ParkedServerStore::default_location()`.
+    pub fn new(
+        detector_registry: &'a DetectorRegistry,
+        parked_store: Option<ParkedServerStore>,
+    ) -> Self {
+        Self {
+            detector_registry,
+            parked_store,
+        }
     }
 
     pub fn mutate(
@@ -37,6 +64,39 @@ impl<'a> McpMutationService<'a> {
         target_source_id: Option<&str>,
         payload: Option<&serde_json::Value>,
     ) -> Result<McpMutationResult, CommandError> {
+        self.mutate_at_revision(
+            client,
+            action,
+            target_id,
+            project_root,
+            target_source_id,
+            payload,
+            None,
+        )
+    }
+
+    /// Like `mutate`, but fails with a conflict when the target file no longer matches
+    /// the revision the caller listed.
+    #[allow(clippy::too_many_arguments)]
+    pub fn mutate_at_revision(
+        &self,
+        client: ClientKind,
+        action: MutationAction,
+        target_id: &str,
+        project_root: Option<&str>,
+        target_source_id: Option<&str>,
+        payload: Option<&serde_json::Value>,
+        expected_revision: Option<&str>,
+    ) -> Result<McpMutationResult, CommandError> {
+        let (verb, preposition) = match action {
+            MutationAction::Add => ("Added", "in"),
+            MutationAction::Update => ("Updated", "in"),
+            MutationAction::Remove => ("Removed", "from"),
+            MutationAction::Approve | MutationAction::Deny => {
+                return record_project_approval(client, action, target_id, project_root);
+            }
+        };
+
         let payload = parse_mcp_mutation_payload(action, payload)?;
         let target_descriptor = McpMutationTargetResolver::new(self.detector_registry).resolve(
             client,
@@ -45,18 +105,55 @@ impl<'a> McpMutationService<'a> {
             target_source_id,
             payload.source_path.as_deref(),
         )?;
+        let write_enabled_field =
+            self.check_version_gates(client, action, &payload, &target_descriptor)?;
+
+        let (current_content, current_revision) =
+            match fs::read_to_string(&target_descriptor.container_path) {
+                Ok(content) => {
+                    let revision = content_revision(content.as_bytes());
+                    (content, Some(revision))
+                }
+                Err(error) if error.kind() == std::io::ErrorKind::NotFound => (String::new(), None),
+                Err(error) => {
+                    return Err(CommandError::io(
+                        format!(
+                            "Failed to read MCP config '{}': {}",
+                            target_descriptor.container_path.display(),
+                            error
+                        ),
+                        error.kind(),
+                    )
+                    .with_path(target_descriptor.container_path.display().to_string())
+                    .with_source_id(&target_descriptor.source_id));
+                }
+            };
 
-        let current_content = match fs::read_to_string(&target_descriptor.container_path) {
-            Ok(content) => content,
-            Err(error) if error.kind() == std::io::ErrorKind::NotFound => String::new(),
-            Err(error) => {
-                return Err(CommandError::internal(format!(
-                    "Failed to read MCP config '{}': {}",
-                    target_descriptor.container_path.display(),
-                    error
-                )));
-            }
-        };
+        if let Some(expected_revision) = expected_revision
+            && current_revision.as_deref() != Some(expected_revision)
+        {
+            return Err(CommandError::conflict(format!(
+                "MCP config '{}' changed since it was listed (expected revision {}, found {}). Refresh and retry.",
+                target_descriptor.container_path.display(),
+                expected_revision,
+                current_revision.as_deref().unwrap_or("<missing>")
+            ))
+            .with_path(target_descriptor.container_path.display().to_string())
+            .with_source_id(&target_descriptor.source_id));
+        }
+
+        if let Some(result) = self.mutate_parked(
+            client,
+            action,
+            target_id,
+            &payload,
+            write_enabled_field,
+            &target_descriptor,
+            &current_content,
+            current_revision.as_deref(),
+        )? {
+            return Ok(result);
+        }
 
         let next_content = match target_descriptor.storage_kind {
             McpSourceStorageKind::JsonSection => mutate_json_content(
@@ -65,51 +162,412 @@ impl<'a> McpMutationService<'a> {
                 target_id,
                 action,
                 &payload,
+                write_enabled_field,
+            )?,
+            McpSourceStorageKind::TomlTable => mutate_toml_content(
+                &current_content,
+                target_id,
+                action,
+                &payload,
+                write_enabled_field,
             )?,
-            McpSourceStorageKind::TomlTable => {
-                mutate_toml_content(&current_content, target_id, action, &payload)?
-            }
         };
 
         let write_result = SafeFileMutator::new()
-            .replace_file(&target_descriptor.container_path, next_content.as_bytes())
+            .replace_file_if_unchanged(
+                &target_descriptor.container_path,
+                next_content.as_bytes(),
+                current_revision.as_deref(),
+            )
             .map_err(|failure| {
-                CommandError::internal(format!(
-                    "[stage={:?}] {} (rollback_succeeded={})",
-                    failure.stage, failure.message, failure.rollback_succeeded
-                ))
+                CommandError::from_mutation_failure(&failure, &target_descriptor.container_path)
+                    .with_source_id(&target_descriptor.source_id)
             })?;
 
-        let mut message = match action {
-            MutationAction::Add => format!(
-                "Added MCP '{}' for '{}' in {}.",
-                target_id,
-                client.as_str(),
-                target_descriptor.source_label
-            ),
-            MutationAction::Remove => format!(
-                "Removed MCP '{}' for '{}' from {}.",
-                target_id,
-                client.as_str(),
-                target_descriptor.source_label
-            ),
-            MutationAction::Update => format!(
-                "Updated MCP '{}' for '{}' in {}.",
-                target_id,
-                client.as_str(),
-                target_descriptor.source_label
-            ),
-        };
+        let mut message = format!(
+            "{} MCP '{}' for '{}' {} {}.",
+            verb,
+            target_id,
+            client.as_str(),
+            preposition,
+            target_descriptor.source_label
+        );
+        if !write_enabled_field {
+            message.push_str(" Omitted `enabled`; the installed client version does not read it.");
+        }
         if let Some(backup_path) = write_result.backup_path {
             message.push_str(&format!(" Backup: {}.", backup_path));
         }
 
+        let warning = (action != MutationAction::Remove)
+            .then(|| committed_secrets_warning(&target_descriptor, &current_content, &next_content))
+            .flatten();
+
         Ok(McpMutationResult {
             source_path: target_descriptor.container_path.display().to_string(),
             target_source_id: target_descriptor.source_id,
             message,
+            warning,
         })
     }
+
+    /// Refuses fields the installed client version cannot read; returns whether the
+    /// `enabled` field should be written.
+    fn check_version_gates(
+        &self,
+        client: ClientKind,
+        action: MutationAction,
+        payload: &McpMutationPayload,
+        target_descriptor: &McpSourceDescriptor,
+    ) -> Result<bool, CommandError> {
+        if matches!(action, MutationAction::Remove) {
+            return Ok(true);
+        }
+
+        let capabilities = ClientCapabilityService::new();
+        let installed = self
+            .detector_registry
+            .installed_version(client)
+            .as_deref()
+            .and_then(ClientVersion::parse);
+
+        if matches!(payload.transport, Some(McpTransportPayload::Sse { .. })) {
+            capabilities.ensure_feature(client, ClientFeature::McpRemoteTransport, installed)?;
+        }
+        if target_descriptor.source_scope != ResourceSourceScope::User {
+            capabilities.ensure_feature(client, ClientFeature::McpProjectScope, installed)?;
+        }
+        if capabilities.supports_feature(client, ClientFeature::McpEnabledToggle, installed) {
+            return Ok(true);
+        }
+        if payload.enabled == Some(false) && self.parked_store.is_none() {
+            capabilities.ensure_feature(client, ClientFeature::McpEnabledToggle, installed)?;
+        }
+
+        Ok(false)
+    }
+
+    /// Disabling a server on a client without a readable `enabled` field moves the entry into
+    /// the parked-server store; enabling it again restores the entry verbatim.
+    #[allow(clippy::too_many_arguments)]
+    fn mutate_parked(
+        &self,
+        client: ClientKind,
+        action: MutationAction,
+        target_id: &str,
+        payload: &McpMutationPayload,
+        write_enabled_field: bool,
+        descriptor: &McpSourceDescriptor,
+        current_content: &str,
+        current_revision: Option<&str>,
+    ) -> Result<Option<McpMutationResult>, CommandError> {
+        let Some(store) = self.parked_store.as_ref() else {
+            return Ok(None);
+        };
+        let snapshot = store.load().map_err(|error| {
+            CommandError::io(
+                format!(
+                    "Failed to read parked MCP servers '{}': {}",
+                    store.path().display(),
+                    error
+                ),
+                error.kind(),
+            )
+            .with_path(store.path().display().to_string())
+        })?;
+        let store_error = |failure: &crate::infra::MutationFailure| {
+            CommandError::from_mutation_failure(failure, store.path())
+        };
+        let config_error = |failure: &crate::infra::MutationFailure| {
+            CommandError::from_mutation_failure(failure, &descriptor.container_path)
+                .with_source_id(&descriptor.source_id)
+        };
+        let result = |message: String| {
+            Some(McpMutationResult {
+                source_path: descriptor.container_path.display().to_string(),
+                target_source_id: descriptor.source_id.clone(),
+                message,
+                warning: None,
+            })
+        };
+
+        let Some(parked) = snapshot.find(&descriptor.source_id, target_id).cloned() else {
+            let lacks_enabled_field =
+                matches!(client, ClientKind::ClaudeCode) || !write_enabled_field;
+            if !lacks_enabled_field
+                || payload.enabled != Some(false)
+                || matches!(action, MutationAction::Remove)
+            {
+                return Ok(None);
+            }
+
+            let (next_content, entry) = if matches!(action, MutationAction::Add) {
+                (
+                    None,
+                    build_parked_entry(descriptor, current_content, target_id, payload)?,
+                )
+            } else {
+                let (next_content, entry) = take_entry(descriptor, current_content, target_id)?;
+                if !transport_matches(&entry, payload.transport.as_ref()) {
+                    return Err(CommandError::validation(format!(
+                        "MCP '{}' is parked as it is when disabled, so `enabled: false` cannot be combined with transport changes. Save the changes first, then disable it.",
+                        target_id
+                    )));
+                }
+                (Some(next_content), entry)
+            };
+
+            let mut servers = snapshot.servers.clone();
+            servers.push(ParkedServer {
+                client,
+                source_id: descriptor.source_id.clone(),
+                container_path: descriptor.container_path.display().to_string(),
+                server_id: target_id.to_string(),
+                entry,
+                parked_at_unix: SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .map_or(0, |elapsed| elapsed.as_secs()),
+            });
+            let store_revision = store
+                .save(servers, snapshot.revision.as_deref())
+                .map_err(|failure| store_error(&failure))?;
+            if let Some(next_content) = next_content
+                && let Err(failure) = SafeFileMutator::new().replace_file_if_unchanged(
+                    &descriptor.container_path,
+                    next_content.as_bytes(),
+                    current_revision,
+                )
+            {
+                let _ = store.save(snapshot.servers, Some(&store_revision));
+                return Err(config_error(&failure));
+            }
+
+            return Ok(result(format!(
+                "Disabled MCP '{}' for '{}' in {}. The entry is parked in {} and is restored verbatim when enabled.",
+                target_id,
+                client.as_str(),
+                descriptor.source_label,
+                store.path().display()
+            )));
+        };
+
+        let remaining: Vec<ParkedServer> = snapshot
+            .servers
+            .iter()
+            .filter(|server| *server != &parked)
+            .cloned()
+            .collect();
+        match action {
+            MutationAction::Remove => {
+                store
+                    .save(remaining, snapshot.revision.as_deref())
+                    .map_err(|failure| store_error(&failure))?;
+                Ok(result(format!(
+                    "Removed disabled MCP '{}' for '{}' from {}.",
+                    target_id,
+                    client.as_str(),
+                    descriptor.source_label
+                )))
+            }
+            MutationAction::Update if payload.enabled == Some(true) => {
+                if !transport_matches(&parked.entry, payload.transport.as_ref()) {
+                    return Err(CommandError::validation(format!(
+                        "MCP '{}' is restored verbatim when enabled, so `enabled: true` cannot be combined with transport changes. Enable it first, then edit it.",
+                        target_id
+                    )));
+                }
+                let next_content =
+                    insert_entry(descriptor, current_content, target_id, &parked.entry)?;
+                let mutator = SafeFileMutator::new();
+                mutator
+                    .replace_file_if_unchanged(
+                        &descriptor.container_path,
+                        next_content.as_bytes(),
+                        current_revision,
+                    )
+                    .map_err(|failure| config_error(&failure))?;
+                if let Err(failure) = store.save(remaining, snapshot.revision.as_deref()) {
+                    if current_revision.is_some() {
+                        let _ = mutator
+                            .replace_file(&descriptor.container_path, current_content.as_bytes());
+                    }
+                    return Err(store_error(&failure));
+                }
+
+                Ok(result(format!(
+                    "Enabled MCP '{}' for '{}' in {} by restoring its parked entry.",
+                    target_id,
+                    client.as_str(),
+                    descriptor.source_label
+                )))
+            }
+            MutationAction::Add => Err(CommandError::validation(format!(
+                "MCP '{}' already exists (disabled).",
+                target_id
+            ))),
+            MutationAction::Update | MutationAction::Approve | MutationAction::Deny => {
+                Err(CommandError::validation(format!(
+                    "MCP '{}' is disabled; enable it before editing.",
+                    target_id
+                )))
+            }
+        }
+    }
+}
+
+/// Approvals live in the checkout's `.claude/settings.local.json`, as Claude Code writes them.
+fn record_project_approval(
+    client: ClientKind,
+    action: MutationAction,
+    target_id: &str,
+    project_root: Option<&str>,
+) -> Result<McpMutationResult, CommandError> {
+    if client != ClientKind::ClaudeCode {
+        return Err(CommandError::validation(
+            "Project MCP approvals only apply to Claude Code.",
+        ));
+    }
+    let Some(project_root) = project_root else {
+        return Err(CommandError::validation(
+            "project_root is required to approve or deny a project MCP server.",
+        ));
+    };
+
+    let descriptor = descriptor_for_scope(
+        client,
+        ResourceSourceScope::ProjectShared,
+        PathBuf::from(project_root).join(".mcp.json"),
+        selector_for_scope(
+            client,
+            ResourceSourceScope::ProjectShared,
+            Some(project_root),
+        ),
+        McpSourceStorageKind::JsonSection,
+        Some(project_root.to_string()),
+    );
+    let shared_content = fs::read_to_string(&descriptor.container_path).map_err(|error| {
+        CommandError::io(
+            format!(
+                "Failed to read project MCP config '{}': {}",
+                descriptor.container_path.display(),
+                error
+            ),
+            error.kind(),
+        )
+        .with_path(descriptor.container_path.display().to_string())
+        .with_source_id(&descriptor.source_id)
+    })?;
+    let (_, defined) = edit_json_section(&descriptor, &shared_content, |section_object| {
+        Ok(section_object.contains_key(target_id))
+    })?;
+    if !defined {
+        return Err(CommandError::validation(format!(
+            "MCP '{}' is not defined in {}.",
+            target_id,
+            descriptor.container_path.display()
+        ))
+        .with_source_id(&descriptor.source_id));
+    }
+
+    let settings_path = claude_local_settings_path(project_root);
+    let settings_path_label = settings_path.display().to_string();
+    let approve = matches!(action, MutationAction::Approve);
+    edit_settings_file(&settings_path, None, |settings| {
+        record_approval(settings, target_id, approve);
+        Ok(())
+    })?;
+
+    Ok(McpMutationResult {
+        source_path: settings_path_label.clone(),
+        target_source_id: descriptor.source_id,
+        message: format!(
+            "{} project MCP '{}' for '{}' in {}.",
+            if approve { "Approved" } else { "Denied" },
+            target_id,
+            client.as_str(),
+            settings_path_label
+        ),
+        warning: approve
+            .then(|| still_denied_warning(project_root, target_id))
+            .flatten(),
+    })
+}
+
+/// A denial in any settings file wins over the local approval, so name the files that
+/// still deny the server after it was approved.
+fn still_denied_warning(project_root: &str, target_id: &str) -> Option<String> {
+    let paths = claude_settings_paths(project_root);
+    let read_source = |path: &str| fs::read_to_string(path);
+    if ClaudeProjectApprovals::load(&paths, read_source).state(target_id)
+        != McpApprovalState::Denied
+    {
+        return None;
+    }
+
+    let denying_files = ClaudeProjectApprovals::denying_files(&paths, target_id, read_source)
+        .iter()
+        .map(|path| path.display().to_string())
+        .collect::<Vec<_>>();
+    Some(format!(
+        "Claude Code still denies MCP '{}': it is listed in `disabledMcpjsonServers` in {}. Remove it there for the approval to take effect.",
+        target_id,
+        denying_files.join(", ")
+    ))
+}
+
+/// Project configs live in the repository; flag writes that add likely secrets to a file
+/// the next commit would include. Secrets `current_content` already held were reported when
+/// they were written.
+fn committed_secrets_warning(
+    descriptor: &McpSourceDescriptor,
+    current_content: &str,
+    next_content: &str,
+) -> Option<String> {
+    if descriptor.source_scope == ResourceSourceScope::User {
+        return None;
+    }
+    let secret_count = scan_for_new_leaks(current_content, next_content).len();
+    if secret_count == 0 {
+        return None;
+    }
+
+    let path = &descriptor.container_path;
+    let repository = GitRepository::discover(path)?;
+    let backup_dir = path.with_file_name(BACKUP_DIR_NAME);
+    let backups_note = format!(
+        " Backups in '{}' will keep copies of them; keep that directory out of Git too.",
+        backup_dir.display()
+    );
+    match repository.status(path) {
+        Ok(GitPathStatus::Tracked) => Some(format!(
+            "'{}' is tracked by Git and contains {} likely secret(s) that will be committed. Move them out with `externalize_secrets`.{}",
+            path.display(),
+            secret_count,
+            backups_note
+        )),
+        Ok(GitPathStatus::Untracked) => Some(format!(
+            "'{}' is not ignored by Git and contains {} likely secret(s). Add it to .gitignore or move them out with `externalize_secrets`.{}",
+            path.display(),
+            secret_count,
+            backups_note
+        )),
+        Ok(GitPathStatus::Ignored) => match repository.status(&backup_dir) {
+            Ok(GitPathStatus::Ignored) => None,
+            _ => Some(format!(
+                "'{}' is ignored by Git, but its backups in '{}' are not; they will keep copies of its {} likely secret(s). Add that directory to .gitignore.",
+                path.display(),
+                backup_dir.display(),
+                secret_count
+            )),
+        },
+        Err(error) => Some(format!(
+            "'{}' contains {} likely secret(s), and whether Git would commit it is unknown because the repository index could not be read: {}. Check `git status` before committing.{}",
+            path.display(),
+            secret_count,
+            error,
+            backups_note
+        )),
+    }
 }
 
 fn mutate_json_content(
@@ -118,13 +576,35 @@ fn mutate_json_content(
     target_id: &str,
     action: MutationAction,
     payload: &McpMutationPayload,
+    write_enabled_field: bool,
 ) -> Result<String, CommandError> {
-    let mut root = if current_content.trim().is_empty() {
-        serde_json::json!({})
+    let (content, ()) = edit_json_section(descriptor, current_content, |section_object| {
+        apply_json_mcp_mutation(
+            descriptor.client,
+            section_object,
+            target_id,
+            action,
+            payload,
+            write_enabled_field,
+        )
+    })?;
+    Ok(content)
+}
+
+fn edit_json_section<T>(
+    descriptor: &McpSourceDescriptor,
+    current_content: &str,
+    edit: impl FnOnce(&mut serde_json::Map<String, serde_json::Value>) -> Result<T, CommandError>,
+) -> Result<(String, T), CommandError> {
+    let (mut root, has_comments) = if current_content.trim().is_empty() {
+        (serde_json::json!({}), false)
     } else {
-        serde_json::from_str::<serde_json::Value>(current_content).map_err(|error| {
-            CommandError::validation(format!("Invalid JSON MCP config: {}", error))
-        })?
+        let document = parse_jsonc(current_content).map_err(|error| {
+            CommandError::parse(format!("Invalid JSON MCP config: {}", error))
+                .with_path(descriptor.container_path.display().to_string())
+                .with_source_id(&descriptor.source_id)
+        })?;
+        (document.value, document.has_comments)
     };
 
     if !root.is_object() {
@@ -134,19 +614,185 @@ fn mutate_json_content(
     }
 
     let section_object = resolve_json_section_map(&mut root, &descriptor.selector)?;
-    apply_json_mcp_mutation(
-        descriptor.client,
-        section_object,
-        target_id,
-        action,
-        payload,
-    )?;
+    let edited = edit(section_object)?;
+
+    if has_comments {
+        // Re-serializing would silently drop the comments, so refuse instead.
+        let patched = patch_jsonc(current_content, &root).ok_or_else(|| {
+            CommandError::validation(format!(
+                "MCP config '{}' has comments that this edit cannot keep. Edit the file by hand, or remove its comments and retry.",
+                descriptor.container_path.display()
+            ))
+            .with_path(descriptor.container_path.display().to_string())
+            .with_source_id(&descriptor.source_id)
+        })?;
+        return Ok((patched, edited));
+    }
 
     let mut serialized = serde_json::to_string_pretty(&root).map_err(|error| {
         CommandError::internal(format!("Failed to serialize JSON MCP config: {}", error))
     })?;
     serialized.push('\n');
-    Ok(serialized)
+    Ok((serialized, edited))
+}
+
+fn approval_action_error(action: MutationAction) -> CommandError {
+    CommandError::validation(format!(
+        "MCP action '{}' only applies to Claude Code project servers.",
+        action.as_str()
+    ))
+}
+
+fn take_entry(
+    descriptor: &McpSourceDescriptor,
+    current_content: &str,
+    target_id: &str,
+) -> Result<(String, serde_json::Value), CommandError> {
+    let missing = || CommandError::validation(format!("MCP '{}' does not exist.", target_id));
+
+    match descriptor.storage_kind {
+        McpSourceStorageKind::JsonSection => {
+            edit_json_section(descriptor, current_content, |section_object| {
+                section_object.remove(target_id).ok_or_else(missing)
+            })
+        }
+        McpSourceStorageKind::TomlTable => edit_toml_section(current_content, |section_table| {
+            let entry = section_table.remove(target_id).ok_or_else(missing)?;
+            serde_json::to_value(entry).map_err(|error| {
+                CommandError::internal(format!("Failed to convert TOML MCP entry: {}", error))
+            })
+        }),
+    }
+}
+
+fn insert_entry(
+    descriptor: &McpSourceDescriptor,
+    current_content: &str,
+    target_id: &str,
+    entry: &serde_json::Value,
+) -> Result<String, CommandError> {
+    let exists = || {
+        CommandError::validation(format!(
+            "MCP '{}' already exists; remove it before enabling the parked entry.",
+            target_id
+        ))
+    };
+
+    let (content, ()) = match descriptor.storage_kind {
+        McpSourceStorageKind::JsonSection => {
+            edit_json_section(descriptor, current_content, |section_object| {
+                if section_object.contains_key(target_id) {
+                    return Err(exists());
+                }
+                section_object.insert(target_id.to_string(), entry.clone());
+                Ok(())
+            })?
+        }
+        McpSourceStorageKind::TomlTable => {
+            let entry = toml::Value::try_from(entry).map_err(|error| {
+                CommandError::parse(format!("Parked MCP entry is not valid TOML: {}", error))
+            })?;
+            edit_toml_section(current_content, |section_table| {
+                if section_table.contains_key(target_id) {
+                    return Err(exists());
+                }
+                section_table.insert(target_id.to_string(), entry);
+                Ok(())
+            })?
+        }
+    };
+    Ok(content)
+}
+
+/// Edits every server entry of a source as JSON, whatever the file format.
+pub(super) fn edit_server_entries<T>(
+    descriptor: &McpSourceDescriptor,
+    current_content: &str,
+    edit: impl FnOnce(&mut serde_json::Map<String, serde_json::Value>) -> Result<T, CommandError>,
+) -> Result<(String, T), CommandError> {
+    match descriptor.storage_kind {
+        McpSourceStorageKind::JsonSection => edit_json_section(descriptor, current_content, edit),
+        McpSourceStorageKind::TomlTable => edit_toml_section(current_content, |section_table| {
+            let mut entries = serde_json::to_value(&*section_table)
+                .ok()
+                .and_then(|value| match value {
+                    serde_json::Value::Object(entries) => Some(entries),
+                    _ => None,
+                })
+                .ok_or_else(|| {
+                    CommandError::internal("Failed to convert TOML MCP entries to JSON.")
+                })?;
+            let edited = edit(&mut entries)?;
+            *section_table = toml::Table::try_from(entries).map_err(|error| {
+                CommandError::internal(format!("Failed to convert MCP entries to TOML: {}", error))
+            })?;
+            Ok(edited)
+        }),
+    }
+}
+
+/// Whether `transport` describes what `entry` already runs; a missing transport changes nothing.
+fn transport_matches(entry: &serde_json::Value, transport: Option<&McpTransportPayload>) -> bool {
+    let field = |name: &str| entry.get(name).and_then(serde_json::Value::as_str);
+    match transport {
+        None => true,
+        Some(McpTransportPayload::Stdio { command, args }) => {
+            let entry_args = entry
+                .get("args")
+                .and_then(serde_json::Value::as_array)
+                .map(|values| {
+                    values
+                        .iter()
+                        .filter_map(serde_json::Value::as_str)
+                        .collect::<Vec<_>>()
+                })
+                .unwrap_or_default();
+            field("command") == Some(command.as_str()) && entry_args == *args
+        }
+        Some(McpTransportPayload::Sse { url }) => field("url") == Some(url.as_str()),
+    }
+}
+
+/// Builds the entry an add would write, without touching the config.
+fn build_parked_entry(
+    descriptor: &McpSourceDescriptor,
+    current_content: &str,
+    target_id: &str,
+    payload: &McpMutationPayload,
+) -> Result<serde_json::Value, CommandError> {
+    let Some(transport) = payload.transport.as_ref() else {
+        return Err(CommandError::validation(
+            "payload.transport is required for MCP add mutation.",
+        ));
+    };
+    let exists = || CommandError::validation(format!("MCP '{}' already exists.", target_id));
+
+    match descriptor.storage_kind {
+        McpSourceStorageKind::JsonSection => {
+            let (_, entry) = edit_json_section(descriptor, current_content, |section_object| {
+                if section_object.contains_key(target_id) {
+                    return Err(exists());
+                }
+                Ok(build_json_transport_payload(
+                    descriptor.client,
+                    transport,
+                    None,
+                ))
+            })?;
+            Ok(entry)
+        }
+        McpSourceStorageKind::TomlTable => {
+            let (_, entry) = edit_toml_section(current_content, |section_table| {
+                if section_table.contains_key(target_id) {
+                    return Err(exists());
+                }
+                Ok(build_toml_transport_payload(transport, None))
+            })?;
+            serde_json::to_value(entry).map_err(|error| {
+                CommandError::internal(format!("Failed to convert TOML MCP entry: {}", error))
+            })
+        }
+    }
 }
 
 fn resolve_json_section_map<'a>(
@@ -225,6 +871,7 @@ fn apply_json_mcp_mutation(
     target_id: &str,
     action: MutationAction,
     payload: &McpMutationPayload,
+    write_enabled_field: bool,
 ) -> Result<(), CommandError> {
     if matches!(client, ClientKind::ClaudeCode) && payload.enabled == Some(false) {
         return Err(CommandError::validation(
@@ -249,7 +896,11 @@ fn apply_json_mcp_mutation(
 
             section_object.insert(
                 target_id.to_string(),
-                build_json_transport_payload(client, transport, payload.enabled.unwrap_or(true)),
+                build_json_transport_payload(
+                    client,
+                    transport,
+                    Some(payload.enabled.unwrap_or(true)).filter(|_| write_enabled_field),
+                ),
             );
         }
         MutationAction::Remove => {
@@ -260,6 +911,9 @@ fn apply_json_mcp_mutation(
                 )));
             }
         }
+        MutationAction::Approve | MutationAction::Deny => {
+            return Err(approval_action_error(action));
+        }
         MutationAction::Update => {
             let Some(current_entry) = section_object.get(target_id) else {
                 return Err(CommandError::validation(format!(
@@ -284,7 +938,8 @@ fn apply_json_mcp_mutation(
                 build_json_transport_payload(
                     client,
                     transport,
-                    payload.enabled.unwrap_or(current_enabled),
+                    Some(payload.enabled.unwrap_or(current_enabled))
+                        .filter(|_| write_enabled_field),
                 ),
             );
         }
@@ -298,13 +953,29 @@ fn mutate_toml_content(
     target_id: &str,
     action: MutationAction,
     payload: &McpMutationPayload,
+    write_enabled_field: bool,
 ) -> Result<String, CommandError> {
+    let (content, ()) = edit_toml_section(current_content, |section_table| {
+        apply_toml_mcp_mutation(
+            section_table,
+            target_id,
+            action,
+            payload,
+            write_enabled_field,
+        )
+    })?;
+    Ok(content)
+}
+
+fn edit_toml_section<T>(
+    current_content: &str,
+    edit: impl FnOnce(&mut toml::Table) -> Result<T, CommandError>,
+) -> Result<(String, T), CommandError> {
     let mut root = if current_content.trim().is_empty() {
         toml::Table::new()
     } else {
-        toml::from_str::<toml::Table>(current_content).map_err(|error| {
-            CommandError::validation(format!("Invalid TOML MCP config: {}", error))
-        })?
+        toml::from_str::<toml::Table>(current_content)
+            .map_err(|error| CommandError::parse(format!("Invalid TOML MCP config: {}", error)))?
     };
 
     let section_key = if root.contains_key("mcp_servers") {
@@ -332,7 +1003,22 @@ fn mutate_toml_content(
             "TOML MCP section must be a table map.",
         ));
     };
+    let edited = edit(section_table)?;
+
+    let mut serialized = toml::to_string_pretty(&root).map_err(|error| {
+        CommandError::internal(format!("Failed to serialize TOML MCP config: {}", error))
+    })?;
+    serialized.push('\n');
+    Ok((serialized, edited))
+}
 
+fn apply_toml_mcp_mutation(
+    section_table: &mut toml::Table,
+    target_id: &str,
+    action: MutationAction,
+    payload: &McpMutationPayload,
+    write_enabled_field: bool,
+) -> Result<(), CommandError> {
     match action {
         MutationAction::Add => {
             if section_table.contains_key(target_id) {
@@ -350,7 +1036,10 @@ fn mutate_toml_content(
 
             section_table.insert(
                 target_id.to_string(),
-                build_toml_transport_payload(transport, payload.enabled.unwrap_or(true)),
+                build_toml_transport_payload(
+                    transport,
+                    Some(payload.enabled.unwrap_or(true)).filter(|_| write_enabled_field),
+                ),
             );
         }
         MutationAction::Remove => {
@@ -361,6 +1050,9 @@ fn mutate_toml_content(
                 )));
             }
         }
+        MutationAction::Approve | MutationAction::Deny => {
+            return Err(approval_action_error(action));
+        }
         MutationAction::Update => {
             let Some(current_entry) = section_table.get(target_id) else {
                 return Err(CommandError::validation(format!(
@@ -382,22 +1074,22 @@ fn mutate_toml_content(
 
             section_table.insert(
                 target_id.to_string(),
-                build_toml_transport_payload(transport, payload.enabled.unwrap_or(current_enabled)),
+                build_toml_transport_payload(
+                    transport,
+                    Some(payload.enabled.unwrap_or(current_enabled))
+                        .filter(|_| write_enabled_field),
+                ),
             );
         }
     }
 
-    let mut serialized = toml::to_string_pretty(&root).map_err(|error| {
-        CommandError::internal(format!("Failed to serialize TOML MCP config: {}", error))
-    })?;
-    serialized.push('\n');
-    Ok(serialized)
+    Ok(())
 }
 
 fn build_json_transport_payload(
     client: ClientKind,
     transport: &McpTransportPayload,
-    enabled: bool,
+    enabled: Option<bool>,
 ) -> serde_json::Value {
     if matches!(client, ClientKind::ClaudeCode) {
         return match transport {
@@ -432,17 +1124,29 @@ fn build_json_transport_payload(
                     ),
                 );
             }
-            object.insert("enabled".to_string(), serde_json::Value::Bool(enabled));
+            if let Some(enabled) = enabled {
+                object.insert("enabled".to_string(), serde_json::Value::Bool(enabled));
+            }
+            serde_json::Value::Object(object)
+        }
+        McpTransportPayload::Sse { url } => {
+            let mut object = serde_json::Map::new();
+            object.insert(
+                "url".to_string(),
+                serde_json::Value::String(url.to_string()),
+            );
+            if let Some(enabled) = enabled {
+                object.insert("enabled".to_string(), serde_json::Value::Bool(enabled));
+            }
             serde_json::Value::Object(object)
         }
-        McpTransportPayload::Sse { url } => serde_json::json!({
-            "url": url,
-            "enabled": enabled
-        }),
     }
 }
 
-fn build_toml_transport_payload(transport: &McpTransportPayload, enabled: bool) -> toml::Value {
+fn build_toml_transport_payload(
+    transport: &McpTransportPayload,
+    enabled: Option<bool>,
+) -> toml::Value {
     let mut table = toml::Table::new();
 
     match transport {
@@ -463,19 +1167,28 @@ fn build_toml_transport_payload(transport: &McpTransportPayload, enabled: bool)
         }
     }
 
-    table.insert("enabled".to_string(), toml::Value::Boolean(enabled));
+    if let Some(enabled) = enabled {
+        table.insert("enabled".to_string(), toml::Value::Boolean(enabled));
+    }
     toml::Value::Table(table)
 }
 
 #[cfg(test)]
 mod tests {
-    use std::{fs, path::PathBuf};
+    use std::{
+        fs,
+        path::{Path, PathBuf},
+    };
 
     use serde_json::{Value, json};
 
     use crate::{
-        infra::DetectorRegistry,
-        interface::contracts::{common::ClientKind, mutate::MutationAction},
+        infra::{
+            DetectorRegistry, ParkedServerStore, content_revision, detection::FixedVersionDetector,
+        },
+        interface::contracts::{
+            command::CommandErrorCode, common::ClientKind, mutate::MutationAction,
+        },
     };
 
     use super::McpMutationService;
@@ -488,8 +1201,8 @@ mod tests {
         let source = temp_dir.join("claude.json");
         fs::write(&source, "{}").expect("should create json config");
 
-        let detector_registry = DetectorRegistry::with_default_detectors();
-        let service = McpMutationService::new(&detector_registry);
+        let detector_registry = DetectorRegistry::with_installed_version("1.0.0");
+        let service = McpMutationService::new(&detector_registry, parked_store_in(&temp_dir));
         let result = service
             .mutate(
                 ClientKind::ClaudeCode,
@@ -551,8 +1264,8 @@ mod tests {
         )
         .expect("should create claude json config");
 
-        let detector_registry = DetectorRegistry::with_default_detectors();
-        let service = McpMutationService::new(&detector_registry);
+        let detector_registry = DetectorRegistry::with_installed_version("1.0.0");
+        let service = McpMutationService::new(&detector_registry, parked_store_in(&temp_dir));
         service
             .mutate(
                 ClientKind::ClaudeCode,
@@ -615,8 +1328,8 @@ mod tests {
         )
         .expect("should create claude json config");
 
-        let detector_registry = DetectorRegistry::with_default_detectors();
-        let service = McpMutationService::new(&detector_registry);
+        let detector_registry = DetectorRegistry::with_installed_version("1.0.0");
+        let service = McpMutationService::new(&detector_registry, parked_store_in(&temp_dir));
         service
             .mutate(
                 ClientKind::ClaudeCode,
@@ -668,8 +1381,8 @@ mod tests {
 }"#;
         fs::write(&source, original).expect("should create json config");
 
-        let detector_registry = DetectorRegistry::with_default_detectors();
-        let service = McpMutationService::new(&detector_registry);
+        let detector_registry = DetectorRegistry::with_installed_version("1.0.0");
+        let service = McpMutationService::new(&detector_registry, parked_store_in(&temp_dir));
         let error = service
             .mutate(
                 ClientKind::Cursor,
@@ -707,8 +1420,8 @@ mod tests {
         )
         .expect("should create json config");
 
-        let detector_registry = DetectorRegistry::with_default_detectors();
-        let service = McpMutationService::new(&detector_registry);
+        let detector_registry = DetectorRegistry::with_installed_version("1.0.0");
+        let service = McpMutationService::new(&detector_registry, parked_store_in(&temp_dir));
         let result = service
             .mutate(
                 ClientKind::Cursor,
@@ -733,19 +1446,20 @@ mod tests {
     }
 
     #[test]
-    fn update_missing_mcp_is_validation_error() {
+    fn stale_expected_revision_is_conflict_and_non_destructive() {
         let temp_dir = std::env::temp_dir().join(format!(
-            "ai-manager-mcp-update-missing-{}",
+            "ai-manager-mcp-update-conflict-{}",
             std::process::id()
         ));
         let _ = fs::create_dir_all(&temp_dir);
         let source = temp_dir.join("cursor.json");
-        fs::write(&source, r#"{ "mcpServers": {} }"#).expect("should create json config");
+        let listed = r#"{ "mcpServers": { "filesystem": { "command": "npx" } } }"#;
+        let external = r#"{ "mcpServers": { "filesystem": { "command": "uvx" } } }"#;
+        fs::write(&source, external).expect("should create json config");
 
-        let detector_registry = DetectorRegistry::with_default_detectors();
-        let service = McpMutationService::new(&detector_registry);
-        let error = service
-            .mutate(
+        let detector_registry = DetectorRegistry::with_installed_version("1.0.0");
+        let error = McpMutationService::new(&detector_registry, parked_store_in(&temp_dir))
+            .mutate_at_revision(
                 ClientKind::Cursor,
                 MutationAction::Update,
                 "filesystem",
@@ -753,20 +1467,55 @@ mod tests {
                 None,
                 Some(&json!({
                     "source_path": source.display().to_string(),
-                    "transport": { "command": "npx" }
+                    "transport": { "command": "node" }
                 })),
+                Some(&content_revision(listed.as_bytes())),
             )
-            .expect_err("update should fail when entry is missing");
+            .expect_err("stale revision should be rejected");
 
+        let content = fs::read_to_string(&source).expect("should read json config");
         let _ = fs::remove_dir_all(&temp_dir);
-        assert!(error.message.contains("does not exist"));
+
+        assert_eq!(error.code, CommandErrorCode::Conflict);
+        assert!(error.message.contains("changed since it was listed"));
+        assert_eq!(content, external);
     }
 
     #[test]
-    fn remove_mcp_from_toml_config_succeeds() {
-        let temp_dir =
-            std::env::temp_dir().join(format!("ai-manager-mcp-remove-toml-{}", std::process::id()));
-        let _ = fs::create_dir_all(&temp_dir);
+    fn update_missing_mcp_is_validation_error() {
+        let temp_dir = std::env::temp_dir().join(format!(
+            "ai-manager-mcp-update-missing-{}",
+            std::process::id()
+        ));
+        let _ = fs::create_dir_all(&temp_dir);
+        let source = temp_dir.join("cursor.json");
+        fs::write(&source, r#"{ "mcpServers": {} }"#).expect("should create json config");
+
+        let detector_registry = DetectorRegistry::with_installed_version("1.0.0");
+        let service = McpMutationService::new(&detector_registry, parked_store_in(&temp_dir));
+        let error = service
+            .mutate(
+                ClientKind::Cursor,
+                MutationAction::Update,
+                "filesystem",
+                None,
+                None,
+                Some(&json!({
+                    "source_path": source.display().to_string(),
+                    "transport": { "command": "npx" }
+                })),
+            )
+            .expect_err("update should fail when entry is missing");
+
+        let _ = fs::remove_dir_all(&temp_dir);
+        assert!(error.message.contains("does not exist"));
+    }
+
+    #[test]
+    fn remove_mcp_from_toml_config_succeeds() {
+        let temp_dir =
+            std::env::temp_dir().join(format!("ai-manager-mcp-remove-toml-{}", std::process::id()));
+        let _ = fs::create_dir_all(&temp_dir);
         let source = temp_dir.join("codex.toml");
         fs::write(
             &source,
@@ -777,8 +1526,8 @@ enabled = true
         )
         .expect("should create toml config");
 
-        let detector_registry = DetectorRegistry::with_default_detectors();
-        let service = McpMutationService::new(&detector_registry);
+        let detector_registry = DetectorRegistry::with_installed_version("1.0.0");
+        let service = McpMutationService::new(&detector_registry, parked_store_in(&temp_dir));
         service
             .mutate(
                 ClientKind::Codex,
@@ -821,8 +1570,8 @@ enabled = true
             escape_json_pointer_token(&project_root_string)
         );
 
-        let detector_registry = DetectorRegistry::with_default_detectors();
-        let service = McpMutationService::new(&detector_registry);
+        let detector_registry = DetectorRegistry::with_installed_version("1.0.0");
+        let service = McpMutationService::new(&detector_registry, parked_store_in(&temp_dir));
         let result = service
             .mutate(
                 ClientKind::ClaudeCode,
@@ -876,8 +1625,8 @@ enabled = true
             project_config.display()
         );
 
-        let detector_registry = DetectorRegistry::with_default_detectors();
-        let service = McpMutationService::new(&detector_registry);
+        let detector_registry = DetectorRegistry::with_installed_version("1.0.0");
+        let service = McpMutationService::new(&detector_registry, parked_store_in(&temp_dir));
         let result = service
             .mutate(
                 ClientKind::Cursor,
@@ -900,6 +1649,226 @@ enabled = true
         assert!(result.target_source_id.contains("project_shared"));
         assert!(content.contains("\"context7\""));
         assert!(content.contains("\"enabled\": true"));
+        assert_eq!(result.warning, None);
+    }
+
+    #[test]
+    fn project_writes_warn_when_secrets_are_not_ignored_by_git() {
+        let temp_dir = temp_root("cursor-project-git-warning");
+        let project_root = temp_dir.join("workspace");
+        let project_config = project_root.join(".cursor").join("mcp.json");
+        let _ = fs::create_dir_all(project_root.join(".git"));
+        let _ = fs::create_dir_all(project_config.parent().expect("project config parent"));
+        fs::write(&project_config, "{}").expect("should create cursor project config");
+        let project_root_string = project_root.display().to_string();
+        let target_source_id = format!(
+            "mcp::cursor::project_shared::{}::/mcpServers",
+            project_config.display()
+        );
+        let payload = |token: &str| {
+            json!({
+                "source_path": project_config.display().to_string(),
+                "transport": { "command": "npx", "args": ["server", "--token", token] }
+            })
+        };
+
+        let detector_registry = DetectorRegistry::with_installed_version("1.0.0");
+        let service = McpMutationService::new(&detector_registry, parked_store_in(&temp_dir));
+        let exposed = service
+            .mutate(
+                ClientKind::Cursor,
+                MutationAction::Add,
+                "github",
+                Some(project_root_string.as_str()),
+                Some(target_source_id.as_str()),
+                Some(&payload("ghp_abcdefghijklmnop1234")),
+            )
+            .expect("project-shared add should succeed");
+        let unchanged = service
+            .mutate(
+                ClientKind::Cursor,
+                MutationAction::Add,
+                "context7",
+                Some(project_root_string.as_str()),
+                Some(target_source_id.as_str()),
+                Some(&json!({
+                    "source_path": project_config.display().to_string(),
+                    "transport": { "command": "context7" }
+                })),
+            )
+            .expect("second project-shared add should succeed");
+        fs::write(project_root.join(".gitignore"), ".cursor/\n").expect("should write gitignore");
+        let ignored = service
+            .mutate(
+                ClientKind::Cursor,
+                MutationAction::Update,
+                "github",
+                Some(project_root_string.as_str()),
+                Some(target_source_id.as_str()),
+                Some(&payload("ghp_zyxwvutsrqponmlk9876")),
+            )
+            .expect("project-shared update should succeed");
+        let _ = fs::remove_dir_all(&temp_dir);
+
+        assert!(
+            exposed
+                .warning
+                .as_deref()
+                .is_some_and(|warning| warning.contains("not ignored by Git")
+                    && warning.contains(".ai-manager-backups"))
+        );
+        assert_eq!(unchanged.warning, None);
+        assert_eq!(ignored.warning, None);
+    }
+
+    #[test]
+    fn project_writes_warn_about_unreadable_indexes_and_exposed_backups() {
+        let temp_dir = temp_root("cursor-project-git-unknown");
+        let project_root = temp_dir.join("workspace");
+        let project_config = project_root.join(".cursor").join("mcp.json");
+        let _ = fs::create_dir_all(project_root.join(".git"));
+        let _ = fs::create_dir_all(project_config.parent().expect("project config parent"));
+        fs::write(project_root.join(".git").join("index"), "not an index")
+            .expect("should write a corrupt index");
+        fs::write(&project_config, "{}").expect("should create cursor project config");
+        let project_root_string = project_root.display().to_string();
+        let target_source_id = format!(
+            "mcp::cursor::project_shared::{}::/mcpServers",
+            project_config.display()
+        );
+        let detector_registry = DetectorRegistry::with_installed_version("1.0.0");
+        let service = McpMutationService::new(&detector_registry, parked_store_in(&temp_dir));
+        let add = |target_id: &str, token: &str| {
+            service.mutate(
+                ClientKind::Cursor,
+                MutationAction::Add,
+                target_id,
+                Some(project_root_string.as_str()),
+                Some(target_source_id.as_str()),
+                Some(&json!({
+                    "source_path": project_config.display().to_string(),
+                    "transport": { "command": "npx", "args": ["server", "--token", token] }
+                })),
+            )
+        };
+
+        let unknown = add("github", "ghp_abcdefghijklmnop1234").expect("add should succeed");
+        fs::remove_file(project_root.join(".git").join("index")).expect("should drop the index");
+        fs::write(project_root.join(".gitignore"), ".cursor/mcp.json\n")
+            .expect("should write gitignore");
+        let exposed_backups =
+            add("gitlab", "ghp_zyxwvutsrqponmlk9876").expect("add should succeed");
+        let _ = fs::remove_dir_all(&temp_dir);
+
+        assert!(
+            unknown
+                .warning
+                .as_deref()
+                .is_some_and(|warning| warning.contains("is unknown"))
+        );
+        assert!(
+            exposed_backups
+                .warning
+                .as_deref()
+                .is_some_and(|warning| warning.contains("its backups in"))
+        );
+    }
+
+    #[test]
+    fn jsonc_edits_that_would_drop_comments_are_refused() {
+        let temp_dir = temp_root("cursor-jsonc-refused");
+        let source = temp_dir.join("mcp.json");
+        let original = r#"{
+  // duplicated by hand
+  "mcpServers": { "a": { "command": "x" }, "a": { "command": "y" } }
+}
+"#;
+        fs::write(&source, original).expect("should create cursor config");
+
+        let detector_registry = DetectorRegistry::with_installed_version("1.0.0");
+        let service = McpMutationService::new(&detector_registry, parked_store_in(&temp_dir));
+        let error = service
+            .mutate(
+                ClientKind::Cursor,
+                MutationAction::Remove,
+                "a",
+                None,
+                None,
+                Some(&json!({ "source_path": source.display().to_string() })),
+            )
+            .expect_err("an edit that cannot keep comments should be refused");
+        let content = fs::read_to_string(&source).expect("should read config");
+        let _ = fs::remove_dir_all(&temp_dir);
+
+        assert_eq!(error.code, CommandErrorCode::ValidationError);
+        assert!(
+            error
+                .message
+                .contains("comments that this edit cannot keep")
+        );
+        assert_eq!(content, original);
+    }
+
+    #[test]
+    fn jsonc_comments_survive_mcp_mutations() {
+        let temp_dir = temp_root("cursor-jsonc");
+        let project_root = temp_dir.join("workspace");
+        let project_config = project_root.join(".cursor").join("mcp.json");
+        let _ = fs::create_dir_all(project_config.parent().expect("project config parent"));
+        fs::write(
+            &project_config,
+            r#"{
+  // team servers
+  "mcpServers": {
+    "context7": { "command": "context7" }, // docs lookup
+  },
+}
+"#,
+        )
+        .expect("should create cursor project config");
+        let project_root_string = project_root.display().to_string();
+        let target_source_id = format!(
+            "mcp::cursor::project_shared::{}::/mcpServers",
+            project_config.display()
+        );
+
+        let detector_registry = DetectorRegistry::with_installed_version("1.0.0");
+        let service = McpMutationService::new(&detector_registry, parked_store_in(&temp_dir));
+        service
+            .mutate(
+                ClientKind::Cursor,
+                MutationAction::Add,
+                "remote",
+                Some(project_root_string.as_str()),
+                Some(target_source_id.as_str()),
+                Some(&json!({
+                    "source_path": project_config.display().to_string(),
+                    "transport": { "url": "https://mcp.example.com/sse" }
+                })),
+            )
+            .expect("jsonc add should succeed");
+        service
+            .mutate(
+                ClientKind::Cursor,
+                MutationAction::Update,
+                "context7",
+                Some(project_root_string.as_str()),
+                Some(target_source_id.as_str()),
+                Some(&json!({
+                    "source_path": project_config.display().to_string(),
+                    "transport": { "command": "context7-mcp" }
+                })),
+            )
+            .expect("jsonc update should succeed");
+
+        let content =
+            fs::read_to_string(&project_config).expect("should read updated project config");
+        let _ = fs::remove_dir_all(&temp_dir);
+
+        assert!(content.contains("// team servers"));
+        assert!(content.contains("// docs lookup"));
+        assert!(content.contains("\"context7-mcp\""));
+        assert!(content.contains("\"https://mcp.example.com/sse\""));
     }
 
     #[test]
@@ -911,8 +1880,8 @@ enabled = true
         fs::write(&source, "").expect("should create codex config");
         let project_root_string = project_root.display().to_string();
 
-        let detector_registry = DetectorRegistry::with_default_detectors();
-        let service = McpMutationService::new(&detector_registry);
+        let detector_registry = DetectorRegistry::with_installed_version("1.0.0");
+        let service = McpMutationService::new(&detector_registry, parked_store_in(&temp_dir));
         let error = service
             .mutate(
                 ClientKind::Codex,
@@ -935,8 +1904,8 @@ enabled = true
 
     #[test]
     fn malformed_transport_is_actionable_validation_error() {
-        let detector_registry = DetectorRegistry::with_default_detectors();
-        let service = McpMutationService::new(&detector_registry);
+        let detector_registry = DetectorRegistry::with_installed_version("1.0.0");
+        let service = McpMutationService::new(&detector_registry, None);
         let error = service
             .mutate(
                 ClientKind::Codex,
@@ -954,40 +1923,322 @@ enabled = true
     }
 
     #[test]
-    fn claude_rejects_enabled_false_for_schema_compatibility() {
-        let temp_dir = std::env::temp_dir().join(format!(
-            "ai-manager-mcp-claude-enabled-false-{}",
-            std::process::id()
-        ));
-        let _ = fs::create_dir_all(&temp_dir);
+    fn claude_disable_parks_entry_and_enable_restores_it_verbatim() {
+        let temp_dir = temp_root("claude-park");
         let source = temp_dir.join("claude.json");
-        fs::write(&source, "{}").expect("should create json config");
+        let original = json!({
+            "mcpServers": {
+                "filesystem": {
+                    "type": "stdio",
+                    "command": "npx",
+                    "args": ["-y", "server"],
+                    "env": { "ROOT": "/data" }
+                }
+            }
+        });
+        fs::write(&source, original.to_string()).expect("should create json config");
+        let store_path = temp_dir.join("parked.json");
+
+        let detector_registry = DetectorRegistry::with_installed_version("1.0.0");
+        let service =
+            McpMutationService::new(&detector_registry, Some(ParkedServerStore::at(&store_path)));
+        let update = |enabled: bool| {
+            service.mutate(
+                ClientKind::ClaudeCode,
+                MutationAction::Update,
+                "filesystem",
+                None,
+                None,
+                Some(&json!({
+                    "source_path": source.display().to_string(),
+                    "transport": { "command": "npx", "args": ["-y", "server"] },
+                    "enabled": enabled
+                })),
+            )
+        };
 
-        let detector_registry = DetectorRegistry::with_default_detectors();
-        let service = McpMutationService::new(&detector_registry);
-        let error = service
+        let disabled = update(false).expect("disabling should park the entry");
+        let parked_config: Value =
+            serde_json::from_str(&fs::read_to_string(&source).expect("should read parked config"))
+                .expect("parked config should stay valid JSON");
+        let edit_while_parked = update(false).expect_err("parked entries cannot be edited");
+        update(true).expect("enabling should restore the entry");
+        let restored_config: Value = serde_json::from_str(
+            &fs::read_to_string(&source).expect("should read restored config"),
+        )
+        .expect("restored config should stay valid JSON");
+        let store_after = ParkedServerStore::at(&store_path)
+            .load()
+            .expect("store should load");
+
+        service
             .mutate(
                 ClientKind::ClaudeCode,
                 MutationAction::Add,
-                "filesystem",
+                "github",
                 None,
                 None,
                 Some(&json!({
                     "source_path": source.display().to_string(),
-                    "transport": { "command": "npx", "args": ["-y", "server"] },
+                    "transport": { "url": "https://mcp.example.com/sse" },
                     "enabled": false
                 })),
             )
-            .expect_err("enabled=false should be rejected for Claude");
+            .expect("adding a disabled server should park it");
+        let parked_add = ParkedServerStore::at(&store_path)
+            .load()
+            .expect("store should load");
         let _ = fs::remove_dir_all(&temp_dir);
 
-        assert!(error.message.contains("does not support `enabled=false`"));
+        assert!(disabled.message.contains("parked"));
+        assert_eq!(parked_config, json!({ "mcpServers": {} }));
+        assert!(
+            edit_while_parked
+                .message
+                .contains("enable it before editing")
+        );
+        assert_eq!(restored_config, original);
+        assert!(store_after.servers.is_empty());
+        assert_eq!(parked_add.servers.len(), 1);
+        assert_eq!(
+            parked_add.servers[0].entry,
+            json!({ "type": "sse", "url": "https://mcp.example.com/sse" })
+        );
+    }
+
+    #[test]
+    fn parked_toggles_reject_transport_changes_in_both_directions() {
+        let temp_dir = temp_root("claude-park-edits");
+        let source = temp_dir.join("claude.json");
+        let original = json!({
+            "mcpServers": {
+                "filesystem": { "type": "stdio", "command": "npx", "args": ["server"] }
+            }
+        });
+        fs::write(&source, original.to_string()).expect("should create json config");
+        let store_path = temp_dir.join("parked.json");
+
+        let detector_registry = DetectorRegistry::with_installed_version("1.0.0");
+        let service =
+            McpMutationService::new(&detector_registry, Some(ParkedServerStore::at(&store_path)));
+        let update = |command: &str, enabled: bool| {
+            service.mutate(
+                ClientKind::ClaudeCode,
+                MutationAction::Update,
+                "filesystem",
+                None,
+                None,
+                Some(&json!({
+                    "source_path": source.display().to_string(),
+                    "transport": { "command": command, "args": ["server"] },
+                    "enabled": enabled
+                })),
+            )
+        };
+
+        let disable_with_edit = update("uvx", false).expect_err("disabling cannot edit");
+        let config_after_refused_disable: Value =
+            serde_json::from_str(&fs::read_to_string(&source).expect("should read config"))
+                .expect("config should stay valid JSON");
+        let store_after_refused_disable = ParkedServerStore::at(&store_path)
+            .load()
+            .expect("store should load");
+        update("npx", false).expect("disabling should park the entry");
+        let enable_with_edit = update("uvx", true).expect_err("enabling cannot edit");
+        let store_after_refused_enable = ParkedServerStore::at(&store_path)
+            .load()
+            .expect("store should load");
+        let _ = fs::remove_dir_all(&temp_dir);
+
+        assert_eq!(disable_with_edit.code, CommandErrorCode::ValidationError);
+        assert!(disable_with_edit.message.contains("`enabled: false`"));
+        assert_eq!(config_after_refused_disable, original);
+        assert!(store_after_refused_disable.servers.is_empty());
+        assert_eq!(enable_with_edit.code, CommandErrorCode::ValidationError);
+        assert!(enable_with_edit.message.contains("`enabled: true`"));
+        assert_eq!(store_after_refused_enable.servers.len(), 1);
+        assert_eq!(
+            store_after_refused_enable.servers[0].entry,
+            original["mcpServers"]["filesystem"]
+        );
     }
 
     fn escape_json_pointer_token(value: &str) -> String {
         value.replace('~', "~0").replace('/', "~1")
     }
 
+    #[test]
+    fn old_codex_versions_omit_enabled_and_refuse_remote_transport() {
+        let temp_dir = temp_root("old-codex");
+        let source = temp_dir.join("config.toml");
+        fs::write(&source, "").expect("should create toml config");
+
+        let detector_registry = DetectorRegistry::from_detectors(vec![Box::new(
+            FixedVersionDetector(ClientKind::Codex, "codex-cli 0.30.0"),
+        )]);
+        let store_path = temp_dir.join("parked.json");
+        let service =
+            McpMutationService::new(&detector_registry, Some(ParkedServerStore::at(&store_path)));
+        let result = service
+            .mutate(
+                ClientKind::Codex,
+                MutationAction::Add,
+                "filesystem",
+                None,
+                None,
+                Some(&json!({
+                    "source_path": source.display().to_string(),
+                    "transport": { "command": "npx" },
+                    "enabled": true
+                })),
+            )
+            .expect("stdio add should succeed on old Codex");
+        let disabled = service
+            .mutate(
+                ClientKind::Codex,
+                MutationAction::Update,
+                "filesystem",
+                None,
+                None,
+                Some(&json!({
+                    "source_path": source.display().to_string(),
+                    "transport": { "command": "npx" },
+                    "enabled": false
+                })),
+            )
+            .expect("old Codex servers are disabled by parking them");
+        let remote = service
+            .mutate(
+                ClientKind::Codex,
+                MutationAction::Add,
+                "remote",
+                None,
+                None,
+                Some(&json!({
+                    "source_path": source.display().to_string(),
+                    "transport": { "url": "https://example.com/mcp" }
+                })),
+            )
+            .expect_err("old Codex cannot read url servers");
+
+        let content = fs::read_to_string(&source).expect("should read updated toml config");
+        let parked = ParkedServerStore::at(&store_path)
+            .load()
+            .expect("store should load");
+        let _ = fs::remove_dir_all(&temp_dir);
+
+        assert!(!content.contains("filesystem"));
+        assert!(!content.contains("remote"));
+        assert!(result.message.contains("Omitted `enabled`"));
+        assert!(disabled.message.contains("parked"));
+        assert_eq!(parked.servers.len(), 1);
+        assert_eq!(parked.servers[0].entry, json!({ "command": "npx" }));
+        assert!(remote.message.contains("url-based MCP servers"));
+    }
+
+    #[test]
+    fn approve_and_deny_record_claude_project_approvals_locally() {
+        let temp_dir = temp_root("claude-approvals");
+        let project_root = temp_dir.join("workspace");
+        fs::create_dir_all(project_root.join(".claude")).expect("should create project dirs");
+        fs::write(
+            project_root.join(".mcp.json"),
+            r#"{ "mcpServers": { "github": { "url": "https://mcp.example.com/sse" } } }"#,
+        )
+        .expect("should create project config");
+        let settings_path = project_root.join(".claude").join("settings.local.json");
+        fs::write(&settings_path, r#"{ "permissions": { "allow": [] } }"#)
+            .expect("should create local settings");
+        let project_root = project_root.display().to_string();
+
+        let detector_registry = DetectorRegistry::with_installed_version("1.0.0");
+        let service = McpMutationService::new(&detector_registry, parked_store_in(&temp_dir));
+        let mutate = |client: ClientKind, action: MutationAction, target_id: &str| {
+            service.mutate(client, action, target_id, Some(&project_root), None, None)
+        };
+
+        let approved = mutate(ClientKind::ClaudeCode, MutationAction::Approve, "github")
+            .expect("approve should succeed");
+        let after_approve: Value = serde_json::from_str(
+            &fs::read_to_string(&settings_path).expect("should read settings"),
+        )
+        .expect("settings should stay valid JSON");
+        mutate(ClientKind::ClaudeCode, MutationAction::Deny, "github")
+            .expect("deny should succeed");
+        let after_deny: Value = serde_json::from_str(
+            &fs::read_to_string(&settings_path).expect("should read settings"),
+        )
+        .expect("settings should stay valid JSON");
+        let unknown = mutate(ClientKind::ClaudeCode, MutationAction::Approve, "missing")
+            .expect_err("unknown servers cannot be approved");
+        let cursor = mutate(ClientKind::Cursor, MutationAction::Approve, "github")
+            .expect_err("approvals are Claude-only");
+        let _ = fs::remove_dir_all(&temp_dir);
+
+        assert_eq!(approved.source_path, settings_path.display().to_string());
+        assert!(approved.target_source_id.contains("project_shared"));
+        assert_eq!(
+            after_approve,
+            json!({ "permissions": { "allow": [] }, "enabledMcpjsonServers": ["github"] })
+        );
+        assert_eq!(
+            after_deny,
+            json!({
+                "permissions": { "allow": [] },
+                "enabledMcpjsonServers": [],
+                "disabledMcpjsonServers": ["github"]
+            })
+        );
+        assert!(unknown.message.contains("is not defined"));
+        assert_eq!(cursor.code, CommandErrorCode::ValidationError);
+    }
+
+    #[test]
+    fn approving_warns_when_another_settings_file_still_denies_the_server() {
+        let temp_dir = temp_root("claude-approval-denied");
+        let project_root = temp_dir.join("workspace");
+        fs::create_dir_all(project_root.join(".claude")).expect("should create project dirs");
+        fs::write(
+            project_root.join(".mcp.json"),
+            r#"{ "mcpServers": { "github": { "url": "https://mcp.example.com/sse" } } }"#,
+        )
+        .expect("should create project config");
+        let shared_settings = project_root.join(".claude").join("settings.json");
+        fs::write(
+            &shared_settings,
+            r#"{ "disabledMcpjsonServers": ["github"] }"#,
+        )
+        .expect("should create shared settings");
+        let project_root = project_root.display().to_string();
+
+        let detector_registry = DetectorRegistry::with_installed_version("1.0.0");
+        let service = McpMutationService::new(&detector_registry, parked_store_in(&temp_dir));
+        let approved = service
+            .mutate(
+                ClientKind::ClaudeCode,
+                MutationAction::Approve,
+                "github",
+                Some(&project_root),
+                None,
+                None,
+            )
+            .expect("approve should still record the local approval");
+        let _ = fs::remove_dir_all(&temp_dir);
+
+        let warning = approved
+            .warning
+            .expect("a remaining denial should produce a warning");
+        assert!(warning.contains("still denies MCP 'github'"));
+        assert!(warning.contains(&shared_settings.display().to_string()));
+    }
+
+    /// Keeps tests away from the real `~/.ai-manager` parked-server store.
+    fn parked_store_in(directory: &Path) -> Option<ParkedServerStore> {
+        Some(ParkedServerStore::at(
+            directory.join("parked-mcp-servers.json"),
+        ))
+    }
+
     fn temp_root(suffix: &str) -> PathBuf {
         let root = std::env::temp_dir().join(format!(
             "ai-manager-mcp-mutation-{}-{}",
@@ -231,7 +231,7 @@ mod tests {
 
     #[test]
     fn resolve_prefers_project_shared_descriptor_for_matching_project_file() {
-        let detector_registry = DetectorRegistry::with_default_detectors();
+        let detector_registry = DetectorRegistry::with_installed_version("1.0.0");
         let resolver = McpMutationTargetResolver::new(&detector_registry);
         let project_root = temp_project_root("target-project-shared");
         let project_config = project_root.join(".cursor").join("mcp.json");
@@ -261,7 +261,7 @@ mod tests {
 
     #[test]
     fn resolve_rejects_unsupported_target_scope_for_codex() {
-        let detector_registry = DetectorRegistry::with_default_detectors();
+        let detector_registry = DetectorRegistry::with_installed_version("1.0.0");
         let resolver = McpMutationTargetResolver::new(&detector_registry);
         let project_root = temp_project_root("target-unsupported-scope");
         let project_source = project_root.join("config.toml");
@@ -285,7 +285,7 @@ mod tests {
 
     #[test]
     fn resolve_builds_project_private_descriptor_from_explicit_target() {
-        let detector_registry = DetectorRegistry::with_default_detectors();
+        let detector_registry = DetectorRegistry::with_installed_version("1.0.0");
         let resolver = McpMutationTargetResolver::new(&detector_registry);
         let project_root = temp_project_root("target-project-private");
         let claude_config = project_root.join("claude.json");
@@ -0,0 +1,290 @@
+use std::time::Duration;
+
+use crate::{
+    infra::{
+        RemoteLaunch, RemoteProbeFailure, RemoteTransport, StdioLaunch, StdioProbeFailure,
+        probe_remote_server, probe_stdio_server,
+    },
+    interface::contracts::{
+        command::CommandError,
+        probe::{
+            McpProbeStatus, McpRemoteTransport, ProbeMcpServerRequest, ProbeMcpServerResponse,
+        },
+    },
+};
+
+const DEFAULT_TIMEOUT_MS: u64 = 10_000;
+const MAX_TIMEOUT_MS: u64 = 60_000;
+
+pub struct McpProbeService;
+
+impl McpProbeService {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn probe(
+        &self,
+        request: &ProbeMcpServerRequest,
+    ) -> Result<ProbeMcpServerResponse, CommandError> {
+        let resource = &request.resource;
+
+        let mut response = ProbeMcpServerResponse {
+            client: resource.client,
+            target_id: resource.display_name.clone(),
+            status: McpProbeStatus::Unsupported,
+            protocol_version: None,
+            server_name: None,
+            server_version: None,
+            tool_count: None,
+            tool_names: Vec::new(),
+            prompt_names: Vec::new(),
+            resource_uris: Vec::new(),
+            remote_transport: None,
+            http_status: None,
+            resolved_addresses: Vec::new(),
+            elapsed_ms: 0,
+            stderr_tail: Vec::new(),
+            message: String::new(),
+        };
+        let timeout_ms = request
+            .timeout_ms
+            .unwrap_or(DEFAULT_TIMEOUT_MS)
+            .clamp(1, MAX_TIMEOUT_MS);
+
+        if let Some(command) = non_empty(resource.transport_command.as_deref()) {
+            probe_stdio(request, command, timeout_ms, &mut response);
+            return Ok(response);
+        }
+
+        let Some(url) = non_empty(resource.transport_url.as_deref()) else {
+            return Err(CommandError::validation(format!(
+                "MCP resource '{}' has no stdio command or URL to probe.",
+                resource.display_name
+            )));
+        };
+        if !url.starts_with("http://") && !url.starts_with("https://") {
+            response.message = format!(
+                "'{}' uses a transport the probe cannot reach; only http(s) endpoints are checked.",
+                resource.display_name
+            );
+            return Ok(response);
+        }
+
+        probe_remote(request, url, timeout_ms, &mut response);
+        Ok(response)
+    }
+}
+
+fn probe_stdio(
+    request: &ProbeMcpServerRequest,
+    command: &str,
+    timeout_ms: u64,
+    response: &mut ProbeMcpServerResponse,
+) {
+    let resource = &request.resource;
+    let report = probe_stdio_server(&StdioLaunch {
+        command: command.to_string(),
+        args: resource.transport_args.clone().unwrap_or_default(),
+        env: request.env.clone(),
+        timeout: Duration::from_millis(timeout_ms),
+    });
+
+    response.elapsed_ms = report.elapsed.as_millis() as u64;
+    response.stderr_tail = report.stderr_tail;
+
+    match report.outcome {
+        Ok(handshake) => {
+            response.status = McpProbeStatus::Healthy;
+            response.message = format!(
+                "'{}' completed initialize and tools/list with {} tool(s).",
+                resource.display_name,
+                handshake.tool_names.len()
+            );
+            response.protocol_version = handshake.protocol_version;
+            response.server_name = handshake.server_name;
+            response.server_version = handshake.server_version;
+            response.tool_count = Some(handshake.tool_names.len());
+            response.tool_names = handshake.tool_names;
+            response.prompt_names = handshake.prompt_names;
+            response.resource_uris = handshake.resource_uris;
+        }
+        Err(StdioProbeFailure::Spawn(reason)) => {
+            response.status = McpProbeStatus::SpawnFailed;
+            response.message = format!("Failed to launch '{command}': {reason}");
+        }
+        Err(StdioProbeFailure::TimedOut { stage }) => {
+            response.status = McpProbeStatus::TimedOut;
+            response.message = format!("No response to '{stage}' within {timeout_ms} ms.");
+        }
+        Err(StdioProbeFailure::Exited { stage }) => {
+            response.status = McpProbeStatus::HandshakeFailed;
+            response.message = format!("Server exited before answering '{stage}'.");
+        }
+        Err(StdioProbeFailure::Protocol { stage, message }) => {
+            response.status = McpProbeStatus::HandshakeFailed;
+            response.message = format!("Server rejected '{stage}': {message}");
+        }
+    }
+}
+
+fn probe_remote(
+    request: &ProbeMcpServerRequest,
+    url: &str,
+    timeout_ms: u64,
+    response: &mut ProbeMcpServerResponse,
+) {
+    let report = probe_remote_server(&RemoteLaunch {
+        url: url.to_string(),
+        headers: request.headers.clone(),
+        timeout: Duration::from_millis(timeout_ms),
+    });
+
+    response.elapsed_ms = report.elapsed.as_millis() as u64;
+    response.http_status = report.http_status;
+    response.resolved_addresses = report.resolved_addresses;
+
+    match report.outcome {
+        Ok(handshake) => {
+            let transport = match handshake.transport {
+                RemoteTransport::StreamableHttp => McpRemoteTransport::StreamableHttp,
+                RemoteTransport::Sse => McpRemoteTransport::Sse,
+            };
+            response.status = McpProbeStatus::Healthy;
+            response.message = format!(
+                "'{}' answered initialize over {}.",
+                request.resource.display_name,
+                match transport {
+                    McpRemoteTransport::StreamableHttp => "streamable HTTP",
+                    McpRemoteTransport::Sse => "SSE",
+                }
+            );
+            response.remote_transport = Some(transport);
+            response.protocol_version = handshake.protocol_version;
+            response.server_name = handshake.server_name;
+            response.server_version = handshake.server_version;
+        }
+        Err(RemoteProbeFailure::InvalidUrl(reason)) => {
+            response.status = McpProbeStatus::Unsupported;
+            response.message = format!("Cannot probe '{url}': {reason}");
+        }
+        Err(RemoteProbeFailure::Dns(reason)) => {
+            response.status = McpProbeStatus::DnsFailed;
+            response.message = format!("DNS lookup failed: {reason}");
+        }
+        Err(RemoteProbeFailure::Connect(reason)) => {
+            response.status = McpProbeStatus::ConnectFailed;
+            response.message = format!("Could not connect to '{url}': {reason}");
+        }
+        Err(RemoteProbeFailure::Tls(reason)) => {
+            response.status = McpProbeStatus::TlsFailed;
+            response.message = format!("TLS handshake failed: {reason}");
+        }
+        Err(RemoteProbeFailure::TimedOut { stage }) => {
+            response.status = McpProbeStatus::TimedOut;
+            response.message = format!("No response to '{stage}' within {timeout_ms} ms.");
+        }
+        Err(RemoteProbeFailure::HttpStatus { stage, status }) => {
+            response.status = McpProbeStatus::HttpError;
+            response.message = format!("Endpoint returned HTTP {status} during '{stage}'.");
+        }
+        Err(RemoteProbeFailure::Protocol { stage, message }) => {
+            response.status = McpProbeStatus::HandshakeFailed;
+            response.message = format!("Server rejected '{stage}': {message}");
+        }
+    }
+}
+
+fn non_empty(value: Option<&str>) -> Option<&str> {
+    value.map(str::trim).filter(|value| !value.is_empty())
+}
+
+impl Default for McpProbeService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::McpProbeService;
+    use crate::{
+        domain::{ClientKind, ResourceRecord, ResourceSourceScope},
+        interface::contracts::probe::{McpProbeStatus, ProbeMcpServerRequest},
+    };
+
+    fn record(command: Option<&str>, url: Option<&str>) -> ResourceRecord {
+        ResourceRecord {
+            id: "cursor::mcp::demo".to_string(),
+            logical_id: "mcp::demo".to_string(),
+            client: ClientKind::Cursor,
+            display_name: "demo".to_string(),
+            enabled: true,
+            transport_kind: Some(if url.is_some() { "sse" } else { "stdio" }.to_string()),
+            transport_command: command.map(str::to_string),
+            transport_args: None,
+            transport_url: url.map(str::to_string),
+            source_path: None,
+            source_id: "mcp::cursor::user".to_string(),
+            source_scope: ResourceSourceScope::User,
+            source_label: "Personal".to_string(),
+            is_effective: true,
+            shadowed_by: None,
+            description: None,
+            install_kind: None,
+            manifest_content: None,
+            approval: None,
+        }
+    }
+
+    #[test]
+    fn non_http_remote_transport_is_reported_as_unsupported() {
+        let response = McpProbeService::new()
+            .probe(&ProbeMcpServerRequest {
+                resource: record(None, Some("ws://example.com/mcp")),
+                env: BTreeMap::new(),
+                headers: BTreeMap::new(),
+                timeout_ms: None,
+            })
+            .expect("remote record should produce a response");
+
+        assert_eq!(response.status, McpProbeStatus::Unsupported);
+    }
+
+    #[test]
+    fn refused_remote_connection_is_reported_with_resolved_addresses() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").expect("port should bind");
+        let port = listener.local_addr().expect("address").port();
+        drop(listener);
+
+        let response = McpProbeService::new()
+            .probe(&ProbeMcpServerRequest {
+                resource: record(None, Some(&format!("http://127.0.0.1:{port}/mcp"))),
+                env: BTreeMap::new(),
+                headers: BTreeMap::new(),
+                timeout_ms: Some(2_000),
+            })
+            .expect("connection failures are reported, not raised");
+
+        assert_eq!(response.status, McpProbeStatus::ConnectFailed);
+        assert_eq!(response.resolved_addresses, vec!["127.0.0.1"]);
+        assert_eq!(response.http_status, None);
+    }
+
+    #[test]
+    fn missing_binary_is_reported_as_spawn_failure() {
+        let response = McpProbeService::new()
+            .probe(&ProbeMcpServerRequest {
+                resource: record(Some("/nonexistent/ai-manager-demo-server"), None),
+                env: BTreeMap::new(),
+                headers: BTreeMap::new(),
+                timeout_ms: Some(1_000),
+            })
+            .expect("spawn failures are reported, not raised");
+
+        assert_eq!(response.status, McpProbeStatus::SpawnFailed);
+        assert_eq!(response.tool_count, None);
+    }
+}
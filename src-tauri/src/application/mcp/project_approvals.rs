@@ -0,0 +1,207 @@
+use std::{
+    collections::BTreeSet,
+    io,
+    path::{Path, PathBuf},
+};
+
+use serde_json::Value;
+
+use crate::{domain::McpApprovalState, infra::parsers::parse_jsonc};
+
+const ENABLED_KEY: &str = "enabledMcpjsonServers";
+const DISABLED_KEY: &str = "disabledMcpjsonServers";
+const ENABLE_ALL_KEY: &str = "enableAllProjectMcpServers";
+
+/// Claude Code settings files that carry project MCP approvals, lowest precedence first.
+pub fn claude_settings_paths(project_root: &str) -> Vec<PathBuf> {
+    let project_settings_dir = Path::new(project_root).join(".claude");
+    let mut paths: Vec<PathBuf> = std::env::var_os("HOME")
+        .or_else(|| std::env::var_os("USERPROFILE"))
+        .map(|home| PathBuf::from(home).join(".claude").join("settings.json"))
+        .into_iter()
+        .collect();
+    paths.push(project_settings_dir.join("settings.json"));
+    paths.push(project_settings_dir.join("settings.local.json"));
+    paths
+}
+
+/// Where Claude Code records approvals made for a single checkout.
+pub fn claude_local_settings_path(project_root: &str) -> PathBuf {
+    Path::new(project_root)
+        .join(".claude")
+        .join("settings.local.json")
+}
+
+/// Approval lists merged across every Claude settings file.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ClaudeProjectApprovals {
+    enabled: BTreeSet<String>,
+    disabled: BTreeSet<String>,
+    enable_all: Option<bool>,
+}
+
+impl ClaudeProjectApprovals {
+    /// Missing or unparsable settings files contribute nothing.
+    pub fn load<F>(paths: &[PathBuf], read_source: F) -> Self
+    where
+        F: Fn(&str) -> io::Result<String>,
+    {
+        let mut approvals = Self::default();
+        for path in paths {
+            let Ok(content) = read_source(&path.display().to_string()) else {
+                continue;
+            };
+            let Ok(document) = parse_jsonc(&content) else {
+                continue;
+            };
+            approvals.merge(&document.value);
+        }
+        approvals
+    }
+
+    fn merge(&mut self, settings: &Value) {
+        self.enabled.extend(string_list(settings, ENABLED_KEY));
+        self.disabled.extend(string_list(settings, DISABLED_KEY));
+        if let Some(enable_all) = settings.get(ENABLE_ALL_KEY).and_then(Value::as_bool) {
+            self.enable_all = Some(enable_all);
+        }
+    }
+
+    /// Settings files among `paths` whose `disabledMcpjsonServers` lists `server_name`.
+    pub fn denying_files<F>(paths: &[PathBuf], server_name: &str, read_source: F) -> Vec<PathBuf>
+    where
+        F: Fn(&str) -> io::Result<String>,
+    {
+        paths
+            .iter()
+            .filter(|path| {
+                Self::load(std::slice::from_ref(*path), &read_source)
+                    .disabled
+                    .contains(server_name)
+            })
+            .cloned()
+            .collect()
+    }
+
+    pub fn state(&self, server_name: &str) -> McpApprovalState {
+        if self.disabled.contains(server_name) {
+            McpApprovalState::Denied
+        } else if self.enabled.contains(server_name) || self.enable_all == Some(true) {
+            McpApprovalState::Approved
+        } else {
+            McpApprovalState::Pending
+        }
+    }
+}
+
+/// Moves `server_name` into the approved (or denied) list of a settings document.
+pub fn record_approval(
+    settings: &mut serde_json::Map<String, Value>,
+    server_name: &str,
+    approve: bool,
+) {
+    let (add_key, remove_key) = if approve {
+        (ENABLED_KEY, DISABLED_KEY)
+    } else {
+        (DISABLED_KEY, ENABLED_KEY)
+    };
+
+    if let Some(Value::Array(entries)) = settings.get_mut(remove_key) {
+        entries.retain(|entry| entry.as_str() != Some(server_name));
+    }
+    let entries = settings
+        .entry(add_key)
+        .or_insert_with(|| Value::Array(Vec::new()));
+    if !entries.is_array() {
+        *entries = Value::Array(Vec::new());
+    }
+    if let Value::Array(entries) = entries
+        && !entries
+            .iter()
+            .any(|entry| entry.as_str() == Some(server_name))
+    {
+        entries.push(Value::String(server_name.to_string()));
+    }
+}
+
+fn string_list<'a>(settings: &'a Value, key: &str) -> impl Iterator<Item = String> + 'a {
+    settings
+        .get(key)
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(Value::as_str)
+        .map(str::to_string)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, io, path::PathBuf};
+
+    use serde_json::json;
+
+    use super::{ClaudeProjectApprovals, record_approval};
+    use crate::domain::McpApprovalState;
+
+    #[test]
+    fn approvals_merge_settings_files_with_denials_winning() {
+        let fixtures: HashMap<&str, &str> = HashMap::from([
+            (
+                "/home/.claude/settings.json",
+                r#"{ "enableAllProjectMcpServers": true }"#,
+            ),
+            (
+                "/repo/.claude/settings.json",
+                r#"{ "disabledMcpjsonServers": ["github"] }"#,
+            ),
+            (
+                "/repo/.claude/settings.local.json",
+                r#"{
+  // approved locally
+  "enabledMcpjsonServers": ["github", "filesystem"],
+  "enableAllProjectMcpServers": false
+}"#,
+            ),
+        ]);
+        let paths = [
+            "/home/.claude/settings.json",
+            "/repo/.claude/settings.json",
+            "/repo/.claude/settings.local.json",
+        ]
+        .map(PathBuf::from);
+
+        let approvals = ClaudeProjectApprovals::load(&paths, |path| {
+            fixtures
+                .get(path)
+                .map(|content| content.to_string())
+                .ok_or_else(|| io::Error::from(io::ErrorKind::NotFound))
+        });
+
+        assert_eq!(approvals.state("github"), McpApprovalState::Denied);
+        assert_eq!(approvals.state("filesystem"), McpApprovalState::Approved);
+        assert_eq!(approvals.state("context7"), McpApprovalState::Pending);
+    }
+
+    #[test]
+    fn record_approval_moves_server_between_lists() {
+        let mut settings = json!({
+            "disabledMcpjsonServers": ["github", "other"],
+            "permissions": { "allow": [] }
+        });
+        let object = settings
+            .as_object_mut()
+            .expect("settings should be an object");
+
+        record_approval(object, "github", true);
+        record_approval(object, "github", true);
+
+        assert_eq!(
+            settings,
+            json!({
+                "disabledMcpjsonServers": ["other"],
+                "enabledMcpjsonServers": ["github"],
+                "permissions": { "allow": [] }
+            })
+        );
+    }
+}
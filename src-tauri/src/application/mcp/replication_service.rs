@@ -2,7 +2,10 @@ use serde_json::json;
 
 use crate::{
     domain::{ClientKind, ResourceKind, ResourceRecord},
-    infra::DetectorRegistry,
+    infra::{
+        DetectorRegistry, ParkedServerStore,
+        parsers::{InterpolationSyntax, translate_interpolation},
+    },
     interface::contracts::{
         command::CommandError,
         list::{ListResourcesRequest, ResourceViewMode},
@@ -25,11 +28,19 @@ pub struct McpReplicationResult {
 
 pub struct McpReplicationService<'a> {
     detector_registry: &'a DetectorRegistry,
+    parked_store: Option<ParkedServerStore>,
 }
 
 impl<'a> McpReplicationService<'a> {
-    pub fn new(detector_registry: &'a DetectorRegistry) -> Self {
-        Self { detector_registry }
+    /// `parked_store` is handed to the mutation that writes the destination entry.
+    pub fn new(
+        detector_registry: &'a DetectorRegistry,
+        parked_store: Option<ParkedServerStore>,
+    ) -> Self {
+        Self {
+            detector_registry,
+            parked_store,
+        }
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -97,22 +108,23 @@ impl<'a> McpReplicationService<'a> {
         }
 
         let payload = json!({
-            "transport": build_transport_payload(&source_record)?,
+            "transport": build_transport_payload(&source_record, destination_client)?,
             "enabled": source_record.enabled,
         });
 
-        let outcome = McpMutationService::new(self.detector_registry).mutate(
-            destination_client,
-            if destination_exists {
-                crate::interface::contracts::mutate::MutationAction::Update
-            } else {
-                crate::interface::contracts::mutate::MutationAction::Add
-            },
-            destination_target_id.as_str(),
-            destination_project_root,
-            Some(resolved_destination_source_id.as_str()),
-            Some(&payload),
-        )?;
+        let outcome = McpMutationService::new(self.detector_registry, self.parked_store.clone())
+            .mutate(
+                destination_client,
+                if destination_exists {
+                    crate::interface::contracts::mutate::MutationAction::Update
+                } else {
+                    crate::interface::contracts::mutate::MutationAction::Add
+                },
+                destination_target_id.as_str(),
+                destination_project_root,
+                Some(resolved_destination_source_id.as_str()),
+                Some(&payload),
+            )?;
 
         Ok(build_replication_result(
             &source_record,
@@ -192,7 +204,25 @@ fn validate_source_context(
 
 fn build_transport_payload(
     source_record: &ResourceRecord,
+    destination_client: ClientKind,
 ) -> Result<serde_json::Value, CommandError> {
+    let translate = |value: &str, field: &str| {
+        translate_interpolation(
+            value,
+            InterpolationSyntax::for_client(source_record.client),
+            InterpolationSyntax::for_client(destination_client),
+        )
+        .map_err(|placeholder| {
+            CommandError::validation(format!(
+                "MCP '{}' uses `{}` in its {}, which has no equivalent in '{}'. Replace the reference with a literal value before replicating.",
+                source_record.display_name,
+                placeholder,
+                field,
+                destination_client.as_str()
+            ))
+        })
+    };
+
     match source_record.transport_kind.as_deref() {
         Some("stdio") => {
             let Some(command) = source_record.transport_command.as_ref() else {
@@ -201,10 +231,16 @@ fn build_transport_payload(
                     source_record.display_name
                 )));
             };
+            let args = source_record
+                .transport_args
+                .iter()
+                .flatten()
+                .map(|arg| translate(arg, "args"))
+                .collect::<Result<Vec<_>, _>>()?;
 
             Ok(json!({
-                "command": command,
-                "args": source_record.transport_args.clone().unwrap_or_default(),
+                "command": translate(command, "command")?,
+                "args": args,
             }))
         }
         Some("sse") => {
@@ -215,7 +251,7 @@ fn build_transport_payload(
                 )));
             };
 
-            Ok(json!({ "url": url }))
+            Ok(json!({ "url": translate(url, "url")? }))
         }
         Some(other) => Err(CommandError::validation(format!(
             "MCP '{}' uses unsupported transport '{}' for replication.",
@@ -262,7 +298,7 @@ fn build_replication_result(
 mod tests {
     use std::{
         fs,
-        path::PathBuf,
+        path::{Path, PathBuf},
         sync::{Mutex, OnceLock},
     };
 
@@ -271,7 +307,7 @@ mod tests {
     use crate::{
         application::mcp::source_catalog_service::McpSourceCatalogService,
         domain::{ClientKind, ResourceSourceScope},
-        infra::DetectorRegistry,
+        infra::{DetectorRegistry, ParkedServerStore},
     };
 
     use super::McpReplicationService;
@@ -319,7 +355,7 @@ mod tests {
             cursor_path.display().to_string(),
         );
 
-        let detector_registry = DetectorRegistry::with_default_detectors();
+        let detector_registry = DetectorRegistry::with_installed_version("1.0.0");
         let source_catalog = McpSourceCatalogService::new(&detector_registry);
         let source_source_id = source_catalog
             .list_sources(ClientKind::ClaudeCode, None)
@@ -334,7 +370,7 @@ mod tests {
             .expect("cursor user source should exist")
             .source_id;
 
-        let result = McpReplicationService::new(&detector_registry)
+        let result = McpReplicationService::new(&detector_registry, parked_store_in(&temp_root))
             .replicate(
                 ClientKind::ClaudeCode,
                 "filesystem",
@@ -389,7 +425,7 @@ mod tests {
             cursor_path.display().to_string(),
         );
 
-        let detector_registry = DetectorRegistry::with_default_detectors();
+        let detector_registry = DetectorRegistry::with_installed_version("1.0.0");
         let source_catalog = McpSourceCatalogService::new(&detector_registry);
         let source_source_id = source_catalog
             .list_sources(ClientKind::Cursor, None)
@@ -398,7 +434,7 @@ mod tests {
             .expect("cursor user source should exist")
             .source_id;
 
-        let error = McpReplicationService::new(&detector_registry)
+        let error = McpReplicationService::new(&detector_registry, parked_store_in(&temp_root))
             .replicate(
                 ClientKind::Cursor,
                 "filesystem",
@@ -457,7 +493,7 @@ mod tests {
             cursor_path.display().to_string(),
         );
 
-        let detector_registry = DetectorRegistry::with_default_detectors();
+        let detector_registry = DetectorRegistry::with_installed_version("1.0.0");
         let source_catalog = McpSourceCatalogService::new(&detector_registry);
         let source_source_id = source_catalog
             .list_sources(ClientKind::ClaudeCode, None)
@@ -466,7 +502,7 @@ mod tests {
             .expect("claude user source should exist")
             .source_id;
 
-        let result = McpReplicationService::new(&detector_registry)
+        let result = McpReplicationService::new(&detector_registry, parked_store_in(&temp_root))
             .replicate(
                 ClientKind::ClaudeCode,
                 "filesystem",
@@ -497,6 +533,85 @@ mod tests {
         let _ = fs::remove_dir_all(&temp_root);
     }
 
+    #[test]
+    fn replicate_translates_env_references_or_refuses_without_an_equivalent() {
+        let _guard = env_lock().lock().expect("env lock should be available");
+        let temp_root = temp_dir("interpolation");
+        let claude_path = temp_root.join(".claude.json");
+        let cursor_path = temp_root.join(".cursor").join("mcp.json");
+        let codex_path = temp_root.join("config.toml");
+        fs::create_dir_all(cursor_path.parent().expect("cursor parent should exist"))
+            .expect("cursor directory should be writable");
+        fs::write(
+            &claude_path,
+            r#"{
+  "mcpServers": {
+    "api": { "command": "npx", "args": ["api-server", "--token=${API_TOKEN}"] }
+  }
+}"#,
+        )
+        .expect("claude source should be writable");
+        fs::write(&cursor_path, "{\n  \"mcpServers\": {}\n}\n")
+            .expect("cursor destination should be writable");
+        fs::write(&codex_path, "").expect("codex destination should be writable");
+
+        let previous: Vec<(&str, Option<String>)> = [
+            ("AI_MANAGER_CLAUDE_CODE_MCP_CONFIG", &claude_path),
+            ("AI_MANAGER_CURSOR_MCP_CONFIG", &cursor_path),
+            ("AI_MANAGER_CODEX_MCP_CONFIG", &codex_path),
+        ]
+        .into_iter()
+        .map(|(name, path)| {
+            let previous = std::env::var(name).ok();
+            set_env_var(name, path.display().to_string());
+            (name, previous)
+        })
+        .collect();
+
+        let detector_registry = DetectorRegistry::with_installed_version("1.0.0");
+        let source_source_id = McpSourceCatalogService::new(&detector_registry)
+            .list_sources(ClientKind::ClaudeCode, None)
+            .into_iter()
+            .find(|descriptor| descriptor.source_scope == ResourceSourceScope::User)
+            .expect("claude user source should exist")
+            .source_id;
+        let replicate_to = |destination_client: ClientKind| {
+            McpReplicationService::new(&detector_registry, parked_store_in(&temp_root)).replicate(
+                ClientKind::ClaudeCode,
+                "api",
+                source_source_id.as_str(),
+                None,
+                destination_client,
+                None,
+                None,
+                None,
+                false,
+            )
+        };
+
+        replicate_to(ClientKind::Cursor).expect("cursor has an env reference equivalent");
+        let codex_error =
+            replicate_to(ClientKind::Codex).expect_err("codex does not expand references");
+        let destination: Value = serde_json::from_str(
+            &fs::read_to_string(&cursor_path).expect("cursor config should exist"),
+        )
+        .expect("destination config should remain valid json");
+        let codex_config = fs::read_to_string(&codex_path).expect("codex config should exist");
+
+        for (name, value) in previous {
+            restore_env(name, value);
+        }
+        let _ = fs::remove_dir_all(&temp_root);
+
+        assert_eq!(
+            destination["mcpServers"]["api"]["args"][1],
+            "--token=${env:API_TOKEN}"
+        );
+        assert!(codex_error.message.contains("`${API_TOKEN}` in its args"));
+        assert!(codex_error.message.contains("'codex'"));
+        assert!(codex_config.is_empty());
+    }
+
     #[test]
     fn replicate_rejects_unsupported_destination_scope() {
         let _guard = env_lock().lock().expect("env lock should be available");
@@ -517,7 +632,7 @@ enabled = true
             codex_path.display().to_string(),
         );
 
-        let detector_registry = DetectorRegistry::with_default_detectors();
+        let detector_registry = DetectorRegistry::with_installed_version("1.0.0");
         let source_catalog = McpSourceCatalogService::new(&detector_registry);
         let source_source_id = source_catalog
             .list_sources(ClientKind::Codex, None)
@@ -526,7 +641,7 @@ enabled = true
             .expect("codex user source should exist")
             .source_id;
 
-        let error = McpReplicationService::new(&detector_registry)
+        let error = McpReplicationService::new(&detector_registry, parked_store_in(&temp_root))
             .replicate(
                 ClientKind::Codex,
                 "filesystem",
@@ -546,6 +661,13 @@ enabled = true
         let _ = fs::remove_dir_all(&temp_root);
     }
 
+    /// Keeps tests away from the real `~/.ai-manager` parked-server store.
+    fn parked_store_in(directory: &Path) -> Option<ParkedServerStore> {
+        Some(ParkedServerStore::at(
+            directory.join("parked-mcp-servers.json"),
+        ))
+    }
+
     fn temp_dir(suffix: &str) -> PathBuf {
         let root = std::env::temp_dir().join(format!(
             "ai-manager-mcp-replication-{}-{}",
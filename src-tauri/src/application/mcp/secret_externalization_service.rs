@@ -0,0 +1,707 @@
+use std::{
+    collections::BTreeMap,
+    env, fs, io,
+    path::{Path, PathBuf},
+};
+
+use serde_json::{Map, Value};
+
+use crate::{
+    domain::ResourceSourceScope,
+    infra::{
+        DetectorRegistry, SafeFileMutator, content_revision,
+        parsers::InterpolationSyntax,
+        security::{
+            redaction::is_sensitive_key,
+            secret_store::{EncryptedFileSecretStore, SecretStore},
+        },
+    },
+    interface::contracts::{
+        command::CommandError,
+        common::ClientKind,
+        secrets::{ExternalizedSecret, SecretReferenceKind},
+    },
+};
+
+use super::{
+    config_repair_service::{read_config, resolve_descriptors},
+    mutation_service::edit_server_entries,
+    source_catalog_service::McpSourceDescriptor,
+};
+
+pub const SECRET_LAUNCHER_SUBCOMMAND: &str = "run-with-secrets";
+const SECRET_NAME_PREFIX: &str = "AI_MANAGER_SECRET";
+const LAUNCHER_BINARY: &str = "ai-manager-cli";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct McpSecretExternalization {
+    pub secrets: Vec<ExternalizedSecret>,
+    pub updated_sources: Vec<String>,
+    pub store_location: String,
+    /// Configs that now need the client started by the launcher, and plaintext copies of
+    /// the original configs that could not be deleted.
+    pub warnings: Vec<String>,
+}
+
+pub struct McpSecretExternalizationService<'a> {
+    detector_registry: &'a DetectorRegistry,
+    store: Option<Box<dyn SecretStore>>,
+    launcher: Option<PathBuf>,
+}
+
+impl<'a> McpSecretExternalizationService<'a> {
+    pub fn new(detector_registry: &'a DetectorRegistry) -> Self {
+        Self {
+            detector_registry,
+            store: EncryptedFileSecretStore::default_location()
+                .map(|store| Box::new(store) as Box<dyn SecretStore>),
+            launcher: default_launcher(),
+        }
+    }
+
+    #[cfg(test)]
+    pub(crate) fn with_store(mut self, store: impl SecretStore + 'static) -> Self {
+        self.store = Some(Box::new(store));
+        self
+    }
+
+    #[cfg(test)]
+    pub(crate) fn with_launcher(mut self, launcher: Option<PathBuf>) -> Self {
+        self.launcher = launcher;
+        self
+    }
+
+    /// Moves sensitive `env` and header values of every server into the secret store. Stdio
+    /// `env` values are handed back by the launcher, except in shared project configs where
+    /// they use env interpolation like header values. The plaintext backup of each rewritten
+    /// config is deleted.
+    pub fn externalize(
+        &self,
+        client: ClientKind,
+        project_root: Option<&str>,
+        target_source_id: Option<&str>,
+        dry_run: bool,
+    ) -> Result<McpSecretExternalization, CommandError> {
+        let Some(store) = self.store.as_deref() else {
+            return Err(CommandError::validation(
+                "The secret store location could not be resolved; set AI_MANAGER_DATA_DIR or HOME.",
+            ));
+        };
+        let store_error = |error: io::Error| {
+            CommandError::io(
+                format!(
+                    "Failed to access the secret store '{}': {}",
+                    store.location(),
+                    error
+                ),
+                error.kind(),
+            )
+            .with_path(store.location())
+        };
+
+        let mut stored = store.load().map_err(store_error)?;
+        let mut secrets = Vec::new();
+        let mut updated_sources = Vec::new();
+        let mut warnings = Vec::new();
+
+        for descriptor in resolve_descriptors(
+            self.detector_registry,
+            client,
+            project_root,
+            target_source_id,
+        )? {
+            let Some(source) = read_config(&descriptor)? else {
+                continue;
+            };
+            let (next_content, found) =
+                externalize_source(&descriptor, &source, self.launcher.as_deref(), &mut stored)?;
+            if found.is_empty() {
+                continue;
+            }
+            if found
+                .iter()
+                .any(|secret| secret.reference == SecretReferenceKind::EnvInterpolation)
+            {
+                warnings.push(format!(
+                    "Servers in '{}' now read secrets from environment variables. Start {} with `{} {} --all -- <client>`, or they will not authenticate.",
+                    descriptor.container_path.display(),
+                    descriptor.client.as_str(),
+                    LAUNCHER_BINARY,
+                    SECRET_LAUNCHER_SUBCOMMAND
+                ));
+            }
+
+            if !dry_run {
+                // The store is written first so the config never references a missing secret.
+                store.save(&stored).map_err(store_error)?;
+                let written = SafeFileMutator::new()
+                    .replace_file_if_unchanged(
+                        &descriptor.container_path,
+                        next_content.as_bytes(),
+                        Some(&content_revision(source.as_bytes())),
+                    )
+                    .map_err(|failure| {
+                        CommandError::from_mutation_failure(&failure, &descriptor.container_path)
+                            .with_source_id(&descriptor.source_id)
+                    })?;
+                // The backup still holds every secret in plaintext, often inside the repo.
+                if let Some(backup_path) = written.backup_path
+                    && let Err(error) = remove_backup(Path::new(&backup_path))
+                {
+                    warnings.push(format!(
+                        "Failed to delete '{}', a plaintext copy of the original config: {}",
+                        backup_path, error
+                    ));
+                }
+            }
+            updated_sources.push(descriptor.source_id.clone());
+            secrets.extend(found);
+        }
+
+        Ok(McpSecretExternalization {
+            secrets,
+            updated_sources,
+            store_location: store.location(),
+            warnings,
+        })
+    }
+}
+
+fn externalize_source(
+    descriptor: &McpSourceDescriptor,
+    source: &str,
+    launcher: Option<&Path>,
+    stored: &mut BTreeMap<String, String>,
+) -> Result<(String, Vec<ExternalizedSecret>), CommandError> {
+    let syntax = InterpolationSyntax::for_client(descriptor.client);
+
+    edit_server_entries(descriptor, source, |servers| {
+        let mut found = Vec::new();
+        for (server_name, entry) in servers.iter_mut() {
+            let Some(entry) = entry.as_object_mut() else {
+                continue;
+            };
+            let mut record = |field: String, secret_name: String, reference| {
+                found.push(ExternalizedSecret {
+                    source_id: descriptor.source_id.clone(),
+                    source_path: descriptor.container_path.display().to_string(),
+                    server_name: server_name.clone(),
+                    field,
+                    secret_name,
+                    reference,
+                });
+            };
+
+            let is_stdio = entry.get("command").and_then(Value::as_str).is_some();
+            if is_stdio && descriptor.source_scope == ResourceSourceScope::ProjectShared {
+                // The launcher path only exists on this machine, so a committed config
+                // references the secrets through env interpolation instead.
+                if let Some(Value::Object(env_values)) = entry.get_mut("env") {
+                    for (key, value) in env_values.iter_mut() {
+                        let Some(text) = sensitive_value(key, value).map(str::to_string) else {
+                            continue;
+                        };
+                        let secret_name = store_secret(stored, server_name, key, &text);
+                        let Some(reference) = interpolation_reference(syntax, &secret_name) else {
+                            return Err(CommandError::validation(format!(
+                                "'{}' cannot reference secrets from a shared project config.",
+                                descriptor.client.as_str()
+                            ))
+                            .with_source_id(&descriptor.source_id));
+                        };
+                        *value = Value::String(reference);
+                        record(
+                            format!("env.{}", key),
+                            secret_name,
+                            SecretReferenceKind::EnvInterpolation,
+                        );
+                    }
+                }
+            } else if is_stdio {
+                let has_secrets = matches!(
+                    entry.get("env"),
+                    Some(Value::Object(env_values))
+                        if env_values.iter().any(|(key, value)| sensitive_value(key, value).is_some())
+                );
+                if has_secrets {
+                    // Without the CLI there is nothing that can hand the secrets back.
+                    let Some(launcher) = launcher else {
+                        return Err(CommandError::validation(format!(
+                            "'{}' was not found next to this app, so secrets of stdio server '{}' cannot be handed back at launch. Install the CLI and retry.",
+                            LAUNCHER_BINARY, server_name
+                        ))
+                        .with_source_id(&descriptor.source_id));
+                    };
+                    let mut bindings = Vec::new();
+                    for (key, value) in take_sensitive_values(entry, "env") {
+                        let secret_name = store_secret(stored, server_name, &key, &value);
+                        bindings.push(format!("{}={}", key, secret_name));
+                        record(
+                            format!("env.{}", key),
+                            secret_name,
+                            SecretReferenceKind::Launcher,
+                        );
+                    }
+                    wrap_with_launcher(entry, &launcher.display().to_string(), bindings);
+                }
+            }
+
+            if syntax == InterpolationSyntax::Literal {
+                for (key, value) in take_sensitive_values(entry, "http_headers") {
+                    if let Some(token) = bearer_token(&key, &value)
+                        && !entry.contains_key("bearer_token_env_var")
+                    {
+                        let secret_name = store_secret(stored, server_name, "bearer_token", token);
+                        entry.insert(
+                            "bearer_token_env_var".to_string(),
+                            Value::String(secret_name.clone()),
+                        );
+                        record(
+                            format!("http_headers.{}", key),
+                            secret_name,
+                            SecretReferenceKind::EnvInterpolation,
+                        );
+                        continue;
+                    }
+
+                    let secret_name = store_secret(stored, server_name, &key, &value);
+                    if let Value::Object(env_headers) = entry
+                        .entry("env_http_headers")
+                        .or_insert_with(|| Value::Object(Map::new()))
+                    {
+                        env_headers.insert(key.clone(), Value::String(secret_name.clone()));
+                    }
+                    record(
+                        format!("http_headers.{}", key),
+                        secret_name,
+                        SecretReferenceKind::EnvInterpolation,
+                    );
+                }
+            } else if let Some(Value::Object(headers)) = entry.get_mut("headers") {
+                for (key, value) in headers.iter_mut() {
+                    let Some(text) = sensitive_value(key, value).map(str::to_string) else {
+                        continue;
+                    };
+                    let (prefix, secret) = match bearer_token(key, &text) {
+                        Some(token) => (&text[..text.len() - token.len()], token),
+                        None => ("", text.as_str()),
+                    };
+                    let secret_name = store_secret(stored, server_name, key, secret);
+                    let reference =
+                        interpolation_reference(syntax, &secret_name).unwrap_or_default();
+                    *value = Value::String(format!("{}{}", prefix, reference));
+                    record(
+                        format!("headers.{}", key),
+                        secret_name,
+                        SecretReferenceKind::EnvInterpolation,
+                    );
+                }
+            }
+        }
+
+        Ok(found)
+    })
+}
+
+/// How `syntax` spells a reference to the environment variable `name`; `None` when the
+/// client does not expand references.
+fn interpolation_reference(syntax: InterpolationSyntax, name: &str) -> Option<String> {
+    match syntax {
+        InterpolationSyntax::Shell => Some(format!("${{{}}}", name)),
+        InterpolationSyntax::EditorEnv => Some(format!("${{env:{}}}", name)),
+        InterpolationSyntax::Literal => None,
+    }
+}
+
+/// Deletes a backup and, when it was the last one, its backup directory.
+fn remove_backup(backup_path: &Path) -> io::Result<()> {
+    fs::remove_file(backup_path)?;
+    if let Some(directory) = backup_path.parent() {
+        let _ = fs::remove_dir(directory);
+    }
+    Ok(())
+}
+
+/// Removes sensitive string values from `entry[field]`, dropping the field once it is empty.
+fn take_sensitive_values(entry: &mut Map<String, Value>, field: &str) -> Vec<(String, String)> {
+    let Some(Value::Object(values)) = entry.get_mut(field) else {
+        return Vec::new();
+    };
+    let keys = values
+        .iter()
+        .filter(|(key, value)| sensitive_value(key, value).is_some())
+        .map(|(key, _)| key.clone())
+        .collect::<Vec<_>>();
+    let taken = keys
+        .into_iter()
+        .filter_map(|key| {
+            let value = values.remove(&key)?.as_str()?.to_string();
+            Some((key, value))
+        })
+        .collect();
+
+    if values.is_empty() {
+        entry.remove(field);
+    }
+    taken
+}
+
+/// Values that already reference a variable are left alone.
+fn sensitive_value<'v>(key: &str, value: &'v Value) -> Option<&'v str> {
+    value
+        .as_str()
+        .filter(|text| is_sensitive_key(key) && !text.trim().is_empty() && !text.contains("${"))
+}
+
+fn bearer_token<'v>(key: &str, value: &'v str) -> Option<&'v str> {
+    if !key.eq_ignore_ascii_case("authorization") {
+        return None;
+    }
+    value
+        .get(..7)
+        .filter(|prefix| prefix.eq_ignore_ascii_case("bearer "))
+        .map(|_| value[7..].trim())
+        .filter(|token| !token.is_empty())
+}
+
+fn wrap_with_launcher(entry: &mut Map<String, Value>, launcher: &str, bindings: Vec<String>) {
+    let command = entry
+        .get("command")
+        .and_then(Value::as_str)
+        .unwrap_or_default()
+        .to_string();
+    let mut args = entry
+        .get("args")
+        .and_then(Value::as_array)
+        .cloned()
+        .unwrap_or_default();
+    let already_wrapped = command == launcher
+        && args.first().and_then(Value::as_str) == Some(SECRET_LAUNCHER_SUBCOMMAND);
+
+    if already_wrapped {
+        args.splice(1..1, bindings.into_iter().map(Value::String));
+    } else {
+        let mut wrapped = vec![Value::String(SECRET_LAUNCHER_SUBCOMMAND.to_string())];
+        wrapped.extend(bindings.into_iter().map(Value::String));
+        wrapped.push(Value::String("--".to_string()));
+        wrapped.push(Value::String(command));
+        wrapped.append(&mut args);
+        args = wrapped;
+    }
+
+    entry.insert("command".to_string(), Value::String(launcher.to_string()));
+    entry.insert("args".to_string(), Value::Array(args));
+}
+
+/// Stores `value` as `AI_MANAGER_SECRET_<SERVER>_<KEY>`, reusing the name when it already
+/// holds the same value and adding a numeric suffix when it holds a different one.
+fn store_secret(
+    stored: &mut BTreeMap<String, String>,
+    server_name: &str,
+    key: &str,
+    value: &str,
+) -> String {
+    let base = format!(
+        "{}_{}_{}",
+        SECRET_NAME_PREFIX,
+        env_name_part(server_name),
+        env_name_part(key)
+    );
+    let mut name = base.clone();
+    let mut suffix = 2;
+    loop {
+        match stored.get(&name) {
+            None => {
+                stored.insert(name.clone(), value.to_string());
+                return name;
+            }
+            Some(existing) if existing == value => return name,
+            Some(_) => {
+                name = format!("{}_{}", base, suffix);
+                suffix += 1;
+            }
+        }
+    }
+}
+
+fn env_name_part(value: &str) -> String {
+    value
+        .chars()
+        .map(|ch| {
+            if ch.is_ascii_alphanumeric() {
+                ch.to_ascii_uppercase()
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+/// The CLI next to the running executable; the CLI is its own launcher. `None` when it is
+/// not installed there.
+fn default_launcher() -> Option<PathBuf> {
+    let current = env::current_exe().ok()?;
+    let cli = current.with_file_name(format!("{}{}", LAUNCHER_BINARY, env::consts::EXE_SUFFIX));
+    cli.is_file().then_some(cli)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::BTreeMap, fs, path::PathBuf};
+
+    use serde_json::{Value, json};
+
+    use crate::{
+        domain::{ClientKind, ResourceSourceScope},
+        infra::{
+            DetectorRegistry,
+            parsers::parse_jsonc,
+            security::secret_store::{PlainFileSecretStore, SecretStore},
+        },
+        interface::contracts::secrets::SecretReferenceKind,
+    };
+
+    use super::{
+        super::source_catalog_service::{McpSourceDescriptor, McpSourceStorageKind},
+        McpSecretExternalizationService, externalize_source,
+    };
+
+    #[test]
+    fn cursor_secrets_move_to_the_store_behind_the_launcher_and_env_references() {
+        let temp_dir = temp_root("cursor");
+        let project_root = temp_dir.join("workspace");
+        let config_path = project_root.join(".cursor").join("mcp.json");
+        fs::create_dir_all(config_path.parent().expect("config parent"))
+            .expect("should create config dir");
+        let original = r#"{
+  "mcpServers": {
+    "github": {
+      "command": "npx",
+      "args": ["-y", "@modelcontextprotocol/server-github"],
+      "env": { "GITHUB_TOKEN": "ghp_inline", "LOG_LEVEL": "debug" }
+    },
+    "remote": {
+      "url": "https://mcp.example.com",
+      "headers": { "Authorization": "Bearer sk-inline", "X-Api-Key": "${env:ALREADY_SET}" }
+    }
+  }
+}
+"#;
+        fs::write(&config_path, original).expect("should write config");
+        let project_root_label = project_root.display().to_string();
+        let source_id = format!(
+            "mcp::cursor::project_shared::{}::/mcpServers",
+            config_path.display()
+        );
+        let store_path = temp_dir.join("secrets.json");
+        let backup_dir = config_path
+            .parent()
+            .expect("config parent")
+            .join(".ai-manager-backups");
+
+        let detector_registry = DetectorRegistry::with_installed_version("1.0.0");
+        let service = McpSecretExternalizationService::new(&detector_registry)
+            .with_store(PlainFileSecretStore::at(&store_path))
+            .with_launcher(Some(PathBuf::from("/opt/ai-manager/ai-manager-cli")));
+        let externalize = |dry_run: bool| {
+            service.externalize(
+                ClientKind::Cursor,
+                Some(&project_root_label),
+                Some(&source_id),
+                dry_run,
+            )
+        };
+
+        let preview = externalize(true).expect("dry run should succeed");
+        let untouched = fs::read_to_string(&config_path).expect("config should exist");
+        let applied = externalize(false).expect("externalize should succeed");
+        let rerun = externalize(false).expect("second run should find nothing");
+        let rewritten = parse_jsonc(&fs::read_to_string(&config_path).expect("config exists"))
+            .expect("rewritten config should parse")
+            .value;
+        let stored = PlainFileSecretStore::at(&store_path)
+            .load()
+            .expect("store should load");
+        let backups_left = backup_dir.exists();
+        let _ = fs::remove_dir_all(&temp_dir);
+
+        assert_eq!(preview.secrets.len(), 2);
+        assert_eq!(applied.warnings.len(), 1);
+        assert!(applied.warnings[0].contains("run-with-secrets --all"));
+        assert!(!backups_left);
+        assert_eq!(untouched, original);
+        assert_eq!(applied.updated_sources, vec![source_id]);
+        assert!(rerun.secrets.is_empty());
+        assert_eq!(
+            applied
+                .secrets
+                .iter()
+                .map(|secret| (secret.field.as_str(), secret.reference))
+                .collect::<Vec<_>>(),
+            vec![
+                ("env.GITHUB_TOKEN", SecretReferenceKind::EnvInterpolation),
+                (
+                    "headers.Authorization",
+                    SecretReferenceKind::EnvInterpolation
+                ),
+            ]
+        );
+        assert_eq!(
+            rewritten["mcpServers"]["github"],
+            json!({
+                "command": "npx",
+                "args": ["-y", "@modelcontextprotocol/server-github"],
+                "env": {
+                    "GITHUB_TOKEN": "${env:AI_MANAGER_SECRET_GITHUB_GITHUB_TOKEN}",
+                    "LOG_LEVEL": "debug"
+                }
+            })
+        );
+        assert_eq!(
+            rewritten["mcpServers"]["remote"]["headers"],
+            json!({
+                "Authorization": "Bearer ${env:AI_MANAGER_SECRET_REMOTE_AUTHORIZATION}",
+                "X-Api-Key": "${env:ALREADY_SET}"
+            })
+        );
+        assert_eq!(
+            stored,
+            BTreeMap::from([
+                (
+                    "AI_MANAGER_SECRET_GITHUB_GITHUB_TOKEN".to_string(),
+                    "ghp_inline".to_string()
+                ),
+                (
+                    "AI_MANAGER_SECRET_REMOTE_AUTHORIZATION".to_string(),
+                    "sk-inline".to_string()
+                ),
+            ])
+        );
+    }
+
+    #[test]
+    fn stdio_secrets_are_not_externalized_without_a_launcher() {
+        let descriptor = McpSourceDescriptor {
+            client: ClientKind::Cursor,
+            source_id: "mcp::cursor::user::/tmp/mcp.json::/mcpServers".to_string(),
+            source_scope: ResourceSourceScope::User,
+            source_label: "Personal config".to_string(),
+            container_path: "/tmp/mcp.json".into(),
+            selector: "/mcpServers".to_string(),
+            storage_kind: McpSourceStorageKind::JsonSection,
+            project_root: None,
+        };
+        let source = r#"{ "mcpServers": { "github": { "command": "npx", "env": { "GITHUB_TOKEN": "ghp_inline" } } } }"#;
+        let mut stored = BTreeMap::new();
+
+        let error = externalize_source(&descriptor, source, None, &mut stored)
+            .expect_err("externalizing stdio secrets needs the launcher");
+
+        assert!(error.message.contains("ai-manager-cli"));
+        assert!(stored.is_empty());
+    }
+
+    #[test]
+    fn user_stdio_secrets_are_handed_back_by_the_launcher() {
+        let descriptor = McpSourceDescriptor {
+            client: ClientKind::Cursor,
+            source_id: "mcp::cursor::user::/tmp/mcp.json::/mcpServers".to_string(),
+            source_scope: ResourceSourceScope::User,
+            source_label: "Personal config".to_string(),
+            container_path: "/tmp/mcp.json".into(),
+            selector: "/mcpServers".to_string(),
+            storage_kind: McpSourceStorageKind::JsonSection,
+            project_root: None,
+        };
+        let source = r#"{ "mcpServers": { "github": { "command": "npx", "args": ["server-github"], "env": { "GITHUB_TOKEN": "ghp_inline" } } } }"#;
+        let mut stored = BTreeMap::new();
+
+        let (content, found) = externalize_source(
+            &descriptor,
+            source,
+            Some(&PathBuf::from("/opt/ai-manager/ai-manager-cli")),
+            &mut stored,
+        )
+        .expect("externalize should succeed");
+        let rewritten = parse_jsonc(&content)
+            .expect("rewritten config should parse")
+            .value;
+
+        assert_eq!(found[0].reference, SecretReferenceKind::Launcher);
+        assert_eq!(
+            rewritten["mcpServers"]["github"],
+            json!({
+                "command": "/opt/ai-manager/ai-manager-cli",
+                "args": [
+                    "run-with-secrets",
+                    "GITHUB_TOKEN=AI_MANAGER_SECRET_GITHUB_GITHUB_TOKEN",
+                    "--",
+                    "npx",
+                    "server-github"
+                ]
+            })
+        );
+    }
+
+    #[test]
+    fn codex_headers_become_env_header_references_and_names_do_not_collide() {
+        let descriptor = McpSourceDescriptor {
+            client: ClientKind::Codex,
+            source_id: "mcp::codex::user::/tmp/config.toml::mcp_servers".to_string(),
+            source_scope: ResourceSourceScope::User,
+            source_label: "Personal config".to_string(),
+            container_path: "/tmp/config.toml".into(),
+            selector: "mcp_servers".to_string(),
+            storage_kind: McpSourceStorageKind::TomlTable,
+            project_root: None,
+        };
+        let source = r#"[mcp_servers.docs]
+url = "https://docs.example.com/mcp"
+
+[mcp_servers.docs.http_headers]
+Authorization = "Bearer sk-docs"
+X-Api-Key = "key-docs"
+X-Region = "eu"
+"#;
+        let mut stored = BTreeMap::from([(
+            "AI_MANAGER_SECRET_DOCS_X_API_KEY".to_string(),
+            "some-other-key".to_string(),
+        )]);
+
+        let (content, found) = externalize_source(&descriptor, source, None, &mut stored)
+            .expect("externalize should succeed");
+        let rewritten: toml::Table = toml::from_str(&content).expect("rewritten TOML parses");
+        let docs = serde_json::to_value(&rewritten["mcp_servers"]["docs"])
+            .expect("entry converts to JSON");
+
+        assert_eq!(found.len(), 2);
+        assert_eq!(
+            docs["bearer_token_env_var"],
+            Value::String("AI_MANAGER_SECRET_DOCS_BEARER_TOKEN".to_string())
+        );
+        assert_eq!(
+            docs["env_http_headers"],
+            json!({ "X-Api-Key": "AI_MANAGER_SECRET_DOCS_X_API_KEY_2" })
+        );
+        assert_eq!(docs["http_headers"], json!({ "X-Region": "eu" }));
+        assert_eq!(
+            stored
+                .get("AI_MANAGER_SECRET_DOCS_BEARER_TOKEN")
+                .map(String::as_str),
+            Some("sk-docs")
+        );
+        assert_eq!(
+            stored
+                .get("AI_MANAGER_SECRET_DOCS_X_API_KEY_2")
+                .map(String::as_str),
+            Some("key-docs")
+        );
+    }
+
+    fn temp_root(suffix: &str) -> PathBuf {
+        std::env::temp_dir().join(format!(
+            "ai-manager-mcp-secrets-{suffix}-{}",
+            std::process::id()
+        ))
+    }
+}
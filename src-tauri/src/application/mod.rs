@@ -1,16 +1,27 @@
 mod adapter_service;
 mod capability;
+mod command;
 #[cfg(test)]
 mod critical_paths_suite;
 mod detection;
+mod instruction;
 mod mcp;
+mod operation_log_service;
 mod project_context_resolver;
+mod security_audit_service;
+mod setting;
 mod skill;
+#[cfg(feature = "desktop")]
+mod source_watch_service;
 mod subagent;
 
 pub use adapter_service::AdapterService;
-pub use capability::client_capability_service::ClientCapabilityService;
+pub use capability::client_capability_service::{ClientCapabilityService, ClientFeature};
+pub use mcp::inventory_service::McpInventoryService;
+pub use mcp::probe_service::McpProbeService;
+pub use mcp::secret_externalization_service::SECRET_LAUNCHER_SUBCOMMAND;
 pub use mcp::source_catalog_service::{
     McpSourceCatalogService, McpSourceDescriptor, McpSourceStorageKind,
 };
+pub use operation_log_service::{OperationLogService, OperationSubject};
 pub use skill::repository_discovery_service::SkillRepositoryDiscoveryService;
@@ -0,0 +1,477 @@
+use std::{
+    collections::BTreeMap,
+    fs, io,
+    path::Path,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use crate::{
+    infra::{
+        LoggedFileChange, LoggedOperation, LoggedOperationKind, OperationLog, RecordedWrite,
+        SafeFileMutator, content_revision, security::redaction::redact_sensitive_text,
+        unified_diff,
+    },
+    interface::contracts::{
+        command::CommandError,
+        common::{ClientKind, ResourceKind},
+    },
+};
+
+/// What a logged operation acted on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OperationSubject {
+    pub client: ClientKind,
+    pub resource_kind: ResourceKind,
+    pub target_id: String,
+    pub source_id: Option<String>,
+}
+
+pub struct OperationLogService {
+    log: Option<OperationLog>,
+}
+
+impl OperationLogService {
+    pub fn new() -> Self {
+        Self {
+            log: OperationLog::default_location(),
+        }
+    }
+
+    #[cfg(test)]
+    pub(crate) fn with_log(mut self, log: OperationLog) -> Self {
+        self.log = Some(log);
+        self
+    }
+
+    /// Appends an entry for `writes`; returns `None` when nothing was written or there is
+    /// no data directory to log to.
+    pub fn record(
+        &self,
+        operation_id: &str,
+        operation: LoggedOperationKind,
+        subject: OperationSubject,
+        undoes: Option<String>,
+        writes: &[RecordedWrite],
+    ) -> io::Result<Option<LoggedOperation>> {
+        let Some(log) = self.log.as_ref() else {
+            return Ok(None);
+        };
+        if writes.is_empty() {
+            return Ok(None);
+        }
+
+        let recorded_at_epoch_ms = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_millis());
+        let entry = LoggedOperation {
+            entry_id: format!("{recorded_at_epoch_ms}-{operation_id}"),
+            operation_id: operation_id.to_string(),
+            recorded_at_epoch_ms,
+            operation,
+            client: subject.client,
+            resource_kind: subject.resource_kind,
+            target_id: subject.target_id,
+            source_id: subject.source_id,
+            changes: writes.iter().map(logged_change).collect(),
+            undoes,
+        };
+        log.append(&entry)?;
+
+        Ok(Some(entry))
+    }
+
+    /// Puts every file the entry changed back to its backup, or removes files it created.
+    /// Refuses before touching anything if a file changed since the operation, and puts
+    /// already reverted files back if a later one fails.
+    pub fn undo(&self, entry_id: &str) -> Result<LoggedOperation, CommandError> {
+        let Some(log) = self.log.as_ref() else {
+            return Err(CommandError::internal(
+                "The operation log location could not be resolved; set AI_MANAGER_DATA_DIR or HOME.",
+            ));
+        };
+        let log_path = log.path().display().to_string();
+        let entry = log
+            .find(entry_id)
+            .map_err(|error| {
+                CommandError::io(
+                    format!("Failed to read operation log '{}': {}", log_path, error),
+                    error.kind(),
+                )
+                .with_path(log_path.clone())
+            })?
+            .ok_or_else(|| {
+                CommandError::validation(format!(
+                    "No operation '{}' in the operation log '{}'.",
+                    entry_id, log_path
+                ))
+            })?;
+        if entry.operation == LoggedOperationKind::ExternalizeSecrets {
+            return Err(CommandError::validation(format!(
+                "Operation '{}' externalized secrets; its plaintext backups were deleted, so it cannot be undone.",
+                entry_id
+            )));
+        }
+        if entry.changes.is_empty() {
+            return Err(CommandError::validation(format!(
+                "Operation '{}' recorded no file changes to revert.",
+                entry_id
+            )));
+        }
+
+        // Only the last change to each file has to match what is on disk now.
+        let latest_changes = entry
+            .changes
+            .iter()
+            .map(|change| (change.path.as_str(), change))
+            .collect::<BTreeMap<_, _>>();
+        for (path, change) in latest_changes {
+            let current_revision = read_revision(Path::new(path))?;
+            if current_revision != change.after_revision {
+                return Err(CommandError::conflict(format!(
+                    "'{}' changed after operation '{}' (expected revision {}, found {}); it can no longer be undone automatically.",
+                    path,
+                    entry_id,
+                    change.after_revision.as_deref().unwrap_or("<missing>"),
+                    current_revision.as_deref().unwrap_or("<missing>")
+                ))
+                .with_path(path.to_string()));
+            }
+        }
+        for backup_path in entry
+            .changes
+            .iter()
+            .filter_map(|change| change.backup_path.as_ref())
+        {
+            if !Path::new(backup_path).is_file() {
+                return Err(CommandError::validation(format!(
+                    "Backup '{}' for operation '{}' no longer exists.",
+                    backup_path, entry_id
+                ))
+                .with_path(backup_path.clone()));
+            }
+        }
+
+        let mut reverted = Vec::new();
+        for change in entry.changes.iter().rev() {
+            let path = Path::new(&change.path);
+            match revert_change(change) {
+                Ok(Some(reverted_file)) => reverted.push((path, reverted_file)),
+                Ok(None) => {}
+                Err(mut error) => {
+                    error.message.push_str(&roll_back_reverted(reverted));
+                    return Err(error);
+                }
+            }
+        }
+
+        Ok(entry)
+    }
+}
+
+impl Default for OperationLogService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// What reverting one file replaced, so a failed undo can put it back.
+enum RevertedFile {
+    /// The file existed; the backup holds its content after the operation.
+    Replaced { undo_backup: String },
+    /// The undo recreated a file the operation removed.
+    Recreated,
+}
+
+/// Reverts one change; `Ok(None)` when there was nothing to do.
+fn revert_change(change: &LoggedFileChange) -> Result<Option<RevertedFile>, CommandError> {
+    let path = Path::new(&change.path);
+    let mutator = SafeFileMutator::new();
+    let result = match (&change.backup_path, &change.after_revision) {
+        (Some(backup_path), _) => {
+            let content = fs::read(backup_path).map_err(|error| {
+                CommandError::io(
+                    format!("Failed to read backup '{}': {}", backup_path, error),
+                    error.kind(),
+                )
+                .with_path(backup_path.clone())
+            })?;
+            mutator.replace_file_if_unchanged(path, &content, change.after_revision.as_deref())
+        }
+        (None, Some(after_revision)) => mutator.remove_file_if_unchanged(path, after_revision),
+        // Removals always keep a backup, so there is nothing to restore here.
+        (None, None) => return Ok(None),
+    };
+
+    result
+        .map(|written| {
+            Some(match written.backup_path {
+                Some(undo_backup) => RevertedFile::Replaced { undo_backup },
+                None => RevertedFile::Recreated,
+            })
+        })
+        .map_err(|failure| CommandError::from_mutation_failure(&failure, path))
+}
+
+/// Puts files reverted before a failure back to their state after the operation, and says
+/// so for the error message.
+fn roll_back_reverted(reverted: Vec<(&Path, RevertedFile)>) -> String {
+    if reverted.is_empty() {
+        return String::new();
+    }
+
+    let mut stuck = Vec::new();
+    for (path, reverted_file) in reverted.into_iter().rev() {
+        let restored = match reverted_file {
+            RevertedFile::Replaced { undo_backup } => {
+                fs::read(&undo_backup).map_err(|_| ()).and_then(|content| {
+                    SafeFileMutator::new()
+                        .replace_file(path, &content)
+                        .map(|_| ())
+                        .map_err(|_| ())
+                })
+            }
+            RevertedFile::Recreated => fs::remove_file(path).map_err(|_| ()),
+        };
+        if restored.is_err() {
+            stuck.push(path.display().to_string());
+        }
+    }
+
+    if stuck.is_empty() {
+        " Files reverted before the failure were put back; nothing was changed.".to_string()
+    } else {
+        format!(
+            " These files were already reverted and could not be put back: {}.",
+            stuck.join(", ")
+        )
+    }
+}
+
+fn logged_change(write: &RecordedWrite) -> LoggedFileChange {
+    let path = write.path.display().to_string();
+    let diff = unified_diff(
+        &path,
+        write.before.as_deref().unwrap_or_default(),
+        write.after.as_deref().unwrap_or_default(),
+    );
+
+    LoggedFileChange {
+        backup_path: write
+            .backup_path
+            .as_ref()
+            .map(|backup_path| backup_path.display().to_string()),
+        after_revision: write.after_revision.clone(),
+        diff: redact_sensitive_text(&diff),
+        path,
+    }
+}
+
+fn read_revision(path: &Path) -> Result<Option<String>, CommandError> {
+    match fs::read(path) {
+        Ok(content) => Ok(Some(content_revision(&content))),
+        Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(error) => Err(CommandError::io(
+            format!("Failed to read '{}': {}", path.display(), error),
+            error.kind(),
+        )
+        .with_path(path.display().to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use serde_json::json;
+
+    use super::{OperationLogService, OperationSubject};
+    use crate::{
+        application::skill::mutation_service::SkillMutationService,
+        infra::{LoggedOperationKind, OperationLog, SafeFileMutator, record_writes},
+        interface::contracts::{
+            command::CommandErrorCode,
+            common::{ClientKind, ResourceKind},
+            mutate::MutationAction,
+        },
+    };
+
+    #[test]
+    fn undo_restores_backups_removes_created_files_and_can_itself_be_undone() {
+        let temp_dir =
+            std::env::temp_dir().join(format!("ai-manager-operation-undo-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&temp_dir);
+        fs::create_dir_all(&temp_dir).expect("should create temp dir");
+        let existing = temp_dir.join("mcp.json");
+        let created = temp_dir.join("parked.json");
+        fs::write(&existing, "{\"token\":\"before-value\"}\n").expect("should seed config");
+        let service =
+            OperationLogService::new().with_log(OperationLog::at(temp_dir.join("log.jsonl")));
+        let subject = OperationSubject {
+            client: ClientKind::Cursor,
+            resource_kind: ResourceKind::Mcp,
+            target_id: "github".to_string(),
+            source_id: None,
+        };
+
+        let (_, writes) = record_writes(|| {
+            let mutator = SafeFileMutator::new();
+            mutator
+                .replace_file(&existing, b"{\"token\":\"after-value\"}\n")
+                .expect("should write config");
+            mutator
+                .replace_file(&created, b"[]\n")
+                .expect("should create file");
+        });
+        let entry = service
+            .record(
+                "mutate-1",
+                LoggedOperationKind::Mutate,
+                subject.clone(),
+                None,
+                &writes,
+            )
+            .expect("should log")
+            .expect("writes should produce an entry");
+
+        let (undone, undo_writes) = record_writes(|| service.undo(&entry.entry_id));
+        undone.expect("undo should succeed");
+        let restored = fs::read_to_string(&existing).expect("should read restored config");
+        let created_after_undo = created.exists();
+        let undo_entry = service
+            .record(
+                "undo-2",
+                LoggedOperationKind::Undo,
+                subject,
+                Some(entry.entry_id.clone()),
+                &undo_writes,
+            )
+            .expect("should log undo")
+            .expect("undo should produce an entry");
+        let repeated = service.undo(&entry.entry_id);
+        service
+            .undo(&undo_entry.entry_id)
+            .expect("undoing the undo should succeed");
+        let redone = fs::read_to_string(&existing).expect("should read redone config");
+        let created_after_redo = created.exists();
+        let _ = fs::remove_dir_all(&temp_dir);
+
+        assert_eq!(entry.changes.len(), 2);
+        assert!(entry.changes[0].diff.contains("[REDACTED]"));
+        assert!(!entry.changes[0].diff.contains("after-value"));
+        assert_eq!(entry.changes[1].backup_path, None);
+        assert_eq!(restored, "{\"token\":\"before-value\"}\n");
+        assert!(!created_after_undo);
+        assert!(matches!(
+            repeated.map_err(|error| error.code),
+            Err(CommandErrorCode::Conflict)
+        ));
+        assert_eq!(redone, "{\"token\":\"after-value\"}\n");
+        assert!(created_after_redo);
+    }
+
+    #[test]
+    fn failed_undo_puts_already_reverted_files_back() {
+        let temp_dir = std::env::temp_dir().join(format!(
+            "ai-manager-operation-undo-rollback-{}",
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&temp_dir);
+        let blocked_dir = temp_dir.join("blocked");
+        fs::create_dir_all(&blocked_dir).expect("should create temp dirs");
+        let existing = temp_dir.join("mcp.json");
+        let created = blocked_dir.join("parked.json");
+        fs::write(&existing, "{\"before\":true}\n").expect("should seed config");
+        let service =
+            OperationLogService::new().with_log(OperationLog::at(temp_dir.join("log.jsonl")));
+
+        let (_, writes) = record_writes(|| {
+            let mutator = SafeFileMutator::new();
+            mutator
+                .replace_file(&created, b"[]\n")
+                .expect("should create file");
+            mutator
+                .replace_file(&existing, b"{\"after\":true}\n")
+                .expect("should write config");
+        });
+        let entry = service
+            .record(
+                "mutate-1",
+                LoggedOperationKind::Mutate,
+                OperationSubject {
+                    client: ClientKind::Cursor,
+                    resource_kind: ResourceKind::Mcp,
+                    target_id: "github".to_string(),
+                    source_id: None,
+                },
+                None,
+                &writes,
+            )
+            .expect("should log")
+            .expect("writes should produce an entry");
+        // Removing the created file needs a backup directory, which a plain file now blocks.
+        fs::write(blocked_dir.join(".ai-manager-backups"), "").expect("should block backups");
+
+        let failed = service.undo(&entry.entry_id);
+        let config = fs::read_to_string(&existing).expect("should read config");
+        let created_kept = created.exists();
+        let _ = fs::remove_dir_all(&temp_dir);
+
+        let error = failed.expect_err("undo should fail");
+        assert!(error.message.contains("were put back"));
+        assert_eq!(config, "{\"after\":true}\n");
+        assert!(created_kept);
+    }
+
+    #[test]
+    fn skill_removal_is_logged_and_can_be_undone() {
+        let temp_dir = std::env::temp_dir().join(format!(
+            "ai-manager-operation-skill-remove-{}",
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&temp_dir);
+        let manifest = temp_dir
+            .join("skills")
+            .join("python-refactor")
+            .join("SKILL.md");
+        fs::create_dir_all(manifest.parent().expect("manifest parent"))
+            .expect("should create skill dir");
+        fs::write(&manifest, "# Python Refactor\n\nRefactors Python.\n")
+            .expect("should seed skill");
+        let service =
+            OperationLogService::new().with_log(OperationLog::at(temp_dir.join("log.jsonl")));
+
+        let (removed, writes) = record_writes(|| {
+            SkillMutationService::new().mutate(
+                ClientKind::Cursor,
+                MutationAction::Remove,
+                "python-refactor",
+                Some(&json!({ "skills_dir": temp_dir.join("skills").display().to_string() })),
+            )
+        });
+        removed.expect("remove should succeed");
+        let removed_from_disk = !manifest.exists();
+        let entry = service
+            .record(
+                "mutate-1",
+                LoggedOperationKind::Mutate,
+                OperationSubject {
+                    client: ClientKind::Cursor,
+                    resource_kind: ResourceKind::Skill,
+                    target_id: "python-refactor".to_string(),
+                    source_id: None,
+                },
+                None,
+                &writes,
+            )
+            .expect("should log")
+            .expect("the removal should produce an entry");
+        service.undo(&entry.entry_id).expect("undo should succeed");
+        let restored = fs::read_to_string(&manifest).expect("should read restored skill");
+        let _ = fs::remove_dir_all(&temp_dir);
+
+        assert!(removed_from_disk);
+        assert_eq!(entry.changes.len(), 1);
+        assert!(entry.changes[0].backup_path.is_some());
+        assert_eq!(restored, "# Python Refactor\n\nRefactors Python.\n");
+    }
+}
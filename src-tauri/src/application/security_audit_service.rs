@@ -0,0 +1,304 @@
+use std::{cmp::Reverse, collections::BTreeSet, fs, path::PathBuf};
+
+use crate::{
+    application::{
+        mcp::source_catalog_service::McpSourceCatalogService,
+        skill::listing_service::SkillListingService,
+        subagent::listing_service::SubagentListingService,
+    },
+    domain::{ClientKind, ResourceSourceScope},
+    infra::{
+        DetectorRegistry, GitRepository,
+        security::leak_scanner::{
+            RULE_HIGH_ENTROPY, RULE_KNOWN_TOKEN_PREFIX, RULE_SENSITIVE_KEY, scan_for_leaks,
+        },
+    },
+    interface::contracts::{
+        common::ResourceKind,
+        list::ResourceViewMode,
+        security_audit::{AuditSeverity, SecurityAuditFinding},
+    },
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SecurityAudit {
+    pub scanned_files: usize,
+    pub findings: Vec<SecurityAuditFinding>,
+    pub high_risk_files: Vec<String>,
+    pub warnings: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct AuditTarget {
+    client: ClientKind,
+    resource_kind: ResourceKind,
+    source_scope: ResourceSourceScope,
+    path: PathBuf,
+}
+
+pub struct SecurityAuditService<'a> {
+    detector_registry: &'a DetectorRegistry,
+}
+
+impl<'a> SecurityAuditService<'a> {
+    pub fn new(detector_registry: &'a DetectorRegistry) -> Self {
+        Self { detector_registry }
+    }
+
+    pub fn audit(&self, clients: &[ClientKind], project_root: Option<&str>) -> SecurityAudit {
+        audit_targets(self.collect_targets(clients, project_root))
+    }
+
+    fn collect_targets(
+        &self,
+        clients: &[ClientKind],
+        project_root: Option<&str>,
+    ) -> Vec<AuditTarget> {
+        let mcp_catalog = McpSourceCatalogService::new(self.detector_registry);
+        let mut targets = Vec::new();
+
+        for client in clients.iter().copied() {
+            targets.extend(
+                mcp_catalog
+                    .list_sources(client, project_root)
+                    .into_iter()
+                    .map(|descriptor| AuditTarget {
+                        client,
+                        resource_kind: ResourceKind::Mcp,
+                        source_scope: descriptor.source_scope,
+                        path: descriptor.container_path,
+                    }),
+            );
+
+            let skills = SkillListingService::new().list(client, None, None);
+            let subagents = SubagentListingService::new().list(
+                client,
+                project_root,
+                None,
+                ResourceViewMode::AllSources,
+                None,
+            );
+            for (resource_kind, records) in [
+                (ResourceKind::Skill, skills.items),
+                (ResourceKind::Subagent, subagents.items),
+            ] {
+                targets.extend(records.into_iter().filter_map(|record| {
+                    Some(AuditTarget {
+                        client,
+                        resource_kind,
+                        source_scope: record.source_scope,
+                        path: PathBuf::from(record.source_path?),
+                    })
+                }));
+            }
+        }
+
+        // Claude keeps user and per-project MCP servers in the same file; scan it once.
+        let mut seen = BTreeSet::new();
+        targets.retain(|target| seen.insert(target.path.clone()));
+        targets
+    }
+}
+
+fn audit_targets(targets: Vec<AuditTarget>) -> SecurityAudit {
+    let mut audit = SecurityAudit {
+        scanned_files: 0,
+        findings: Vec::new(),
+        high_risk_files: Vec::new(),
+        warnings: Vec::new(),
+    };
+
+    for target in targets {
+        if !target.path.is_file() {
+            continue;
+        }
+        let content = match fs::read_to_string(&target.path) {
+            Ok(content) => content,
+            Err(error) => {
+                audit.warnings.push(format!(
+                    "[{}:AUDIT_READ_ERROR] failed to read '{}': {}",
+                    target.client.as_str(),
+                    target.path.display(),
+                    error
+                ));
+                continue;
+            }
+        };
+        audit.scanned_files += 1;
+
+        let matches = scan_for_leaks(&content);
+        if matches.is_empty() {
+            continue;
+        }
+
+        let committed_to_git = is_committed(&target, &mut audit.warnings);
+        let file_path = target.path.display().to_string();
+        if committed_to_git {
+            audit.high_risk_files.push(file_path.clone());
+        }
+        audit
+            .findings
+            .extend(matches.into_iter().map(|found| SecurityAuditFinding {
+                client: target.client,
+                resource_kind: target.resource_kind,
+                source_scope: target.source_scope,
+                file_path: file_path.clone(),
+                line: found.line,
+                column: found.column,
+                rule: found.rule.to_string(),
+                severity: if committed_to_git {
+                    AuditSeverity::High
+                } else {
+                    severity_for_rule(found.rule)
+                },
+                excerpt: found.excerpt,
+                committed_to_git,
+            }));
+    }
+
+    audit.findings.sort_by(|left, right| {
+        (
+            Reverse(left.severity),
+            &left.file_path,
+            left.line,
+            left.column,
+        )
+            .cmp(&(
+                Reverse(right.severity),
+                &right.file_path,
+                right.line,
+                right.column,
+            ))
+    });
+    audit
+}
+
+fn is_committed(target: &AuditTarget, warnings: &mut Vec<String>) -> bool {
+    let Some(repository) = GitRepository::discover(&target.path) else {
+        return false;
+    };
+    match repository.is_tracked(&target.path) {
+        Ok(tracked) => tracked,
+        Err(error) => {
+            warnings.push(format!(
+                "[{}:AUDIT_GIT_INDEX_ERROR] could not tell whether '{}' is committed: {}",
+                target.client.as_str(),
+                target.path.display(),
+                error
+            ));
+            false
+        }
+    }
+}
+
+fn severity_for_rule(rule: &str) -> AuditSeverity {
+    match rule {
+        RULE_KNOWN_TOKEN_PREFIX => AuditSeverity::High,
+        RULE_SENSITIVE_KEY => AuditSeverity::Medium,
+        RULE_HIGH_ENTROPY => AuditSeverity::Low,
+        _ => AuditSeverity::Low,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, path::Path};
+
+    use super::{AuditTarget, audit_targets};
+    use crate::{
+        domain::{ClientKind, ResourceSourceScope},
+        interface::contracts::{common::ResourceKind, security_audit::AuditSeverity},
+    };
+
+    fn index_with(paths: &[&str]) -> Vec<u8> {
+        let mut bytes = b"DIRC".to_vec();
+        bytes.extend_from_slice(&2_u32.to_be_bytes());
+        bytes.extend_from_slice(&(paths.len() as u32).to_be_bytes());
+        for path in paths {
+            let mut entry = vec![0_u8; 40 + 20];
+            entry.extend_from_slice(&(path.len() as u16).to_be_bytes());
+            entry.extend_from_slice(path.as_bytes());
+            let padded = (entry.len() + 8) & !7;
+            entry.resize(padded, 0);
+            bytes.extend_from_slice(&entry);
+        }
+        bytes
+    }
+
+    fn target(client: ClientKind, scope: ResourceSourceScope, path: &Path) -> AuditTarget {
+        AuditTarget {
+            client,
+            resource_kind: ResourceKind::Mcp,
+            source_scope: scope,
+            path: path.to_path_buf(),
+        }
+    }
+
+    #[test]
+    fn committed_project_configs_are_high_risk_and_ranked_first() {
+        let temp_root =
+            std::env::temp_dir().join(format!("ai-manager-security-audit-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&temp_root);
+        fs::create_dir_all(temp_root.join(".git")).expect("should create git dir");
+        fs::create_dir_all(temp_root.join(".cursor")).expect("should create cursor dir");
+        fs::write(temp_root.join(".git/index"), index_with(&[".mcp.json"]))
+            .expect("should write index");
+        let shared = temp_root.join(".mcp.json");
+        let local = temp_root.join(".cursor/mcp.json");
+        fs::write(
+            &shared,
+            r#"{"mcpServers":{"db":{"env":{"DB_PASSWORD":"correct-horse-battery"}}}}"#,
+        )
+        .expect("should write shared config");
+        fs::write(
+            &local,
+            "{\n  \"mcpServers\": {\n    \"gh\": {\"env\": {\"DB_PASSWORD\": \"hunter2-staple\", \"LEVEL\": \"debug\"}}\n  }\n}",
+        )
+        .expect("should write local config");
+
+        let audit = audit_targets(vec![
+            target(
+                ClientKind::Cursor,
+                ResourceSourceScope::ProjectShared,
+                &local,
+            ),
+            target(
+                ClientKind::ClaudeCode,
+                ResourceSourceScope::ProjectShared,
+                &shared,
+            ),
+            target(
+                ClientKind::Codex,
+                ResourceSourceScope::User,
+                &temp_root.join("missing.toml"),
+            ),
+        ]);
+        let _ = fs::remove_dir_all(&temp_root);
+
+        assert_eq!(audit.scanned_files, 2);
+        assert_eq!(audit.high_risk_files, vec![shared.display().to_string()]);
+        assert_eq!(
+            audit
+                .findings
+                .iter()
+                .map(|finding| (
+                    finding.client,
+                    finding.severity,
+                    finding.line,
+                    finding.committed_to_git
+                ))
+                .collect::<Vec<_>>(),
+            vec![
+                (ClientKind::ClaudeCode, AuditSeverity::High, 1, true),
+                (ClientKind::Cursor, AuditSeverity::Medium, 3, false),
+            ]
+        );
+        assert!(
+            audit
+                .findings
+                .iter()
+                .all(|finding| !finding.excerpt.contains("horse")
+                    && !finding.excerpt.contains("hunter2"))
+        );
+    }
+}
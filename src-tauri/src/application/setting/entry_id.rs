@@ -0,0 +1,115 @@
+use std::fmt;
+
+const PERMISSION_PREFIX: &str = "permission";
+const HOOK_PREFIX: &str = "hook";
+
+/// Claude evaluates `deny` before `ask` before `allow`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PermissionDecision {
+    Deny,
+    Ask,
+    Allow,
+}
+
+impl PermissionDecision {
+    pub const ALL: [Self; 3] = [Self::Deny, Self::Ask, Self::Allow];
+
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Deny => "deny",
+            Self::Ask => "ask",
+            Self::Allow => "allow",
+        }
+    }
+
+    fn parse(value: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|decision| decision.as_str() == value)
+    }
+}
+
+/// Logical identity of one settings entry, e.g. `permission::allow::Bash(npm test:*)` or
+/// `hook::PreToolUse::Edit|Write::./scripts/format.sh`. Hooks without a matcher leave it empty.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum SettingEntryId {
+    Permission {
+        decision: PermissionDecision,
+        rule: String,
+    },
+    Hook {
+        event: String,
+        matcher: String,
+        command: String,
+    },
+}
+
+impl SettingEntryId {
+    pub fn parse(value: &str) -> Option<Self> {
+        let (prefix, rest) = value.split_once("::")?;
+        match prefix {
+            PERMISSION_PREFIX => {
+                let (decision, rule) = rest.split_once("::")?;
+                let decision = PermissionDecision::parse(decision)?;
+                let rule = rule.trim();
+                (!rule.is_empty()).then(|| Self::Permission {
+                    decision,
+                    rule: rule.to_string(),
+                })
+            }
+            HOOK_PREFIX => {
+                let mut parts = rest.splitn(3, "::");
+                let event = parts.next()?.trim();
+                let matcher = parts.next()?.trim();
+                let command = parts.next()?.trim();
+                (!event.is_empty() && !command.is_empty()).then(|| Self::Hook {
+                    event: event.to_string(),
+                    matcher: matcher.to_string(),
+                    command: command.to_string(),
+                })
+            }
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for SettingEntryId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Permission { decision, rule } => {
+                write!(f, "{PERMISSION_PREFIX}::{}::{rule}", decision.as_str())
+            }
+            Self::Hook {
+                event,
+                matcher,
+                command,
+            } => write!(f, "{HOOK_PREFIX}::{event}::{matcher}::{command}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PermissionDecision, SettingEntryId};
+
+    #[test]
+    fn entry_ids_round_trip_and_reject_malformed_values() {
+        let permission = SettingEntryId::Permission {
+            decision: PermissionDecision::Allow,
+            rule: "Bash(npm run test:*)".to_string(),
+        };
+        let hook = SettingEntryId::Hook {
+            event: "Stop".to_string(),
+            matcher: String::new(),
+            command: "notify-send done::ok".to_string(),
+        };
+
+        for id in [permission, hook] {
+            assert_eq!(SettingEntryId::parse(&id.to_string()), Some(id));
+        }
+        assert_eq!(SettingEntryId::parse("permission::maybe::Read"), None);
+        assert_eq!(SettingEntryId::parse("permission::allow::  "), None);
+        assert_eq!(SettingEntryId::parse("hook::PreToolUse::Bash"), None);
+        assert_eq!(SettingEntryId::parse("env::FOO"), None);
+    }
+}
@@ -0,0 +1,480 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fs, io,
+};
+
+use serde_json::Value;
+
+use crate::{
+    domain::{ClientKind, ResourceSourceMetadata, ResourceSourceScope},
+    infra::{content_revision, parsers::parse_jsonc},
+    interface::contracts::list::{ResourceRecord, ResourceViewMode, SourceRevision},
+};
+
+use super::{
+    entry_id::{PermissionDecision, SettingEntryId},
+    source_catalog_service::{SettingSourceCatalogService, SettingSourceDescriptor},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SettingListResult {
+    pub items: Vec<ResourceRecord>,
+    pub warning: Option<String>,
+    pub source_revisions: Vec<SourceRevision>,
+}
+
+pub struct SettingListingService;
+
+impl SettingListingService {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn list(
+        &self,
+        client: ClientKind,
+        project_root: Option<&str>,
+        enabled_filter: Option<bool>,
+        view_mode: ResourceViewMode,
+        scope_filter: Option<&[ResourceSourceScope]>,
+    ) -> SettingListResult {
+        let descriptors = SettingSourceCatalogService::new().list_sources(client, project_root);
+        collect_from_descriptors(
+            descriptors,
+            enabled_filter,
+            view_mode,
+            scope_filter,
+            |path| fs::read_to_string(path),
+        )
+    }
+}
+
+impl Default for SettingListingService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn collect_from_descriptors<I, F>(
+    descriptors: I,
+    enabled_filter: Option<bool>,
+    view_mode: ResourceViewMode,
+    scope_filter: Option<&[ResourceSourceScope]>,
+    read_source: F,
+) -> SettingListResult
+where
+    I: IntoIterator<Item = SettingSourceDescriptor>,
+    F: Fn(&str) -> io::Result<String>,
+{
+    let mut items: Vec<ResourceRecord> = Vec::new();
+    let mut warnings: Vec<String> = Vec::new();
+    let mut source_revisions: Vec<SourceRevision> = Vec::new();
+
+    for descriptor in descriptors {
+        if scope_filter.is_some_and(|scopes| !scopes.contains(&descriptor.source_scope)) {
+            continue;
+        }
+
+        let source_path = descriptor.settings_path.display().to_string();
+        let source = match read_source(&source_path) {
+            Ok(source) => source,
+            Err(error) => {
+                if error.kind() != io::ErrorKind::NotFound {
+                    warnings.push(format!(
+                        "[{}:SETTINGS_READ_ERROR] failed to read '{}': {}",
+                        descriptor.client.as_str(),
+                        source_path,
+                        error
+                    ));
+                }
+                source_revisions.push(SourceRevision {
+                    source_id: descriptor.source_id,
+                    source_path,
+                    revision: None,
+                });
+                continue;
+            }
+        };
+        source_revisions.push(SourceRevision {
+            source_id: descriptor.source_id.clone(),
+            source_path: source_path.clone(),
+            revision: Some(content_revision(source.as_bytes())),
+        });
+
+        // Rules and hooks are always active while present in a settings file.
+        if enabled_filter == Some(false) || source.trim().is_empty() {
+            continue;
+        }
+
+        match parse_jsonc(&source) {
+            Ok(document) => items.extend(settings_records(&descriptor, &document.value)),
+            Err(error) => warnings.push(format!(
+                "[{}:SETTINGS_PARSE_ERROR] failed to parse '{}': {}",
+                descriptor.client.as_str(),
+                source_path,
+                error
+            )),
+        }
+    }
+
+    apply_effective_precedence(&mut items);
+    if !matches!(view_mode, ResourceViewMode::AllSources) {
+        items.retain(|item| item.is_effective);
+    }
+
+    items.sort_by(|left, right| {
+        (
+            left.logical_id.as_str(),
+            precedence_rank(left.source_scope),
+            left.id.as_str(),
+        )
+            .cmp(&(
+                right.logical_id.as_str(),
+                precedence_rank(right.source_scope),
+                right.id.as_str(),
+            ))
+    });
+
+    SettingListResult {
+        items,
+        warning: (!warnings.is_empty()).then(|| warnings.join(" | ")),
+        source_revisions,
+    }
+}
+
+fn settings_records(descriptor: &SettingSourceDescriptor, settings: &Value) -> Vec<ResourceRecord> {
+    let mut records: Vec<ResourceRecord> = Vec::new();
+    let mut seen: HashSet<String> = HashSet::new();
+    let mut push = |entry: SettingEntryId, description: String, manifest: Option<String>| {
+        let logical_id = entry.to_string();
+        if !seen.insert(logical_id.clone()) {
+            return;
+        }
+        let (display_name, install_kind) = match entry {
+            SettingEntryId::Permission { rule, .. } => (rule, "permission"),
+            SettingEntryId::Hook { command, .. } => (command, "hook"),
+        };
+        records.push(setting_record(
+            descriptor,
+            logical_id,
+            display_name,
+            description,
+            install_kind,
+            manifest,
+        ));
+    };
+
+    for decision in PermissionDecision::ALL {
+        let rules = settings
+            .pointer(&format!("/permissions/{}", decision.as_str()))
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(Value::as_str)
+            .map(str::trim)
+            .filter(|rule| !rule.is_empty());
+        for rule in rules {
+            push(
+                SettingEntryId::Permission {
+                    decision,
+                    rule: rule.to_string(),
+                },
+                format!("{} permission rule", decision.as_str()),
+                None,
+            );
+        }
+    }
+
+    let hook_events = settings
+        .get("hooks")
+        .and_then(Value::as_object)
+        .into_iter()
+        .flatten();
+    for (event, groups) in hook_events {
+        for group in groups.as_array().into_iter().flatten() {
+            let matcher = group
+                .get("matcher")
+                .and_then(Value::as_str)
+                .unwrap_or_default()
+                .trim();
+            for hook in group
+                .get("hooks")
+                .and_then(Value::as_array)
+                .into_iter()
+                .flatten()
+            {
+                let Some(command) = hook
+                    .get("command")
+                    .and_then(Value::as_str)
+                    .map(str::trim)
+                    .filter(|command| !command.is_empty())
+                else {
+                    continue;
+                };
+                let description = if matcher.is_empty() {
+                    format!("{} hook", event)
+                } else {
+                    format!("{} hook for `{}`", event, matcher)
+                };
+                push(
+                    SettingEntryId::Hook {
+                        event: event.clone(),
+                        matcher: matcher.to_string(),
+                        command: command.to_string(),
+                    },
+                    description,
+                    serde_json::to_string_pretty(hook).ok(),
+                );
+            }
+        }
+    }
+
+    records
+}
+
+fn setting_record(
+    descriptor: &SettingSourceDescriptor,
+    logical_id: String,
+    display_name: String,
+    description: String,
+    install_kind: &str,
+    manifest_content: Option<String>,
+) -> ResourceRecord {
+    ResourceRecord {
+        id: format!(
+            "{}::setting::{}::{}",
+            descriptor.client.as_str(),
+            descriptor.source_id,
+            logical_id
+        ),
+        logical_id,
+        client: descriptor.client,
+        display_name,
+        enabled: true,
+        transport_kind: None,
+        transport_command: None,
+        transport_args: None,
+        transport_url: None,
+        source_path: Some(descriptor.settings_path.display().to_string()),
+        source_id: String::new(),
+        source_scope: descriptor.source_scope,
+        source_label: String::new(),
+        is_effective: true,
+        shadowed_by: None,
+        description: Some(description),
+        install_kind: Some(install_kind.to_string()),
+        manifest_content,
+        approval: None,
+    }
+    .with_source_metadata(ResourceSourceMetadata {
+        source_id: descriptor.source_id.clone(),
+        source_scope: descriptor.source_scope,
+        source_label: descriptor.source_label.clone(),
+        is_effective: true,
+        shadowed_by: None,
+    })
+}
+
+/// The same entry in several files is effective only in the highest-precedence one; a
+/// permission rule is also shadowed by the same rule under a stricter decision.
+fn apply_effective_precedence(items: &mut [ResourceRecord]) {
+    let mut indices_by_entry: HashMap<(String, String), Vec<usize>> = HashMap::new();
+    for (index, item) in items.iter().enumerate() {
+        indices_by_entry
+            .entry((item.client.as_str().to_string(), item.logical_id.clone()))
+            .or_default()
+            .push(index);
+    }
+
+    for indices in indices_by_entry.values() {
+        let Some(&winner_index) = indices
+            .iter()
+            .min_by_key(|index| (precedence_rank(items[**index].source_scope), **index))
+        else {
+            continue;
+        };
+
+        let winner_id = items[winner_index].id.clone();
+        for index in indices {
+            let item = &mut items[*index];
+            item.is_effective = *index == winner_index;
+            item.shadowed_by = (*index != winner_index).then(|| winner_id.clone());
+        }
+    }
+
+    let mut strictest: HashMap<(String, String), (PermissionDecision, String)> = HashMap::new();
+    for item in items.iter().filter(|item| item.is_effective) {
+        if let Some(SettingEntryId::Permission { decision, rule }) =
+            SettingEntryId::parse(&item.logical_id)
+        {
+            strictest
+                .entry((item.client.as_str().to_string(), rule))
+                .and_modify(|current| {
+                    if decision < current.0 {
+                        *current = (decision, item.id.clone());
+                    }
+                })
+                .or_insert_with(|| (decision, item.id.clone()));
+        }
+    }
+
+    for item in items.iter_mut().filter(|item| item.is_effective) {
+        let Some(SettingEntryId::Permission { decision, rule }) =
+            SettingEntryId::parse(&item.logical_id)
+        else {
+            continue;
+        };
+        if let Some((strictest_decision, strictest_id)) =
+            strictest.get(&(item.client.as_str().to_string(), rule))
+            && *strictest_decision < decision
+        {
+            item.is_effective = false;
+            item.shadowed_by = Some(strictest_id.clone());
+        }
+    }
+}
+
+fn precedence_rank(scope: ResourceSourceScope) -> u8 {
+    match scope {
+        ResourceSourceScope::ProjectPrivate => 0,
+        ResourceSourceScope::ProjectShared => 1,
+        ResourceSourceScope::User => 2,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, io, path::PathBuf};
+
+    use super::collect_from_descriptors;
+    use crate::{
+        domain::{ClientKind, ResourceSourceScope},
+        interface::contracts::list::ResourceViewMode,
+    };
+
+    use super::super::source_catalog_service::SettingSourceDescriptor;
+
+    fn descriptor(scope: ResourceSourceScope, path: &str) -> SettingSourceDescriptor {
+        SettingSourceDescriptor {
+            client: ClientKind::ClaudeCode,
+            source_id: format!("setting::claude_code::{}::{}", scope.as_str(), path),
+            source_scope: scope,
+            source_label: scope.as_str().to_string(),
+            settings_path: PathBuf::from(path),
+            project_root: None,
+        }
+    }
+
+    #[test]
+    fn listing_applies_scope_precedence_and_stricter_permission_decisions() {
+        let fixtures: HashMap<&str, &str> = HashMap::from([
+            (
+                "/home/.claude/settings.json",
+                r#"{
+  "permissions": { "allow": ["Bash(npm test:*)", "Read(.env)"] },
+  "hooks": {
+    "PostToolUse": [
+      { "matcher": "Edit|Write", "hooks": [{ "type": "command", "command": "./format.sh" }] }
+    ]
+  }
+}"#,
+            ),
+            (
+                "/repo/.claude/settings.json",
+                r#"{
+  // shared with the team
+  "permissions": { "deny": ["Read(.env)"] },
+  "hooks": {
+    "PostToolUse": [
+      { "matcher": "Edit|Write", "hooks": [{ "type": "command", "command": "./format.sh" }] }
+    ],
+    "Stop": [{ "hooks": [{ "type": "command", "command": "notify-send done" }] }]
+  }
+}"#,
+            ),
+            ("/repo/.claude/settings.local.json", "{ broken"),
+        ]);
+        let descriptors = vec![
+            descriptor(ResourceSourceScope::User, "/home/.claude/settings.json"),
+            descriptor(
+                ResourceSourceScope::ProjectShared,
+                "/repo/.claude/settings.json",
+            ),
+            descriptor(
+                ResourceSourceScope::ProjectPrivate,
+                "/repo/.claude/settings.local.json",
+            ),
+        ];
+
+        let result = collect_from_descriptors(
+            descriptors,
+            None,
+            ResourceViewMode::AllSources,
+            None,
+            |path| match fixtures.get(path) {
+                Some(payload) => Ok((*payload).to_string()),
+                None => Err(io::Error::new(io::ErrorKind::NotFound, path.to_string())),
+            },
+        );
+
+        let states: Vec<(&str, ResourceSourceScope, bool)> = result
+            .items
+            .iter()
+            .map(|item| {
+                (
+                    item.logical_id.as_str(),
+                    item.source_scope,
+                    item.is_effective,
+                )
+            })
+            .collect();
+        assert_eq!(
+            states,
+            vec![
+                (
+                    "hook::PostToolUse::Edit|Write::./format.sh",
+                    ResourceSourceScope::ProjectShared,
+                    true
+                ),
+                (
+                    "hook::PostToolUse::Edit|Write::./format.sh",
+                    ResourceSourceScope::User,
+                    false
+                ),
+                (
+                    "hook::Stop::::notify-send done",
+                    ResourceSourceScope::ProjectShared,
+                    true
+                ),
+                (
+                    "permission::allow::Bash(npm test:*)",
+                    ResourceSourceScope::User,
+                    true
+                ),
+                (
+                    "permission::allow::Read(.env)",
+                    ResourceSourceScope::User,
+                    false
+                ),
+                (
+                    "permission::deny::Read(.env)",
+                    ResourceSourceScope::ProjectShared,
+                    true
+                ),
+            ]
+        );
+        let shadowed_allow = &result.items[4];
+        assert_eq!(
+            shadowed_allow.shadowed_by.as_ref(),
+            Some(&result.items[5].id)
+        );
+        assert_eq!(result.items[0].install_kind.as_deref(), Some("hook"));
+        assert_eq!(result.source_revisions.len(), 3);
+        assert!(
+            result
+                .warning
+                .as_deref()
+                .is_some_and(|warning| warning.contains("SETTINGS_PARSE_ERROR"))
+        );
+    }
+}
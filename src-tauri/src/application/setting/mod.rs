@@ -0,0 +1,5 @@
+pub(super) mod entry_id;
+pub(super) mod listing_service;
+pub(super) mod mutation_service;
+pub(super) mod settings_file;
+pub(super) mod source_catalog_service;
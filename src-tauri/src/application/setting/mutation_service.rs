@@ -0,0 +1,369 @@
+use serde_json::{Map, Value, json};
+
+use crate::{
+    domain::ClientKind,
+    interface::contracts::{command::CommandError, mutate::MutationAction},
+};
+
+use super::{
+    entry_id::SettingEntryId, settings_file::edit_settings_file,
+    source_catalog_service::SettingSourceCatalogService,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SettingMutationResult {
+    pub source_path: String,
+    pub target_source_id: String,
+    pub message: String,
+}
+
+pub struct SettingMutationService;
+
+impl SettingMutationService {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Adds or removes one permission rule or hook command. `target_id` is the entry's
+    /// logical id; without `target_source_id` the user settings file is edited.
+    pub fn mutate(
+        &self,
+        client: ClientKind,
+        action: MutationAction,
+        target_id: &str,
+        project_root: Option<&str>,
+        target_source_id: Option<&str>,
+        expected_revision: Option<&str>,
+    ) -> Result<SettingMutationResult, CommandError> {
+        if !matches!(action, MutationAction::Add | MutationAction::Remove) {
+            return Err(CommandError::validation(format!(
+                "Settings do not support the '{}' action; remove the entry and add the new one.",
+                action.as_str()
+            )));
+        }
+
+        let descriptors = SettingSourceCatalogService::new().list_sources(client, project_root);
+        if descriptors.is_empty() {
+            return Err(CommandError::validation(format!(
+                "'{}' does not have managed settings.",
+                client.as_str()
+            )));
+        }
+
+        let entry = SettingEntryId::parse(target_id).ok_or_else(|| {
+            CommandError::validation(format!(
+                "target_id '{}' is not a setting entry; expected 'permission::<allow|ask|deny>::<rule>' or 'hook::<event>::<matcher>::<command>'.",
+                target_id
+            ))
+        })?;
+
+        let descriptor = match target_source_id {
+            Some(target_source_id) => descriptors
+                .into_iter()
+                .find(|descriptor| descriptor.source_id == target_source_id)
+                .ok_or_else(|| {
+                    CommandError::validation(format!(
+                        "target_source_id '{}' is not a settings source for '{}'{}.",
+                        target_source_id,
+                        client.as_str(),
+                        if project_root.is_some() {
+                            ""
+                        } else {
+                            " (project settings require project_root)"
+                        }
+                    ))
+                })?,
+            None => descriptors
+                .into_iter()
+                .next()
+                .expect("settings catalog always includes the user source"),
+        };
+
+        edit_settings_file(&descriptor.settings_path, expected_revision, |settings| {
+            if matches!(action, MutationAction::Add) {
+                add_entry(settings, &entry, target_id)
+            } else {
+                remove_entry(settings, &entry, target_id)
+            }
+        })
+        .map_err(|error| error.with_source_id(&descriptor.source_id))?;
+
+        let source_path = descriptor.settings_path.display().to_string();
+        let message = if matches!(action, MutationAction::Add) {
+            format!("Added setting '{}' to {}.", target_id, source_path)
+        } else {
+            format!("Removed setting '{}' from {}.", target_id, source_path)
+        };
+
+        Ok(SettingMutationResult {
+            source_path,
+            target_source_id: descriptor.source_id,
+            message,
+        })
+    }
+}
+
+impl Default for SettingMutationService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn add_entry(
+    settings: &mut Map<String, Value>,
+    entry: &SettingEntryId,
+    target_id: &str,
+) -> Result<(), CommandError> {
+    let exists = || CommandError::validation(format!("Setting '{}' already exists.", target_id));
+
+    match entry {
+        SettingEntryId::Permission { decision, rule } => {
+            let rules = array_member(object_member(settings, "permissions")?, decision.as_str())?;
+            if rules.iter().any(|value| value.as_str() == Some(rule)) {
+                return Err(exists());
+            }
+            rules.push(Value::String(rule.clone()));
+        }
+        SettingEntryId::Hook {
+            event,
+            matcher,
+            command,
+        } => {
+            let groups = array_member(object_member(settings, "hooks")?, event)?;
+            if groups
+                .iter()
+                .any(|group| group_matches(group, matcher) && group_has_command(group, command))
+            {
+                return Err(exists());
+            }
+
+            let hook = json!({ "type": "command", "command": command });
+            match groups
+                .iter_mut()
+                .filter(|group| group_matches(group, matcher))
+                .find_map(|group| group.get_mut("hooks").and_then(Value::as_array_mut))
+            {
+                Some(hooks) => hooks.push(hook),
+                None if matcher.is_empty() => groups.push(json!({ "hooks": [hook] })),
+                None => groups.push(json!({ "matcher": matcher, "hooks": [hook] })),
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn remove_entry(
+    settings: &mut Map<String, Value>,
+    entry: &SettingEntryId,
+    target_id: &str,
+) -> Result<(), CommandError> {
+    let removed = match entry {
+        SettingEntryId::Permission { decision, rule } => settings
+            .get_mut("permissions")
+            .and_then(|permissions| permissions.get_mut(decision.as_str()))
+            .and_then(Value::as_array_mut)
+            .is_some_and(|rules| {
+                let before = rules.len();
+                rules.retain(|value| value.as_str() != Some(rule));
+                rules.len() != before
+            }),
+        SettingEntryId::Hook {
+            event,
+            matcher,
+            command,
+        } => remove_hook(settings, event, matcher, command),
+    };
+
+    if removed {
+        Ok(())
+    } else {
+        Err(CommandError::validation(format!(
+            "Setting '{}' does not exist.",
+            target_id
+        )))
+    }
+}
+
+/// Drops the command and any matcher group, event or `hooks` object it leaves empty.
+fn remove_hook(
+    settings: &mut Map<String, Value>,
+    event: &str,
+    matcher: &str,
+    command: &str,
+) -> bool {
+    let Some(events) = settings.get_mut("hooks").and_then(Value::as_object_mut) else {
+        return false;
+    };
+    let Some(groups) = events.get_mut(event).and_then(Value::as_array_mut) else {
+        return false;
+    };
+
+    let mut removed = false;
+    for group in groups
+        .iter_mut()
+        .filter(|group| group_matches(group, matcher))
+    {
+        if let Some(hooks) = group.get_mut("hooks").and_then(Value::as_array_mut) {
+            let before = hooks.len();
+            hooks.retain(|hook| !hook_runs(hook, command));
+            removed |= hooks.len() != before;
+        }
+    }
+    groups.retain(|group| {
+        group
+            .get("hooks")
+            .and_then(Value::as_array)
+            .is_none_or(|hooks| !hooks.is_empty())
+    });
+
+    if groups.is_empty() {
+        events.remove(event);
+    }
+    if events.is_empty() {
+        settings.remove("hooks");
+    }
+    removed
+}
+
+fn group_matches(group: &Value, matcher: &str) -> bool {
+    group
+        .get("matcher")
+        .and_then(Value::as_str)
+        .unwrap_or_default()
+        .trim()
+        == matcher
+}
+
+fn group_has_command(group: &Value, command: &str) -> bool {
+    group
+        .get("hooks")
+        .and_then(Value::as_array)
+        .is_some_and(|hooks| hooks.iter().any(|hook| hook_runs(hook, command)))
+}
+
+fn hook_runs(hook: &Value, command: &str) -> bool {
+    hook.get("command")
+        .and_then(Value::as_str)
+        .is_some_and(|value| value.trim() == command)
+}
+
+fn object_member<'a>(
+    object: &'a mut Map<String, Value>,
+    key: &str,
+) -> Result<&'a mut Map<String, Value>, CommandError> {
+    object
+        .entry(key)
+        .or_insert_with(|| Value::Object(Map::new()))
+        .as_object_mut()
+        .ok_or_else(|| {
+            CommandError::validation(format!("Settings field '{}' must be an object.", key))
+        })
+}
+
+fn array_member<'a>(
+    object: &'a mut Map<String, Value>,
+    key: &str,
+) -> Result<&'a mut Vec<Value>, CommandError> {
+    object
+        .entry(key)
+        .or_insert_with(|| Value::Array(Vec::new()))
+        .as_array_mut()
+        .ok_or_else(|| {
+            CommandError::validation(format!("Settings field '{}' must be an array.", key))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use serde_json::{Value, json};
+
+    use super::SettingMutationService;
+    use crate::{
+        domain::ClientKind,
+        interface::contracts::{command::CommandErrorCode, mutate::MutationAction},
+    };
+
+    #[test]
+    fn add_and_remove_edit_individual_rules_and_hooks_in_place() {
+        let project_root = std::env::temp_dir().join(format!(
+            "ai-manager-setting-mutation-{}",
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&project_root);
+        let settings_dir = project_root.join(".claude");
+        fs::create_dir_all(&settings_dir).expect("should create settings dir");
+        let settings_path = settings_dir.join("settings.json");
+        fs::write(
+            &settings_path,
+            r#"{
+  // team defaults
+  "permissions": { "allow": ["Read(src/**)"] },
+  "model": "sonnet"
+}
+"#,
+        )
+        .expect("should write settings");
+        let project_root_label = project_root.display().to_string();
+        let source_id = format!(
+            "setting::claude_code::project_shared::{}",
+            settings_path.display()
+        );
+
+        let service = SettingMutationService::new();
+        let mutate = |action: MutationAction, target_id: &str| {
+            service.mutate(
+                ClientKind::ClaudeCode,
+                action,
+                target_id,
+                Some(&project_root_label),
+                Some(&source_id),
+                None,
+            )
+        };
+
+        mutate(MutationAction::Add, "permission::deny::Bash(rm -rf:*)")
+            .expect("adding a deny rule should succeed");
+        mutate(MutationAction::Add, "hook::PreToolUse::Bash::./guard.sh")
+            .expect("adding a hook should succeed");
+        mutate(MutationAction::Add, "hook::PreToolUse::Bash::./audit.sh")
+            .expect("adding a second hook to the matcher should succeed");
+        let duplicate = mutate(MutationAction::Add, "hook::PreToolUse::Bash::./guard.sh")
+            .expect_err("duplicate hooks should be rejected");
+        let with_hooks = fs::read_to_string(&settings_path).expect("should read settings");
+        mutate(MutationAction::Remove, "hook::PreToolUse::Bash::./guard.sh")
+            .expect("removing a hook should succeed");
+        mutate(MutationAction::Remove, "hook::PreToolUse::Bash::./audit.sh")
+            .expect("removing the last hook should succeed");
+        let missing = mutate(MutationAction::Remove, "permission::allow::Write(**)")
+            .expect_err("missing rules cannot be removed");
+        let update = mutate(MutationAction::Update, "permission::allow::Read(src/**)")
+            .expect_err("settings entries are not updated in place");
+        let final_content = fs::read_to_string(&settings_path).expect("should read settings");
+        let _ = fs::remove_dir_all(&project_root);
+
+        let with_hooks: Value = crate::infra::parsers::parse_jsonc(&with_hooks)
+            .expect("settings should stay valid JSONC")
+            .value;
+        assert_eq!(
+            with_hooks["hooks"],
+            json!({
+                "PreToolUse": [{
+                    "matcher": "Bash",
+                    "hooks": [
+                        { "type": "command", "command": "./guard.sh" },
+                        { "type": "command", "command": "./audit.sh" }
+                    ]
+                }]
+            })
+        );
+        assert!(final_content.contains("// team defaults"));
+        assert!(!final_content.contains("hooks"));
+        assert!(final_content.contains("Bash(rm -rf:*)"));
+        assert!(duplicate.message.contains("already exists"));
+        assert!(missing.message.contains("does not exist"));
+        assert_eq!(update.code, CommandErrorCode::ValidationError);
+    }
+}
@@ -0,0 +1,82 @@
+use std::{fs, io, path::Path};
+
+use serde_json::{Map, Value};
+
+use crate::{
+    infra::{
+        SafeFileMutator, content_revision,
+        parsers::{parse_jsonc, patch_jsonc},
+    },
+    interface::contracts::command::CommandError,
+};
+
+/// Applies `edit` to the root object of a Claude settings file and writes the result back,
+/// keeping comments when the document has any; edits that cannot keep them are refused. The
+/// write is also refused unless the file still matches `expected_revision`, or the revision
+/// read here when none is given.
+pub fn edit_settings_file<T, F>(
+    path: &Path,
+    expected_revision: Option<&str>,
+    edit: F,
+) -> Result<T, CommandError>
+where
+    F: FnOnce(&mut Map<String, Value>) -> Result<T, CommandError>,
+{
+    let path_label = path.display().to_string();
+    let (current_content, current_revision) = match fs::read_to_string(path) {
+        Ok(content) => {
+            let revision = content_revision(content.as_bytes());
+            (content, Some(revision))
+        }
+        Err(error) if error.kind() == io::ErrorKind::NotFound => (String::new(), None),
+        Err(error) => {
+            return Err(CommandError::io(
+                format!("Failed to read Claude settings '{}': {}", path_label, error),
+                error.kind(),
+            )
+            .with_path(path_label));
+        }
+    };
+    let (mut settings, has_comments) = if current_content.trim().is_empty() {
+        (Value::Object(Map::new()), false)
+    } else {
+        let document = parse_jsonc(&current_content).map_err(|error| {
+            CommandError::parse(format!("Invalid Claude settings JSON: {}", error))
+                .with_path(path_label.clone())
+        })?;
+        (document.value, document.has_comments)
+    };
+    let Some(settings_object) = settings.as_object_mut() else {
+        return Err(
+            CommandError::validation("Claude settings root must be an object.")
+                .with_path(path_label),
+        );
+    };
+    let outcome = edit(settings_object)?;
+
+    let next_content = if has_comments {
+        // Re-serializing would silently drop the comments, so refuse instead.
+        patch_jsonc(&current_content, &settings).ok_or_else(|| {
+            CommandError::validation(format!(
+                "Claude settings '{}' has comments that this edit cannot keep. Edit the file by hand, or remove its comments and retry.",
+                path_label
+            ))
+            .with_path(path_label.clone())
+        })?
+    } else {
+        let mut serialized = serde_json::to_string_pretty(&settings).map_err(|error| {
+            CommandError::internal(format!("Failed to serialize Claude settings: {}", error))
+        })?;
+        serialized.push('\n');
+        serialized
+    };
+    SafeFileMutator::new()
+        .replace_file_if_unchanged(
+            path,
+            next_content.as_bytes(),
+            expected_revision.or(current_revision.as_deref()),
+        )
+        .map_err(|failure| CommandError::from_mutation_failure(&failure, path))?;
+
+    Ok(outcome)
+}
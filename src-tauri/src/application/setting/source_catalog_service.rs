@@ -0,0 +1,132 @@
+use std::path::PathBuf;
+
+use crate::{
+    domain::{ClientKind, ResourceSourceScope, profile_for_client},
+    infra::expand_user_path,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SettingSourceDescriptor {
+    pub client: ClientKind,
+    pub source_id: String,
+    pub source_scope: ResourceSourceScope,
+    pub source_label: String,
+    pub settings_path: PathBuf,
+    pub project_root: Option<String>,
+}
+
+pub struct SettingSourceCatalogService;
+
+impl SettingSourceCatalogService {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Claude Code settings files, lowest precedence first.
+    pub fn list_sources(
+        &self,
+        client: ClientKind,
+        project_root: Option<&str>,
+    ) -> Vec<SettingSourceDescriptor> {
+        if !profile_for_client(client).capabilities.supports_settings {
+            return Vec::new();
+        }
+
+        let mut descriptors = vec![descriptor_for_scope(
+            client,
+            ResourceSourceScope::User,
+            expand_user_path("~/.claude/settings.json"),
+            None,
+        )];
+
+        if let Some(project_root) = project_root {
+            let settings_dir = PathBuf::from(project_root).join(".claude");
+            descriptors.push(descriptor_for_scope(
+                client,
+                ResourceSourceScope::ProjectShared,
+                settings_dir.join("settings.json"),
+                Some(project_root.to_string()),
+            ));
+            descriptors.push(descriptor_for_scope(
+                client,
+                ResourceSourceScope::ProjectPrivate,
+                settings_dir.join("settings.local.json"),
+                Some(project_root.to_string()),
+            ));
+        }
+
+        descriptors
+    }
+}
+
+impl Default for SettingSourceCatalogService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn descriptor_for_scope(
+    client: ClientKind,
+    source_scope: ResourceSourceScope,
+    settings_path: PathBuf,
+    project_root: Option<String>,
+) -> SettingSourceDescriptor {
+    let source_label = match source_scope {
+        ResourceSourceScope::User => "User settings",
+        ResourceSourceScope::ProjectShared => "Project settings",
+        ResourceSourceScope::ProjectPrivate => "Project local settings",
+    };
+
+    let source_id = format!(
+        "setting::{}::{}::{}",
+        client.as_str(),
+        source_scope.as_str(),
+        settings_path.display()
+    );
+
+    SettingSourceDescriptor {
+        client,
+        source_id,
+        source_scope,
+        source_label: source_label.to_string(),
+        settings_path,
+        project_root,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::SettingSourceCatalogService;
+    use crate::domain::{ClientKind, ResourceSourceScope};
+
+    #[test]
+    fn claude_catalog_lists_user_project_and_local_settings() {
+        let descriptors = SettingSourceCatalogService::new()
+            .list_sources(ClientKind::ClaudeCode, Some("/Users/test/workspace/demo"));
+
+        assert_eq!(
+            descriptors
+                .iter()
+                .map(|descriptor| descriptor.source_scope)
+                .collect::<Vec<_>>(),
+            vec![
+                ResourceSourceScope::User,
+                ResourceSourceScope::ProjectShared,
+                ResourceSourceScope::ProjectPrivate
+            ]
+        );
+        assert_eq!(
+            descriptors[2].settings_path,
+            PathBuf::from("/Users/test/workspace/demo")
+                .join(".claude")
+                .join("settings.local.json")
+        );
+        assert!(
+            SettingSourceCatalogService::new()
+                .list_sources(ClientKind::Codex, Some("/tmp/demo"))
+                .is_empty()
+        );
+    }
+}
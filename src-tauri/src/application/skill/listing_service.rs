@@ -147,6 +147,7 @@ fn collect_skills_from_directory(
                 description: metadata.description,
                 install_kind: Some(manifest_candidate.install_kind.to_string()),
                 manifest_content: Some(manifest_source),
+                approval: None,
             }
             .with_source_metadata(ResourceSourceMetadata::personal(
                 source_id,
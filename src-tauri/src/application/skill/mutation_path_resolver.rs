@@ -1,7 +1,8 @@
-use std::{env, path::PathBuf};
+use std::path::PathBuf;
 
-use crate::interface::contracts::{
-    command::CommandError, common::ClientKind, mutate::MutationAction,
+use crate::{
+    infra::expand_user_path,
+    interface::contracts::{command::CommandError, common::ClientKind, mutate::MutationAction},
 };
 
 use super::path_resolver::{preferred_skill_dir, resolve_skill_dir};
@@ -45,13 +46,3 @@ pub fn resolve_skill_root_path(
         ))),
     }
 }
-
-fn expand_user_path(value: &str) -> PathBuf {
-    if let Some(stripped) = value.strip_prefix("~/")
-        && let Some(home) = env::var_os("HOME")
-    {
-        return PathBuf::from(home).join(stripped);
-    }
-
-    PathBuf::from(value)
-}
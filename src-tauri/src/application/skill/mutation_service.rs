@@ -1,11 +1,11 @@
 use std::{
-    env, fs,
+    fs,
     io::ErrorKind,
     path::{Path, PathBuf},
 };
 
 use crate::{
-    infra::{MutationTestHooks, SafeFileMutator},
+    infra::{MutationTestHooks, SafeFileMutator, content_revision, expand_user_path},
     interface::contracts::{command::CommandError, common::ClientKind, mutate::MutationAction},
 };
 
@@ -43,6 +43,9 @@ impl SkillMutationService {
             MutationAction::Add => add_skill(client, target_id, &payload),
             MutationAction::Remove => remove_skill(client, target_id, &payload),
             MutationAction::Update => update_skill(client, target_id, &payload),
+            MutationAction::Approve | MutationAction::Deny => Err(CommandError::validation(
+                format!("Skills do not support the '{}' action.", action.as_str()),
+            )),
         }
     }
 }
@@ -101,11 +104,15 @@ fn add_skill(
 
     if let Some(parent) = destination_manifest.parent() {
         fs::create_dir_all(parent).map_err(|error| {
-            CommandError::internal(format!(
-                "Failed to create skill destination directory '{}': {}",
-                parent.display(),
-                error
-            ))
+            CommandError::io(
+                format!(
+                    "Failed to create skill destination directory '{}': {}",
+                    parent.display(),
+                    error
+                ),
+                error.kind(),
+            )
+            .with_path(parent.display().to_string())
         })?;
     }
 
@@ -122,12 +129,7 @@ fn add_skill(
     } else {
         mutator.replace_file(&destination_manifest, manifest_source.manifest.as_bytes())
     }
-    .map_err(|failure| {
-        CommandError::internal(format!(
-            "[stage={:?}] {} (rollback_succeeded={})",
-            failure.stage, failure.message, failure.rollback_succeeded
-        ))
-    })?;
+    .map_err(|failure| CommandError::from_mutation_failure(&failure, &destination_manifest))?;
 
     let mut message = format!(
         "Added skill '{}' for '{}'. Installed at '{}'.",
@@ -161,14 +163,24 @@ fn remove_skill(
     let removal_targets =
         resolve_removal_targets(&root_path, target_id, payload.source_path.as_deref())?;
 
+    let mutator = SafeFileMutator::new();
+    let mut backup_paths = Vec::new();
     for target in &removal_targets {
-        fs::remove_file(target).map_err(|error| {
-            CommandError::internal(format!(
-                "Failed to remove skill manifest '{}': {}",
-                target.display(),
-                error
-            ))
+        let content = fs::read(target).map_err(|error| {
+            CommandError::io(
+                format!(
+                    "Failed to read skill manifest '{}': {}",
+                    target.display(),
+                    error
+                ),
+                error.kind(),
+            )
+            .with_path(target.display().to_string())
         })?;
+        let outcome = mutator
+            .remove_file_if_unchanged(target, &content_revision(&content))
+            .map_err(|failure| CommandError::from_mutation_failure(&failure, target))?;
+        backup_paths.extend(outcome.backup_path);
     }
 
     for target in &removal_targets {
@@ -187,6 +199,9 @@ fn remove_skill(
             .collect::<Vec<_>>()
             .join(", ")
     ));
+    if !backup_paths.is_empty() {
+        message.push_str(&format!(" Backup: {}.", backup_paths.join(", ")));
+    }
 
     Ok(SkillMutationResult {
         source_path: removal_targets
@@ -239,12 +254,7 @@ fn update_skill(
     } else {
         mutator.replace_file(&target_manifest, manifest_source.manifest.as_bytes())
     }
-    .map_err(|failure| {
-        CommandError::internal(format!(
-            "[stage={:?}] {} (rollback_succeeded={})",
-            failure.stage, failure.message, failure.rollback_succeeded
-        ))
-    })?;
+    .map_err(|failure| CommandError::from_mutation_failure(&failure, &target_manifest))?;
 
     let mut message = format!(
         "Updated skill '{}' for '{}'. Installed at '{}'.",
@@ -308,11 +318,15 @@ fn resolve_manifest_source(
         }
 
         let manifest = fs::read_to_string(&manifest_path).map_err(|error| {
-            CommandError::internal(format!(
-                "Failed to read skill source manifest '{}': {}",
-                manifest_path.display(),
-                error
-            ))
+            CommandError::io(
+                format!(
+                    "Failed to read skill source manifest '{}': {}",
+                    manifest_path.display(),
+                    error
+                ),
+                error.kind(),
+            )
+            .with_path(manifest_path.display().to_string())
         })?;
 
         return Ok(SkillManifestSource {
@@ -457,22 +471,16 @@ fn cleanup_skill_directory_if_empty(manifest_path: &Path) -> Result<(), CommandE
         {
             Ok(())
         }
-        Err(error) => Err(CommandError::internal(format!(
-            "Failed to clean up empty skill directory '{}': {}",
-            parent.display(),
-            error
-        ))),
-    }
-}
-
-fn expand_user_path(value: &str) -> PathBuf {
-    if let Some(stripped) = value.strip_prefix("~/")
-        && let Some(home) = env::var_os("HOME")
-    {
-        return PathBuf::from(home).join(stripped);
+        Err(error) => Err(CommandError::io(
+            format!(
+                "Failed to clean up empty skill directory '{}': {}",
+                parent.display(),
+                error
+            ),
+            error.kind(),
+        )
+        .with_path(parent.display().to_string())),
     }
-
-    PathBuf::from(value)
 }
 
 #[cfg(test)]
@@ -3,7 +3,12 @@ use std::{
     path::{Path, PathBuf},
 };
 
-use crate::domain::ClientKind;
+use crate::{
+    domain::ClientKind,
+    infra::{
+        HostOs, PathCandidateKind, PathCandidateProfile, expand_user_path, support_matrix_client,
+    },
+};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SkillDirResolution {
@@ -13,7 +18,7 @@ pub struct SkillDirResolution {
 
 pub fn resolve_skill_dir(client: ClientKind) -> SkillDirResolution {
     let profile = profile_for_client(client);
-    let override_value = read_env_value(profile.override_env_vars);
+    let override_value = read_env_value(&profile.override_env_vars);
 
     resolve_skill_dir_with_override(client, override_value.as_deref())
 }
@@ -21,7 +26,7 @@ pub fn resolve_skill_dir(client: ClientKind) -> SkillDirResolution {
 pub fn preferred_skill_dir(client: ClientKind) -> PathBuf {
     let profile = profile_for_client(client);
 
-    if let Some(override_value) = read_env_value(profile.override_env_vars) {
+    if let Some(override_value) = read_env_value(&profile.override_env_vars) {
         return expand_user_path(&override_value);
     }
 
@@ -60,7 +65,7 @@ pub fn resolve_skill_dir_with_override(
         };
     }
 
-    for fallback in profile.fallback_paths {
+    for fallback in &profile.fallback_paths {
         let expanded = expand_user_path(fallback);
         if is_readable_dir(&expanded) {
             return SkillDirResolution {
@@ -81,30 +86,8 @@ pub fn resolve_skill_dir_with_override(
     }
 }
 
-#[derive(Debug, Clone, Copy)]
-struct SkillPathProfile {
-    override_env_vars: &'static [&'static str],
-    fallback_paths: &'static [&'static str],
-}
-
-fn profile_for_client(client: ClientKind) -> SkillPathProfile {
-    match client {
-        ClientKind::ClaudeCode => SkillPathProfile {
-            override_env_vars: &["AI_MANAGER_CLAUDE_CODE_SKILLS_DIR"],
-            fallback_paths: &["~/.claude/skills"],
-        },
-        ClientKind::Codex => SkillPathProfile {
-            override_env_vars: &["AI_MANAGER_CODEX_SKILLS_DIR"],
-            fallback_paths: &["~/.codex/skills"],
-        },
-        ClientKind::Cursor => SkillPathProfile {
-            override_env_vars: &["AI_MANAGER_CURSOR_SKILLS_DIR"],
-            fallback_paths: &[
-                "~/.cursor/skills",
-                "~/Library/Application Support/Cursor/User/skills",
-            ],
-        },
-    }
+fn profile_for_client(client: ClientKind) -> PathCandidateProfile {
+    support_matrix_client(client).path_profile(PathCandidateKind::SkillsDir, HostOs::current())
 }
 
 fn read_env_value(names: &[&str]) -> Option<String> {
@@ -114,16 +97,6 @@ fn read_env_value(names: &[&str]) -> Option<String> {
         .filter(|value| !value.is_empty())
 }
 
-fn expand_user_path(value: &str) -> PathBuf {
-    if let Some(stripped) = value.strip_prefix("~/")
-        && let Some(home) = env::var_os("HOME")
-    {
-        return PathBuf::from(home).join(stripped);
-    }
-
-    PathBuf::from(value)
-}
-
 fn is_readable_dir(path: &Path) -> bool {
     path.is_dir() && std::fs::read_dir(path).is_ok()
 }
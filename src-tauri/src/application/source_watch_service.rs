@@ -0,0 +1,146 @@
+use crate::{
+    application::{
+        command::source_catalog_service::CommandSourceCatalogService,
+        instruction::source_catalog_service::InstructionSourceCatalogService,
+        mcp::source_catalog_service::McpSourceCatalogService,
+        setting::source_catalog_service::SettingSourceCatalogService,
+        skill::path_resolver::preferred_skill_dir,
+        subagent::source_catalog_service::SubagentSourceCatalogService,
+    },
+    domain::{ClientKind, profile_for_client},
+    infra::{DetectorRegistry, WatchTarget},
+};
+
+const WATCHED_CLIENTS: [ClientKind; 3] = [
+    ClientKind::ClaudeCode,
+    ClientKind::Codex,
+    ClientKind::Cursor,
+];
+
+pub struct SourceWatchService<'a> {
+    detector_registry: &'a DetectorRegistry,
+}
+
+impl<'a> SourceWatchService<'a> {
+    pub fn new(detector_registry: &'a DetectorRegistry) -> Self {
+        Self { detector_registry }
+    }
+
+    pub fn watch_targets(&self, project_root: Option<&str>) -> Vec<WatchTarget> {
+        let mcp_catalog = McpSourceCatalogService::new(self.detector_registry);
+        let subagent_catalog = SubagentSourceCatalogService::new();
+        let setting_catalog = SettingSourceCatalogService::new();
+        let command_catalog = CommandSourceCatalogService::new();
+        let instruction_catalog = InstructionSourceCatalogService::new();
+        let mut targets = Vec::new();
+
+        for client in WATCHED_CLIENTS {
+            targets.extend(
+                mcp_catalog
+                    .list_sources(client, project_root)
+                    .into_iter()
+                    .map(|descriptor| WatchTarget {
+                        source_id: descriptor.source_id,
+                        path: descriptor.container_path,
+                        recursive: false,
+                    }),
+            );
+
+            if profile_for_client(client).capabilities.supports_skills {
+                let skill_root = preferred_skill_dir(client);
+                targets.push(WatchTarget {
+                    source_id: format!("skill::user::{}", skill_root.display()),
+                    path: skill_root,
+                    recursive: true,
+                });
+            }
+
+            targets.extend(
+                subagent_catalog
+                    .list_sources(client, project_root)
+                    .into_iter()
+                    .map(|descriptor| WatchTarget {
+                        source_id: descriptor.source_id,
+                        path: descriptor.directory_path,
+                        recursive: true,
+                    }),
+            );
+
+            targets.extend(
+                setting_catalog
+                    .list_sources(client, project_root)
+                    .into_iter()
+                    .map(|descriptor| WatchTarget {
+                        source_id: descriptor.source_id,
+                        path: descriptor.settings_path,
+                        recursive: false,
+                    }),
+            );
+
+            targets.extend(
+                command_catalog
+                    .list_sources(client, project_root)
+                    .into_iter()
+                    .map(|descriptor| WatchTarget {
+                        source_id: descriptor.source_id,
+                        path: descriptor.directory_path,
+                        recursive: false,
+                    }),
+            );
+
+            targets.extend(
+                instruction_catalog
+                    .list_sources(client, project_root)
+                    .into_iter()
+                    .map(|descriptor| WatchTarget {
+                        source_id: descriptor.source_id,
+                        path: descriptor.location.path().to_path_buf(),
+                        recursive: false,
+                    }),
+            );
+        }
+
+        targets
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SourceWatchService;
+    use crate::infra::DetectorRegistry;
+
+    #[test]
+    fn project_root_adds_project_scoped_mcp_and_subagent_targets() {
+        let registry = DetectorRegistry::with_default_detectors();
+        let service = SourceWatchService::new(&registry);
+
+        let user_only = service.watch_targets(None);
+        let with_project = service.watch_targets(Some("/tmp/ai-manager-watch-project"));
+
+        assert!(with_project.len() > user_only.len());
+        assert!(with_project.iter().any(|target| {
+            target.path.ends_with(".mcp.json") && target.source_id.contains("project_shared")
+        }));
+        assert!(
+            with_project
+                .iter()
+                .any(|target| target.recursive && target.path.ends_with(".claude/agents"))
+        );
+        assert!(with_project.iter().any(|target| {
+            target.path.ends_with(".claude/settings.local.json")
+                && target
+                    .source_id
+                    .starts_with("setting::claude_code::project_private::")
+        }));
+        assert!(
+            with_project
+                .iter()
+                .any(|target| target.path.ends_with(".cursor/rules"))
+        );
+        assert!(
+            user_only
+                .iter()
+                .any(|target| target.source_id.starts_with("skill::user::"))
+        );
+    }
+}
@@ -178,6 +178,7 @@ fn collect_subagents_from_directory(
                 description: metadata.description,
                 install_kind: Some("file".to_string()),
                 manifest_content: Some(manifest_source),
+                approval: None,
             }
             .with_source_metadata(ResourceSourceMetadata {
                 source_id: descriptor.source_id.clone(),
@@ -199,7 +200,7 @@ fn is_markdown_file(path: &Path) -> bool {
             .is_some_and(|extension| extension.eq_ignore_ascii_case("md"))
 }
 
-fn apply_effective_precedence(items: &mut [ResourceRecord]) {
+pub fn apply_effective_precedence(items: &mut [ResourceRecord]) {
     let mut indices_by_resource: HashMap<(String, String), Vec<usize>> = HashMap::new();
 
     for (index, item) in items.iter().enumerate() {
@@ -21,7 +21,7 @@ pub fn parse_subagent_metadata(source: &str) -> SubagentMetadata {
     }
 }
 
-fn split_frontmatter(source: &str) -> (Option<String>, String) {
+pub fn split_frontmatter(source: &str) -> (Option<String>, String) {
     let mut lines = source.lines();
     let Some(first_line) = lines.next() else {
         return (None, source.to_string());
@@ -55,7 +55,27 @@ fn split_frontmatter(source: &str) -> (Option<String>, String) {
     (Some(frontmatter_lines.join("\n")), body_lines.join("\n"))
 }
 
-fn parse_frontmatter_scalar(frontmatter: &str, key: &str) -> Option<String> {
+/// Splits off a leading `---` frontmatter block, keeping both delimiters in the first part.
+pub fn split_frontmatter_block(source: &str) -> (Option<&str>, &str) {
+    let Some(rest) = source
+        .strip_prefix("---\n")
+        .or_else(|| source.strip_prefix("---\r\n"))
+    else {
+        return (None, source);
+    };
+
+    let mut offset = source.len() - rest.len();
+    for line in rest.split_inclusive('\n') {
+        offset += line.len();
+        if line.trim() == "---" {
+            return (Some(&source[..offset]), &source[offset..]);
+        }
+    }
+
+    (None, source)
+}
+
+pub fn parse_frontmatter_scalar(frontmatter: &str, key: &str) -> Option<String> {
     let prefix = format!("{key}:");
 
     frontmatter.lines().find_map(|line| {
@@ -1,6 +1,9 @@
-use std::{env, path::PathBuf};
+use std::path::PathBuf;
 
-use crate::domain::{ClientKind, ResourceSourceScope};
+use crate::{
+    domain::{ClientKind, ResourceSourceScope},
+    infra::expand_user_path,
+};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SubagentSourceDescriptor {
@@ -83,16 +86,6 @@ fn descriptor_for_scope(
     }
 }
 
-fn expand_user_path(value: &str) -> PathBuf {
-    if let Some(stripped) = value.strip_prefix("~/")
-        && let Some(home) = env::var_os("HOME")
-    {
-        return PathBuf::from(home).join(stripped);
-    }
-
-    PathBuf::from(value)
-}
-
 #[cfg(test)]
 mod tests {
     use std::path::PathBuf;
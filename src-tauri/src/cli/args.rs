@@ -0,0 +1,186 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+const SWITCHES: [&str; 5] = ["json", "include-versions", "overwrite", "dry-run", "help"];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UsageError(pub String);
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ParsedArgs {
+    pub command: Option<String>,
+    pub positionals: Vec<String>,
+    options: BTreeMap<String, String>,
+    switches: BTreeSet<String>,
+}
+
+impl ParsedArgs {
+    pub fn parse(args: &[String]) -> Result<Self, UsageError> {
+        let mut parsed = Self::default();
+        let mut iter = args.iter();
+
+        while let Some(arg) = iter.next() {
+            let Some(flag) = arg.strip_prefix("--") else {
+                if parsed.command.is_none() {
+                    parsed.command = Some(arg.clone());
+                } else {
+                    parsed.positionals.push(arg.clone());
+                }
+                continue;
+            };
+
+            if let Some((name, value)) = flag.split_once('=') {
+                parsed.insert_option(name, value.to_string())?;
+            } else if SWITCHES.contains(&flag) {
+                parsed.switches.insert(flag.to_string());
+            } else {
+                let value = iter
+                    .next()
+                    .ok_or_else(|| UsageError(format!("option '--{flag}' requires a value")))?;
+                parsed.insert_option(flag, value.clone())?;
+            }
+        }
+
+        Ok(parsed)
+    }
+
+    fn insert_option(&mut self, name: &str, value: String) -> Result<(), UsageError> {
+        if self.options.insert(name.to_string(), value).is_some() {
+            return Err(UsageError(format!(
+                "option '--{name}' was given more than once"
+            )));
+        }
+        Ok(())
+    }
+
+    pub fn switch(&self, name: &str) -> bool {
+        self.switches.contains(name)
+    }
+
+    pub fn optional(&self, name: &str) -> Option<String> {
+        self.options.get(name).cloned()
+    }
+
+    pub fn required(&self, name: &str) -> Result<String, UsageError> {
+        self.optional(name)
+            .ok_or_else(|| UsageError(format!("missing required option '--{name}'")))
+    }
+
+    pub fn optional_enum<T: DeserializeOwned>(&self, name: &str) -> Result<Option<T>, UsageError> {
+        self.options
+            .get(name)
+            .map(|value| parse_enum(name, value))
+            .transpose()
+    }
+
+    pub fn required_enum<T: DeserializeOwned>(&self, name: &str) -> Result<T, UsageError> {
+        parse_enum(name, &self.required(name)?)
+    }
+
+    pub fn optional_enum_list<T: DeserializeOwned>(
+        &self,
+        name: &str,
+    ) -> Result<Option<Vec<T>>, UsageError> {
+        self.options
+            .get(name)
+            .map(|value| {
+                value
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|item| !item.is_empty())
+                    .map(|item| parse_enum(name, item))
+                    .collect()
+            })
+            .transpose()
+    }
+
+    pub fn optional_bool(&self, name: &str) -> Result<Option<bool>, UsageError> {
+        self.options
+            .get(name)
+            .map(|value| match value.as_str() {
+                "true" => Ok(true),
+                "false" => Ok(false),
+                _ => Err(UsageError(format!(
+                    "option '--{name}' expects 'true' or 'false', got '{value}'"
+                ))),
+            })
+            .transpose()
+    }
+
+    pub fn ensure_known_options(&self, allowed: &[&str]) -> Result<(), UsageError> {
+        match self
+            .options
+            .keys()
+            .chain(self.switches.iter())
+            .find(|name| name.as_str() != "json" && !allowed.contains(&name.as_str()))
+        {
+            Some(name) => Err(UsageError(format!("unknown option '--{name}'"))),
+            None => Ok(()),
+        }
+    }
+}
+
+fn parse_enum<T: DeserializeOwned>(name: &str, value: &str) -> Result<T, UsageError> {
+    serde_json::from_value(Value::String(value.replace('-', "_")))
+        .map_err(|_| UsageError(format!("invalid value '{value}' for option '--{name}'")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ParsedArgs, UsageError};
+    use crate::domain::{ClientKind, ResourceSourceScope};
+
+    fn args(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    #[test]
+    fn parses_command_options_and_switches_in_any_order() {
+        let parsed = ParsedArgs::parse(&args(&[
+            "--json",
+            "list",
+            "--kind=mcp",
+            "--client",
+            "claude-code",
+            "--scope",
+            "user,project_shared",
+        ]))
+        .expect("args should parse");
+
+        assert_eq!(parsed.command.as_deref(), Some("list"));
+        assert!(parsed.switch("json"));
+        assert_eq!(parsed.optional("kind").as_deref(), Some("mcp"));
+        assert_eq!(
+            parsed.optional_enum::<ClientKind>("client"),
+            Ok(Some(ClientKind::ClaudeCode))
+        );
+        assert_eq!(
+            parsed.optional_enum_list::<ResourceSourceScope>("scope"),
+            Ok(Some(vec![
+                ResourceSourceScope::User,
+                ResourceSourceScope::ProjectShared
+            ]))
+        );
+    }
+
+    #[test]
+    fn reports_missing_values_and_unknown_options() {
+        assert_eq!(
+            ParsedArgs::parse(&args(&["list", "--kind"])),
+            Err(UsageError("option '--kind' requires a value".to_string()))
+        );
+
+        let parsed =
+            ParsedArgs::parse(&args(&["detect", "--verbose", "yes"])).expect("args should parse");
+        assert_eq!(
+            parsed.ensure_known_options(&["include-versions"]),
+            Err(UsageError("unknown option '--verbose'".to_string()))
+        );
+        assert_eq!(
+            parsed.required_enum::<ClientKind>("client"),
+            Err(UsageError("missing required option '--client'".to_string()))
+        );
+    }
+}
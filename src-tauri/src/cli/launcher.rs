@@ -0,0 +1,169 @@
+use std::{collections::BTreeMap, process::Command};
+
+use super::{CliOutput, EXIT_COMMAND_FAILED, EXIT_USAGE};
+use crate::infra::security::secret_store::{EncryptedFileSecretStore, SecretStore};
+
+const LAUNCHER_USAGE: &str =
+    "Usage: ai-manager-cli run-with-secrets [--all] [VAR=SECRET ...] -- <command> [args...]";
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct Bindings {
+    all: bool,
+    variables: Vec<(String, String)>,
+}
+
+/// Starts `<command>` with secrets from the store set as environment variables and exits
+/// with its status. Stdio is inherited so stdio MCP servers work unchanged.
+pub fn run(args: &[String]) -> CliOutput {
+    let (bindings, command) = match parse(args) {
+        Ok(parsed) => parsed,
+        Err(message) => return failure(EXIT_USAGE, format!("{message}\n\n{LAUNCHER_USAGE}")),
+    };
+    let Some(store) = EncryptedFileSecretStore::default_location() else {
+        return failure(
+            EXIT_COMMAND_FAILED,
+            "the secret store location could not be resolved; set AI_MANAGER_DATA_DIR or HOME"
+                .to_string(),
+        );
+    };
+    let secrets = match store.load() {
+        Ok(secrets) => secrets,
+        Err(error) => {
+            return failure(
+                EXIT_COMMAND_FAILED,
+                format!(
+                    "failed to read the secret store '{}': {error}",
+                    store.location()
+                ),
+            );
+        }
+    };
+    let environment = match resolve(&bindings, &secrets) {
+        Ok(environment) => environment,
+        Err(message) => return failure(EXIT_COMMAND_FAILED, message),
+    };
+
+    let (program, program_args) = command
+        .split_first()
+        .expect("parse rejects an empty command");
+    match Command::new(program)
+        .args(program_args)
+        .envs(environment)
+        .status()
+    {
+        Ok(status) => CliOutput {
+            exit_code: status.code().unwrap_or(EXIT_COMMAND_FAILED),
+            stdout: String::new(),
+            stderr: String::new(),
+        },
+        Err(error) => failure(
+            EXIT_COMMAND_FAILED,
+            format!("failed to start '{program}': {error}"),
+        ),
+    }
+}
+
+fn parse(args: &[String]) -> Result<(Bindings, Vec<String>), String> {
+    let Some(separator) = args.iter().position(|arg| arg == "--") else {
+        return Err("missing '--' before the command".to_string());
+    };
+
+    let mut bindings = Bindings::default();
+    for arg in &args[..separator] {
+        if arg == "--all" {
+            bindings.all = true;
+            continue;
+        }
+        let Some((variable, secret)) = arg
+            .split_once('=')
+            .filter(|(variable, secret)| !variable.is_empty() && !secret.is_empty())
+        else {
+            return Err(format!("expected VAR=SECRET, got '{arg}'"));
+        };
+        bindings
+            .variables
+            .push((variable.to_string(), secret.to_string()));
+    }
+
+    let command = args[separator + 1..].to_vec();
+    if command.is_empty() {
+        return Err("missing command after '--'".to_string());
+    }
+    Ok((bindings, command))
+}
+
+fn resolve(
+    bindings: &Bindings,
+    secrets: &BTreeMap<String, String>,
+) -> Result<BTreeMap<String, String>, String> {
+    let mut environment = if bindings.all {
+        secrets.clone()
+    } else {
+        BTreeMap::new()
+    };
+    for (variable, secret) in &bindings.variables {
+        let value = secrets
+            .get(secret)
+            .ok_or_else(|| format!("secret '{secret}' is not in the secret store"))?;
+        environment.insert(variable.clone(), value.clone());
+    }
+    Ok(environment)
+}
+
+fn failure(exit_code: i32, message: String) -> CliOutput {
+    CliOutput {
+        exit_code,
+        stdout: String::new(),
+        stderr: format!("error: {message}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::{parse, resolve};
+
+    fn args(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    #[test]
+    fn bindings_resolve_from_the_store_and_the_command_is_passed_through() {
+        let secrets = BTreeMap::from([
+            (
+                "AI_MANAGER_SECRET_GH_TOKEN".to_string(),
+                "ghp_x".to_string(),
+            ),
+            ("AI_MANAGER_SECRET_OTHER".to_string(), "other".to_string()),
+        ]);
+        let (bindings, command) = parse(&args(&[
+            "GITHUB_TOKEN=AI_MANAGER_SECRET_GH_TOKEN",
+            "--",
+            "npx",
+            "--",
+            "-y",
+        ]))
+        .expect("arguments should parse");
+        let (all, _) = parse(&args(&["--all", "--", "claude"])).expect("--all should parse");
+
+        assert_eq!(command, args(&["npx", "--", "-y"]));
+        assert_eq!(
+            resolve(&bindings, &secrets),
+            Ok(BTreeMap::from([(
+                "GITHUB_TOKEN".to_string(),
+                "ghp_x".to_string()
+            )]))
+        );
+        assert_eq!(resolve(&all, &secrets), Ok(secrets.clone()));
+        assert!(parse(&args(&["TOKEN", "--", "npx"])).is_err());
+        assert!(parse(&args(&["A=B", "--"])).is_err());
+        assert!(
+            resolve(
+                &parse(&args(&["A=MISSING", "--", "npx"])).expect("parses").0,
+                &secrets
+            )
+            .is_err()
+        );
+    }
+}
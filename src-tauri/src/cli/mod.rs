@@ -0,0 +1,431 @@
+mod args;
+mod launcher;
+mod output;
+mod serve;
+
+use std::{fs, io::Write, sync::Arc};
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::application::SECRET_LAUNCHER_SUBCOMMAND;
+use crate::interface::{
+    contracts::{
+        command::CommandEnvelope, detect::DetectClientsRequest, list::ListResourcesRequest,
+        mutate::MutateResourceRequest, replicate::ReplicateResourceRequest,
+        secrets::ExternalizeSecretsRequest, security_audit::SecurityAuditRequest,
+        skill_discovery::DiscoverSkillRepositoryRequest, undo::UndoOperationRequest,
+    },
+    mcp_server::{MCP_SERVER_SUBCOMMAND, McpServer, self_registration_request},
+    operations,
+    state::AppState,
+};
+use args::{ParsedArgs, UsageError};
+
+pub const EXIT_SUCCESS: i32 = 0;
+pub const EXIT_COMMAND_FAILED: i32 = 1;
+pub const EXIT_USAGE: i32 = 2;
+
+const USAGE: &str = "\
+Usage: ai-manager-cli [--json] <command> [options]
+
+Commands:
+  detect            [--include-versions]
+  list              --kind <mcp|skill|subagent|setting|command|instruction> [--client <client>]
+                    [--enabled <true|false>] [--project-root <path>] [--view <effective|all_sources>]
+                    [--scope <user,project_shared,project_private>]
+  mutate            --client <client> --kind <kind> --action <add|remove|update|approve|deny> --target-id <id>
+                    [--project-root <path>] [--source-id <id>] [--expected-revision <rev>]
+                    [--payload <json> | --payload-file <path>]
+  replicate         --kind <kind> --from-client <client> --target-id <id> --from-source <id>
+                    --to-client <client> [--from-project-root <path>] [--to-target-id <id>]
+                    [--to-source <id>] [--to-project-root <path>] [--overwrite]
+  discover-skills   <github-repo-url>
+  externalize-secrets --client <client> [--project-root <path>] [--source-id <id>] [--dry-run]
+                    Moves inline MCP secrets into the encrypted secret store.
+  security-audit    [--client <client>] [--project-root <path>]
+                    Scans MCP configs, skills and subagents for likely secrets.
+  undo              <entry-id>
+                    Reverts a mutate, replicate or undo recorded in the operation log.
+  run-with-secrets  [--all] [VAR=SECRET ...] -- <command> [args...]
+                    Runs a command with secrets from the store set as environment variables.
+  serve             [--port <port> | --socket <path>]
+                    Runs the local JSON-RPC control API until interrupted.
+  mcp-server        Serves detect/list/mutate/replicate as MCP tools over stdio.
+  register-mcp      --client <client> [--project-root <path>] [--source-id <id>]
+                    Adds this binary as the 'ai-manager' MCP server in the client config.
+
+Clients: claude_code, codex, cursor
+
+Output is a table by default; --json prints the full command envelope.
+Exit codes: 0 success, 1 command failed, 2 usage error.";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CliOutput {
+    pub exit_code: i32,
+    pub stdout: String,
+    pub stderr: String,
+}
+
+pub fn run_cli(args: Vec<String>) -> i32 {
+    // The launched command owns stdio, so the launcher bypasses the regular parser.
+    if args.first().map(String::as_str) == Some(SECRET_LAUNCHER_SUBCOMMAND) {
+        return emit(launcher::run(&args[1..]));
+    }
+
+    let state = Arc::new(AppState::new());
+    let output = match ParsedArgs::parse(&args) {
+        Ok(parsed) if parsed.command.as_deref() == Some("serve") && !parsed.switch("help") => {
+            serve::run(Arc::clone(&state), &parsed).unwrap_or_else(usage_failure)
+        }
+        _ => execute(&state, &args),
+    };
+    state.mark_shutdown_requested();
+
+    emit(output)
+}
+
+fn emit(output: CliOutput) -> i32 {
+    if !output.stdout.is_empty() {
+        let _ = writeln!(std::io::stdout(), "{}", output.stdout);
+    }
+    if !output.stderr.is_empty() {
+        let _ = writeln!(std::io::stderr(), "{}", output.stderr);
+    }
+    output.exit_code
+}
+
+pub fn execute(state: &AppState, args: &[String]) -> CliOutput {
+    let parsed = match ParsedArgs::parse(args) {
+        Ok(parsed) => parsed,
+        Err(error) => return usage_failure(error),
+    };
+
+    if parsed.switch("help") || parsed.command.as_deref() == Some("help") {
+        return CliOutput {
+            exit_code: EXIT_SUCCESS,
+            stdout: USAGE.to_string(),
+            stderr: String::new(),
+        };
+    }
+
+    match dispatch(state, &parsed) {
+        Ok(output) => output,
+        Err(error) => usage_failure(error),
+    }
+}
+
+fn dispatch(state: &AppState, parsed: &ParsedArgs) -> Result<CliOutput, UsageError> {
+    let json = parsed.switch("json");
+
+    match parsed.command.as_deref() {
+        Some("detect") => {
+            parsed.ensure_known_options(&["include-versions"])?;
+            let request = DetectClientsRequest {
+                include_versions: parsed.switch("include-versions"),
+            };
+            Ok(render(
+                operations::detect_clients(state, request),
+                json,
+                output::render_detect,
+            ))
+        }
+        Some("list") => {
+            parsed.ensure_known_options(&[
+                "kind",
+                "client",
+                "enabled",
+                "project-root",
+                "view",
+                "scope",
+            ])?;
+            let request = ListResourcesRequest {
+                client: parsed.optional_enum("client")?,
+                resource_kind: parsed.required_enum("kind")?,
+                enabled: parsed.optional_bool("enabled")?,
+                project_root: parsed.optional("project-root"),
+                view_mode: parsed.optional_enum("view")?.unwrap_or_default(),
+                scope_filter: parsed.optional_enum_list("scope")?,
+            };
+            Ok(render(
+                operations::list_resources(state, request),
+                json,
+                output::render_list,
+            ))
+        }
+        Some("mutate") => {
+            parsed.ensure_known_options(&[
+                "client",
+                "kind",
+                "action",
+                "target-id",
+                "project-root",
+                "source-id",
+                "expected-revision",
+                "payload",
+                "payload-file",
+            ])?;
+            let request = MutateResourceRequest {
+                client: parsed.required_enum("client")?,
+                resource_kind: parsed.required_enum("kind")?,
+                action: parsed.required_enum("action")?,
+                target_id: parsed.required("target-id")?,
+                project_root: parsed.optional("project-root"),
+                target_source_id: parsed.optional("source-id"),
+                payload: read_payload(parsed)?,
+                expected_revision: parsed.optional("expected-revision"),
+            };
+            Ok(render(
+                operations::mutate_resource(state, request),
+                json,
+                output::render_mutation,
+            ))
+        }
+        Some("replicate") => {
+            parsed.ensure_known_options(&[
+                "kind",
+                "from-client",
+                "target-id",
+                "from-source",
+                "from-project-root",
+                "to-client",
+                "to-target-id",
+                "to-source",
+                "to-project-root",
+                "overwrite",
+            ])?;
+            let request = ReplicateResourceRequest {
+                resource_kind: parsed.required_enum("kind")?,
+                source_client: parsed.required_enum("from-client")?,
+                source_target_id: parsed.required("target-id")?,
+                source_source_id: parsed.required("from-source")?,
+                source_project_root: parsed.optional("from-project-root"),
+                destination_client: parsed.required_enum("to-client")?,
+                destination_target_id: parsed.optional("to-target-id"),
+                destination_source_id: parsed.optional("to-source"),
+                destination_project_root: parsed.optional("to-project-root"),
+                overwrite: parsed.switch("overwrite"),
+            };
+            Ok(render(
+                operations::replicate_resource(state, request),
+                json,
+                output::render_replication,
+            ))
+        }
+        Some("discover-skills") => {
+            parsed.ensure_known_options(&[])?;
+            let [github_repo_url] = parsed.positionals.as_slice() else {
+                return Err(UsageError(
+                    "discover-skills expects exactly one repository url".to_string(),
+                ));
+            };
+            let request = DiscoverSkillRepositoryRequest {
+                github_repo_url: github_repo_url.clone(),
+            };
+            Ok(render(
+                operations::discover_skill_repository(state, request),
+                json,
+                output::render_skill_discovery,
+            ))
+        }
+        Some("externalize-secrets") => {
+            parsed.ensure_known_options(&["client", "project-root", "source-id", "dry-run"])?;
+            let request = ExternalizeSecretsRequest {
+                client: parsed.required_enum("client")?,
+                project_root: parsed.optional("project-root"),
+                target_source_id: parsed.optional("source-id"),
+                dry_run: parsed.switch("dry-run"),
+            };
+            Ok(render(
+                operations::externalize_secrets(state, request),
+                json,
+                output::render_secret_externalization,
+            ))
+        }
+        Some("security-audit") => {
+            parsed.ensure_known_options(&["client", "project-root"])?;
+            let request = SecurityAuditRequest {
+                client: parsed.optional_enum("client")?,
+                project_root: parsed.optional("project-root"),
+            };
+            Ok(render(
+                operations::security_audit(state, request),
+                json,
+                output::render_security_audit,
+            ))
+        }
+        Some("undo") => {
+            parsed.ensure_known_options(&[])?;
+            let [entry_id] = parsed.positionals.as_slice() else {
+                return Err(UsageError(
+                    "undo expects exactly one operation log entry id".to_string(),
+                ));
+            };
+            let request = UndoOperationRequest {
+                entry_id: entry_id.clone(),
+            };
+            Ok(render(
+                operations::undo_operation(state, request),
+                json,
+                output::render_undo,
+            ))
+        }
+        Some(MCP_SERVER_SUBCOMMAND) => {
+            parsed.ensure_known_options(&[])?;
+            let served = McpServer::new(state).serve(std::io::stdin().lock(), std::io::stdout());
+            Ok(CliOutput {
+                exit_code: if served.is_ok() {
+                    EXIT_SUCCESS
+                } else {
+                    EXIT_COMMAND_FAILED
+                },
+                stdout: String::new(),
+                stderr: served
+                    .err()
+                    .map(|error| format!("error: MCP server stopped: {error}"))
+                    .unwrap_or_default(),
+            })
+        }
+        Some("register-mcp") => {
+            parsed.ensure_known_options(&["client", "project-root", "source-id"])?;
+            let executable = std::env::current_exe().map_err(|error| {
+                UsageError(format!(
+                    "failed to resolve the ai-manager-cli path: {error}"
+                ))
+            })?;
+            let request = self_registration_request(
+                parsed.required_enum("client")?,
+                &executable.display().to_string(),
+                parsed.optional("project-root"),
+                parsed.optional("source-id"),
+            );
+            Ok(render(
+                operations::mutate_resource(state, request),
+                json,
+                output::render_mutation,
+            ))
+        }
+        Some(command) => Err(UsageError(format!("unknown command '{command}'"))),
+        None => Err(UsageError("missing command".to_string())),
+    }
+}
+
+fn read_payload(parsed: &ParsedArgs) -> Result<Option<Value>, UsageError> {
+    let raw = match (parsed.optional("payload"), parsed.optional("payload-file")) {
+        (Some(_), Some(_)) => {
+            return Err(UsageError(
+                "use either '--payload' or '--payload-file', not both".to_string(),
+            ));
+        }
+        (Some(raw), None) => raw,
+        (None, Some(path)) => fs::read_to_string(&path).map_err(|error| {
+            UsageError(format!("failed to read payload file '{path}': {error}"))
+        })?,
+        (None, None) => return Ok(None),
+    };
+
+    serde_json::from_str(&raw)
+        .map(Some)
+        .map_err(|error| UsageError(format!("payload is not valid JSON: {error}")))
+}
+
+fn render<T: Serialize>(
+    envelope: CommandEnvelope<T>,
+    json: bool,
+    render_data: fn(&T) -> String,
+) -> CliOutput {
+    let exit_code = if envelope.ok {
+        EXIT_SUCCESS
+    } else {
+        EXIT_COMMAND_FAILED
+    };
+
+    if json {
+        return CliOutput {
+            exit_code,
+            stdout: serde_json::to_string_pretty(&envelope)
+                .unwrap_or_else(|error| format!("{{\"ok\":false,\"error\":\"{error}\"}}")),
+            stderr: String::new(),
+        };
+    }
+
+    CliOutput {
+        exit_code,
+        stdout: envelope.data.as_ref().map(render_data).unwrap_or_default(),
+        stderr: envelope
+            .error
+            .as_ref()
+            .map(output::render_error)
+            .unwrap_or_default(),
+    }
+}
+
+fn usage_failure(error: UsageError) -> CliOutput {
+    CliOutput {
+        exit_code: EXIT_USAGE,
+        stdout: String::new(),
+        stderr: format!("error: {}\n\n{USAGE}", error.0),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::Value;
+
+    use super::{EXIT_COMMAND_FAILED, EXIT_SUCCESS, EXIT_USAGE, execute};
+    use crate::interface::state::AppState;
+
+    fn run(values: &[&str]) -> super::CliOutput {
+        let args: Vec<String> = values.iter().map(|value| value.to_string()).collect();
+        execute(&AppState::new(), &args)
+    }
+
+    #[test]
+    fn detect_json_prints_command_envelope() {
+        let output = run(&["detect", "--json"]);
+
+        assert_eq!(output.exit_code, EXIT_SUCCESS);
+        let envelope: Value = serde_json::from_str(&output.stdout).expect("stdout should be JSON");
+        assert_eq!(envelope["ok"], Value::Bool(true));
+        assert_eq!(envelope["meta"]["operation_id"], "detect-1");
+        assert_eq!(
+            envelope["data"]["clients"].as_array().map(Vec::len),
+            Some(3)
+        );
+    }
+
+    #[test]
+    fn usage_errors_exit_with_usage_code() {
+        for args in [
+            &["list"][..],
+            &["list", "--kind", "plugin"][..],
+            &["frobnicate"][..],
+            &[][..],
+        ] {
+            let output = run(args);
+            assert_eq!(output.exit_code, EXIT_USAGE, "args: {args:?}");
+            assert!(output.stderr.starts_with("error: "));
+        }
+    }
+
+    #[test]
+    fn command_failures_exit_with_failure_code_and_report_error() {
+        let output = run(&[
+            "mutate",
+            "--client",
+            "cursor",
+            "--kind",
+            "mcp",
+            "--action",
+            "add",
+            "--target-id",
+            "",
+            "--payload",
+            "{}",
+        ]);
+
+        assert_eq!(output.exit_code, EXIT_COMMAND_FAILED);
+        assert!(output.stdout.is_empty());
+        assert!(output.stderr.contains("VALIDATION_ERROR"));
+    }
+}
@@ -0,0 +1,309 @@
+use crate::interface::contracts::{
+    command::CommandError, detect::DetectClientsResponse, list::ListResourcesResponse,
+    mutate::MutateResourceResponse, replicate::ReplicateResourceResponse,
+    secrets::ExternalizeSecretsResponse, security_audit::SecurityAuditResponse,
+    skill_discovery::DiscoverSkillRepositoryResponse, undo::UndoOperationResponse,
+};
+
+const EMPTY_CELL: &str = "-";
+
+pub fn render_table(headers: &[&str], rows: &[Vec<String>]) -> String {
+    let mut widths: Vec<usize> = headers
+        .iter()
+        .map(|header| header.chars().count())
+        .collect();
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let header_row: Vec<String> = headers.iter().map(|header| header.to_uppercase()).collect();
+    std::iter::once(&header_row)
+        .chain(rows)
+        .map(|row| {
+            row.iter()
+                .zip(&widths)
+                .map(|(cell, width)| format!("{cell:<width$}"))
+                .collect::<Vec<_>>()
+                .join("  ")
+                .trim_end()
+                .to_string()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub fn render_detect(response: &DetectClientsResponse) -> String {
+    let rows: Vec<Vec<String>> = response
+        .clients
+        .iter()
+        .map(|detection| {
+            vec![
+                detection.client.as_str().to_string(),
+                serde_label(&detection.status),
+                format!("{}%", detection.confidence),
+                cell(detection.evidence.version.as_deref()),
+                cell(
+                    detection
+                        .evidence
+                        .binary_path
+                        .as_deref()
+                        .or(detection.evidence.app_path.as_deref()),
+                ),
+                cell(detection.evidence.config_path.as_deref()),
+            ]
+        })
+        .collect();
+
+    render_table(
+        &[
+            "client",
+            "status",
+            "confidence",
+            "version",
+            "install",
+            "config",
+        ],
+        &rows,
+    )
+}
+
+pub fn render_list(response: &ListResourcesResponse) -> String {
+    let rows: Vec<Vec<String>> = response
+        .items
+        .iter()
+        .map(|record| {
+            vec![
+                record.client.as_str().to_string(),
+                record.display_name.clone(),
+                if record.enabled { "yes" } else { "no" }.to_string(),
+                record.source_scope.as_str().to_string(),
+                if record.is_effective {
+                    "effective".to_string()
+                } else {
+                    format!("shadowed by {}", cell(record.shadowed_by.as_deref()))
+                },
+                cell(record.source_path.as_deref()),
+            ]
+        })
+        .collect();
+
+    let mut output = render_table(
+        &["client", "name", "enabled", "scope", "status", "source"],
+        &rows,
+    );
+    if let Some(warning) = &response.warning {
+        output.push_str(&format!("\nwarning: {warning}"));
+    }
+    for diagnostic in &response.diagnostics {
+        output.push_str(&format!(
+            "\n{}: {}",
+            serde_label(&diagnostic.severity),
+            diagnostic.summary()
+        ));
+    }
+    output
+}
+
+pub fn render_mutation(response: &MutateResourceResponse) -> String {
+    let mut output = format!(
+        "{} '{}': {}",
+        response.action.as_str(),
+        response.target_id,
+        response.message
+    );
+    if let Some(source_path) = &response.source_path {
+        output.push_str(&format!("\nsource: {source_path}"));
+    }
+    if let Some(warning) = &response.warning {
+        output.push_str(&format!("\nwarning: {warning}"));
+    }
+    output
+}
+
+pub fn render_replication(response: &ReplicateResourceResponse) -> String {
+    format!(
+        "{} '{}' -> {} '{}': {}\ndestination source: {}",
+        response.source_client.as_str(),
+        response.source_target_id,
+        response.destination_client.as_str(),
+        response.destination_target_id,
+        response.message,
+        response.destination_source_id
+    )
+}
+
+pub fn render_skill_discovery(response: &DiscoverSkillRepositoryResponse) -> String {
+    let rows: Vec<Vec<String>> = response
+        .items
+        .iter()
+        .map(|candidate| {
+            vec![
+                candidate.suggested_target_id.clone(),
+                candidate.manifest_path.clone(),
+                candidate.summary.clone(),
+            ]
+        })
+        .collect();
+
+    format!(
+        "repository: {}\n{}\nwarning: {}",
+        response.normalized_repo_url,
+        render_table(&["target id", "manifest", "summary"], &rows),
+        response.warning
+    )
+}
+
+pub fn render_secret_externalization(response: &ExternalizeSecretsResponse) -> String {
+    let rows: Vec<Vec<String>> = response
+        .secrets
+        .iter()
+        .map(|secret| {
+            vec![
+                secret.server_name.clone(),
+                secret.field.clone(),
+                secret.secret_name.clone(),
+                serde_label(&secret.reference),
+                secret.source_path.clone(),
+            ]
+        })
+        .collect();
+
+    format!(
+        "{}
+{}
+store: {}",
+        render_table(&["server", "field", "secret", "reference", "source"], &rows),
+        response.message,
+        response.store_location
+    )
+}
+
+pub fn render_security_audit(response: &SecurityAuditResponse) -> String {
+    let rows: Vec<Vec<String>> = response
+        .findings
+        .iter()
+        .map(|finding| {
+            vec![
+                serde_label(&finding.severity),
+                finding.rule.clone(),
+                format!("{}:{}", finding.file_path, finding.line),
+                finding.client.as_str().to_string(),
+                finding.excerpt.clone(),
+            ]
+        })
+        .collect();
+
+    let mut output = format!(
+        "{}\n{}",
+        render_table(
+            &["severity", "rule", "location", "client", "excerpt"],
+            &rows
+        ),
+        response.message
+    );
+    for path in &response.high_risk_files {
+        output.push_str(&format!("\ncommitted to git: {path}"));
+    }
+    for warning in &response.warnings {
+        output.push_str(&format!("\nwarning: {warning}"));
+    }
+    output
+}
+
+pub fn render_undo(response: &UndoOperationResponse) -> String {
+    let mut output = response.message.clone();
+    for path in &response.restored_paths {
+        output.push_str(&format!("\nrestored: {path}"));
+    }
+    if let Some(undo_entry_id) = &response.undo_entry_id {
+        output.push_str(&format!("\nundo entry: {undo_entry_id}"));
+    }
+    output
+}
+
+pub fn render_error(error: &CommandError) -> String {
+    let mut output = format!(
+        "error: [{}] {}: {}",
+        error.canonical_code,
+        serde_label(&error.code),
+        error.message
+    );
+    if let Some(path) = &error.context.path {
+        output.push_str(&format!("\npath: {path}"));
+    }
+    if !error.suggested_actions.is_empty() {
+        output.push_str(&format!(
+            "\nsuggested actions: {}",
+            error.suggested_actions.join(", ")
+        ));
+    }
+    output
+}
+
+fn cell(value: Option<&str>) -> String {
+    value.unwrap_or(EMPTY_CELL).to_string()
+}
+
+fn serde_label(value: &impl serde::Serialize) -> String {
+    match serde_json::to_value(value) {
+        Ok(serde_json::Value::String(label)) => label,
+        _ => EMPTY_CELL.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{render_detect, render_error, render_table};
+    use crate::interface::contracts::{
+        command::CommandError,
+        common::ClientKind,
+        detect::{ClientDetection, DetectClientsResponse, DetectionEvidence, DetectionStatus},
+    };
+
+    #[test]
+    fn table_pads_columns_to_widest_cell() {
+        let output = render_table(
+            &["name", "state"],
+            &[
+                vec!["filesystem".to_string(), "on".to_string()],
+                vec!["git".to_string(), "off".to_string()],
+            ],
+        );
+
+        assert_eq!(output, "NAME        STATE\nfilesystem  on\ngit         off");
+    }
+
+    #[test]
+    fn detect_table_uses_placeholders_for_missing_evidence() {
+        let output = render_detect(&DetectClientsResponse {
+            clients: vec![ClientDetection {
+                client: ClientKind::Codex,
+                status: DetectionStatus::Absent,
+                confidence: 0,
+                evidence: DetectionEvidence {
+                    binary_path: None,
+                    app_path: None,
+                    config_path: None,
+                    version: None,
+                },
+                note: String::new(),
+            }],
+        });
+
+        assert!(output.lines().nth(1).is_some_and(|row| {
+            row.starts_with("codex") && row.contains("absent") && row.ends_with('-')
+        }));
+    }
+
+    #[test]
+    fn error_output_includes_canonical_code_and_path() {
+        let output = render_error(
+            &CommandError::validation("payload is invalid").with_path("/tmp/.mcp.json"),
+        );
+
+        assert!(output.starts_with("error: [AM_VALIDATION_001] VALIDATION_ERROR:"));
+        assert!(output.contains("path: /tmp/.mcp.json"));
+    }
+}
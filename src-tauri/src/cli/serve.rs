@@ -0,0 +1,91 @@
+use std::{io::Write, sync::Arc};
+
+use super::{
+    CliOutput, EXIT_COMMAND_FAILED, EXIT_SUCCESS,
+    args::{ParsedArgs, UsageError},
+    output,
+};
+use crate::interface::{
+    contracts::control::{ControlServerInfo, ControlTransport, StartControlServerRequest},
+    control,
+    state::AppState,
+};
+
+/// Starts the control server and blocks until it stops on Ctrl-C or SIGTERM; only returns
+/// early on failure.
+pub fn run(state: Arc<AppState>, parsed: &ParsedArgs) -> Result<CliOutput, UsageError> {
+    parsed.ensure_known_options(&["port", "socket"])?;
+    let port = parsed
+        .optional("port")
+        .map(|port| {
+            port.parse::<u16>()
+                .map_err(|_| UsageError(format!("invalid value '{port}' for option '--port'")))
+        })
+        .transpose()?;
+    let socket_path = parsed.optional("socket");
+    if port.is_some() && socket_path.is_some() {
+        return Err(UsageError(
+            "use either '--port' or '--socket', not both".to_string(),
+        ));
+    }
+
+    let request = StartControlServerRequest {
+        transport: if socket_path.is_some() {
+            ControlTransport::Unix
+        } else {
+            ControlTransport::Tcp
+        },
+        port,
+        socket_path,
+    };
+
+    let handler_state = Arc::clone(&state);
+    let (server, info) = match control::start_control_server(&request, move |auth, request| {
+        control::handle_request(&handler_state, auth, request)
+    }) {
+        Ok(started) => started,
+        Err(error) => {
+            return Ok(CliOutput {
+                exit_code: EXIT_COMMAND_FAILED,
+                stdout: String::new(),
+                stderr: output::render_error(&error),
+            });
+        }
+    };
+
+    let announcement = if parsed.switch("json") {
+        serde_json::to_string_pretty(&info).unwrap_or_default()
+    } else {
+        render_server_info(&info)
+    };
+    let mut stdout = std::io::stdout();
+    let _ = writeln!(stdout, "{announcement}");
+    let _ = stdout.flush();
+
+    // Ctrl-C or SIGTERM stops the server so the discovery file and socket get removed.
+    let stop_handle = server.stop_handle();
+    if let Err(error) = ctrlc::set_handler(move || stop_handle.request_stop()) {
+        let _ = writeln!(
+            std::io::stderr(),
+            "warning: could not install a signal handler ({error}); the discovery file may outlive the server"
+        );
+    }
+    server.wait();
+
+    Ok(CliOutput {
+        exit_code: EXIT_SUCCESS,
+        stdout: String::new(),
+        stderr: String::new(),
+    })
+}
+
+fn render_server_info(info: &ControlServerInfo) -> String {
+    let mut output = format!(
+        "listening on {} (protocol v{})\ntoken: {}",
+        info.address, info.protocol_version, info.token
+    );
+    if let Some(discovery_path) = &info.discovery_path {
+        output.push_str(&format!("\ndiscovery file: {discovery_path}"));
+    }
+    output
+}
@@ -11,6 +11,25 @@ const CLAUDE_SUBAGENT_SCOPES: &[ResourceSourceScope] = &[
     ResourceSourceScope::User,
     ResourceSourceScope::ProjectShared,
 ];
+const CLAUDE_SETTING_SCOPES: &[ResourceSourceScope] = &[
+    ResourceSourceScope::User,
+    ResourceSourceScope::ProjectShared,
+    ResourceSourceScope::ProjectPrivate,
+];
+const CLAUDE_COMMAND_SCOPES: &[ResourceSourceScope] = &[
+    ResourceSourceScope::User,
+    ResourceSourceScope::ProjectShared,
+];
+const CLAUDE_INSTRUCTION_SCOPES: &[ResourceSourceScope] = &[
+    ResourceSourceScope::User,
+    ResourceSourceScope::ProjectShared,
+    ResourceSourceScope::ProjectPrivate,
+];
+const CODEX_INSTRUCTION_SCOPES: &[ResourceSourceScope] = &[
+    ResourceSourceScope::User,
+    ResourceSourceScope::ProjectShared,
+];
+const CURSOR_INSTRUCTION_SCOPES: &[ResourceSourceScope] = &[ResourceSourceScope::ProjectShared];
 const CURSOR_MCP_SCOPES: &[ResourceSourceScope] = &[
     ResourceSourceScope::User,
     ResourceSourceScope::ProjectShared,
@@ -37,9 +56,15 @@ pub struct ClientCapabilities {
     pub supports_mcp: bool,
     pub supports_skills: bool,
     pub supports_subagents: bool,
+    pub supports_settings: bool,
+    pub supports_commands: bool,
+    pub supports_instructions: bool,
     pub mcp: Option<ResourceScopeCapabilities>,
     pub skills: Option<ResourceScopeCapabilities>,
     pub subagents: Option<ResourceScopeCapabilities>,
+    pub settings: Option<ResourceScopeCapabilities>,
+    pub commands: Option<ResourceScopeCapabilities>,
+    pub instructions: Option<ResourceScopeCapabilities>,
 }
 
 impl ClientCapabilities {
@@ -48,6 +73,9 @@ impl ClientCapabilities {
             ResourceKind::Mcp => self.mcp,
             ResourceKind::Skill => self.skills,
             ResourceKind::Subagent => self.subagents,
+            ResourceKind::Setting => self.settings,
+            ResourceKind::Command => self.commands,
+            ResourceKind::Instruction => self.instructions,
         }
     }
 
@@ -97,6 +125,9 @@ pub const CLAUDE_CODE_PROFILE: ClientProfile = ClientProfile {
         supports_mcp: true,
         supports_skills: true,
         supports_subagents: true,
+        supports_settings: true,
+        supports_commands: true,
+        supports_instructions: true,
         mcp: Some(ResourceScopeCapabilities {
             source_scopes: CLAUDE_MCP_SCOPES,
             destination_scopes: CLAUDE_MCP_SCOPES,
@@ -109,6 +140,18 @@ pub const CLAUDE_CODE_PROFILE: ClientProfile = ClientProfile {
             source_scopes: CLAUDE_SUBAGENT_SCOPES,
             destination_scopes: CLAUDE_SUBAGENT_SCOPES,
         }),
+        settings: Some(ResourceScopeCapabilities {
+            source_scopes: CLAUDE_SETTING_SCOPES,
+            destination_scopes: CLAUDE_SETTING_SCOPES,
+        }),
+        commands: Some(ResourceScopeCapabilities {
+            source_scopes: CLAUDE_COMMAND_SCOPES,
+            destination_scopes: CLAUDE_COMMAND_SCOPES,
+        }),
+        instructions: Some(ResourceScopeCapabilities {
+            source_scopes: CLAUDE_INSTRUCTION_SCOPES,
+            destination_scopes: CLAUDE_INSTRUCTION_SCOPES,
+        }),
     },
 };
 
@@ -120,6 +163,9 @@ pub const CODEX_PROFILE: ClientProfile = ClientProfile {
         supports_mcp: true,
         supports_skills: true,
         supports_subagents: false,
+        supports_settings: false,
+        supports_commands: true,
+        supports_instructions: true,
         mcp: Some(ResourceScopeCapabilities {
             source_scopes: USER_ONLY_SCOPES,
             destination_scopes: USER_ONLY_SCOPES,
@@ -129,6 +175,15 @@ pub const CODEX_PROFILE: ClientProfile = ClientProfile {
             destination_scopes: USER_ONLY_SCOPES,
         }),
         subagents: None,
+        settings: None,
+        commands: Some(ResourceScopeCapabilities {
+            source_scopes: USER_ONLY_SCOPES,
+            destination_scopes: USER_ONLY_SCOPES,
+        }),
+        instructions: Some(ResourceScopeCapabilities {
+            source_scopes: CODEX_INSTRUCTION_SCOPES,
+            destination_scopes: CODEX_INSTRUCTION_SCOPES,
+        }),
     },
 };
 
@@ -140,6 +195,9 @@ pub const CURSOR_PROFILE: ClientProfile = ClientProfile {
         supports_mcp: true,
         supports_skills: true,
         supports_subagents: false,
+        supports_settings: false,
+        supports_commands: false,
+        supports_instructions: true,
         mcp: Some(ResourceScopeCapabilities {
             source_scopes: CURSOR_MCP_SCOPES,
             destination_scopes: CURSOR_MCP_SCOPES,
@@ -149,6 +207,12 @@ pub const CURSOR_PROFILE: ClientProfile = ClientProfile {
             destination_scopes: USER_ONLY_SCOPES,
         }),
         subagents: None,
+        settings: None,
+        commands: None,
+        instructions: Some(ResourceScopeCapabilities {
+            source_scopes: CURSOR_INSTRUCTION_SCOPES,
+            destination_scopes: CURSOR_INSTRUCTION_SCOPES,
+        }),
     },
 };
 
@@ -162,7 +226,7 @@ pub fn profile_for_client(client: ClientKind) -> &'static ClientProfile {
 
 #[cfg(test)]
 mod tests {
-    use super::{ClientKind, profile_for_client};
+    use super::{CLAUDE_INSTRUCTION_SCOPES, ClientKind, profile_for_client};
     use crate::domain::{ResourceKind, ResourceSourceScope};
 
     #[test]
@@ -260,4 +324,66 @@ mod tests {
             );
         }
     }
+    #[test]
+    fn setting_support_is_claude_only_across_all_scopes() {
+        let claude_capabilities = profile_for_client(ClientKind::ClaudeCode).capabilities;
+        assert!(claude_capabilities.supports_settings);
+        assert!(
+            claude_capabilities
+                .supports_destination(ResourceKind::Setting, ResourceSourceScope::ProjectPrivate)
+        );
+
+        for client in [ClientKind::Codex, ClientKind::Cursor] {
+            let capabilities = profile_for_client(client).capabilities;
+            assert!(!capabilities.supports_settings);
+            assert!(
+                capabilities
+                    .source_scopes_for(ResourceKind::Setting)
+                    .is_empty()
+            );
+        }
+    }
+    #[test]
+    fn command_support_covers_claude_projects_and_codex_user_prompts() {
+        let claude_capabilities = profile_for_client(ClientKind::ClaudeCode).capabilities;
+        assert_eq!(
+            claude_capabilities.destination_scopes_for(ResourceKind::Command),
+            &[
+                ResourceSourceScope::User,
+                ResourceSourceScope::ProjectShared
+            ]
+        );
+        assert_eq!(
+            profile_for_client(ClientKind::Codex)
+                .capabilities
+                .source_scopes_for(ResourceKind::Command),
+            &[ResourceSourceScope::User]
+        );
+        assert!(
+            !profile_for_client(ClientKind::Cursor)
+                .capabilities
+                .supports_commands
+        );
+    }
+
+    #[test]
+    fn instruction_support_spans_every_client_at_its_native_scopes() {
+        let capabilities_for = |client: ClientKind| profile_for_client(client).capabilities;
+
+        assert_eq!(
+            capabilities_for(ClientKind::ClaudeCode).source_scopes_for(ResourceKind::Instruction),
+            CLAUDE_INSTRUCTION_SCOPES
+        );
+        assert!(
+            capabilities_for(ClientKind::Codex)
+                .support_for(ResourceKind::Instruction)
+                .is_some_and(
+                    |support| !support.supports_destination(ResourceSourceScope::ProjectPrivate)
+                )
+        );
+        assert_eq!(
+            capabilities_for(ClientKind::Cursor).destination_scopes_for(ResourceKind::Instruction),
+            &[ResourceSourceScope::ProjectShared]
+        );
+    }
 }
@@ -0,0 +1,61 @@
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ClientVersion {
+    pub major: u64,
+    pub minor: u64,
+    pub patch: u64,
+}
+
+impl ClientVersion {
+    pub const fn new(major: u64, minor: u64, patch: u64) -> Self {
+        Self {
+            major,
+            minor,
+            patch,
+        }
+    }
+
+    /// Extracts the first `major.minor[.patch]` triple from free-form `--version` output,
+    /// e.g. `codex-cli 0.46.0` or `1.0.27 (Claude Code)`.
+    pub fn parse(text: &str) -> Option<Self> {
+        text.split(|ch: char| !(ch.is_ascii_digit() || ch == '.'))
+            .find_map(|token| {
+                let mut parts = token.split('.').filter(|part| !part.is_empty());
+                let major = parts.next()?.parse().ok()?;
+                let minor = parts.next()?.parse().ok()?;
+                let patch = parts.next().map_or(Some(0), |part| part.parse().ok())?;
+                Some(Self::new(major, minor, patch))
+            })
+    }
+}
+
+impl fmt::Display for ClientVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ClientVersion;
+
+    #[test]
+    fn parse_reads_first_version_triple_from_cli_output() {
+        assert_eq!(
+            ClientVersion::parse("1.0.27 (Claude Code)"),
+            Some(ClientVersion::new(1, 0, 27))
+        );
+        assert_eq!(
+            ClientVersion::parse("codex-cli 0.46.0"),
+            Some(ClientVersion::new(0, 46, 0))
+        );
+        assert_eq!(
+            ClientVersion::parse("Cursor v2.1"),
+            Some(ClientVersion::new(2, 1, 0))
+        );
+        assert_eq!(ClientVersion::parse("ok"), None);
+        assert_eq!(ClientVersion::parse("build 42"), None);
+        assert!(ClientVersion::new(0, 46, 0) > ClientVersion::new(0, 9, 12));
+    }
+}
@@ -1,6 +1,7 @@
 mod client_adapter;
 mod client_kind;
 mod client_profile;
+mod client_version;
 mod mutation_action;
 mod resource_kind;
 mod resource_record;
@@ -12,7 +13,8 @@ pub use client_profile::{
     CLAUDE_CODE_PROFILE, CODEX_PROFILE, CURSOR_PROFILE, ClientCapabilities, ClientProfile,
     ResourceScopeCapabilities, profile_for_client,
 };
+pub use client_version::ClientVersion;
 pub use mutation_action::MutationAction;
 pub use resource_kind::ResourceKind;
-pub use resource_record::ResourceRecord;
+pub use resource_record::{McpApprovalState, ResourceRecord};
 pub use resource_source::{ResourceSourceMetadata, ResourceSourceScope};
@@ -6,6 +6,8 @@ pub enum MutationAction {
     Add,
     Remove,
     Update,
+    Approve,
+    Deny,
 }
 
 impl MutationAction {
@@ -14,6 +16,8 @@ impl MutationAction {
             Self::Add => "add",
             Self::Remove => "remove",
             Self::Update => "update",
+            Self::Approve => "approve",
+            Self::Deny => "deny",
         }
     }
 }
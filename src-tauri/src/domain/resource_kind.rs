@@ -6,6 +6,9 @@ pub enum ResourceKind {
     Mcp,
     Skill,
     Subagent,
+    Setting,
+    Command,
+    Instruction,
 }
 
 impl ResourceKind {
@@ -14,6 +17,9 @@ impl ResourceKind {
             Self::Mcp => "mcp",
             Self::Skill => "skill",
             Self::Subagent => "subagent",
+            Self::Setting => "setting",
+            Self::Command => "command",
+            Self::Instruction => "instruction",
         }
     }
 }
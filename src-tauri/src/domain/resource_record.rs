@@ -22,6 +22,16 @@ pub struct ResourceRecord {
     pub description: Option<String>,
     pub install_kind: Option<String>,
     pub manifest_content: Option<String>,
+    pub approval: Option<McpApprovalState>,
+}
+
+/// Whether Claude Code activates a project `.mcp.json` server, per its settings files.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum McpApprovalState {
+    Approved,
+    Denied,
+    Pending,
 }
 
 impl ResourceRecord {
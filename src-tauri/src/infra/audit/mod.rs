@@ -0,0 +1,3 @@
+mod operation_log;
+
+pub use operation_log::{LoggedFileChange, LoggedOperation, LoggedOperationKind, OperationLog};
@@ -0,0 +1,177 @@
+use std::{
+    fs::{self, OpenOptions},
+    io::{self, BufRead, BufReader, Read, Seek, SeekFrom, Write},
+    path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    infra::app_data_dir,
+    interface::contracts::common::{ClientKind, ResourceKind},
+};
+
+const OPERATION_LOG_FILE: &str = "operation-log.jsonl";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LoggedOperationKind {
+    Mutate,
+    Replicate,
+    Repair,
+    ExternalizeSecrets,
+    Undo,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LoggedFileChange {
+    pub path: String,
+    /// Content before the change; `None` when the change created the file.
+    pub backup_path: Option<String>,
+    /// Revision right after the change; `None` when the change removed the file.
+    pub after_revision: Option<String>,
+    pub diff: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LoggedOperation {
+    /// Durable id; `operation_id` restarts at 1 in every process.
+    pub entry_id: String,
+    pub operation_id: String,
+    pub recorded_at_epoch_ms: u128,
+    pub operation: LoggedOperationKind,
+    pub client: ClientKind,
+    pub resource_kind: ResourceKind,
+    pub target_id: String,
+    pub source_id: Option<String>,
+    pub changes: Vec<LoggedFileChange>,
+    /// The entry this operation reverted, for `undo`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub undoes: Option<String>,
+}
+
+/// Append-only JSON-lines record of file-changing operations.
+pub struct OperationLog {
+    path: PathBuf,
+}
+
+impl OperationLog {
+    pub fn at(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    pub fn default_location() -> Option<Self> {
+        app_data_dir().map(|directory| Self::at(directory.join(OPERATION_LOG_FILE)))
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn append(&self, entry: &LoggedOperation) -> io::Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut file = OpenOptions::new()
+            .create(true)
+            .read(true)
+            .append(true)
+            .open(&self.path)?;
+
+        // Start a fresh line if an interrupted append left a partial one.
+        let mut line = Vec::new();
+        if file.metadata()?.len() > 0 {
+            let mut last = [0_u8; 1];
+            file.seek(SeekFrom::End(-1))?;
+            file.read_exact(&mut last)?;
+            if last[0] != b'\n' {
+                line.push(b'\n');
+            }
+        }
+        line.extend(serde_json::to_vec(entry).map_err(io::Error::other)?);
+        line.push(b'\n');
+
+        // One write per entry keeps concurrent appends from interleaving.
+        file.write_all(&line)
+    }
+
+    /// Entries oldest first. Unreadable lines, such as a partial last line, are skipped.
+    pub fn load(&self) -> io::Result<Vec<LoggedOperation>> {
+        let file = match fs::File::open(&self.path) {
+            Ok(file) => file,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(error) => return Err(error),
+        };
+
+        let mut entries = Vec::new();
+        for line in BufReader::new(file).lines() {
+            if let Ok(entry) = serde_json::from_str(&line?) {
+                entries.push(entry);
+            }
+        }
+        Ok(entries)
+    }
+
+    pub fn find(&self, entry_id: &str) -> io::Result<Option<LoggedOperation>> {
+        Ok(self
+            .load()?
+            .into_iter()
+            .find(|entry| entry.entry_id == entry_id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::{LoggedFileChange, LoggedOperation, LoggedOperationKind, OperationLog};
+    use crate::interface::contracts::common::{ClientKind, ResourceKind};
+
+    #[test]
+    fn appends_entries_and_skips_torn_lines() {
+        let temp_dir =
+            std::env::temp_dir().join(format!("ai-manager-operation-log-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&temp_dir);
+        let log = OperationLog::at(temp_dir.join("log").join("operations.jsonl"));
+        let entry = |entry_id: &str| LoggedOperation {
+            entry_id: entry_id.to_string(),
+            operation_id: "mutate-1".to_string(),
+            recorded_at_epoch_ms: 1,
+            operation: LoggedOperationKind::Mutate,
+            client: ClientKind::Cursor,
+            resource_kind: ResourceKind::Mcp,
+            target_id: "github".to_string(),
+            source_id: None,
+            changes: vec![LoggedFileChange {
+                path: "/tmp/mcp.json".to_string(),
+                backup_path: None,
+                after_revision: Some("abc".to_string()),
+                diff: String::new(),
+            }],
+            undoes: None,
+        };
+
+        let empty = log.load().expect("missing log should load empty");
+        log.append(&entry("1-mutate-1")).expect("should append");
+        fs::OpenOptions::new()
+            .append(true)
+            .open(log.path())
+            .and_then(|mut file| std::io::Write::write_all(&mut file, b"{\"entry_id\":"))
+            .expect("should write torn line");
+        log.append(&entry("2-mutate-1"))
+            .expect("should append after torn line");
+        let entries = log.load().expect("should load");
+        let found = log.find("2-mutate-1").expect("should search");
+        let _ = fs::remove_dir_all(&temp_dir);
+
+        assert!(empty.is_empty());
+        assert_eq!(
+            entries
+                .iter()
+                .map(|entry| entry.entry_id.as_str())
+                .collect::<Vec<_>>(),
+            vec!["1-mutate-1", "2-mutate-1"]
+        );
+        assert_eq!(found, Some(entry("2-mutate-1")));
+    }
+}
@@ -0,0 +1,164 @@
+use std::{
+    collections::BTreeMap,
+    io::{self, BufRead, Read, Write},
+};
+
+const MAX_HEADER_BYTES: usize = 16 * 1024;
+const MAX_BODY_BYTES: usize = 1024 * 1024;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpRequest {
+    pub method: String,
+    pub path: String,
+    pub headers: BTreeMap<String, String>,
+    pub body: Vec<u8>,
+}
+
+impl HttpRequest {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .get(&name.to_ascii_lowercase())
+            .map(String::as_str)
+    }
+
+    pub fn bearer_token(&self) -> Option<&str> {
+        self.header("authorization")?
+            .strip_prefix("Bearer ")
+            .map(str::trim)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpResponse {
+    pub status: u16,
+    pub body: String,
+}
+
+impl HttpResponse {
+    pub fn json(status: u16, body: String) -> Self {
+        Self { status, body }
+    }
+}
+
+pub fn read_request(reader: &mut impl BufRead) -> io::Result<HttpRequest> {
+    let mut header_bytes = 0;
+    let request_line = read_line(reader, &mut header_bytes)?;
+    let mut parts = request_line.split_whitespace();
+    let (Some(method), Some(path)) = (parts.next(), parts.next()) else {
+        return Err(invalid_data("malformed request line"));
+    };
+
+    let mut headers = BTreeMap::new();
+    loop {
+        let line = read_line(reader, &mut header_bytes)?;
+        if line.is_empty() {
+            break;
+        }
+        let Some((name, value)) = line.split_once(':') else {
+            return Err(invalid_data("malformed header line"));
+        };
+        headers.insert(name.trim().to_ascii_lowercase(), value.trim().to_string());
+    }
+
+    let content_length = match headers.get("content-length") {
+        Some(value) => value
+            .parse::<usize>()
+            .map_err(|_| invalid_data("invalid content-length"))?,
+        None => 0,
+    };
+    if content_length > MAX_BODY_BYTES {
+        return Err(invalid_data("request body is too large"));
+    }
+
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body)?;
+
+    Ok(HttpRequest {
+        method: method.to_string(),
+        path: path.to_string(),
+        headers,
+        body,
+    })
+}
+
+pub fn write_response(writer: &mut impl Write, response: &HttpResponse) -> io::Result<()> {
+    write!(
+        writer,
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        response.status,
+        reason_phrase(response.status),
+        response.body.len(),
+        response.body
+    )?;
+    writer.flush()
+}
+
+fn read_line(reader: &mut impl BufRead, consumed: &mut usize) -> io::Result<String> {
+    let mut line = String::new();
+    let read = reader
+        .by_ref()
+        .take((MAX_HEADER_BYTES - *consumed) as u64)
+        .read_line(&mut line)?;
+    *consumed += read;
+    if read == 0 || !line.ends_with('\n') {
+        return Err(invalid_data("incomplete request head"));
+    }
+
+    Ok(line.trim_end_matches(['\r', '\n']).to_string())
+}
+
+fn reason_phrase(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        400 => "Bad Request",
+        401 => "Unauthorized",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        503 => "Service Unavailable",
+        _ => "Internal Server Error",
+    }
+}
+
+fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::BufReader;
+
+    use super::{HttpResponse, read_request, write_response};
+
+    #[test]
+    fn reads_request_head_and_body() {
+        let raw = "POST /rpc HTTP/1.1\r\nHost: localhost\r\nAuthorization: Bearer abc\r\nContent-Length: 2\r\n\r\n{}";
+        let request =
+            read_request(&mut BufReader::new(raw.as_bytes())).expect("request should parse");
+
+        assert_eq!(request.method, "POST");
+        assert_eq!(request.path, "/rpc");
+        assert_eq!(request.bearer_token(), Some("abc"));
+        assert_eq!(request.body, b"{}");
+    }
+
+    #[test]
+    fn rejects_truncated_body_and_oversized_length() {
+        let truncated = "POST /rpc HTTP/1.1\r\nContent-Length: 10\r\n\r\n{}";
+        assert!(read_request(&mut BufReader::new(truncated.as_bytes())).is_err());
+
+        let oversized = "POST /rpc HTTP/1.1\r\nContent-Length: 99999999\r\n\r\n";
+        assert!(read_request(&mut BufReader::new(oversized.as_bytes())).is_err());
+    }
+
+    #[test]
+    fn writes_json_response_with_content_length() {
+        let mut output = Vec::new();
+        write_response(&mut output, &HttpResponse::json(401, "{}".to_string()))
+            .expect("response should be written");
+
+        let text = String::from_utf8(output).expect("response should be utf-8");
+        assert!(text.starts_with("HTTP/1.1 401 Unauthorized\r\n"));
+        assert!(text.contains("Content-Length: 2\r\n"));
+        assert!(text.ends_with("\r\n\r\n{}"));
+    }
+}
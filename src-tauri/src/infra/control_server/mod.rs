@@ -0,0 +1,5 @@
+mod http;
+mod server;
+
+pub use http::{HttpRequest, HttpResponse};
+pub use server::{ControlEndpoint, ControlServer};
@@ -0,0 +1,312 @@
+use std::{
+    fs,
+    io::{self, BufReader, Read, Write},
+    net::{Ipv4Addr, SocketAddr, TcpListener},
+    path::PathBuf,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+    thread::{self, JoinHandle},
+    time::Duration,
+};
+
+#[cfg(unix)]
+use std::{os::unix::net::UnixListener, path::Path};
+
+use super::http::{HttpRequest, HttpResponse, read_request, write_response};
+
+const ACCEPT_POLL_INTERVAL: Duration = Duration::from_millis(25);
+const CONNECTION_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ControlEndpoint {
+    Tcp {
+        port: u16,
+    },
+    #[cfg(unix)]
+    Unix {
+        path: PathBuf,
+    },
+}
+
+enum Listener {
+    Tcp(TcpListener),
+    #[cfg(unix)]
+    Unix(UnixListener),
+}
+
+/// Asks a running server to stop from another thread, e.g. a signal handler.
+#[derive(Debug, Clone)]
+pub struct ControlServerStopHandle {
+    stop_requested: Arc<AtomicBool>,
+}
+
+impl ControlServerStopHandle {
+    pub fn request_stop(&self) {
+        self.stop_requested.store(true, Ordering::Relaxed);
+    }
+}
+
+pub struct ControlServer {
+    address: String,
+    stop_requested: Arc<AtomicBool>,
+    worker: Option<JoinHandle<()>>,
+    owned_files: Vec<PathBuf>,
+}
+
+impl ControlServer {
+    /// Binds the endpoint and serves one request per connection until stopped.
+    pub fn bind(
+        endpoint: &ControlEndpoint,
+        handler: impl Fn(HttpRequest) -> HttpResponse + Send + 'static,
+    ) -> io::Result<Self> {
+        let (listener, address, owned_files) = bind_listener(endpoint)?;
+        let stop_requested = Arc::new(AtomicBool::new(false));
+        let worker_stop = Arc::clone(&stop_requested);
+
+        let worker = thread::spawn(move || {
+            while !worker_stop.load(Ordering::Relaxed) {
+                if !matches!(accept_and_serve(&listener, &handler), Ok(true)) {
+                    thread::sleep(ACCEPT_POLL_INTERVAL);
+                }
+            }
+        });
+
+        Ok(Self {
+            address,
+            stop_requested,
+            worker: Some(worker),
+            owned_files,
+        })
+    }
+
+    pub fn address(&self) -> &str {
+        &self.address
+    }
+
+    /// Registers a file to delete when the server stops, e.g. a discovery file.
+    pub fn remove_on_stop(&mut self, path: PathBuf) {
+        self.owned_files.push(path);
+    }
+
+    pub fn stop_handle(&self) -> ControlServerStopHandle {
+        ControlServerStopHandle {
+            stop_requested: Arc::clone(&self.stop_requested),
+        }
+    }
+
+    /// Blocks until the server is stopped through a stop handle, then cleans up like `stop`.
+    pub fn wait(mut self) {
+        if let Some(worker) = self.worker.take() {
+            let _ = worker.join();
+        }
+    }
+
+    pub fn stop(mut self) {
+        self.shutdown();
+    }
+
+    fn shutdown(&mut self) {
+        self.stop_requested.store(true, Ordering::Relaxed);
+        if let Some(worker) = self.worker.take() {
+            let _ = worker.join();
+        }
+        for path in self.owned_files.drain(..) {
+            let _ = fs::remove_file(path);
+        }
+    }
+}
+
+impl Drop for ControlServer {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
+
+fn bind_listener(endpoint: &ControlEndpoint) -> io::Result<(Listener, String, Vec<PathBuf>)> {
+    match endpoint {
+        ControlEndpoint::Tcp { port } => {
+            let listener = TcpListener::bind(SocketAddr::from((Ipv4Addr::LOCALHOST, *port)))?;
+            listener.set_nonblocking(true)?;
+            let address = format!("http://{}", listener.local_addr()?);
+            Ok((Listener::Tcp(listener), address, Vec::new()))
+        }
+        #[cfg(unix)]
+        ControlEndpoint::Unix { path } => {
+            remove_stale_socket(path)?;
+            let listener = bind_private_socket(path)?;
+            listener.set_nonblocking(true)?;
+            Ok((
+                Listener::Unix(listener),
+                format!("unix:{}", path.display()),
+                vec![path.clone()],
+            ))
+        }
+    }
+}
+
+/// Removes a socket left by an earlier server; anything else at `path` is an error.
+#[cfg(unix)]
+fn remove_stale_socket(path: &Path) -> io::Result<()> {
+    use std::os::unix::fs::FileTypeExt;
+
+    match fs::symlink_metadata(path) {
+        Ok(metadata) if metadata.file_type().is_socket() => fs::remove_file(path),
+        Ok(_) => Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("'{}' exists and is not a socket", path.display()),
+        )),
+        Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(()),
+        Err(error) => Err(error),
+    }
+}
+
+/// Binds inside a fresh `0700` directory and links the socket into place only after it is
+/// `0600`, so other local users never get a window to connect.
+#[cfg(unix)]
+fn bind_private_socket(path: &Path) -> io::Result<UnixListener> {
+    use std::os::unix::fs::{DirBuilderExt, PermissionsExt};
+
+    let parent = path
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+        .unwrap_or(Path::new("."));
+    let staging = parent.join(format!(".ai-manager-socket-{}", std::process::id()));
+    fs::DirBuilder::new().mode(0o700).create(&staging)?;
+    let staged = staging.join("control.sock");
+
+    let bound = UnixListener::bind(&staged).and_then(|listener| {
+        fs::set_permissions(&staged, fs::Permissions::from_mode(0o600))?;
+        fs::hard_link(&staged, path)?;
+        Ok(listener)
+    });
+    let _ = fs::remove_file(&staged);
+    let _ = fs::remove_dir(&staging);
+    bound
+}
+
+fn accept_and_serve(
+    listener: &Listener,
+    handler: &impl Fn(HttpRequest) -> HttpResponse,
+) -> io::Result<bool> {
+    let accepted = match listener {
+        Listener::Tcp(listener) => listener.accept().and_then(|(stream, _)| {
+            stream.set_nonblocking(false)?;
+            stream.set_read_timeout(Some(CONNECTION_TIMEOUT))?;
+            stream.set_write_timeout(Some(CONNECTION_TIMEOUT))?;
+            serve_connection(&stream, &stream, handler)
+        }),
+        #[cfg(unix)]
+        Listener::Unix(listener) => listener.accept().and_then(|(stream, _)| {
+            stream.set_nonblocking(false)?;
+            stream.set_read_timeout(Some(CONNECTION_TIMEOUT))?;
+            stream.set_write_timeout(Some(CONNECTION_TIMEOUT))?;
+            serve_connection(&stream, &stream, handler)
+        }),
+    };
+
+    match accepted {
+        Ok(()) => Ok(true),
+        Err(error) if error.kind() == io::ErrorKind::WouldBlock => Ok(false),
+        Err(error) => Err(error),
+    }
+}
+
+fn serve_connection(
+    reader: impl Read,
+    mut writer: impl Write,
+    handler: &impl Fn(HttpRequest) -> HttpResponse,
+) -> io::Result<()> {
+    let response = match read_request(&mut BufReader::new(reader)) {
+        Ok(request) => handler(request),
+        Err(error) => HttpResponse::json(
+            400,
+            serde_json::json!({ "error": error.to_string() }).to_string(),
+        ),
+    };
+
+    write_response(&mut writer, &response)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{Read, Write},
+        net::TcpStream,
+    };
+
+    use super::{ControlEndpoint, ControlServer};
+    use crate::infra::control_server::HttpResponse;
+
+    #[test]
+    fn tcp_server_round_trips_a_request_on_localhost() {
+        let server = ControlServer::bind(&ControlEndpoint::Tcp { port: 0 }, |request| {
+            HttpResponse::json(200, String::from_utf8_lossy(&request.body).into_owned())
+        })
+        .expect("server should bind");
+        let address = server
+            .address()
+            .strip_prefix("http://")
+            .expect("tcp address should be http")
+            .to_string();
+        assert!(address.starts_with("127.0.0.1:"));
+
+        let mut stream = TcpStream::connect(&address).expect("client should connect");
+        stream
+            .write_all(b"POST /rpc HTTP/1.1\r\nContent-Length: 7\r\n\r\n{\"a\":1}")
+            .expect("request should be sent");
+        let mut response = String::new();
+        stream
+            .read_to_string(&mut response)
+            .expect("response should be read");
+
+        assert!(response.starts_with("HTTP/1.1 200 OK"));
+        assert!(response.ends_with("{\"a\":1}"));
+        server.stop();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn unix_socket_is_private_and_never_replaces_other_files() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let temp_dir =
+            std::env::temp_dir().join(format!("ai-manager-control-socket-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&temp_dir);
+        std::fs::create_dir_all(&temp_dir).expect("should create temp dir");
+        let regular_file = temp_dir.join("notes.txt");
+        std::fs::write(&regular_file, "keep me").expect("should seed file");
+        let socket_path = temp_dir.join("control.sock");
+        let handler = |_| HttpResponse::json(200, String::new());
+
+        let refused = ControlServer::bind(
+            &ControlEndpoint::Unix {
+                path: regular_file.clone(),
+            },
+            handler,
+        );
+        let server = ControlServer::bind(
+            &ControlEndpoint::Unix {
+                path: socket_path.clone(),
+            },
+            handler,
+        )
+        .expect("server should bind");
+        let mode = std::fs::metadata(&socket_path)
+            .expect("socket should exist")
+            .permissions()
+            .mode();
+        server.stop();
+        let preserved = std::fs::read_to_string(&regular_file).expect("file should remain");
+        let leftovers = std::fs::read_dir(&temp_dir)
+            .expect("should list temp dir")
+            .count();
+        let _ = std::fs::remove_dir_all(&temp_dir);
+
+        assert!(refused.is_err());
+        assert_eq!(preserved, "keep me");
+        assert_eq!(mode & 0o777, 0o600);
+        assert_eq!(leftovers, 1);
+    }
+}
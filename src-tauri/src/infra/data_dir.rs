@@ -0,0 +1,15 @@
+use std::{env, path::PathBuf};
+
+const DATA_DIR_ENV: &str = "AI_MANAGER_DATA_DIR";
+const DATA_DIR: &str = ".ai-manager";
+
+/// AI Manager's own data directory: `AI_MANAGER_DATA_DIR`, else `~/.ai-manager`.
+pub fn app_data_dir() -> Option<PathBuf> {
+    if let Some(path) = env::var_os(DATA_DIR_ENV).filter(|value| !value.is_empty()) {
+        return Some(PathBuf::from(path));
+    }
+
+    env::var_os("HOME")
+        .or_else(|| env::var_os("USERPROFILE"))
+        .map(|home| PathBuf::from(home).join(DATA_DIR))
+}
@@ -5,37 +5,29 @@ use crate::interface::contracts::{
 
 use super::super::{
     client_detector::ClientDetector,
-    path_based::{
-        DetectionGate, DetectorKind, PathBasedDetectorConfig, evaluate_path_based_detector,
-    },
+    path_based::{PathBasedDetectorConfig, evaluate_path_based_detector},
+    support_matrix::{HostOs, support_matrix_client},
 };
 
-const CONFIG: PathBasedDetectorConfig = PathBasedDetectorConfig {
-    client: ClientKind::ClaudeCode,
-    display_name: "Claude Code",
-    kind: DetectorKind::Cli,
-    detection_gate: DetectionGate::CliVersion,
-    startup_probe_command: Some("claude"),
-    binary_candidates: &["claude", "claude-code"],
-    app_candidates: &[],
-    config_override_env_vars: &["AI_MANAGER_CLAUDE_CODE_MCP_CONFIG"],
-    config_fallback_paths: &["~/.claude.json", "~/.claude/claude_code_config.json"],
-};
-
-pub struct ClaudeCodeDetector;
+pub struct ClaudeCodeDetector {
+    config: PathBasedDetectorConfig,
+}
 
 impl ClaudeCodeDetector {
     pub fn new() -> Self {
-        Self
+        Self {
+            config: support_matrix_client(ClientKind::ClaudeCode)
+                .detector_config(HostOs::current()),
+        }
     }
 }
 
 impl ClientDetector for ClaudeCodeDetector {
     fn client_kind(&self) -> ClientKind {
-        CONFIG.client
+        self.config.client
     }
 
     fn detect(&self, request: &DetectClientsRequest) -> ClientDetection {
-        evaluate_path_based_detector(&CONFIG, request)
+        evaluate_path_based_detector(&self.config, request)
     }
 }
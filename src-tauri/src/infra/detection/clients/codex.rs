@@ -5,37 +5,28 @@ use crate::interface::contracts::{
 
 use super::super::{
     client_detector::ClientDetector,
-    path_based::{
-        DetectionGate, DetectorKind, PathBasedDetectorConfig, evaluate_path_based_detector,
-    },
+    path_based::{PathBasedDetectorConfig, evaluate_path_based_detector},
+    support_matrix::{HostOs, support_matrix_client},
 };
 
-const CONFIG: PathBasedDetectorConfig = PathBasedDetectorConfig {
-    client: ClientKind::Codex,
-    display_name: "Codex",
-    kind: DetectorKind::Desktop,
-    detection_gate: DetectionGate::AppInstall,
-    startup_probe_command: Some("codex"),
-    binary_candidates: &["codex", "codex-cli"],
-    app_candidates: &["/Applications/Codex.app", "~/Applications/Codex.app"],
-    config_override_env_vars: &["AI_MANAGER_CODEX_MCP_CONFIG"],
-    config_fallback_paths: &["~/.codex/config.toml"],
-};
-
-pub struct CodexDetector;
+pub struct CodexDetector {
+    config: PathBasedDetectorConfig,
+}
 
 impl CodexDetector {
     pub fn new() -> Self {
-        Self
+        Self {
+            config: support_matrix_client(ClientKind::Codex).detector_config(HostOs::current()),
+        }
     }
 }
 
 impl ClientDetector for CodexDetector {
     fn client_kind(&self) -> ClientKind {
-        CONFIG.client
+        self.config.client
     }
 
     fn detect(&self, request: &DetectClientsRequest) -> ClientDetection {
-        evaluate_path_based_detector(&CONFIG, request)
+        evaluate_path_based_detector(&self.config, request)
     }
 }
@@ -5,40 +5,28 @@ use crate::interface::contracts::{
 
 use super::super::{
     client_detector::ClientDetector,
-    path_based::{
-        DetectionGate, DetectorKind, PathBasedDetectorConfig, evaluate_path_based_detector,
-    },
+    path_based::{PathBasedDetectorConfig, evaluate_path_based_detector},
+    support_matrix::{HostOs, support_matrix_client},
 };
 
-const CONFIG: PathBasedDetectorConfig = PathBasedDetectorConfig {
-    client: ClientKind::Cursor,
-    display_name: "Cursor",
-    kind: DetectorKind::Desktop,
-    detection_gate: DetectionGate::AppInstall,
-    startup_probe_command: Some("cursor"),
-    binary_candidates: &["cursor", "Cursor"],
-    app_candidates: &["/Applications/Cursor.app", "~/Applications/Cursor.app"],
-    config_override_env_vars: &["AI_MANAGER_CURSOR_MCP_CONFIG"],
-    config_fallback_paths: &[
-        "~/.cursor/mcp.json",
-        "~/Library/Application Support/Cursor/User/mcp.json",
-    ],
-};
-
-pub struct CursorDetector;
+pub struct CursorDetector {
+    config: PathBasedDetectorConfig,
+}
 
 impl CursorDetector {
     pub fn new() -> Self {
-        Self
+        Self {
+            config: support_matrix_client(ClientKind::Cursor).detector_config(HostOs::current()),
+        }
     }
 }
 
 impl ClientDetector for CursorDetector {
     fn client_kind(&self) -> ClientKind {
-        CONFIG.client
+        self.config.client
     }
 
     fn detect(&self, request: &DetectClientsRequest) -> ClientDetection {
-        evaluate_path_based_detector(&CONFIG, request)
+        evaluate_path_based_detector(&self.config, request)
     }
 }
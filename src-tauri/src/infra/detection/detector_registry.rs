@@ -1,11 +1,27 @@
+use std::{
+    collections::HashMap,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
 use super::{
     ClientDetector,
     clients::{ClaudeCodeDetector, CodexDetector, CursorDetector},
 };
-use crate::interface::contracts::common::ClientKind;
+use crate::interface::contracts::{
+    common::ClientKind,
+    detect::{ClientDetection, DetectClientsRequest},
+};
+
+/// How long a probed `--version` is trusted before the client is probed again, so upgrades
+/// made while the app is running are picked up.
+const INSTALLED_VERSION_TTL: Duration = Duration::from_secs(10 * 60);
+
+type VersionCache = HashMap<ClientKind, (Instant, Option<String>)>;
 
 pub struct DetectorRegistry {
     detectors: Vec<Box<dyn ClientDetector>>,
+    installed_versions: Mutex<VersionCache>,
 }
 
 impl DetectorRegistry {
@@ -16,12 +32,33 @@ impl DetectorRegistry {
                 Box::new(CodexDetector::new()),
                 Box::new(CursorDetector::new()),
             ],
+            installed_versions: Mutex::new(HashMap::new()),
         }
     }
 
     #[cfg(test)]
     pub(crate) fn from_detectors(detectors: Vec<Box<dyn ClientDetector>>) -> Self {
-        Self { detectors }
+        Self {
+            detectors,
+            installed_versions: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Every client reports `version`, so tests never probe the host's real binaries.
+    #[cfg(test)]
+    pub(crate) fn with_installed_version(version: &'static str) -> Self {
+        Self::from_detectors(
+            [
+                ClientKind::ClaudeCode,
+                ClientKind::Codex,
+                ClientKind::Cursor,
+            ]
+            .into_iter()
+            .map(|client| {
+                Box::new(FixedVersionDetector(client, version)) as Box<dyn ClientDetector>
+            })
+            .collect(),
+        )
     }
 
     pub fn all(&self) -> impl Iterator<Item = &dyn ClientDetector> {
@@ -31,16 +68,76 @@ impl DetectorRegistry {
     pub fn find(&self, client: ClientKind) -> Option<&dyn ClientDetector> {
         self.all().find(|detector| detector.client_kind() == client)
     }
+
+    /// Raw `--version` output of the installed client, served from cache for
+    /// [`INSTALLED_VERSION_TTL`] and probed again after that.
+    pub fn installed_version(&self, client: ClientKind) -> Option<String> {
+        if let Some((probed_at, version)) = self.lock_versions().get(&client)
+            && probed_at.elapsed() < INSTALLED_VERSION_TTL
+        {
+            return version.clone();
+        }
+
+        let detection = self.find(client)?.detect(&DetectClientsRequest {
+            include_versions: true,
+        });
+        self.remember_version(&detection);
+        detection.evidence.version
+    }
+
+    /// Refreshes the cached version from a detection that was run with `include_versions`.
+    pub fn remember_version(&self, detection: &ClientDetection) {
+        self.lock_versions().insert(
+            detection.client,
+            (Instant::now(), detection.evidence.version.clone()),
+        );
+    }
+
+    fn lock_versions(&self) -> std::sync::MutexGuard<'_, VersionCache> {
+        self.installed_versions
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
+/// Test detector that always reports the same installed version.
+#[cfg(test)]
+pub(crate) struct FixedVersionDetector(pub ClientKind, pub &'static str);
+
+#[cfg(test)]
+impl ClientDetector for FixedVersionDetector {
+    fn client_kind(&self) -> ClientKind {
+        self.0
+    }
+
+    fn detect(&self, _request: &DetectClientsRequest) -> ClientDetection {
+        use crate::interface::contracts::detect::{DetectionEvidence, DetectionStatus};
+
+        ClientDetection {
+            client: self.0,
+            status: DetectionStatus::Detected,
+            confidence: 100,
+            evidence: DetectionEvidence {
+                binary_path: None,
+                app_path: None,
+                config_path: None,
+                version: Some(self.1.to_string()),
+            },
+            note: String::new(),
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::time::Instant;
+
     use crate::interface::contracts::{
         common::ClientKind,
         detect::{DetectClientsRequest, DetectionStatus},
     };
 
-    use super::DetectorRegistry;
+    use super::{DetectorRegistry, INSTALLED_VERSION_TTL};
 
     #[test]
     fn registry_contains_all_supported_detectors() {
@@ -99,4 +196,28 @@ mod tests {
             ClientKind::Cursor
         );
     }
+
+    #[test]
+    fn installed_version_is_probed_again_once_the_cache_expires() {
+        let registry = DetectorRegistry::with_installed_version("0.47.0");
+        let stale = Instant::now()
+            .checked_sub(INSTALLED_VERSION_TTL)
+            .expect("test host uptime should exceed the cache TTL");
+        registry.lock_versions().insert(
+            ClientKind::Codex,
+            (Instant::now(), Some("codex-cli 0.30.0".to_string())),
+        );
+        registry
+            .lock_versions()
+            .insert(ClientKind::Cursor, (stale, Some("0.30.0".to_string())));
+
+        assert_eq!(
+            registry.installed_version(ClientKind::Codex).as_deref(),
+            Some("codex-cli 0.30.0")
+        );
+        assert_eq!(
+            registry.installed_version(ClientKind::Cursor).as_deref(),
+            Some("0.47.0")
+        );
+    }
 }
@@ -3,6 +3,11 @@ mod clients;
 mod detector_registry;
 mod path_based;
 mod probe;
+mod support_matrix;
 
 pub use client_detector::ClientDetector;
 pub use detector_registry::DetectorRegistry;
+#[cfg(test)]
+pub(crate) use detector_registry::FixedVersionDetector;
+pub use probe::expand_user_path;
+pub use support_matrix::{HostOs, PathCandidateKind, PathCandidateProfile, support_matrix_client};
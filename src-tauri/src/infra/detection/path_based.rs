@@ -19,17 +19,17 @@ pub enum DetectionGate {
     AppInstall,
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub struct PathBasedDetectorConfig {
     pub client: ClientKind,
     pub display_name: &'static str,
     pub kind: DetectorKind,
     pub detection_gate: DetectionGate,
     pub startup_probe_command: Option<&'static str>,
-    pub binary_candidates: &'static [&'static str],
-    pub app_candidates: &'static [&'static str],
-    pub config_override_env_vars: &'static [&'static str],
-    pub config_fallback_paths: &'static [&'static str],
+    pub binary_candidates: Vec<&'static str>,
+    pub app_candidates: Vec<&'static str>,
+    pub config_override_env_vars: Vec<&'static str>,
+    pub config_fallback_paths: Vec<&'static str>,
 }
 
 pub fn evaluate_path_based_detector(
@@ -38,7 +38,7 @@ pub fn evaluate_path_based_detector(
 ) -> ClientDetection {
     let startup_probe_candidates: Vec<&str> = match config.startup_probe_command {
         Some(command) => vec![command],
-        None => config.binary_candidates.to_vec(),
+        None => config.binary_candidates.clone(),
     };
 
     let (cli_found, binary_path, version) = match config.kind {
@@ -46,19 +46,19 @@ pub fn evaluate_path_based_detector(
             let probe = probe_cli_binary(&startup_probe_candidates, request.include_versions);
             let binary_path = probe
                 .binary_path
-                .or_else(|| probe_binary_path(config.binary_candidates));
+                .or_else(|| probe_binary_path(&config.binary_candidates));
             (probe.found, binary_path, probe.version)
         }
     };
-    let app_path = probe_app_path(config.app_candidates);
+    let app_path = probe_app_path(&config.app_candidates);
     let app_found = app_path.is_some();
     let gate_satisfied = match config.detection_gate {
         DetectionGate::CliVersion => cli_found,
         DetectionGate::AppInstall => app_found,
     };
     let config_probe = probe_config_path(
-        config.config_override_env_vars,
-        config.config_fallback_paths,
+        &config.config_override_env_vars,
+        &config.config_fallback_paths,
     );
 
     let (config_path, probe_issue) = match config_probe {
@@ -215,7 +215,12 @@ fn resolve_status_and_note(
 
 #[cfg(test)]
 mod tests {
-    use crate::interface::contracts::{common::ClientKind, detect::DetectionStatus};
+    use std::fs;
+
+    use crate::{
+        infra::detection::support_matrix::{HostOs, support_matrix_client},
+        interface::contracts::{common::ClientKind, detect::DetectionStatus},
+    };
 
     use super::{
         DetectionGate, DetectorKind, PathBasedDetectorConfig, ProbeIssue,
@@ -231,10 +236,10 @@ mod tests {
             kind: DetectorKind::Cli,
             detection_gate: DetectionGate::CliVersion,
             startup_probe_command: None,
-            binary_candidates: &[],
-            app_candidates: &[],
-            config_override_env_vars: &["AI_MANAGER_TEST_INVALID_OVERRIDE"],
-            config_fallback_paths: &[],
+            binary_candidates: vec![],
+            app_candidates: Vec::new(),
+            config_override_env_vars: vec!["AI_MANAGER_TEST_INVALID_OVERRIDE"],
+            config_fallback_paths: vec![],
         };
 
         let (status, confidence, note) = resolve_status_and_note(
@@ -260,10 +265,10 @@ mod tests {
             kind: DetectorKind::Cli,
             detection_gate: DetectionGate::CliVersion,
             startup_probe_command: None,
-            binary_candidates: &[],
-            app_candidates: &[],
-            config_override_env_vars: &["AI_MANAGER_CLAUDE_CODE_MCP_CONFIG"],
-            config_fallback_paths: &[],
+            binary_candidates: vec![],
+            app_candidates: Vec::new(),
+            config_override_env_vars: vec!["AI_MANAGER_CLAUDE_CODE_MCP_CONFIG"],
+            config_fallback_paths: vec![],
         };
 
         let fixtures = vec![
@@ -299,10 +304,10 @@ mod tests {
             kind: DetectorKind::Desktop,
             detection_gate: DetectionGate::AppInstall,
             startup_probe_command: None,
-            binary_candidates: &[],
-            app_candidates: &[],
-            config_override_env_vars: &["AI_MANAGER_CURSOR_MCP_CONFIG"],
-            config_fallback_paths: &[],
+            binary_candidates: vec![],
+            app_candidates: Vec::new(),
+            config_override_env_vars: vec!["AI_MANAGER_CURSOR_MCP_CONFIG"],
+            config_fallback_paths: vec![],
         };
 
         let (status, confidence, note) = resolve_status_and_note(
@@ -327,10 +332,10 @@ mod tests {
             kind: DetectorKind::Desktop,
             detection_gate: DetectionGate::AppInstall,
             startup_probe_command: None,
-            binary_candidates: &[],
-            app_candidates: &[],
-            config_override_env_vars: &["AI_MANAGER_CURSOR_MCP_CONFIG"],
-            config_fallback_paths: &[],
+            binary_candidates: vec![],
+            app_candidates: Vec::new(),
+            config_override_env_vars: vec!["AI_MANAGER_CURSOR_MCP_CONFIG"],
+            config_fallback_paths: vec![],
         };
 
         let fixtures = vec![
@@ -367,10 +372,10 @@ mod tests {
             kind: DetectorKind::Cli,
             detection_gate: DetectionGate::CliVersion,
             startup_probe_command: Some("/definitely/missing/primary-cli"),
-            binary_candidates: &["sh"],
-            app_candidates: &[],
-            config_override_env_vars: &[],
-            config_fallback_paths: &[],
+            binary_candidates: vec!["sh"],
+            app_candidates: Vec::new(),
+            config_override_env_vars: vec![],
+            config_fallback_paths: vec![],
         };
 
         let detection = evaluate_path_based_detector(
@@ -384,4 +389,39 @@ mod tests {
         assert!(detection.evidence.binary_path.is_some());
         assert_eq!(detection.evidence.version, None);
     }
+
+    #[cfg(unix)]
+    #[test]
+    fn app_candidates_are_selected_for_the_host_os() {
+        let temp_dir =
+            std::env::temp_dir().join(format!("ai-manager-os-candidates-{}", std::process::id()));
+        let installed = temp_dir.join("Programs").join("cursor").join("Cursor.exe");
+        fs::create_dir_all(installed.parent().expect("app parent")).expect("should create dir");
+        fs::write(&installed, "").expect("should create app");
+        // Only Windows candidates read LOCALAPPDATA, so no other unix test depends on it.
+        unsafe {
+            std::env::set_var("LOCALAPPDATA", &temp_dir);
+        }
+        let cursor = support_matrix_client(ClientKind::Cursor);
+        let request = DetectClientsRequest {
+            include_versions: false,
+        };
+
+        let win32 = evaluate_path_based_detector(&cursor.detector_config(HostOs::Win32), &request);
+        let linux = evaluate_path_based_detector(&cursor.detector_config(HostOs::Linux), &request);
+        let darwin =
+            evaluate_path_based_detector(&cursor.detector_config(HostOs::Darwin), &request);
+        unsafe {
+            std::env::remove_var("LOCALAPPDATA");
+        }
+        let _ = fs::remove_dir_all(&temp_dir);
+
+        let installed = installed.display().to_string();
+        assert_eq!(win32.evidence.app_path.as_deref(), Some(installed.as_str()));
+        assert_ne!(linux.evidence.app_path.as_deref(), Some(installed.as_str()));
+        assert_ne!(
+            darwin.evidence.app_path.as_deref(),
+            Some(installed.as_str())
+        );
+    }
 }
@@ -1,3 +1,4 @@
+use std::cmp::Ordering;
 use std::env;
 use std::path::{Path, PathBuf};
 use std::process::Command;
@@ -50,14 +51,77 @@ pub fn probe_binary_path(candidates: &[&str]) -> Option<String> {
 pub fn probe_app_path(candidates: &[&str]) -> Option<String> {
     for candidate in candidates {
         let expanded = expand_user_path(candidate);
-        if app_path_exists(&expanded) {
-            return Some(expanded.to_string_lossy().to_string());
+        if let Some(path) = resolve_app_candidate(&expanded) {
+            return Some(path.to_string_lossy().to_string());
         }
     }
 
     None
 }
 
+/// Resolves an app candidate, allowing one `*` in the file name (e.g. versioned AppImages).
+fn resolve_app_candidate(path: &Path) -> Option<PathBuf> {
+    let wildcard = path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .and_then(|name| name.split_once('*'));
+    let Some((prefix, suffix)) = wildcard else {
+        return app_path_exists(path).then(|| path.to_path_buf());
+    };
+
+    std::fs::read_dir(path.parent()?)
+        .ok()?
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter_map(|candidate| {
+            let name = candidate.file_name()?.to_str()?;
+            let matched = name.strip_prefix(prefix)?.strip_suffix(suffix)?.to_string();
+            Some((matched, candidate))
+        })
+        .filter(|(_, candidate)| app_path_exists(candidate))
+        .max_by(|(left, _), (right, _)| compare_natural(left, right))
+        .map(|(_, candidate)| candidate)
+}
+
+/// Orders strings with digit runs compared as numbers, so `1.10` sorts after `1.9`.
+fn compare_natural(left: &str, right: &str) -> Ordering {
+    let mut left = left.chars().peekable();
+    let mut right = right.chars().peekable();
+    loop {
+        let ordering = match (left.peek().copied(), right.peek().copied()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(l), Some(r)) if l.is_ascii_digit() && r.is_ascii_digit() => {
+                let left_run = take_digits(&mut left);
+                let right_run = take_digits(&mut right);
+                let left_run = left_run.trim_start_matches('0');
+                let right_run = right_run.trim_start_matches('0');
+                left_run
+                    .len()
+                    .cmp(&right_run.len())
+                    .then_with(|| left_run.cmp(right_run))
+            }
+            (Some(l), Some(r)) => {
+                left.next();
+                right.next();
+                l.cmp(&r)
+            }
+        };
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+}
+
+fn take_digits(chars: &mut std::iter::Peekable<std::str::Chars<'_>>) -> String {
+    let mut digits = String::new();
+    while let Some(digit) = chars.next_if(char::is_ascii_digit) {
+        digits.push(digit);
+    }
+    digits
+}
+
 pub fn probe_config_path(env_vars: &[&str], fallbacks: &[&str]) -> ConfigProbe {
     let override_value = read_env_value(env_vars);
     probe_config_path_with_override(override_value.as_deref(), fallbacks)
@@ -113,13 +177,22 @@ fn read_env_value(names: &[&str]) -> Option<String> {
         .filter(|value| !value.is_empty())
 }
 
-fn expand_user_path(value: &str) -> PathBuf {
+/// Expands a leading `~/` to the home directory and a leading `%VAR%` to that variable, as
+/// used by support matrix candidates and user-supplied paths.
+pub fn expand_user_path(value: &str) -> PathBuf {
     if let Some(stripped) = value.strip_prefix("~/")
-        && let Some(home) = env::var_os("HOME")
+        && let Some(home) = env::var_os("HOME").or_else(|| env::var_os("USERPROFILE"))
     {
         return PathBuf::from(home).join(stripped);
     }
 
+    if let Some(rest) = value.strip_prefix('%')
+        && let Some((name, remainder)) = rest.split_once('%')
+        && let Some(base) = env::var_os(name)
+    {
+        return PathBuf::from(base).join(remainder.trim_start_matches(['/', '\\']));
+    }
+
     PathBuf::from(value)
 }
 
@@ -453,4 +526,55 @@ mod tests {
 
         assert_eq!(outcome.as_deref(), Some(app_bundle_value.as_str()));
     }
+
+    #[test]
+    fn app_path_probe_expands_wildcard_file_names() {
+        let temp_dir =
+            std::env::temp_dir().join(format!("ai-manager-app-glob-probe-{}", std::process::id()));
+        let _ = fs::create_dir_all(&temp_dir);
+        fs::write(temp_dir.join("Cursor-1.2.0-x86_64.AppImage"), "").expect("appimage stub");
+        fs::write(temp_dir.join("Cursor-1.4.0-x86_64.AppImage"), "").expect("appimage stub");
+        fs::write(temp_dir.join("Cursor.desktop"), "").expect("desktop entry stub");
+
+        let pattern = temp_dir.join("Cursor*.AppImage");
+        let outcome = probe_app_path(&[pattern.to_str().expect("pattern should be utf-8")]);
+
+        let _ = fs::remove_dir_all(&temp_dir);
+
+        assert_eq!(
+            outcome,
+            Some(
+                temp_dir
+                    .join("Cursor-1.4.0-x86_64.AppImage")
+                    .to_string_lossy()
+                    .to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn app_path_probe_orders_wildcard_versions_numerically() {
+        let temp_dir = std::env::temp_dir().join(format!(
+            "ai-manager-app-version-probe-{}",
+            std::process::id()
+        ));
+        let _ = fs::create_dir_all(&temp_dir);
+        fs::write(temp_dir.join("Cursor-1.9.0-x86_64.AppImage"), "").expect("appimage stub");
+        fs::write(temp_dir.join("Cursor-1.10.0-x86_64.AppImage"), "").expect("appimage stub");
+
+        let pattern = temp_dir.join("Cursor*.AppImage");
+        let outcome = probe_app_path(&[pattern.to_str().expect("pattern should be utf-8")]);
+
+        let _ = fs::remove_dir_all(&temp_dir);
+
+        assert_eq!(
+            outcome,
+            Some(
+                temp_dir
+                    .join("Cursor-1.10.0-x86_64.AppImage")
+                    .to_string_lossy()
+                    .to_string()
+            )
+        );
+    }
 }
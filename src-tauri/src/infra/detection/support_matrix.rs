@@ -0,0 +1,346 @@
+use std::sync::OnceLock;
+
+use serde::Deserialize;
+
+use crate::interface::contracts::common::ClientKind;
+
+use super::path_based::{DetectionGate, DetectorKind, PathBasedDetectorConfig};
+
+/// Checked-in copy of `docs/spec/support-matrix.v1.json`, so the crate builds on its own.
+/// `embedded_matrix_matches_the_docs_spec` fails when the two drift apart.
+const SUPPORT_MATRIX_JSON: &str = include_str!("support-matrix.v1.json");
+
+/// Host operating systems, keyed like the `os` field of `support-matrix.v1.json`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HostOs {
+    Darwin,
+    Linux,
+    Win32,
+}
+
+impl HostOs {
+    pub const fn current() -> Self {
+        if cfg!(target_os = "macos") {
+            Self::Darwin
+        } else if cfg!(windows) {
+            Self::Win32
+        } else {
+            Self::Linux
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PathCandidateKind {
+    McpConfig,
+    SkillsDir,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum ClientCategory {
+    Cli,
+    Desktop,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum CandidateRole {
+    HappyPath,
+    Fallback,
+}
+
+#[derive(Debug, Deserialize)]
+struct SupportMatrixDocument {
+    clients: Vec<SupportMatrixClient>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SupportMatrixClient {
+    id: ClientKind,
+    display_name: String,
+    category: ClientCategory,
+    binary_candidates: Vec<BinaryCandidate>,
+    #[serde(default)]
+    app_candidates: Vec<AppCandidate>,
+    config_path_candidates: Vec<PathCandidate>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BinaryCandidate {
+    priority: u32,
+    command: String,
+    role: CandidateRole,
+}
+
+#[derive(Debug, Deserialize)]
+struct AppCandidate {
+    priority: u32,
+    os: Vec<HostOs>,
+    path: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct PathCandidate {
+    kind: PathCandidateKind,
+    priority: u32,
+    os: Vec<HostOs>,
+    path: String,
+    role: CandidateRole,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PathCandidateProfile {
+    pub override_env_vars: Vec<&'static str>,
+    pub fallback_paths: Vec<&'static str>,
+}
+
+/// Returns the support matrix entry for a client; the matrix is embedded at build time.
+pub fn support_matrix_client(client: ClientKind) -> &'static SupportMatrixClient {
+    static CLIENTS: OnceLock<[SupportMatrixClient; 3]> = OnceLock::new();
+
+    let clients = CLIENTS.get_or_init(|| {
+        // `embedded_matrix_loads_every_client` keeps the checked-in copy loadable.
+        load_support_matrix(SUPPORT_MATRIX_JSON).expect("embedded support matrix should load")
+    });
+    &clients[client_slot(client)]
+}
+
+const CLIENT_ORDER: [ClientKind; 3] = [
+    ClientKind::ClaudeCode,
+    ClientKind::Codex,
+    ClientKind::Cursor,
+];
+
+fn client_slot(client: ClientKind) -> usize {
+    match client {
+        ClientKind::ClaudeCode => 0,
+        ClientKind::Codex => 1,
+        ClientKind::Cursor => 2,
+    }
+}
+
+/// Parses a support matrix document and checks that it describes every client kind.
+fn load_support_matrix(json: &str) -> Result<[SupportMatrixClient; 3], String> {
+    let document = serde_json::from_str::<SupportMatrixDocument>(json)
+        .map_err(|error| format!("Support matrix is not valid: {}", error))?;
+
+    let mut slots: [Option<SupportMatrixClient>; 3] = Default::default();
+    for entry in document.clients {
+        let slot = client_slot(entry.id);
+        slots[slot] = Some(entry);
+    }
+    match slots {
+        [Some(claude_code), Some(codex), Some(cursor)] => Ok([claude_code, codex, cursor]),
+        slots => {
+            let missing = CLIENT_ORDER
+                .iter()
+                .zip(slots.iter())
+                .filter(|(_, entry)| entry.is_none())
+                .map(|(client, _)| client.as_str())
+                .collect::<Vec<_>>();
+            Err(format!(
+                "Support matrix does not describe {}.",
+                missing.join(", ")
+            ))
+        }
+    }
+}
+
+impl SupportMatrixClient {
+    pub fn detector_config(&'static self, os: HostOs) -> PathBasedDetectorConfig {
+        let (kind, detection_gate) = match self.category {
+            ClientCategory::Cli => (DetectorKind::Cli, DetectionGate::CliVersion),
+            ClientCategory::Desktop => (DetectorKind::Desktop, DetectionGate::AppInstall),
+        };
+
+        let mut binaries: Vec<&BinaryCandidate> = self.binary_candidates.iter().collect();
+        binaries.sort_by_key(|candidate| candidate.priority);
+        let startup_probe_command = binaries
+            .iter()
+            .find(|candidate| candidate.role == CandidateRole::HappyPath)
+            .map(|candidate| candidate.command.as_str());
+
+        let mut apps: Vec<&AppCandidate> = self
+            .app_candidates
+            .iter()
+            .filter(|candidate| candidate.os.contains(&os))
+            .collect();
+        apps.sort_by_key(|candidate| candidate.priority);
+
+        let mcp_paths = self.path_profile(PathCandidateKind::McpConfig, os);
+
+        PathBasedDetectorConfig {
+            client: self.id,
+            display_name: &self.display_name,
+            kind,
+            detection_gate,
+            startup_probe_command,
+            binary_candidates: binaries
+                .iter()
+                .map(|candidate| candidate.command.as_str())
+                .collect(),
+            app_candidates: apps
+                .iter()
+                .map(|candidate| candidate.path.as_str())
+                .collect(),
+            config_override_env_vars: mcp_paths.override_env_vars,
+            config_fallback_paths: mcp_paths.fallback_paths,
+        }
+    }
+
+    /// Splits `${VAR}` happy-path candidates into override env vars and keeps the remaining
+    /// candidates for `os` as ordered fallbacks.
+    pub fn path_profile(
+        &'static self,
+        kind: PathCandidateKind,
+        os: HostOs,
+    ) -> PathCandidateProfile {
+        let mut candidates: Vec<&PathCandidate> = self
+            .config_path_candidates
+            .iter()
+            .filter(|candidate| candidate.kind == kind && candidate.os.contains(&os))
+            .collect();
+        candidates.sort_by_key(|candidate| candidate.priority);
+
+        let mut profile = PathCandidateProfile {
+            override_env_vars: Vec::new(),
+            fallback_paths: Vec::new(),
+        };
+        for candidate in candidates {
+            let env_var = candidate
+                .path
+                .strip_prefix("${")
+                .and_then(|rest| rest.strip_suffix('}'));
+            match (candidate.role, env_var) {
+                (CandidateRole::HappyPath, Some(env_var)) => {
+                    profile.override_env_vars.push(env_var)
+                }
+                _ => profile.fallback_paths.push(&candidate.path),
+            }
+        }
+
+        profile
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, path::Path};
+
+    use super::{
+        HostOs, PathCandidateKind, SUPPORT_MATRIX_JSON, load_support_matrix, support_matrix_client,
+    };
+    use crate::{
+        domain::profile_for_client,
+        infra::detection::path_based::{DetectionGate, DetectorKind},
+        interface::contracts::common::ClientKind,
+    };
+
+    const CLIENTS: [ClientKind; 3] = [
+        ClientKind::ClaudeCode,
+        ClientKind::Codex,
+        ClientKind::Cursor,
+    ];
+    const HOSTS: [HostOs; 3] = [HostOs::Darwin, HostOs::Linux, HostOs::Win32];
+
+    #[test]
+    fn matrix_agrees_with_client_profiles_on_every_os() {
+        for client in CLIENTS {
+            let entry = support_matrix_client(client);
+
+            for os in HOSTS {
+                let config = entry.detector_config(os);
+                assert_eq!(config.client, client);
+                assert_eq!(config.display_name, profile_for_client(client).display_name);
+                assert!(config.startup_probe_command.is_some(), "{client:?}/{os:?}");
+                assert_eq!(
+                    config.config_override_env_vars.len(),
+                    1,
+                    "{client:?}/{os:?}"
+                );
+                assert!(
+                    !config.config_fallback_paths.is_empty(),
+                    "{client:?}/{os:?}"
+                );
+                if config.detection_gate == DetectionGate::AppInstall {
+                    assert!(!config.app_candidates.is_empty(), "{client:?}/{os:?}");
+                }
+
+                let skills = entry.path_profile(PathCandidateKind::SkillsDir, os);
+                assert_eq!(skills.override_env_vars.len(), 1, "{client:?}/{os:?}");
+                assert!(!skills.fallback_paths.is_empty(), "{client:?}/{os:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn detector_configs_follow_matrix_candidate_order() {
+        let claude = support_matrix_client(ClientKind::ClaudeCode).detector_config(HostOs::Darwin);
+        assert_eq!(claude.kind, DetectorKind::Cli);
+        assert_eq!(claude.startup_probe_command, Some("claude"));
+        assert_eq!(claude.binary_candidates, vec!["claude", "claude-code"]);
+        assert_eq!(
+            claude.config_override_env_vars,
+            vec!["AI_MANAGER_CLAUDE_CODE_MCP_CONFIG"]
+        );
+        assert_eq!(
+            claude.config_fallback_paths,
+            vec!["~/.claude.json", "~/.claude/claude_code_config.json"]
+        );
+
+        let cursor = support_matrix_client(ClientKind::Cursor);
+        assert_eq!(
+            cursor.detector_config(HostOs::Darwin).app_candidates,
+            vec!["/Applications/Cursor.app", "~/Applications/Cursor.app"]
+        );
+        assert!(
+            cursor
+                .detector_config(HostOs::Linux)
+                .app_candidates
+                .contains(&"~/Applications/Cursor*.AppImage")
+        );
+        assert_eq!(
+            cursor
+                .path_profile(PathCandidateKind::McpConfig, HostOs::Win32)
+                .fallback_paths,
+            vec!["%APPDATA%/Cursor/User/mcp.json"]
+        );
+    }
+
+    #[test]
+    fn embedded_matrix_loads_every_client() {
+        let clients =
+            load_support_matrix(SUPPORT_MATRIX_JSON).expect("embedded matrix should load");
+
+        assert_eq!(clients.map(|entry| entry.id), CLIENTS);
+    }
+
+    #[test]
+    fn embedded_matrix_matches_the_docs_spec() {
+        let spec = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("..")
+            .join("docs")
+            .join("spec")
+            .join("support-matrix.v1.json");
+        let spec = fs::read_to_string(&spec).expect("docs spec should be readable");
+
+        assert_eq!(
+            SUPPORT_MATRIX_JSON, spec,
+            "copy docs/spec/support-matrix.v1.json to src/infra/detection/"
+        );
+    }
+
+    #[test]
+    fn matrix_without_every_client_is_rejected() {
+        let error = load_support_matrix(r#"{ "clients": [] }"#)
+            .expect_err("an empty matrix should be rejected");
+        let invalid = load_support_matrix("{").err();
+
+        assert!(error.contains("claude_code, codex, cursor"));
+        assert!(invalid.is_some());
+    }
+}
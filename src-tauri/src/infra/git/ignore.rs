@@ -0,0 +1,228 @@
+/// One pattern line from a `.gitignore` or `info/exclude` file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct IgnoreRule {
+    /// Directory of the file that declared the rule, relative to the work tree.
+    base: String,
+    pattern: Vec<char>,
+    negated: bool,
+    directory_only: bool,
+    /// Patterns containing a `/` match the whole path below `base`, others the file name.
+    anchored: bool,
+}
+
+/// Ignore rules in precedence order: later rules override earlier ones.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IgnoreRules {
+    rules: Vec<IgnoreRule>,
+}
+
+impl IgnoreRules {
+    /// Adds the rules in `content`, declared by the ignore file in `base` (`""` for the
+    /// work tree root).
+    pub fn add_file(&mut self, base: &str, content: &str) {
+        self.rules.extend(
+            content
+                .lines()
+                .filter_map(|line| parse_rule(base.trim_matches('/'), line)),
+        );
+    }
+
+    /// Whether `relative` (work-tree relative, `/`-separated) is ignored. A path inside an
+    /// ignored directory stays ignored even if a later rule re-includes it, as in Git.
+    pub fn is_ignored(&self, relative: &str, is_dir: bool) -> bool {
+        let components = relative
+            .split('/')
+            .filter(|component| !component.is_empty())
+            .collect::<Vec<_>>();
+
+        for depth in 1..=components.len() {
+            let prefix = components[..depth].join("/");
+            let is_last = depth == components.len();
+            let ignored = self.last_match(&prefix, !is_last || is_dir);
+            if is_last {
+                return ignored;
+            }
+            if ignored {
+                return true;
+            }
+        }
+        false
+    }
+
+    fn last_match(&self, path: &str, is_dir: bool) -> bool {
+        self.rules
+            .iter()
+            .rev()
+            .find(|rule| rule.matches(path, is_dir))
+            .is_some_and(|rule| !rule.negated)
+    }
+}
+
+impl IgnoreRule {
+    fn matches(&self, path: &str, is_dir: bool) -> bool {
+        if self.directory_only && !is_dir {
+            return false;
+        }
+        let below_base = if self.base.is_empty() {
+            path
+        } else {
+            match path
+                .strip_prefix(self.base.as_str())
+                .and_then(|rest| rest.strip_prefix('/'))
+            {
+                Some(rest) => rest,
+                None => return false,
+            }
+        };
+        let subject = if self.anchored {
+            below_base
+        } else {
+            below_base.rsplit('/').next().unwrap_or(below_base)
+        };
+
+        glob_match(&self.pattern, &subject.chars().collect::<Vec<_>>())
+    }
+}
+
+fn parse_rule(base: &str, line: &str) -> Option<IgnoreRule> {
+    let line = line.strip_suffix('\r').unwrap_or(line);
+    if line.is_empty() || line.starts_with('#') {
+        return None;
+    }
+
+    // Trailing spaces are dropped unless escaped; `\#`, `\!` and `\ ` stay escaped for
+    // `glob_match`.
+    let trimmed = line.trim_end_matches(' ');
+    let line = if trimmed.ends_with('\\') && trimmed.len() < line.len() {
+        &line[..=trimmed.len()]
+    } else {
+        trimmed
+    };
+    let (negated, line) = match line.strip_prefix('!') {
+        Some(rest) => (true, rest),
+        None => (false, line),
+    };
+
+    let (directory_only, line) = match line.strip_suffix('/') {
+        Some(rest) => (true, rest),
+        None => (false, line),
+    };
+    let anchored = line.contains('/');
+    let pattern = line.strip_prefix('/').unwrap_or(line);
+    if pattern.is_empty() {
+        return None;
+    }
+
+    Some(IgnoreRule {
+        base: base.to_string(),
+        pattern: pattern.chars().collect(),
+        negated,
+        directory_only,
+        anchored,
+    })
+}
+
+/// Git wildmatch: `*` and `?` stop at `/`, `**` between slashes spans directories.
+fn glob_match(pattern: &[char], text: &[char]) -> bool {
+    match pattern {
+        [] => text.is_empty(),
+        ['*', '*', '/', rest @ ..] => {
+            glob_match(rest, text)
+                || text
+                    .iter()
+                    .enumerate()
+                    .any(|(index, ch)| *ch == '/' && glob_match(rest, &text[index + 1..]))
+        }
+        ['*', '*', rest @ ..] => (0..=text.len()).any(|index| glob_match(rest, &text[index..])),
+        ['*', rest @ ..] => {
+            for index in 0..=text.len() {
+                if glob_match(rest, &text[index..]) {
+                    return true;
+                }
+                if text.get(index) == Some(&'/') {
+                    break;
+                }
+            }
+            false
+        }
+        ['?', rest @ ..] => {
+            matches!(text.first(), Some(ch) if *ch != '/') && glob_match(rest, &text[1..])
+        }
+        ['[', rest @ ..] => match (text.first(), parse_class(rest)) {
+            (Some(ch), Some((matches_class, consumed))) => {
+                *ch != '/' && matches_class(*ch) && glob_match(&rest[consumed..], &text[1..])
+            }
+            // An unterminated class is a literal `[`.
+            (Some('['), None) => glob_match(rest, &text[1..]),
+            _ => false,
+        },
+        ['\\', escaped, rest @ ..] => text.first() == Some(escaped) && glob_match(rest, &text[1..]),
+        [literal, rest @ ..] => text.first() == Some(literal) && glob_match(rest, &text[1..]),
+    }
+}
+
+/// Parses the body of a `[...]` class, returning a matcher and the characters consumed
+/// including the closing `]`.
+fn parse_class(pattern: &[char]) -> Option<(impl Fn(char) -> bool, usize)> {
+    let mut index = 0;
+    let negated = matches!(pattern.first(), Some('!' | '^'));
+    if negated {
+        index += 1;
+    }
+
+    let mut ranges = Vec::new();
+    let mut first = true;
+    loop {
+        let start = *pattern.get(index)?;
+        if start == ']' && !first {
+            break;
+        }
+        first = false;
+        if pattern.get(index + 1) == Some(&'-')
+            && let Some(end) = pattern.get(index + 2).filter(|end| **end != ']')
+        {
+            ranges.push((start, *end));
+            index += 3;
+        } else {
+            ranges.push((start, start));
+            index += 1;
+        }
+    }
+
+    let matcher = move |ch: char| {
+        ranges
+            .iter()
+            .any(|(start, end)| (*start..=*end).contains(&ch))
+            != negated
+    };
+    Some((matcher, index + 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::IgnoreRules;
+
+    #[test]
+    fn follows_git_precedence_anchoring_and_directory_rules() {
+        let mut rules = IgnoreRules::default();
+        rules.add_file(
+            "",
+            "# local files\n.env*\n!.env.example\nbuild/\n/.cursor/mcp.json\n",
+        );
+        rules.add_file("packages/app", "*.local.json\n**/secrets/**\n");
+        rules.add_file("", "!build/keep.txt\n");
+
+        assert!(rules.is_ignored(".env", false));
+        assert!(rules.is_ignored("packages/.env.local", false));
+        assert!(!rules.is_ignored(".env.example", false));
+        assert!(rules.is_ignored(".cursor/mcp.json", false));
+        assert!(!rules.is_ignored("packages/.cursor/mcp.json", false));
+        assert!(rules.is_ignored("packages/app/config/settings.local.json", false));
+        assert!(!rules.is_ignored("packages/other/settings.local.json", false));
+        assert!(rules.is_ignored("packages/app/a/secrets/token.txt", false));
+        assert!(!rules.is_ignored("build", false));
+        // Files inside an ignored directory cannot be re-included.
+        assert!(rules.is_ignored("build/keep.txt", false));
+        assert!(!rules.is_ignored(".mcp.json", false));
+    }
+}
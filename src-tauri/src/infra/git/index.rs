@@ -0,0 +1,136 @@
+use std::collections::BTreeSet;
+
+const SIGNATURE: &[u8; 4] = b"DIRC";
+const HEADER_LEN: usize = 12;
+/// ctime, mtime, dev, ino, mode, uid, gid and size, before the object id.
+const STAT_LEN: usize = 40;
+const FLAGS_LEN: usize = 2;
+const EXTENDED_FLAG: u16 = 0x4000;
+
+/// Reads the paths recorded in a `.git/index` file (versions 2 to 4), relative to the work
+/// tree and `/`-separated. Returns `None` when the file is not a readable index.
+pub fn parse_index_paths(content: &[u8], object_id_len: usize) -> Option<BTreeSet<String>> {
+    if content.len() < HEADER_LEN || &content[..4] != SIGNATURE {
+        return None;
+    }
+    let version = read_u32(content, 4)?;
+    if !(2..=4).contains(&version) {
+        return None;
+    }
+    let entry_count = read_u32(content, 8)?;
+
+    let mut paths = BTreeSet::new();
+    let mut previous = Vec::new();
+    let mut offset = HEADER_LEN;
+    for _ in 0..entry_count {
+        let flags_offset = offset + STAT_LEN + object_id_len;
+        let flags = u16::from_be_bytes(
+            content
+                .get(flags_offset..flags_offset + FLAGS_LEN)?
+                .try_into()
+                .ok()?,
+        );
+        let mut path_offset = flags_offset + FLAGS_LEN;
+        if version >= 3 && flags & EXTENDED_FLAG != 0 {
+            path_offset += FLAGS_LEN;
+        }
+
+        let path = if version == 4 {
+            let (strip, suffix_offset) = read_varint(content, path_offset)?;
+            let suffix_len = content
+                .get(suffix_offset..)?
+                .iter()
+                .position(|byte| *byte == 0)?;
+            let keep = previous.len().checked_sub(usize::try_from(strip).ok()?)?;
+            previous.truncate(keep);
+            previous.extend_from_slice(&content[suffix_offset..suffix_offset + suffix_len]);
+            offset = suffix_offset + suffix_len + 1;
+            previous.clone()
+        } else {
+            let path_len = content
+                .get(path_offset..)?
+                .iter()
+                .position(|byte| *byte == 0)?;
+            let path = content[path_offset..path_offset + path_len].to_vec();
+            // Entries are NUL-padded to a multiple of eight bytes.
+            let entry_len = path_offset - offset + path_len;
+            offset += (entry_len + 8) & !7;
+            path
+        };
+
+        paths.insert(String::from_utf8_lossy(&path).into_owned());
+    }
+
+    Some(paths)
+}
+
+fn read_u32(content: &[u8], offset: usize) -> Option<u32> {
+    Some(u32::from_be_bytes(
+        content.get(offset..offset + 4)?.try_into().ok()?,
+    ))
+}
+
+/// Git's offset encoding: each continuation byte adds one before shifting.
+fn read_varint(content: &[u8], offset: usize) -> Option<(u64, usize)> {
+    let mut index = offset;
+    let mut byte = *content.get(index)?;
+    let mut value = u64::from(byte & 0x7f);
+    while byte & 0x80 != 0 {
+        index += 1;
+        byte = *content.get(index)?;
+        value = ((value + 1) << 7) | u64::from(byte & 0x7f);
+    }
+    Some((value, index + 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_index_paths;
+
+    fn entry(path: &str, version: u32) -> Vec<u8> {
+        let mut bytes = vec![0_u8; 40 + 20];
+        bytes.extend_from_slice(&(path.len() as u16).to_be_bytes());
+        if version == 4 {
+            return bytes;
+        }
+        bytes.extend_from_slice(path.as_bytes());
+        let padded = (bytes.len() + 8) & !7;
+        bytes.resize(padded, 0);
+        bytes
+    }
+
+    fn index(version: u32, entries: &[Vec<u8>]) -> Vec<u8> {
+        let mut bytes = b"DIRC".to_vec();
+        bytes.extend_from_slice(&version.to_be_bytes());
+        bytes.extend_from_slice(&(entries.len() as u32).to_be_bytes());
+        for entry in entries {
+            bytes.extend_from_slice(entry);
+        }
+        bytes
+    }
+
+    #[test]
+    fn reads_padded_and_prefix_compressed_paths() {
+        let v2 = index(2, &[entry(".mcp.json", 2), entry("src/main.rs", 2)]);
+
+        let mut first = entry(".cursor/mcp.json", 4);
+        first.extend_from_slice(b"\0.cursor/mcp.json\0");
+        let mut second = entry(".cursor/rules", 4);
+        // Strip "mcp.json" (8 bytes) from the previous path and append "rules".
+        second.extend_from_slice(b"\x08rules\0");
+        let v4 = index(4, &[first, second]);
+
+        assert_eq!(
+            parse_index_paths(&v2, 20).map(|paths| paths.into_iter().collect::<Vec<_>>()),
+            Some(vec![".mcp.json".to_string(), "src/main.rs".to_string()])
+        );
+        assert_eq!(
+            parse_index_paths(&v4, 20).map(|paths| paths.into_iter().collect::<Vec<_>>()),
+            Some(vec![
+                ".cursor/mcp.json".to_string(),
+                ".cursor/rules".to_string()
+            ])
+        );
+        assert_eq!(parse_index_paths(b"not an index", 20), None);
+    }
+}
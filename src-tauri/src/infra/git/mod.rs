@@ -0,0 +1,5 @@
+mod ignore;
+mod index;
+mod repository;
+
+pub use repository::{GitPathStatus, GitRepository};
@@ -0,0 +1,313 @@
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+};
+
+use super::{ignore::IgnoreRules, index::parse_index_paths};
+use crate::infra::detection::expand_user_path;
+
+const SHA1_LEN: usize = 20;
+const SHA256_LEN: usize = 32;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GitPathStatus {
+    /// Recorded in the index; the next commit includes its changes.
+    Tracked,
+    Ignored,
+    /// Neither tracked nor ignored, so a plain `git add .` would pick it up.
+    Untracked,
+}
+
+/// A Git work tree found on disk, inspected without a `git` binary.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GitRepository {
+    work_tree: PathBuf,
+    git_dir: PathBuf,
+    /// Holds `config` and `info/`; differs from `git_dir` in linked worktrees.
+    common_dir: PathBuf,
+}
+
+impl GitRepository {
+    /// Finds the repository containing `path` by walking up to the nearest `.git`
+    /// directory, or `.git` file as used by worktrees and submodules.
+    pub fn discover(path: &Path) -> Option<Self> {
+        let start = fs::canonicalize(path).ok()?;
+        start.ancestors().find_map(|directory| {
+            let marker = directory.join(".git");
+            let git_dir = if marker.is_dir() {
+                marker
+            } else if marker.is_file() {
+                let pointer = fs::read_to_string(&marker).ok()?;
+                directory.join(pointer.trim().strip_prefix("gitdir:")?.trim())
+            } else {
+                return None;
+            };
+
+            let common_dir = match fs::read_to_string(git_dir.join("commondir")) {
+                Ok(pointer) => git_dir.join(pointer.trim()),
+                Err(_) => git_dir.clone(),
+            };
+
+            Some(Self {
+                work_tree: directory.to_path_buf(),
+                git_dir,
+                common_dir,
+            })
+        })
+    }
+
+    /// Whether `path` is recorded in the index, i.e. committed or staged.
+    pub fn is_tracked(&self, path: &Path) -> io::Result<bool> {
+        let Some(relative) = self.relative_path(path) else {
+            return Ok(false);
+        };
+        let content = match fs::read(self.git_dir.join("index")) {
+            Ok(content) => content,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(false),
+            Err(error) => return Err(error),
+        };
+        let paths = parse_index_paths(&content, self.object_id_len()).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("'{}' is not a readable Git index.", self.git_dir.display()),
+            )
+        })?;
+
+        Ok(paths.contains(&relative))
+    }
+
+    /// Tracked files are never ignored, matching Git; everything else is checked against
+    /// `core.excludesFile`, `info/exclude` and the `.gitignore` files between the work tree
+    /// and `path`.
+    pub fn status(&self, path: &Path) -> io::Result<GitPathStatus> {
+        if self.is_tracked(path)? {
+            return Ok(GitPathStatus::Tracked);
+        }
+        let Some(relative) = self.relative_path(path) else {
+            return Ok(GitPathStatus::Untracked);
+        };
+
+        let mut rules = IgnoreRules::default();
+        if let Some(content) = self
+            .excludes_file()
+            .and_then(|path| fs::read_to_string(path).ok())
+        {
+            rules.add_file("", &content);
+        }
+        if let Ok(content) = fs::read_to_string(self.common_dir.join("info").join("exclude")) {
+            rules.add_file("", &content);
+        }
+        let mut base = String::new();
+        for component in relative.split('/') {
+            if let Ok(content) = fs::read_to_string(self.work_tree.join(&base).join(".gitignore")) {
+                rules.add_file(&base, &content);
+            }
+            if !base.is_empty() {
+                base.push('/');
+            }
+            base.push_str(component);
+        }
+
+        Ok(if rules.is_ignored(&relative, path.is_dir()) {
+            GitPathStatus::Ignored
+        } else {
+            GitPathStatus::Untracked
+        })
+    }
+
+    /// `path` relative to the work tree, `/`-separated as Git stores it.
+    pub fn relative_path(&self, path: &Path) -> Option<String> {
+        // Files about to be created have no canonical path yet; their directory does.
+        let absolute = match fs::canonicalize(path) {
+            Ok(absolute) => absolute,
+            Err(_) => fs::canonicalize(path.parent()?)
+                .ok()?
+                .join(path.file_name()?),
+        };
+        let relative = absolute.strip_prefix(&self.work_tree).ok()?;
+        let components = relative
+            .components()
+            .map(|component| component.as_os_str().to_string_lossy().into_owned())
+            .collect::<Vec<_>>();
+
+        (!components.is_empty()).then(|| components.join("/"))
+    }
+
+    fn object_id_len(&self) -> usize {
+        let uses_sha256 = fs::read_to_string(self.common_dir.join("config")).is_ok_and(|config| {
+            config.lines().any(|line| {
+                let line = line.trim().to_ascii_lowercase();
+                line.starts_with("objectformat")
+                    && line.split('=').nth(1).map(str::trim) == Some("sha256")
+            })
+        });
+        if uses_sha256 { SHA256_LEN } else { SHA1_LEN }
+    }
+
+    /// `core.excludesFile` from the repository config, `~/.gitconfig` or the XDG config,
+    /// in Git's order of precedence, else Git's default `$XDG_CONFIG_HOME/git/ignore`.
+    fn excludes_file(&self) -> Option<PathBuf> {
+        let home = env::var_os("HOME").map(PathBuf::from);
+        let config_home = xdg_config_home(env::var_os("XDG_CONFIG_HOME"), home.clone());
+        let configs = [
+            Some(self.common_dir.join("config")),
+            home.map(|home| home.join(".gitconfig")),
+            config_home
+                .as_ref()
+                .map(|base| base.join("git").join("config")),
+        ];
+
+        configs
+            .into_iter()
+            .flatten()
+            .filter_map(|path| fs::read_to_string(path).ok())
+            .find_map(|content| config_value(&content, "core", "excludesfile"))
+            .map(|value| expand_user_path(&value))
+            .or_else(|| config_home.map(|base| base.join("git").join("ignore")))
+    }
+}
+
+fn xdg_config_home(
+    xdg_config_home: Option<std::ffi::OsString>,
+    home: Option<PathBuf>,
+) -> Option<PathBuf> {
+    xdg_config_home
+        .filter(|value| !value.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| home.map(|home| home.join(".config")))
+}
+
+/// Last value of `key` in `[section]` of a Git config file; names are case-insensitive.
+fn config_value(content: &str, section: &str, key: &str) -> Option<String> {
+    let mut in_section = false;
+    let mut value = None;
+    for line in content.lines().map(str::trim) {
+        if let Some(header) = line.strip_prefix('[') {
+            let name = header.split([']', ' ', '"']).next().unwrap_or_default();
+            in_section = name.eq_ignore_ascii_case(section);
+            continue;
+        }
+        let Some((name, raw)) = line.split_once('=') else {
+            continue;
+        };
+        if in_section && name.trim().eq_ignore_ascii_case(key) {
+            value = Some(raw.trim().trim_matches('"').to_string());
+        }
+    }
+    value
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use std::path::PathBuf;
+
+    use super::{GitPathStatus, GitRepository, xdg_config_home};
+
+    #[test]
+    fn discovers_work_trees_from_git_directories_and_worktree_pointers() {
+        let temp_root =
+            std::env::temp_dir().join(format!("ai-manager-git-repository-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&temp_root);
+        let main = temp_root.join("main");
+        let linked = temp_root.join("linked");
+        fs::create_dir_all(main.join(".git")).expect("should create git dir");
+        fs::create_dir_all(main.join(".cursor")).expect("should create project dir");
+        fs::write(main.join(".cursor").join(".gitignore"), "mcp.json\n")
+            .expect("should write nested gitignore");
+        fs::create_dir_all(&linked).expect("should create linked worktree");
+        fs::write(linked.join(".git"), "gitdir: ../main/.git\n").expect("should write pointer");
+        let outside = temp_root.join("outside");
+        fs::create_dir_all(&outside).expect("should create outside dir");
+
+        let repository = GitRepository::discover(&main.join(".cursor"));
+        let relative = repository.as_ref().and_then(|repository| {
+            repository.relative_path(&main.join(".cursor").join("mcp.json"))
+        });
+        let tracked = repository
+            .as_ref()
+            .map(|repository| repository.is_tracked(&main.join(".mcp.json")));
+        let statuses = repository.as_ref().map(|repository| {
+            (
+                repository
+                    .status(&main.join(".cursor").join("mcp.json"))
+                    .ok(),
+                repository.status(&main.join(".mcp.json")).ok(),
+            )
+        });
+        let linked_repository = GitRepository::discover(&linked);
+        let outside_repository = GitRepository::discover(&outside);
+        let _ = fs::remove_dir_all(&temp_root);
+
+        assert_eq!(relative.as_deref(), Some(".cursor/mcp.json"));
+        assert!(matches!(tracked, Some(Ok(false))));
+        assert_eq!(
+            statuses,
+            Some((Some(GitPathStatus::Ignored), Some(GitPathStatus::Untracked)))
+        );
+        assert!(linked_repository.is_some());
+        assert_ne!(outside_repository, repository);
+    }
+
+    #[test]
+    fn linked_worktrees_read_excludes_and_config_from_the_common_dir() {
+        let temp_root =
+            std::env::temp_dir().join(format!("ai-manager-git-worktree-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&temp_root);
+        let main_git = temp_root.join("main").join(".git");
+        let worktree_git = main_git.join("worktrees").join("linked");
+        let linked = temp_root.join("linked");
+        fs::create_dir_all(main_git.join("info")).expect("should create info dir");
+        fs::create_dir_all(&worktree_git).expect("should create worktree git dir");
+        fs::create_dir_all(&linked).expect("should create linked worktree");
+        fs::write(main_git.join("info").join("exclude"), "secrets.json\n")
+            .expect("should write exclude");
+        let global_ignore = temp_root.join("global-ignore");
+        fs::write(&global_ignore, "*.local.json\n").expect("should write global ignore");
+        fs::write(
+            main_git.join("config"),
+            format!(
+                "[core]\n\tbare = false\n\texcludesFile = \"{}\"\n",
+                global_ignore.display()
+            ),
+        )
+        .expect("should write config");
+        fs::write(worktree_git.join("commondir"), "../..\n").expect("should write commondir");
+        fs::write(
+            linked.join(".git"),
+            "gitdir: ../main/.git/worktrees/linked\n",
+        )
+        .expect("should write pointer");
+
+        let statuses = GitRepository::discover(&linked).map(|repository| {
+            [
+                repository.status(&linked.join("secrets.json")).ok(),
+                repository.status(&linked.join("settings.local.json")).ok(),
+                repository.status(&linked.join("mcp.json")).ok(),
+            ]
+        });
+        let _ = fs::remove_dir_all(&temp_root);
+
+        assert_eq!(
+            statuses,
+            Some([
+                Some(GitPathStatus::Ignored),
+                Some(GitPathStatus::Ignored),
+                Some(GitPathStatus::Untracked),
+            ])
+        );
+    }
+
+    #[test]
+    fn xdg_config_home_falls_back_to_dot_config() {
+        assert_eq!(
+            xdg_config_home(Some("/xdg".into()), Some(PathBuf::from("/home/me"))),
+            Some(PathBuf::from("/xdg"))
+        );
+        assert_eq!(
+            xdg_config_home(Some("".into()), Some(PathBuf::from("/home/me"))),
+            Some(PathBuf::from("/home/me/.config"))
+        );
+    }
+}
@@ -0,0 +1,31 @@
+use std::{collections::BTreeMap, sync::RwLock};
+
+use crate::interface::contracts::inventory::McpServerInventory;
+
+/// In-memory cache of the last successful probe per MCP resource id.
+#[derive(Default)]
+pub struct McpInventoryStore {
+    entries: RwLock<BTreeMap<String, McpServerInventory>>,
+}
+
+impl McpInventoryStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record(&self, inventory: McpServerInventory) {
+        let mut entries = match self.entries.write() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        entries.insert(inventory.resource_id.clone(), inventory);
+    }
+
+    pub fn get(&self, resource_id: &str) -> Option<McpServerInventory> {
+        let entries = match self.entries.read() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        entries.get(resource_id).cloned()
+    }
+}
@@ -0,0 +1,3 @@
+mod mcp_inventory_store;
+
+pub use mcp_inventory_store::McpInventoryStore;
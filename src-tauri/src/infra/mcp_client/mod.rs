@@ -0,0 +1,5 @@
+mod remote_probe;
+mod stdio_probe;
+
+pub use remote_probe::{RemoteLaunch, RemoteProbeFailure, RemoteTransport, probe_remote_server};
+pub use stdio_probe::{StdioLaunch, StdioProbeFailure, probe_stdio_server};
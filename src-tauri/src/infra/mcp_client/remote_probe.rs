@@ -0,0 +1,626 @@
+use std::{
+    collections::BTreeMap,
+    io::{self, BufRead, BufReader, Read},
+    net::ToSocketAddrs,
+    sync::mpsc,
+    thread,
+    time::{Duration, Instant},
+};
+
+use serde_json::{Value, json};
+use ureq::{Agent, http::Uri};
+
+use super::stdio_probe::PROBE_PROTOCOL_VERSION;
+
+const MAX_BODY_BYTES: u64 = 1024 * 1024;
+const MAX_SSE_EVENTS: usize = 64;
+const MAX_REDIRECTS: u32 = 3;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RemoteLaunch {
+    pub url: String,
+    pub headers: BTreeMap<String, String>,
+    pub timeout: Duration,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RemoteTransport {
+    StreamableHttp,
+    Sse,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RemoteHandshake {
+    pub transport: RemoteTransport,
+    pub protocol_version: Option<String>,
+    pub server_name: Option<String>,
+    pub server_version: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RemoteProbeFailure {
+    InvalidUrl(String),
+    Dns(String),
+    Connect(String),
+    Tls(String),
+    TimedOut {
+        stage: &'static str,
+    },
+    HttpStatus {
+        stage: &'static str,
+        status: u16,
+    },
+    Protocol {
+        stage: &'static str,
+        message: String,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RemoteProbeReport {
+    pub outcome: Result<RemoteHandshake, RemoteProbeFailure>,
+    pub resolved_addresses: Vec<String>,
+    pub http_status: Option<u16>,
+    pub elapsed: Duration,
+}
+
+/// Resolves the endpoint host, then runs `initialize` over streamable HTTP, falling back to the
+/// legacy SSE transport when the endpoint rejects JSON-RPC posts.
+pub fn probe_remote_server(launch: &RemoteLaunch) -> RemoteProbeReport {
+    let started = Instant::now();
+    let mut report = RemoteProbeReport {
+        outcome: Err(RemoteProbeFailure::TimedOut { stage: "resolve" }),
+        resolved_addresses: Vec::new(),
+        http_status: None,
+        elapsed: Duration::ZERO,
+    };
+
+    report.outcome = run_probe(launch, started + launch.timeout, &mut report);
+    report.elapsed = started.elapsed();
+    report
+}
+
+fn run_probe(
+    launch: &RemoteLaunch,
+    deadline: Instant,
+    report: &mut RemoteProbeReport,
+) -> Result<RemoteHandshake, RemoteProbeFailure> {
+    let uri = launch
+        .url
+        .parse::<Uri>()
+        .map_err(|error| RemoteProbeFailure::InvalidUrl(error.to_string()))?;
+    let default_port = match uri.scheme_str() {
+        Some("http") => 80,
+        Some("https") => 443,
+        _ => {
+            return Err(RemoteProbeFailure::InvalidUrl(format!(
+                "'{}' is not an http(s) URL",
+                launch.url
+            )));
+        }
+    };
+    let Some(host) = uri.host() else {
+        return Err(RemoteProbeFailure::InvalidUrl(format!(
+            "'{}' has no host",
+            launch.url
+        )));
+    };
+
+    report.resolved_addresses = resolve(host, uri.port_u16().unwrap_or(default_port), deadline)?;
+
+    let agent: Agent = Agent::config_builder()
+        .http_status_as_error(false)
+        .max_redirects(MAX_REDIRECTS)
+        .build()
+        .into();
+    let initialize = initialize_message();
+
+    let mut response = post_json(&agent, &launch.url, launch, &initialize, deadline)
+        .map_err(|error| classify(error, "initialize"))?;
+    let status = response.status().as_u16();
+    report.http_status = Some(status);
+
+    if (200..300).contains(&status) {
+        let event_stream = is_event_stream(&response);
+        let mut body = BufReader::new(response.body_mut().as_reader().take(MAX_BODY_BYTES));
+        let message = if event_stream {
+            await_sse_response(&mut body, "initialize")?
+        } else {
+            let mut text = String::new();
+            body.read_to_string(&mut text)
+                .map_err(|error| classify_io(error, "initialize"))?;
+            parse_json(&text, "initialize")?
+        };
+        return handshake_from(message, RemoteTransport::StreamableHttp, "initialize");
+    }
+    if !matches!(status, 400 | 404 | 405) {
+        return Err(RemoteProbeFailure::HttpStatus {
+            stage: "initialize",
+            status,
+        });
+    }
+
+    probe_legacy_sse(&agent, &uri, launch, &initialize, deadline, report)
+}
+
+fn probe_legacy_sse(
+    agent: &Agent,
+    uri: &Uri,
+    launch: &RemoteLaunch,
+    initialize: &Value,
+    deadline: Instant,
+    report: &mut RemoteProbeReport,
+) -> Result<RemoteHandshake, RemoteProbeFailure> {
+    let mut request = agent.get(&launch.url).header("accept", "text/event-stream");
+    for (name, value) in &launch.headers {
+        request = request.header(name, value);
+    }
+    let mut stream = request
+        .config()
+        .timeout_global(Some(remaining(deadline)))
+        .build()
+        .call()
+        .map_err(|error| classify(error, "sse stream"))?;
+    let status = stream.status().as_u16();
+    report.http_status = Some(status);
+    if !(200..300).contains(&status) || !is_event_stream(&stream) {
+        return Err(RemoteProbeFailure::HttpStatus {
+            stage: "sse stream",
+            status,
+        });
+    }
+
+    let mut events = BufReader::new(stream.body_mut().as_reader().take(MAX_BODY_BYTES));
+    let endpoint = loop {
+        match next_sse_event(&mut events).map_err(|error| classify_io(error, "sse endpoint"))? {
+            Some((event, data)) if event == "endpoint" => break resolve_endpoint(uri, &data),
+            Some(_) => continue,
+            None => {
+                return Err(RemoteProbeFailure::Protocol {
+                    stage: "sse endpoint",
+                    message: "stream closed before announcing a message endpoint".to_string(),
+                });
+            }
+        }
+    };
+
+    let posted = post_json(agent, &endpoint, launch, initialize, deadline)
+        .map_err(|error| classify(error, "initialize"))?;
+    let status = posted.status().as_u16();
+    if !(200..300).contains(&status) {
+        report.http_status = Some(status);
+        return Err(RemoteProbeFailure::HttpStatus {
+            stage: "initialize",
+            status,
+        });
+    }
+
+    let message = await_sse_response(&mut events, "initialize")?;
+    handshake_from(message, RemoteTransport::Sse, "initialize")
+}
+
+fn resolve(host: &str, port: u16, deadline: Instant) -> Result<Vec<String>, RemoteProbeFailure> {
+    let (sender, receiver) = mpsc::channel();
+    let target = format!("{host}:{port}");
+    thread::spawn(move || {
+        let _ = sender.send(
+            target
+                .to_socket_addrs()
+                .map(|addresses| addresses.collect::<Vec<_>>()),
+        );
+    });
+
+    match receiver.recv_timeout(remaining(deadline)) {
+        Ok(Ok(addresses)) if !addresses.is_empty() => Ok(addresses
+            .iter()
+            .map(|address| address.ip().to_string())
+            .collect()),
+        Ok(Ok(_)) => Err(RemoteProbeFailure::Dns(format!(
+            "'{host}' resolved to no addresses"
+        ))),
+        Ok(Err(error)) => Err(RemoteProbeFailure::Dns(format!(
+            "failed to resolve '{host}': {error}"
+        ))),
+        Err(_) => Err(RemoteProbeFailure::TimedOut { stage: "resolve" }),
+    }
+}
+
+fn post_json(
+    agent: &Agent,
+    url: &str,
+    launch: &RemoteLaunch,
+    message: &Value,
+    deadline: Instant,
+) -> Result<ureq::http::Response<ureq::Body>, ureq::Error> {
+    let mut request = agent
+        .post(url)
+        .header("content-type", "application/json")
+        .header("accept", "application/json, text/event-stream");
+    for (name, value) in &launch.headers {
+        request = request.header(name, value);
+    }
+
+    request
+        .config()
+        .timeout_global(Some(remaining(deadline)))
+        .build()
+        .send(message.to_string())
+}
+
+fn initialize_message() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": "initialize",
+        "params": {
+            "protocolVersion": PROBE_PROTOCOL_VERSION,
+            "capabilities": {},
+            "clientInfo": { "name": "ai-manager-probe", "version": env!("CARGO_PKG_VERSION") }
+        }
+    })
+}
+
+fn is_event_stream(response: &ureq::http::Response<ureq::Body>) -> bool {
+    response
+        .headers()
+        .get("content-type")
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.starts_with("text/event-stream"))
+}
+
+/// Reads one server-sent event, returning its name (default `message`) and joined data lines.
+fn next_sse_event(reader: &mut impl BufRead) -> io::Result<Option<(String, String)>> {
+    let mut event = String::new();
+    let mut data: Vec<String> = Vec::new();
+    let mut buffer = String::new();
+
+    loop {
+        buffer.clear();
+        if reader.read_line(&mut buffer)? == 0 {
+            return Ok(None);
+        }
+        let line = buffer.trim_end_matches(['\r', '\n']);
+
+        if line.is_empty() {
+            if data.is_empty() {
+                event.clear();
+                continue;
+            }
+            if event.is_empty() {
+                event.push_str("message");
+            }
+            return Ok(Some((event, data.join("\n"))));
+        }
+        if let Some(value) = line.strip_prefix("event:") {
+            event = value.trim_start().to_string();
+        } else if let Some(value) = line.strip_prefix("data:") {
+            data.push(value.strip_prefix(' ').unwrap_or(value).to_string());
+        }
+    }
+}
+
+fn await_sse_response(
+    reader: &mut impl BufRead,
+    stage: &'static str,
+) -> Result<Value, RemoteProbeFailure> {
+    for _ in 0..MAX_SSE_EVENTS {
+        let Some((event, data)) =
+            next_sse_event(reader).map_err(|error| classify_io(error, stage))?
+        else {
+            break;
+        };
+        if event != "message" {
+            continue;
+        }
+        let message = parse_json(&data, stage)?;
+        if message.get("id") == Some(&json!(1)) {
+            return Ok(message);
+        }
+    }
+
+    Err(RemoteProbeFailure::Protocol {
+        stage,
+        message: "event stream ended without an initialize response".to_string(),
+    })
+}
+
+fn parse_json(text: &str, stage: &'static str) -> Result<Value, RemoteProbeFailure> {
+    serde_json::from_str(text).map_err(|error| RemoteProbeFailure::Protocol {
+        stage,
+        message: format!("response is not JSON-RPC: {error}"),
+    })
+}
+
+fn handshake_from(
+    message: Value,
+    transport: RemoteTransport,
+    stage: &'static str,
+) -> Result<RemoteHandshake, RemoteProbeFailure> {
+    if let Some(error) = message.get("error") {
+        return Err(RemoteProbeFailure::Protocol {
+            stage,
+            message: error
+                .get("message")
+                .and_then(Value::as_str)
+                .unwrap_or("server returned an error")
+                .to_string(),
+        });
+    }
+    let Some(result) = message.get("result").filter(|result| result.is_object()) else {
+        return Err(RemoteProbeFailure::Protocol {
+            stage,
+            message: "response has no result object".to_string(),
+        });
+    };
+
+    let server_info = result.get("serverInfo");
+    let text_field = |value: Option<&Value>, key: &str| {
+        value
+            .and_then(|value| value.get(key))
+            .and_then(Value::as_str)
+            .map(str::to_string)
+    };
+
+    Ok(RemoteHandshake {
+        transport,
+        protocol_version: text_field(Some(result), "protocolVersion"),
+        server_name: text_field(server_info, "name"),
+        server_version: text_field(server_info, "version"),
+    })
+}
+
+fn resolve_endpoint(base: &Uri, endpoint: &str) -> String {
+    let endpoint = endpoint.trim();
+    if endpoint.contains("://") {
+        return endpoint.to_string();
+    }
+
+    let scheme = base.scheme_str().unwrap_or("http");
+    let authority = base.authority().map(|value| value.as_str()).unwrap_or("");
+    if endpoint.starts_with('/') {
+        return format!("{scheme}://{authority}{endpoint}");
+    }
+
+    let directory = base
+        .path()
+        .rsplit_once('/')
+        .map(|(directory, _)| directory)
+        .unwrap_or("");
+    format!("{scheme}://{authority}{directory}/{endpoint}")
+}
+
+fn classify(error: ureq::Error, stage: &'static str) -> RemoteProbeFailure {
+    match error {
+        ureq::Error::Timeout(_) => RemoteProbeFailure::TimedOut { stage },
+        ureq::Error::HostNotFound => RemoteProbeFailure::Dns("host not found".to_string()),
+        ureq::Error::ConnectionFailed => {
+            RemoteProbeFailure::Connect("connection failed".to_string())
+        }
+        ureq::Error::Tls(message) => RemoteProbeFailure::Tls(message.to_string()),
+        ureq::Error::Rustls(error) => RemoteProbeFailure::Tls(error.to_string()),
+        ureq::Error::Pem(error) => RemoteProbeFailure::Tls(error.to_string()),
+        ureq::Error::StatusCode(status) => RemoteProbeFailure::HttpStatus { stage, status },
+        ureq::Error::Io(error) => classify_io(error, stage),
+        other => RemoteProbeFailure::Protocol {
+            stage,
+            message: other.to_string(),
+        },
+    }
+}
+
+fn classify_io(error: io::Error, stage: &'static str) -> RemoteProbeFailure {
+    match error.kind() {
+        io::ErrorKind::TimedOut | io::ErrorKind::WouldBlock => {
+            RemoteProbeFailure::TimedOut { stage }
+        }
+        io::ErrorKind::ConnectionRefused
+        | io::ErrorKind::ConnectionReset
+        | io::ErrorKind::ConnectionAborted => RemoteProbeFailure::Connect(error.to_string()),
+        _ => RemoteProbeFailure::Protocol {
+            stage,
+            message: error.to_string(),
+        },
+    }
+}
+
+fn remaining(deadline: Instant) -> Duration {
+    deadline
+        .saturating_duration_since(Instant::now())
+        .max(Duration::from_millis(1))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::BTreeMap,
+        io::{BufRead, BufReader, Read, Write},
+        net::{TcpListener, TcpStream},
+        sync::mpsc,
+        thread,
+        time::Duration,
+    };
+
+    use super::{RemoteLaunch, RemoteProbeFailure, RemoteTransport, probe_remote_server};
+
+    const INITIALIZE_RESULT: &str = r#"{"jsonrpc":"2.0","id":1,"result":{"protocolVersion":"2025-06-18","capabilities":{},"serverInfo":{"name":"mock-remote","version":"2.1.0"}}}"#;
+
+    fn read_request(stream: &mut TcpStream) -> String {
+        let mut reader = BufReader::new(stream.try_clone().expect("stream should clone"));
+        let mut head = String::new();
+        let mut content_length = 0;
+        loop {
+            let mut line = String::new();
+            if reader.read_line(&mut line).unwrap_or(0) == 0 || line == "\r\n" {
+                break;
+            }
+            if let Some((name, value)) = line.split_once(':')
+                && name.eq_ignore_ascii_case("content-length")
+            {
+                content_length = value.trim().parse().unwrap_or(0);
+            }
+            head.push_str(&line);
+        }
+        let mut body = vec![0; content_length];
+        let _ = reader.read_exact(&mut body);
+        head + &String::from_utf8_lossy(&body)
+    }
+
+    fn respond(stream: &mut TcpStream, status: &str, content_type: &str, body: &str) {
+        let _ = write!(
+            stream,
+            "HTTP/1.1 {status}\r\nContent-Type: {content_type}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+            body.len()
+        );
+    }
+
+    fn launch(url: String, timeout: Duration) -> RemoteLaunch {
+        RemoteLaunch {
+            url,
+            headers: BTreeMap::from([(
+                "Authorization".to_string(),
+                "Bearer probe-secret".to_string(),
+            )]),
+            timeout,
+        }
+    }
+
+    #[test]
+    fn streamable_http_initialize_reports_server_and_sends_configured_headers() {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("mock should bind");
+        let port = listener.local_addr().expect("mock address").port();
+        let (requests, received) = mpsc::channel();
+        thread::spawn(move || {
+            let (mut stream, _) = listener.accept().expect("probe should connect");
+            requests
+                .send(read_request(&mut stream))
+                .expect("request should be captured");
+            respond(&mut stream, "200 OK", "application/json", INITIALIZE_RESULT);
+        });
+
+        let report = probe_remote_server(&launch(
+            format!("http://127.0.0.1:{port}/mcp"),
+            Duration::from_secs(5),
+        ));
+
+        let handshake = report
+            .outcome
+            .expect("mock server should complete initialize");
+        assert_eq!(handshake.transport, RemoteTransport::StreamableHttp);
+        assert_eq!(handshake.server_name.as_deref(), Some("mock-remote"));
+        assert_eq!(handshake.protocol_version.as_deref(), Some("2025-06-18"));
+        assert_eq!(report.http_status, Some(200));
+        assert_eq!(report.resolved_addresses, vec!["127.0.0.1"]);
+
+        let request = received
+            .recv()
+            .expect("request should be received")
+            .to_lowercase();
+        assert!(request.starts_with("post /mcp "));
+        assert!(request.contains("authorization: bearer probe-secret"));
+        assert!(request.contains("\"method\":\"initialize\""));
+    }
+
+    #[test]
+    fn legacy_sse_endpoint_is_used_when_post_is_rejected() {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("mock should bind");
+        let port = listener.local_addr().expect("mock address").port();
+        thread::spawn(move || {
+            let (mut rejected, _) = listener.accept().expect("probe should post first");
+            read_request(&mut rejected);
+            respond(&mut rejected, "405 Method Not Allowed", "text/plain", "");
+
+            let (mut events, _) = listener.accept().expect("probe should open the stream");
+            read_request(&mut events);
+            let _ = write!(
+                events,
+                "HTTP/1.1 200 OK\r\nContent-Type: text/event-stream\r\nConnection: close\r\n\r\nevent: endpoint\ndata: /messages?session=abc\n\n"
+            );
+            let _ = events.flush();
+
+            let (mut posted, _) = listener
+                .accept()
+                .expect("probe should post to the endpoint");
+            let request = read_request(&mut posted);
+            assert!(request.starts_with("POST /messages?session=abc "));
+            respond(&mut posted, "202 Accepted", "text/plain", "");
+
+            let _ = write!(
+                events,
+                ": keepalive\n\nevent: message\ndata: {INITIALIZE_RESULT}\n\n"
+            );
+        });
+
+        let report = probe_remote_server(&launch(
+            format!("http://127.0.0.1:{port}/sse"),
+            Duration::from_secs(5),
+        ));
+
+        let handshake = report
+            .outcome
+            .expect("legacy transport should complete initialize");
+        assert_eq!(handshake.transport, RemoteTransport::Sse);
+        assert_eq!(handshake.server_version.as_deref(), Some("2.1.0"));
+    }
+
+    #[test]
+    fn rejected_credentials_are_reported_as_http_status() {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("mock should bind");
+        let port = listener.local_addr().expect("mock address").port();
+        thread::spawn(move || {
+            let (mut stream, _) = listener.accept().expect("probe should connect");
+            read_request(&mut stream);
+            respond(&mut stream, "401 Unauthorized", "text/plain", "denied");
+        });
+
+        let report = probe_remote_server(&launch(
+            format!("http://127.0.0.1:{port}/mcp"),
+            Duration::from_secs(5),
+        ));
+
+        assert_eq!(
+            report.outcome,
+            Err(RemoteProbeFailure::HttpStatus {
+                stage: "initialize",
+                status: 401
+            })
+        );
+    }
+
+    #[test]
+    fn silent_server_times_out_during_initialize() {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("mock should bind");
+        let port = listener.local_addr().expect("mock address").port();
+        thread::spawn(move || {
+            let (mut stream, _) = listener.accept().expect("probe should connect");
+            read_request(&mut stream);
+            thread::sleep(Duration::from_secs(2));
+        });
+
+        let report = probe_remote_server(&launch(
+            format!("http://127.0.0.1:{port}/mcp"),
+            Duration::from_millis(300),
+        ));
+
+        assert_eq!(
+            report.outcome,
+            Err(RemoteProbeFailure::TimedOut {
+                stage: "initialize"
+            })
+        );
+        assert!(report.elapsed < Duration::from_secs(2));
+    }
+
+    #[test]
+    fn unresolvable_host_fails_before_connecting() {
+        let report = probe_remote_server(&launch(
+            "https://ai-manager-probe.invalid/mcp".to_string(),
+            Duration::from_secs(5),
+        ));
+
+        assert!(matches!(
+            report.outcome,
+            Err(RemoteProbeFailure::Dns(_) | RemoteProbeFailure::TimedOut { stage: "resolve" })
+        ));
+        assert_eq!(report.http_status, None);
+    }
+}
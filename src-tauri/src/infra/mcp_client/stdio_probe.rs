@@ -0,0 +1,452 @@
+use std::{
+    collections::{BTreeMap, VecDeque},
+    io::{BufRead, BufReader, Write},
+    process::{ChildStdin, Command, Stdio},
+    sync::{
+        Arc, Mutex,
+        mpsc::{self, Receiver, RecvTimeoutError},
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use serde_json::{Value, json};
+
+pub const PROBE_PROTOCOL_VERSION: &str = "2025-06-18";
+const STDERR_TAIL_LINES: usize = 20;
+const MAX_LIST_PAGES: usize = 10;
+const STDERR_DRAIN_GRACE: Duration = Duration::from_millis(200);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StdioLaunch {
+    pub command: String,
+    pub args: Vec<String>,
+    pub env: BTreeMap<String, String>,
+    pub timeout: Duration,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct McpHandshake {
+    pub protocol_version: Option<String>,
+    pub server_name: Option<String>,
+    pub server_version: Option<String>,
+    pub tool_names: Vec<String>,
+    pub prompt_names: Vec<String>,
+    pub resource_uris: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StdioProbeFailure {
+    Spawn(String),
+    TimedOut {
+        stage: &'static str,
+    },
+    Exited {
+        stage: &'static str,
+    },
+    Protocol {
+        stage: &'static str,
+        message: String,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StdioProbeReport {
+    pub outcome: Result<McpHandshake, StdioProbeFailure>,
+    pub stderr_tail: Vec<String>,
+    pub elapsed: Duration,
+}
+
+/// Launches a stdio MCP server, runs `initialize` and `tools/list`, then terminates it.
+pub fn probe_stdio_server(launch: &StdioLaunch) -> StdioProbeReport {
+    let started = Instant::now();
+    let deadline = started + launch.timeout;
+
+    let spawned = Command::new(&launch.command)
+        .args(&launch.args)
+        .envs(&launch.env)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn();
+    let mut child = match spawned {
+        Ok(child) => child,
+        Err(error) => {
+            return StdioProbeReport {
+                outcome: Err(StdioProbeFailure::Spawn(error.to_string())),
+                stderr_tail: Vec::new(),
+                elapsed: started.elapsed(),
+            };
+        }
+    };
+
+    let stderr_tail = Arc::new(Mutex::new(VecDeque::new()));
+    let stderr_reader = child.stderr.take().map(|stderr| {
+        let tail = Arc::clone(&stderr_tail);
+        thread::spawn(move || {
+            for line in BufReader::new(stderr).lines().map_while(Result::ok) {
+                let mut tail = match tail.lock() {
+                    Ok(guard) => guard,
+                    Err(poisoned) => poisoned.into_inner(),
+                };
+                if tail.len() == STDERR_TAIL_LINES {
+                    tail.pop_front();
+                }
+                tail.push_back(line);
+            }
+        })
+    });
+
+    let (sender, receiver) = mpsc::channel();
+    if let Some(stdout) = child.stdout.take() {
+        thread::spawn(move || {
+            for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+                if sender.send(line).is_err() {
+                    break;
+                }
+            }
+        });
+    }
+
+    let outcome = match child.stdin.take() {
+        Some(mut stdin) => handshake(&mut stdin, &receiver, deadline),
+        None => Err(StdioProbeFailure::Exited {
+            stage: "initialize",
+        }),
+    };
+
+    let _ = child.kill();
+    let _ = child.wait();
+
+    // Grandchildren can keep stderr open, so only wait briefly for the reader to drain.
+    if let Some(stderr_reader) = stderr_reader {
+        let drain_deadline = Instant::now() + STDERR_DRAIN_GRACE;
+        while !stderr_reader.is_finished() && Instant::now() < drain_deadline {
+            thread::sleep(Duration::from_millis(10));
+        }
+    }
+    let stderr_tail = match stderr_tail.lock() {
+        Ok(guard) => guard.iter().cloned().collect(),
+        Err(poisoned) => poisoned.into_inner().iter().cloned().collect(),
+    };
+
+    StdioProbeReport {
+        outcome,
+        stderr_tail,
+        elapsed: started.elapsed(),
+    }
+}
+
+fn handshake(
+    stdin: &mut ChildStdin,
+    receiver: &Receiver<String>,
+    deadline: Instant,
+) -> Result<McpHandshake, StdioProbeFailure> {
+    send(
+        stdin,
+        "initialize",
+        json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "initialize",
+            "params": {
+                "protocolVersion": PROBE_PROTOCOL_VERSION,
+                "capabilities": {},
+                "clientInfo": { "name": "ai-manager-probe", "version": env!("CARGO_PKG_VERSION") }
+            }
+        }),
+    )?;
+    let initialized = await_response(receiver, 1, deadline, "initialize")?;
+    send(
+        stdin,
+        "initialize",
+        json!({ "jsonrpc": "2.0", "method": "notifications/initialized" }),
+    )?;
+
+    let capabilities = initialized.get("capabilities");
+    let advertises = |capability: &str| {
+        capabilities
+            .and_then(|value| value.get(capability))
+            .is_some()
+    };
+    let mut next_id = 2;
+
+    let tool_names = list_all(
+        stdin,
+        receiver,
+        deadline,
+        &mut next_id,
+        "tools/list",
+        "tools",
+        "name",
+    )?;
+    let prompt_names = if advertises("prompts") {
+        list_all(
+            stdin,
+            receiver,
+            deadline,
+            &mut next_id,
+            "prompts/list",
+            "prompts",
+            "name",
+        )?
+    } else {
+        Vec::new()
+    };
+    let resource_uris = if advertises("resources") {
+        list_all(
+            stdin,
+            receiver,
+            deadline,
+            &mut next_id,
+            "resources/list",
+            "resources",
+            "uri",
+        )?
+    } else {
+        Vec::new()
+    };
+
+    let server_info = initialized.get("serverInfo");
+    let text_field = |value: Option<&Value>, key: &str| {
+        value
+            .and_then(|value| value.get(key))
+            .and_then(Value::as_str)
+            .map(str::to_string)
+    };
+
+    Ok(McpHandshake {
+        protocol_version: text_field(Some(&initialized), "protocolVersion"),
+        server_name: text_field(server_info, "name"),
+        server_version: text_field(server_info, "version"),
+        tool_names,
+        prompt_names,
+        resource_uris,
+    })
+}
+
+/// Follows `nextCursor` pagination and collects one string field from every listed item.
+fn list_all(
+    stdin: &mut ChildStdin,
+    receiver: &Receiver<String>,
+    deadline: Instant,
+    next_id: &mut u64,
+    method: &'static str,
+    collection: &str,
+    field: &str,
+) -> Result<Vec<String>, StdioProbeFailure> {
+    let mut values = Vec::new();
+    let mut cursor: Option<String> = None;
+
+    for _ in 0..MAX_LIST_PAGES {
+        let id = *next_id;
+        *next_id += 1;
+        let params = match &cursor {
+            Some(cursor) => json!({ "cursor": cursor }),
+            None => json!({}),
+        };
+        send(
+            stdin,
+            method,
+            json!({ "jsonrpc": "2.0", "id": id, "method": method, "params": params }),
+        )?;
+        let listed = await_response(receiver, id, deadline, method)?;
+
+        values.extend(
+            listed
+                .get(collection)
+                .and_then(Value::as_array)
+                .into_iter()
+                .flatten()
+                .filter_map(|item| item.get(field).and_then(Value::as_str))
+                .map(str::to_string),
+        );
+        cursor = listed
+            .get("nextCursor")
+            .and_then(Value::as_str)
+            .map(str::to_string);
+        if cursor.is_none() {
+            break;
+        }
+    }
+
+    Ok(values)
+}
+
+fn send(
+    stdin: &mut ChildStdin,
+    stage: &'static str,
+    message: Value,
+) -> Result<(), StdioProbeFailure> {
+    writeln!(stdin, "{message}")
+        .and_then(|_| stdin.flush())
+        .map_err(|_| StdioProbeFailure::Exited { stage })
+}
+
+fn await_response(
+    receiver: &Receiver<String>,
+    id: u64,
+    deadline: Instant,
+    stage: &'static str,
+) -> Result<Value, StdioProbeFailure> {
+    loop {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        let line = match receiver.recv_timeout(remaining) {
+            Ok(line) => line,
+            Err(RecvTimeoutError::Timeout) => return Err(StdioProbeFailure::TimedOut { stage }),
+            Err(RecvTimeoutError::Disconnected) => return Err(StdioProbeFailure::Exited { stage }),
+        };
+
+        // Servers may interleave notifications or stray log lines on stdout.
+        let Ok(message) = serde_json::from_str::<Value>(&line) else {
+            continue;
+        };
+        if message.get("id").and_then(Value::as_u64) != Some(id) {
+            continue;
+        }
+
+        if let Some(error) = message.get("error") {
+            return Err(StdioProbeFailure::Protocol {
+                stage,
+                message: error
+                    .get("message")
+                    .and_then(Value::as_str)
+                    .unwrap_or("server returned an error")
+                    .to_string(),
+            });
+        }
+        return message
+            .get("result")
+            .cloned()
+            .ok_or_else(|| StdioProbeFailure::Protocol {
+                stage,
+                message: "response is missing a result".to_string(),
+            });
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use std::{
+        collections::BTreeMap,
+        fs,
+        os::unix::fs::PermissionsExt,
+        path::{Path, PathBuf},
+        time::Duration,
+    };
+
+    use super::{StdioLaunch, StdioProbeFailure, probe_stdio_server};
+
+    const STAND_IN_SERVER: &str = r#"#!/bin/sh
+echo "stand-in starting as $STAND_IN_NAME" >&2
+while IFS= read -r line; do
+  case "$line" in
+    *'"initialize"'*)
+      echo 'not json: banner line'
+      echo '{"jsonrpc":"2.0","id":1,"result":{"protocolVersion":"2025-06-18","capabilities":{"tools":{},"prompts":{}},"serverInfo":{"name":"stand-in","version":"0.0.1"}}}'
+      ;;
+    *'"tools/list"'*)
+      echo '{"jsonrpc":"2.0","method":"notifications/message","params":{}}'
+      echo '{"jsonrpc":"2.0","id":2,"result":{"tools":[{"name":"read_file"},{"name":"write_file"}]}}'
+      ;;
+    *'"prompts/list"'*)
+      echo '{"jsonrpc":"2.0","id":3,"result":{"prompts":[{"name":"summarize"}]}}'
+      ;;
+  esac
+done
+"#;
+
+    fn write_script(name: &str, content: &str) -> PathBuf {
+        let temp_dir = std::env::temp_dir().join(format!(
+            "ai-manager-stdio-probe-{}-{}",
+            name,
+            std::process::id()
+        ));
+        let _ = fs::create_dir_all(&temp_dir);
+        let script_path = temp_dir.join("server.sh");
+        fs::write(&script_path, content).expect("should write stand-in server");
+        let mut mode = fs::metadata(&script_path)
+            .expect("script should exist")
+            .permissions();
+        mode.set_mode(0o755);
+        fs::set_permissions(&script_path, mode).expect("should make script executable");
+        script_path
+    }
+
+    fn launch(script_path: &Path, timeout: Duration) -> StdioLaunch {
+        StdioLaunch {
+            command: script_path.display().to_string(),
+            args: Vec::new(),
+            env: BTreeMap::from([("STAND_IN_NAME".to_string(), "probe-test".to_string())]),
+            timeout,
+        }
+    }
+
+    #[test]
+    fn stand_in_server_completes_initialize_and_tools_list() {
+        let script_path = write_script("healthy", STAND_IN_SERVER);
+
+        let report = probe_stdio_server(&launch(&script_path, Duration::from_secs(10)));
+        let _ = fs::remove_dir_all(script_path.parent().expect("script has a parent"));
+
+        let handshake = report.outcome.expect("handshake should succeed");
+        assert_eq!(handshake.protocol_version.as_deref(), Some("2025-06-18"));
+        assert_eq!(handshake.server_name.as_deref(), Some("stand-in"));
+        assert_eq!(handshake.server_version.as_deref(), Some("0.0.1"));
+        assert_eq!(handshake.tool_names, vec!["read_file", "write_file"]);
+        assert_eq!(handshake.prompt_names, vec!["summarize"]);
+        assert!(handshake.resource_uris.is_empty());
+        assert_eq!(
+            report.stderr_tail,
+            vec!["stand-in starting as probe-test".to_string()]
+        );
+    }
+
+    #[test]
+    fn silent_server_times_out_during_initialize() {
+        let script_path = write_script("silent", "#!/bin/sh\nexec sleep 5\n");
+
+        let report = probe_stdio_server(&launch(&script_path, Duration::from_millis(200)));
+        let _ = fs::remove_dir_all(script_path.parent().expect("script has a parent"));
+
+        assert_eq!(
+            report.outcome,
+            Err(StdioProbeFailure::TimedOut {
+                stage: "initialize"
+            })
+        );
+        assert!(report.elapsed < Duration::from_secs(5));
+    }
+
+    #[test]
+    fn crashing_server_reports_exit_and_stderr_tail() {
+        let script_path = write_script(
+            "crash",
+            "#!/bin/sh\necho 'fatal: missing API key' >&2\nexit 1\n",
+        );
+
+        let report = probe_stdio_server(&launch(&script_path, Duration::from_secs(5)));
+        let _ = fs::remove_dir_all(script_path.parent().expect("script has a parent"));
+
+        assert!(matches!(
+            report.outcome,
+            Err(StdioProbeFailure::Exited { .. })
+        ));
+        assert_eq!(
+            report.stderr_tail,
+            vec!["fatal: missing API key".to_string()]
+        );
+    }
+
+    #[test]
+    fn missing_command_is_a_spawn_failure() {
+        let report = probe_stdio_server(&StdioLaunch {
+            command: "/nonexistent/ai-manager-probe-server".to_string(),
+            args: Vec::new(),
+            env: BTreeMap::new(),
+            timeout: Duration::from_secs(1),
+        });
+
+        assert!(matches!(report.outcome, Err(StdioProbeFailure::Spawn(_))));
+    }
+}
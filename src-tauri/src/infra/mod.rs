@@ -1,10 +1,37 @@
 pub mod adapters;
+mod audit;
+mod control_server;
+mod data_dir;
 pub mod detection;
+mod git;
+mod inventory;
+mod mcp_client;
 mod mutation;
+mod parking;
 pub mod parsers;
 pub mod registry;
 pub mod security;
+#[cfg(feature = "desktop")]
+mod watcher;
 
-pub use detection::DetectorRegistry;
-pub use mutation::{MutationTestHooks, SafeFileMutator};
+pub use audit::{LoggedFileChange, LoggedOperation, LoggedOperationKind, OperationLog};
+pub use control_server::{ControlEndpoint, ControlServer, HttpRequest, HttpResponse};
+pub use data_dir::app_data_dir;
+pub use detection::{
+    DetectorRegistry, HostOs, PathCandidateKind, PathCandidateProfile, expand_user_path,
+    support_matrix_client,
+};
+pub use git::{GitPathStatus, GitRepository};
+pub use inventory::McpInventoryStore;
+pub use mcp_client::{
+    RemoteLaunch, RemoteProbeFailure, RemoteTransport, StdioLaunch, StdioProbeFailure,
+    probe_remote_server, probe_stdio_server,
+};
+pub use mutation::{
+    BACKUP_DIR_NAME, MutationFailure, MutationStage, MutationTestHooks, RecordedWrite,
+    SafeFileMutator, content_revision, record_writes, unified_diff,
+};
+pub use parking::{ParkedServer, ParkedServerStore};
 pub use registry::AdapterRegistry;
+#[cfg(feature = "desktop")]
+pub use watcher::{PollWatcher, WatchTarget, WatchTiming};
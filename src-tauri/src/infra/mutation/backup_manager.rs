@@ -1,9 +1,13 @@
 use std::{
-    fs,
+    fs::{self, File, OpenOptions},
+    io,
     path::{Path, PathBuf},
     time::{SystemTime, UNIX_EPOCH},
 };
 
+/// Sibling directory of each target that holds its pre-write copies.
+pub const BACKUP_DIR_NAME: &str = ".ai-manager-backups";
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct BackupArtifact {
     pub backup_path: Option<PathBuf>,
@@ -32,12 +36,9 @@ impl BackupManager {
             )));
         }
 
-        let backup_path = build_backup_path(target_path)?;
-        if let Some(parent) = backup_path.parent() {
-            fs::create_dir_all(parent)?;
-        }
-
-        fs::copy(target_path, &backup_path)?;
+        let (backup_path, mut backup_file) = create_backup_file(target_path)?;
+        io::copy(&mut File::open(target_path)?, &mut backup_file)?;
+        backup_file.set_permissions(fs::metadata(target_path)?.permissions())?;
 
         Ok(BackupArtifact {
             backup_path: Some(backup_path),
@@ -71,7 +72,9 @@ impl Default for BackupManager {
     }
 }
 
-fn build_backup_path(target_path: &Path) -> std::io::Result<PathBuf> {
+/// Creates `<file>.<timestamp_ms>.bak`, adding a counter when a backup from the same
+/// millisecond already exists so an earlier copy is never overwritten.
+fn create_backup_file(target_path: &Path) -> std::io::Result<(PathBuf, File)> {
     let parent = target_path.parent().ok_or_else(|| {
         std::io::Error::other(format!(
             "target path '{}' has no parent directory",
@@ -88,9 +91,26 @@ fn build_backup_path(target_path: &Path) -> std::io::Result<PathBuf> {
         .map_err(std::io::Error::other)?
         .as_millis();
 
-    Ok(parent
-        .join(".ai-manager-backups")
-        .join(format!("{}.{}.bak", filename, timestamp_ms)))
+    let backup_dir = parent.join(BACKUP_DIR_NAME);
+    fs::create_dir_all(&backup_dir)?;
+
+    let mut attempt = 0_u32;
+    loop {
+        let backup_name = match attempt {
+            0 => format!("{}.{}.bak", filename, timestamp_ms),
+            _ => format!("{}.{}.{}.bak", filename, timestamp_ms, attempt),
+        };
+        let backup_path = backup_dir.join(backup_name);
+        match OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&backup_path)
+        {
+            Ok(file) => return Ok((backup_path, file)),
+            Err(error) if error.kind() == io::ErrorKind::AlreadyExists => attempt += 1,
+            Err(error) => return Err(error),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -125,4 +145,33 @@ mod tests {
         assert!(backup.backup_path.is_some());
         assert!(backup.target_existed);
     }
+
+    #[test]
+    fn back_to_back_backups_get_distinct_paths() {
+        let temp_dir =
+            std::env::temp_dir().join(format!("ai-manager-backup-distinct-{}", std::process::id()));
+        let _ = fs::create_dir_all(&temp_dir);
+
+        let target = temp_dir.join("config.json");
+        fs::write(&target, "first").expect("should create target file");
+
+        let manager = BackupManager::new();
+        let first = manager
+            .create_backup(&target)
+            .expect("should create first backup");
+        fs::write(&target, "second").expect("should mutate target");
+        let second = manager
+            .create_backup(&target)
+            .expect("should create second backup");
+
+        let first_path = first.backup_path.expect("first backup path");
+        let second_path = second.backup_path.expect("second backup path");
+        let first_content = fs::read_to_string(&first_path).expect("should read first backup");
+        let second_content = fs::read_to_string(&second_path).expect("should read second backup");
+        let _ = fs::remove_dir_all(&temp_dir);
+
+        assert_ne!(first_path, second_path);
+        assert_eq!(first_content, "first");
+        assert_eq!(second_content, "second");
+    }
 }
@@ -1,5 +1,12 @@
 mod atomic_writer;
 mod backup_manager;
+mod revision;
 mod safe_file_mutator;
+mod text_diff;
+mod write_journal;
 
-pub use safe_file_mutator::{MutationTestHooks, SafeFileMutator};
+pub use backup_manager::BACKUP_DIR_NAME;
+pub use revision::content_revision;
+pub use safe_file_mutator::{MutationFailure, MutationStage, MutationTestHooks, SafeFileMutator};
+pub use text_diff::unified_diff;
+pub use write_journal::{RecordedWrite, record_writes};
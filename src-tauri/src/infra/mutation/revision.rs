@@ -0,0 +1,55 @@
+use std::{fs, io, path::Path};
+
+pub fn content_revision(content: &[u8]) -> String {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in content {
+        hash ^= u64::from(*byte);
+        hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+    }
+    format!("{hash:016x}-{}", content.len())
+}
+
+/// Returns `None` when the file does not exist yet.
+pub fn file_revision(path: &Path) -> io::Result<Option<String>> {
+    match fs::read(path) {
+        Ok(content) => Ok(Some(content_revision(&content))),
+        Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(error) => Err(error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::{content_revision, file_revision};
+
+    #[test]
+    fn revision_changes_with_content_and_is_stable_otherwise() {
+        assert_eq!(content_revision(b"{}"), content_revision(b"{}"));
+        assert_ne!(content_revision(b"{}"), content_revision(b"{ }"));
+        assert_eq!(content_revision(b""), "cbf29ce484222325-0");
+    }
+
+    #[test]
+    fn missing_file_has_no_revision() {
+        let temp_dir =
+            std::env::temp_dir().join(format!("ai-manager-revision-{}", std::process::id()));
+        let _ = fs::create_dir_all(&temp_dir);
+        let target = temp_dir.join("mcp.json");
+        let _ = fs::remove_file(&target);
+
+        assert_eq!(
+            file_revision(&target).expect("missing file is not an error"),
+            None
+        );
+
+        fs::write(&target, "{}").expect("should create target");
+        assert_eq!(
+            file_revision(&target).expect("should read target"),
+            Some(content_revision(b"{}"))
+        );
+
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+}
@@ -1,8 +1,10 @@
-use std::path::Path;
+use std::{fs, io, path::Path};
 
 use super::{
     atomic_writer::AtomicWriter,
     backup_manager::{BackupArtifact, BackupManager},
+    revision::{content_revision, file_revision},
+    write_journal::{RecordedWrite, note_write},
 };
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -12,16 +14,29 @@ pub struct SafeFileMutationResult {
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum MutationStage {
+    RevisionCheck,
     Backup,
     Write,
     PostWriteValidation,
 }
 
+impl MutationStage {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::RevisionCheck => "revision_check",
+            Self::Backup => "backup",
+            Self::Write => "write",
+            Self::PostWriteValidation => "post_write_validation",
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct MutationFailure {
     pub stage: MutationStage,
     pub message: String,
     pub rollback_succeeded: bool,
+    pub io_kind: Option<io::ErrorKind>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -51,6 +66,59 @@ impl SafeFileMutator {
         self.replace_file_with_hooks(target_path, new_content, MutationTestHooks::default())
     }
 
+    /// Refuses to write when the file no longer matches `expected_revision`
+    /// (`None` means the file must not exist yet).
+    pub fn replace_file_if_unchanged(
+        &self,
+        target_path: &Path,
+        new_content: &[u8],
+        expected_revision: Option<&str>,
+    ) -> Result<SafeFileMutationResult, MutationFailure> {
+        ensure_revision(target_path, expected_revision)?;
+        self.replace_file(target_path, new_content)
+    }
+
+    /// Backs the file up and deletes it, refusing when it no longer matches
+    /// `expected_revision`.
+    pub fn remove_file_if_unchanged(
+        &self,
+        target_path: &Path,
+        expected_revision: &str,
+    ) -> Result<SafeFileMutationResult, MutationFailure> {
+        ensure_revision(target_path, Some(expected_revision))?;
+        let backup = self
+            .backup_manager
+            .create_backup(target_path)
+            .map_err(|error| MutationFailure {
+                stage: MutationStage::Backup,
+                message: error.to_string(),
+                rollback_succeeded: false,
+                io_kind: Some(error.kind()),
+            })?;
+
+        fs::remove_file(target_path).map_err(|error| MutationFailure {
+            stage: MutationStage::Write,
+            message: error.to_string(),
+            // Nothing was changed, so there is nothing to roll back.
+            rollback_succeeded: true,
+            io_kind: Some(error.kind()),
+        })?;
+
+        note_write(|| RecordedWrite {
+            path: target_path.to_path_buf(),
+            before: read_backup(&backup),
+            backup_path: backup.backup_path.clone(),
+            after: None,
+            after_revision: None,
+        });
+        Ok(SafeFileMutationResult {
+            backup_path: backup
+                .backup_path
+                .as_ref()
+                .map(|path| path.display().to_string()),
+        })
+    }
+
     pub(crate) fn replace_file_with_hooks(
         &self,
         target_path: &Path,
@@ -64,6 +132,7 @@ impl SafeFileMutator {
                 stage: MutationStage::Backup,
                 message: error.to_string(),
                 rollback_succeeded: false,
+                io_kind: Some(error.kind()),
             })?;
 
         if hooks.fail_after_backup {
@@ -72,6 +141,7 @@ impl SafeFileMutator {
                 &backup,
                 MutationStage::Backup,
                 "Injected failure after backup.".to_string(),
+                None,
             ));
         }
 
@@ -81,6 +151,7 @@ impl SafeFileMutator {
                 &backup,
                 MutationStage::Write,
                 error.to_string(),
+                Some(error.kind()),
             ));
         }
 
@@ -90,9 +161,17 @@ impl SafeFileMutator {
                 &backup,
                 MutationStage::PostWriteValidation,
                 "Injected failure after atomic write.".to_string(),
+                None,
             ));
         }
 
+        note_write(|| RecordedWrite {
+            path: target_path.to_path_buf(),
+            before: read_backup(&backup),
+            backup_path: backup.backup_path.clone(),
+            after: Some(String::from_utf8_lossy(new_content).into_owned()),
+            after_revision: Some(content_revision(new_content)),
+        });
         Ok(SafeFileMutationResult {
             backup_path: backup
                 .backup_path
@@ -107,17 +186,20 @@ impl SafeFileMutator {
         backup: &BackupArtifact,
         stage: MutationStage,
         original_message: String,
+        io_kind: Option<io::ErrorKind>,
     ) -> MutationFailure {
         match self.backup_manager.restore_backup(target_path, backup) {
             Ok(()) => MutationFailure {
                 stage,
                 message: original_message,
                 rollback_succeeded: true,
+                io_kind,
             },
             Err(rollback_error) => MutationFailure {
                 stage,
                 message: format!("{original_message} Rollback failed: {rollback_error}"),
                 rollback_succeeded: false,
+                io_kind,
             },
         }
     }
@@ -129,11 +211,44 @@ impl Default for SafeFileMutator {
     }
 }
 
+fn ensure_revision(
+    target_path: &Path,
+    expected_revision: Option<&str>,
+) -> Result<(), MutationFailure> {
+    let current_revision = file_revision(target_path).map_err(|error| MutationFailure {
+        stage: MutationStage::RevisionCheck,
+        message: error.to_string(),
+        rollback_succeeded: false,
+        io_kind: Some(error.kind()),
+    })?;
+
+    if current_revision.as_deref() != expected_revision {
+        return Err(MutationFailure {
+            stage: MutationStage::RevisionCheck,
+            message: format!(
+                "'{}' changed since it was read (expected revision {}, found {}).",
+                target_path.display(),
+                expected_revision.unwrap_or("<missing>"),
+                current_revision.as_deref().unwrap_or("<missing>")
+            ),
+            rollback_succeeded: false,
+            io_kind: None,
+        });
+    }
+    Ok(())
+}
+
+fn read_backup(backup: &BackupArtifact) -> Option<String> {
+    let content = fs::read(backup.backup_path.as_ref()?).ok()?;
+    Some(String::from_utf8_lossy(&content).into_owned())
+}
+
 #[cfg(test)]
 mod tests {
     use std::fs;
 
     use super::{MutationStage, MutationTestHooks, SafeFileMutator};
+    use crate::infra::mutation::revision::content_revision;
 
     #[test]
     fn successful_replace_creates_backup_and_updates_content() {
@@ -205,4 +320,33 @@ mod tests {
         assert!(error.rollback_succeeded);
         assert!(!target.exists());
     }
+
+    #[test]
+    fn replace_if_unchanged_rejects_stale_revision_without_writing() {
+        let temp_dir = std::env::temp_dir().join(format!(
+            "ai-manager-safe-mutate-revision-{}",
+            std::process::id()
+        ));
+        let _ = fs::create_dir_all(&temp_dir);
+        let target = temp_dir.join("mcp.json");
+        fs::write(&target, "{\"external\":true}").expect("should create target");
+
+        let stale = content_revision(b"{\"before\":true}");
+        let failure = SafeFileMutator::new()
+            .replace_file_if_unchanged(&target, b"{\"ours\":true}", Some(&stale))
+            .expect_err("stale revision should be rejected");
+
+        assert_eq!(failure.stage, MutationStage::RevisionCheck);
+        assert_eq!(
+            fs::read_to_string(&target).expect("should read target"),
+            "{\"external\":true}"
+        );
+
+        let current = content_revision(b"{\"external\":true}");
+        SafeFileMutator::new()
+            .replace_file_if_unchanged(&target, b"{\"ours\":true}", Some(&current))
+            .expect("matching revision should write");
+
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
 }
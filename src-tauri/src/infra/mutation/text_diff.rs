@@ -0,0 +1,165 @@
+const CONTEXT_LINES: usize = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DiffLine<'a> {
+    Same(&'a str),
+    Removed(&'a str),
+    Added(&'a str),
+}
+
+pub fn unified_diff(label: &str, before: &str, after: &str) -> String {
+    if before == after {
+        return String::new();
+    }
+
+    let before_lines = before.lines().collect::<Vec<_>>();
+    let after_lines = after.lines().collect::<Vec<_>>();
+    let lines = diff_lines(&before_lines, &after_lines);
+
+    let mut output = format!("--- a/{label}\n+++ b/{label}\n");
+    let changed = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| !matches!(line, DiffLine::Same(_)))
+        .map(|(index, _)| index)
+        .collect::<Vec<_>>();
+
+    let mut hunk_ranges: Vec<(usize, usize)> = Vec::new();
+    for index in changed {
+        let start = index.saturating_sub(CONTEXT_LINES);
+        let end = (index + CONTEXT_LINES + 1).min(lines.len());
+        match hunk_ranges.last_mut() {
+            Some((_, last_end)) if start <= *last_end => *last_end = end,
+            _ => hunk_ranges.push((start, end)),
+        }
+    }
+
+    for (start, end) in hunk_ranges {
+        let before_start = 1 + lines[..start]
+            .iter()
+            .filter(|line| !matches!(line, DiffLine::Added(_)))
+            .count();
+        let after_start = 1 + lines[..start]
+            .iter()
+            .filter(|line| !matches!(line, DiffLine::Removed(_)))
+            .count();
+        let hunk = &lines[start..end];
+        let before_count = hunk
+            .iter()
+            .filter(|line| !matches!(line, DiffLine::Added(_)))
+            .count();
+        let after_count = hunk
+            .iter()
+            .filter(|line| !matches!(line, DiffLine::Removed(_)))
+            .count();
+
+        output.push_str(&format!(
+            "@@ -{before_start},{before_count} +{after_start},{after_count} @@\n"
+        ));
+        for line in hunk {
+            let (marker, text) = match line {
+                DiffLine::Same(text) => (' ', text),
+                DiffLine::Removed(text) => ('-', text),
+                DiffLine::Added(text) => ('+', text),
+            };
+            output.push(marker);
+            output.push_str(text);
+            output.push('\n');
+        }
+    }
+
+    output
+}
+
+// Common prefix and suffix are trimmed before the LCS pass; config repairs touch a
+// few lines, so the quadratic table stays small even for large files.
+fn diff_lines<'a>(before: &[&'a str], after: &[&'a str]) -> Vec<DiffLine<'a>> {
+    let prefix = before
+        .iter()
+        .zip(after)
+        .take_while(|(left, right)| left == right)
+        .count();
+    let suffix = before[prefix..]
+        .iter()
+        .rev()
+        .zip(after[prefix..].iter().rev())
+        .take_while(|(left, right)| left == right)
+        .count();
+
+    let before_middle = &before[prefix..before.len() - suffix];
+    let after_middle = &after[prefix..after.len() - suffix];
+
+    let mut lengths = vec![vec![0usize; after_middle.len() + 1]; before_middle.len() + 1];
+    for left in (0..before_middle.len()).rev() {
+        for right in (0..after_middle.len()).rev() {
+            lengths[left][right] = if before_middle[left] == after_middle[right] {
+                lengths[left + 1][right + 1] + 1
+            } else {
+                lengths[left + 1][right].max(lengths[left][right + 1])
+            };
+        }
+    }
+
+    let mut lines = before[..prefix]
+        .iter()
+        .map(|line| DiffLine::Same(line))
+        .collect::<Vec<_>>();
+    let (mut left, mut right) = (0, 0);
+    while left < before_middle.len() || right < after_middle.len() {
+        if left < before_middle.len()
+            && right < after_middle.len()
+            && before_middle[left] == after_middle[right]
+        {
+            lines.push(DiffLine::Same(before_middle[left]));
+            left += 1;
+            right += 1;
+        } else if left < before_middle.len()
+            && (right == after_middle.len() || lengths[left + 1][right] >= lengths[left][right + 1])
+        {
+            lines.push(DiffLine::Removed(before_middle[left]));
+            left += 1;
+        } else {
+            lines.push(DiffLine::Added(after_middle[right]));
+            right += 1;
+        }
+    }
+    lines.extend(
+        before[before.len() - suffix..]
+            .iter()
+            .map(|line| DiffLine::Same(line)),
+    );
+
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::unified_diff;
+
+    #[test]
+    fn identical_content_produces_empty_diff() {
+        assert_eq!(unified_diff("mcp.json", "{}\n", "{}\n"), "");
+    }
+
+    #[test]
+    fn changed_lines_are_grouped_into_hunks_with_context() {
+        let before = "a\nb\nc\nd\ne\nf\ng\nh\n";
+        let after = "a\nb\nc\nd\nE\nf\ng\nh\n";
+
+        assert_eq!(
+            unified_diff("config.json", before, after),
+            "--- a/config.json\n+++ b/config.json\n@@ -2,7 +2,7 @@\n b\n c\n d\n-e\n+E\n f\n g\n h\n"
+        );
+    }
+
+    #[test]
+    fn removed_lines_shift_following_hunk_offsets() {
+        let before = "1\n2\n3\n4\n5\n6\n7\n8\n9\n10\n11\n12\n";
+        let after = "2\n3\n4\n5\n6\n7\n8\n9\n10\n11\n12\n13\n";
+
+        let diff = unified_diff("numbers.txt", before, after);
+
+        assert!(diff.contains("@@ -1,4 +1,3 @@\n-1\n 2\n 3\n 4\n"));
+        assert!(diff.contains("@@ -10,3 +9,4 @@\n 10\n 11\n 12\n+13\n"));
+    }
+}
@@ -0,0 +1,42 @@
+use std::{cell::RefCell, path::PathBuf};
+
+/// A file change made through `SafeFileMutator` while `record_writes` was active.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordedWrite {
+    pub path: PathBuf,
+    /// Copy of the previous content; `None` when the file did not exist.
+    pub backup_path: Option<PathBuf>,
+    pub before: Option<String>,
+    /// `None` when the change removed the file.
+    pub after: Option<String>,
+    pub after_revision: Option<String>,
+}
+
+thread_local! {
+    static ACTIVE_JOURNAL: RefCell<Option<Vec<RecordedWrite>>> = const { RefCell::new(None) };
+}
+
+/// Runs `operation` and returns the file changes it made on this thread, in order.
+pub fn record_writes<T>(operation: impl FnOnce() -> T) -> (T, Vec<RecordedWrite>) {
+    let outer = ACTIVE_JOURNAL.with(|journal| journal.borrow_mut().replace(Vec::new()));
+    let output = operation();
+    let writes = ACTIVE_JOURNAL.with(|journal| {
+        let writes = journal.borrow_mut().take().unwrap_or_default();
+        *journal.borrow_mut() = outer.map(|mut outer| {
+            outer.extend(writes.iter().cloned());
+            outer
+        });
+        writes
+    });
+
+    (output, writes)
+}
+
+/// Builds the record only while a journal is active, so unrecorded writes skip the reads.
+pub(super) fn note_write(write: impl FnOnce() -> RecordedWrite) {
+    ACTIVE_JOURNAL.with(|journal| {
+        if let Some(writes) = journal.borrow_mut().as_mut() {
+            writes.push(write());
+        }
+    });
+}
@@ -0,0 +1,3 @@
+mod parked_server_store;
+
+pub use parked_server_store::{ParkedServer, ParkedServerStore};
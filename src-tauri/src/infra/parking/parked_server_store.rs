@@ -0,0 +1,146 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    infra::{MutationFailure, MutationStage, SafeFileMutator, app_data_dir, content_revision},
+    interface::contracts::common::ClientKind,
+};
+
+const PARKED_SERVERS_FILE: &str = "parked-mcp-servers.json";
+
+/// A server entry moved out of its client config, kept verbatim so it can be restored.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ParkedServer {
+    pub client: ClientKind,
+    pub source_id: String,
+    pub container_path: String,
+    pub server_id: String,
+    pub entry: serde_json::Value,
+    pub parked_at_unix: u64,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct ParkedServersDocument {
+    #[serde(default)]
+    servers: Vec<ParkedServer>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ParkedServersSnapshot {
+    pub servers: Vec<ParkedServer>,
+    pub revision: Option<String>,
+}
+
+impl ParkedServersSnapshot {
+    pub fn find(&self, source_id: &str, server_id: &str) -> Option<&ParkedServer> {
+        self.servers
+            .iter()
+            .find(|server| server.source_id == source_id && server.server_id == server_id)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ParkedServerStore {
+    path: PathBuf,
+}
+
+impl ParkedServerStore {
+    pub fn at(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    pub fn default_location() -> Option<Self> {
+        app_data_dir().map(|directory| Self::at(directory.join(PARKED_SERVERS_FILE)))
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn load(&self) -> io::Result<ParkedServersSnapshot> {
+        let content = match fs::read(&self.path) {
+            Ok(content) => content,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => {
+                return Ok(ParkedServersSnapshot::default());
+            }
+            Err(error) => return Err(error),
+        };
+        let document: ParkedServersDocument = serde_json::from_slice(&content)
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+
+        Ok(ParkedServersSnapshot {
+            servers: document.servers,
+            revision: Some(content_revision(&content)),
+        })
+    }
+
+    /// Writes `servers` if the store still matches `expected_revision`; returns the new revision.
+    pub fn save(
+        &self,
+        servers: Vec<ParkedServer>,
+        expected_revision: Option<&str>,
+    ) -> Result<String, MutationFailure> {
+        let mut content =
+            serde_json::to_vec_pretty(&ParkedServersDocument { servers }).map_err(|error| {
+                MutationFailure {
+                    stage: MutationStage::Write,
+                    message: format!("failed to serialize parked servers: {error}"),
+                    rollback_succeeded: true,
+                    io_kind: None,
+                }
+            })?;
+        content.push(b'\n');
+
+        SafeFileMutator::new().replace_file_if_unchanged(
+            &self.path,
+            &content,
+            expected_revision,
+        )?;
+        Ok(content_revision(&content))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use serde_json::json;
+
+    use super::{ParkedServer, ParkedServerStore};
+    use crate::interface::contracts::common::ClientKind;
+
+    #[test]
+    fn store_round_trips_entries_and_rejects_stale_revisions() {
+        let temp_dir =
+            std::env::temp_dir().join(format!("ai-manager-parked-store-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&temp_dir);
+        let store = ParkedServerStore::at(temp_dir.join("parked.json"));
+
+        let empty = store.load().expect("missing store should load empty");
+        assert!(empty.servers.is_empty());
+        assert_eq!(empty.revision, None);
+
+        let parked = ParkedServer {
+            client: ClientKind::ClaudeCode,
+            source_id: "mcp::claude_code::user::/tmp/claude.json::/mcpServers".to_string(),
+            container_path: "/tmp/claude.json".to_string(),
+            server_id: "filesystem".to_string(),
+            entry: json!({ "type": "stdio", "command": "npx", "env": { "A": "1" } }),
+            parked_at_unix: 1,
+        };
+        let revision = store
+            .save(vec![parked.clone()], None)
+            .expect("first save should succeed");
+        let loaded = store.load().expect("store should load");
+        let stale = store.save(Vec::new(), None);
+        let _ = fs::remove_dir_all(&temp_dir);
+
+        assert_eq!(loaded.revision.as_deref(), Some(revision.as_str()));
+        assert_eq!(loaded.find(&parked.source_id, "filesystem"), Some(&parked));
+        assert!(stale.is_err());
+    }
+}
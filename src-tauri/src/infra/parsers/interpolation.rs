@@ -0,0 +1,253 @@
+use std::{collections::BTreeSet, env};
+
+use crate::domain::ClientKind;
+
+use super::{ParseWarning, SourcePosition};
+
+/// How a client expands variable references inside MCP `command`, `args`, `env` and `url`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InterpolationSyntax {
+    /// `${VAR}` and `${VAR:-default}`, as in Claude Code `.mcp.json`.
+    Shell,
+    /// `${env:VAR}`, plus editor variables such as `${workspaceFolder}`, as in Cursor.
+    EditorEnv,
+    /// Values are passed literally, as in Codex `config.toml`.
+    Literal,
+}
+
+impl InterpolationSyntax {
+    pub const fn for_client(client: ClientKind) -> Self {
+        match client {
+            ClientKind::ClaudeCode => Self::Shell,
+            ClientKind::Cursor => Self::EditorEnv,
+            ClientKind::Codex => Self::Literal,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Placeholder<'a> {
+    Env {
+        name: &'a str,
+        default: Option<&'a str>,
+    },
+    /// A client-specific variable that is not an environment lookup.
+    Client(&'a str),
+}
+
+/// Lists the environment variables a value references, with their fallback if any.
+fn env_references(value: &str, syntax: InterpolationSyntax) -> Vec<(String, Option<String>)> {
+    scan(value, syntax)
+        .into_iter()
+        .filter_map(|(_, _, placeholder)| match placeholder {
+            Placeholder::Env { name, default } => {
+                Some((name.to_string(), default.map(str::to_string)))
+            }
+            Placeholder::Client(_) => None,
+        })
+        .collect()
+}
+
+/// Rewrites every reference in `value` from one syntax to another. Fails with the offending
+/// placeholder when the destination has no equivalent.
+pub fn translate_interpolation(
+    value: &str,
+    from: InterpolationSyntax,
+    to: InterpolationSyntax,
+) -> Result<String, String> {
+    if from == to {
+        return Ok(value.to_string());
+    }
+
+    let mut translated = String::with_capacity(value.len());
+    let mut cursor = 0;
+    for (start, end, placeholder) in scan(value, from) {
+        translated.push_str(&value[cursor..start]);
+        let original = &value[start..end];
+        let rewritten = match (placeholder, to) {
+            (Placeholder::Env { name, default }, InterpolationSyntax::Shell) => match default {
+                Some(default) => format!("${{{}:-{}}}", name, default),
+                None => format!("${{{}}}", name),
+            },
+            (
+                Placeholder::Env {
+                    name,
+                    default: None,
+                },
+                InterpolationSyntax::EditorEnv,
+            ) => {
+                format!("${{env:{}}}", name)
+            }
+            _ => return Err(original.to_string()),
+        };
+        translated.push_str(&rewritten);
+        cursor = end;
+    }
+    translated.push_str(&value[cursor..]);
+
+    Ok(translated)
+}
+
+/// Warns about references to unset variables, and about `${...}` text in clients that pass
+/// values through literally.
+pub fn interpolation_warnings<'a>(
+    client: ClientKind,
+    server_name: &str,
+    values: impl IntoIterator<Item = &'a str>,
+    position: Option<SourcePosition>,
+) -> Vec<ParseWarning> {
+    let syntax = InterpolationSyntax::for_client(client);
+    let mut unset: BTreeSet<String> = BTreeSet::new();
+    let mut literal: BTreeSet<String> = BTreeSet::new();
+
+    for value in values {
+        if syntax == InterpolationSyntax::Literal {
+            for other in [InterpolationSyntax::Shell, InterpolationSyntax::EditorEnv] {
+                literal.extend(
+                    scan(value, other)
+                        .into_iter()
+                        .map(|(start, end, _)| value[start..end].to_string()),
+                );
+            }
+            continue;
+        }
+
+        unset.extend(
+            env_references(value, syntax)
+                .into_iter()
+                .filter(|(name, default)| default.is_none() && env::var_os(name).is_none())
+                .map(|(name, _)| name),
+        );
+    }
+
+    let unset = unset.into_iter().map(|name| ParseWarning {
+        code: "PARSER_ENV_VAR_UNSET",
+        message: format!(
+            "Server `{server_name}` references environment variable `{name}`, which is not set."
+        ),
+        server_name: Some(server_name.to_string()),
+        position,
+    });
+    let literal = literal.into_iter().map(|placeholder| ParseWarning {
+        code: "PARSER_INTERPOLATION_UNSUPPORTED",
+        message: format!(
+            "Server `{server_name}` contains `{placeholder}`, but '{}' does not expand variables; the text is passed literally.",
+            client.as_str()
+        ),
+        server_name: Some(server_name.to_string()),
+        position,
+    });
+
+    unset.chain(literal).collect()
+}
+
+fn scan(value: &str, syntax: InterpolationSyntax) -> Vec<(usize, usize, Placeholder<'_>)> {
+    let mut placeholders = Vec::new();
+    if syntax == InterpolationSyntax::Literal {
+        return placeholders;
+    }
+
+    let mut index = 0;
+    while let Some(offset) = value[index..].find("${") {
+        let start = index + offset;
+        let Some(length) = value[start + 2..].find('}') else {
+            break;
+        };
+        let end = start + 2 + length + 1;
+        let inner = &value[start + 2..end - 1];
+
+        let placeholder = match syntax {
+            InterpolationSyntax::Shell => {
+                let (name, default) = match inner.split_once(":-") {
+                    Some((name, default)) => (name, Some(default)),
+                    None => (inner, None),
+                };
+                is_variable_name(name).then_some(Placeholder::Env { name, default })
+            }
+            InterpolationSyntax::EditorEnv => match inner.strip_prefix("env:") {
+                Some(name) => is_variable_name(name).then_some(Placeholder::Env {
+                    name,
+                    default: None,
+                }),
+                None => (!inner.is_empty()).then_some(Placeholder::Client(inner)),
+            },
+            InterpolationSyntax::Literal => None,
+        };
+
+        if let Some(placeholder) = placeholder {
+            placeholders.push((start, end, placeholder));
+        }
+        index = end;
+    }
+
+    placeholders
+}
+
+fn is_variable_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|first| first.is_ascii_alphabetic() || first == '_')
+        && chars.all(|ch| ch.is_ascii_alphanumeric() || ch == '_')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{InterpolationSyntax, interpolation_warnings, translate_interpolation};
+    use crate::domain::ClientKind;
+
+    #[test]
+    fn references_translate_between_claude_and_cursor_syntax() {
+        let shell = InterpolationSyntax::Shell;
+        let editor = InterpolationSyntax::EditorEnv;
+
+        assert_eq!(
+            translate_interpolation("--token=${API_TOKEN} ${HOME}/data", shell, editor),
+            Ok("--token=${env:API_TOKEN} ${env:HOME}/data".to_string())
+        );
+        assert_eq!(
+            translate_interpolation("https://${env:HOST}/mcp", editor, shell),
+            Ok("https://${HOST}/mcp".to_string())
+        );
+        assert_eq!(
+            translate_interpolation("${PORT:-8080}", shell, editor),
+            Err("${PORT:-8080}".to_string())
+        );
+        assert_eq!(
+            translate_interpolation("${workspaceFolder}/src", editor, shell),
+            Err("${workspaceFolder}".to_string())
+        );
+        assert_eq!(
+            translate_interpolation("${API_TOKEN}", shell, InterpolationSyntax::Literal),
+            Err("${API_TOKEN}".to_string())
+        );
+        assert_eq!(
+            translate_interpolation("plain ${not valid}", shell, InterpolationSyntax::Literal),
+            Ok("plain ${not valid}".to_string())
+        );
+    }
+
+    #[test]
+    fn warnings_cover_unset_variables_and_literal_clients() {
+        let unset = format!("AI_MANAGER_UNSET_{}", std::process::id());
+        let claude_arg = format!("${{{}}}", unset);
+        let claude = interpolation_warnings(
+            ClientKind::ClaudeCode,
+            "api",
+            [
+                claude_arg.as_str(),
+                claude_arg.as_str(),
+                "${PATH}",
+                "${UNSET_WITH_DEFAULT_FOR_TEST:-x}",
+            ],
+            None,
+        );
+        let codex = interpolation_warnings(ClientKind::Codex, "api", ["${env:TOKEN}"], None);
+
+        assert_eq!(claude.len(), 1);
+        assert_eq!(claude[0].code, "PARSER_ENV_VAR_UNSET");
+        assert!(claude[0].message.contains(&unset));
+        assert_eq!(codex.len(), 1);
+        assert_eq!(codex[0].code, "PARSER_INTERPOLATION_UNSUPPORTED");
+    }
+}
@@ -3,7 +3,9 @@ use serde_json::Value;
 use crate::domain::ClientKind;
 
 use super::{
-    ClientConfigParser, ParseError, ParseOutcome, ParseWarning, ParsedClientConfig, ParsedMcpServer,
+    ClientConfigParser, ParseError, ParseOutcome, ParseWarning, ParsedClientConfig,
+    ParsedMcpServer, SourcePosition, interpolation::interpolation_warnings, locate_jsonc_member,
+    parse_jsonc,
 };
 
 pub struct JsonClientConfigParser {
@@ -22,28 +24,45 @@ impl ClientConfigParser for JsonClientConfigParser {
     }
 
     fn parse(&self, source: &str) -> ParseOutcome<ParsedClientConfig> {
-        let parsed_value = match serde_json::from_str::<Value>(source) {
-            Ok(value) => value,
+        let document = match parse_jsonc(source) {
+            Ok(document) => document,
             Err(error) => {
                 return ParseOutcome::Failure {
                     warnings: Vec::new(),
                     errors: vec![ParseError {
                         code: "PARSER_JSON_SYNTAX",
                         message: format!("Invalid JSON payload: {error}"),
+                        position: Some(SourcePosition {
+                            line: error.line(),
+                            column: error.column(),
+                        }),
                     }],
                 };
             }
         };
 
+        let parsed_value = document.value;
         let mut warnings: Vec<ParseWarning> = Vec::new();
         let mut servers: Vec<ParsedMcpServer> = Vec::new();
 
+        if document.has_comments {
+            warnings.push(ParseWarning {
+                code: "PARSER_JSONC_COMMENTS",
+                message: "Config contains comments (JSONC); edits keep existing comments in place."
+                    .to_string(),
+                server_name: None,
+                position: None,
+            });
+        }
+
         let mcp_servers = resolve_mcp_servers_section(self.client_kind, &parsed_value);
 
-        let Some(mcp_servers) = mcp_servers else {
+        let Some((section_key, mcp_servers)) = mcp_servers else {
             warnings.push(ParseWarning {
                 code: "PARSER_MCP_SECTION_MISSING",
                 message: "No MCP section (`mcpServers` or `mcp_servers`) was found.".to_string(),
+                server_name: None,
+                position: None,
             });
 
             return ParseOutcome::Success {
@@ -60,6 +79,8 @@ impl ClientConfigParser for JsonClientConfigParser {
             warnings.push(ParseWarning {
                 code: "PARSER_MCP_SECTION_INVALID",
                 message: "MCP section exists but is not an object map.".to_string(),
+                server_name: None,
+                position: None,
             });
 
             return ParseOutcome::Success {
@@ -79,6 +100,8 @@ impl ClientConfigParser for JsonClientConfigParser {
                     message: format!(
                         "Server `{server_name}` entry is not an object and was skipped."
                     ),
+                    server_name: Some(server_name.to_string()),
+                    position: locate_jsonc_member(source, &[section_key, server_name]),
                 });
                 continue;
             };
@@ -101,6 +124,8 @@ impl ClientConfigParser for JsonClientConfigParser {
                     message: format!(
                         "Server `{server_name}` has no supported transport fields (`command` or `url`)."
                     ),
+                    server_name: Some(server_name.to_string()),
+                    position: locate_jsonc_member(source, &[section_key, server_name]),
                 });
                 continue;
             };
@@ -125,6 +150,25 @@ impl ClientConfigParser for JsonClientConfigParser {
                 Vec::new()
             };
 
+            let string_values = |key: &str| {
+                server_object
+                    .get(key)
+                    .and_then(Value::as_object)
+                    .into_iter()
+                    .flat_map(|map| map.values().filter_map(Value::as_str))
+            };
+            warnings.extend(interpolation_warnings(
+                self.client_kind,
+                server_name,
+                command
+                    .into_iter()
+                    .chain(url)
+                    .chain(transport_args.iter().map(String::as_str))
+                    .chain(string_values("env"))
+                    .chain(string_values("headers")),
+                locate_jsonc_member(source, &[section_key, server_name]),
+            ));
+
             servers.push(ParsedMcpServer {
                 name: server_name.to_string(),
                 transport_kind: transport_kind.to_string(),
@@ -146,14 +190,17 @@ impl ClientConfigParser for JsonClientConfigParser {
     }
 }
 
-fn resolve_mcp_servers_section(_client_kind: ClientKind, parsed_value: &Value) -> Option<&Value> {
+fn resolve_mcp_servers_section(
+    _client_kind: ClientKind,
+    parsed_value: &Value,
+) -> Option<(&'static str, &Value)> {
     resolve_root_mcp_servers(parsed_value)
 }
 
-fn resolve_root_mcp_servers(parsed_value: &Value) -> Option<&Value> {
-    parsed_value
-        .get("mcpServers")
-        .or_else(|| parsed_value.get("mcp_servers"))
+fn resolve_root_mcp_servers(parsed_value: &Value) -> Option<(&'static str, &Value)> {
+    ["mcpServers", "mcp_servers"]
+        .into_iter()
+        .find_map(|key| parsed_value.get(key).map(|section| (key, section)))
 }
 
 #[cfg(test)]
@@ -195,4 +242,30 @@ mod tests {
         assert_eq!(warnings.len(), 1);
         assert_eq!(warnings[0].code, "PARSER_MCP_SECTION_MISSING");
     }
+
+    #[test]
+    fn cursor_parser_warns_about_unset_env_references_in_server_env() {
+        let unset = format!("AI_MANAGER_UNSET_TOKEN_{}", std::process::id());
+        let source = json!({
+            "mcpServers": {
+                "api": {
+                    "url": "https://${env:PATH}.example.test/mcp",
+                    "env": { "TOKEN": format!("${{env:{}}}", unset) }
+                }
+            }
+        })
+        .to_string();
+
+        let parser = JsonClientConfigParser::new(ClientKind::Cursor);
+        let ParseOutcome::Success { data, warnings } = parser.parse(&source) else {
+            panic!("Cursor config should parse");
+        };
+
+        assert_eq!(data.mcp_servers.len(), 1);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].code, "PARSER_ENV_VAR_UNSET");
+        assert_eq!(warnings[0].server_name.as_deref(), Some("api"));
+        assert!(warnings[0].message.contains(&unset));
+        assert!(warnings[0].position.is_some());
+    }
 }
@@ -0,0 +1,839 @@
+use serde::Serialize;
+use serde_json::{Value, ser::PrettyFormatter};
+
+use super::SourcePosition;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsoncDocument {
+    pub value: Value,
+    pub has_comments: bool,
+    pub has_trailing_commas: bool,
+}
+
+pub fn parse_jsonc(source: &str) -> Result<JsoncDocument, serde_json::Error> {
+    let normalized = normalize_jsonc(source);
+    let value = serde_json::from_str::<Value>(&normalized.text)?;
+
+    Ok(JsoncDocument {
+        value,
+        has_comments: normalized.has_comments,
+        has_trailing_commas: normalized.has_trailing_commas,
+    })
+}
+
+pub fn locate_jsonc_member(source: &str, path: &[&str]) -> Option<SourcePosition> {
+    let (without_comments, _) = blank_comments(source);
+    let without_comments = into_text(without_comments, source);
+    let mut scanner = Scanner {
+        text: without_comments.as_bytes(),
+        position: 0,
+    };
+    let mut node = scanner.parse_value()?;
+    let mut key_start = None;
+
+    for segment in path {
+        let Node::Object { members, .. } = node else {
+            return None;
+        };
+        let member = members
+            .into_iter()
+            .rev()
+            .find(|member| member.key == *segment)?;
+        key_start = Some(member.key_start);
+        node = member.value;
+    }
+
+    key_start.map(|offset| SourcePosition::from_offset(source, offset))
+}
+
+// Rewrites `original` so it deserializes to `updated` while keeping comments and
+// formatting outside the changed entries. Returns `None` when a minimal edit cannot
+// be produced safely; mutations are then refused rather than dropping the comments.
+pub fn patch_jsonc(original: &str, updated: &Value) -> Option<String> {
+    let current = serde_json::from_str::<Value>(&normalize_jsonc(original).text).ok()?;
+    // Trailing commas stay visible here so edits can keep or extend them.
+    let (without_comments, _) = blank_comments(original);
+    let without_comments = into_text(without_comments, original);
+    let mut scanner = Scanner {
+        text: without_comments.as_bytes(),
+        position: 0,
+    };
+    let root = scanner.parse_value()?;
+    if scanner.skip_whitespace() != without_comments.len() {
+        return None;
+    }
+
+    let context = PatchContext {
+        original,
+        without_comments: &without_comments,
+        indent_unit: detect_indent_unit(original),
+    };
+    let mut edits: Vec<TextEdit> = Vec::new();
+    context.diff_node(&root, &current, updated, &mut edits)?;
+
+    let patched = apply_edits(original, edits)?;
+    let reparsed = serde_json::from_str::<Value>(&normalize_jsonc(&patched).text).ok()?;
+    (reparsed == *updated).then_some(patched)
+}
+
+pub fn strip_trailing_commas(source: &str) -> (String, Vec<SourcePosition>) {
+    let (without_comments, _) = blank_comments(source);
+    let offsets = trailing_comma_offsets(&without_comments);
+    let positions = offsets
+        .iter()
+        .map(|offset| SourcePosition::from_offset(source, *offset))
+        .collect();
+
+    let mut stripped = source.to_string();
+    for offset in offsets.iter().rev() {
+        stripped.remove(*offset);
+    }
+
+    (stripped, positions)
+}
+
+// Drops earlier occurrences of repeated keys in the object at `path`, keeping the
+// last one because that is the entry JSON readers actually load.
+pub fn remove_duplicate_members(
+    source: &str,
+    path: &[&str],
+) -> Option<(String, Vec<(String, SourcePosition)>)> {
+    let (without_comments, _) = blank_comments(source);
+    let without_comments = into_text(without_comments, source);
+    let mut scanner = Scanner {
+        text: without_comments.as_bytes(),
+        position: 0,
+    };
+    let mut node = scanner.parse_value()?;
+    for segment in path {
+        let Node::Object { members, .. } = node else {
+            return None;
+        };
+        node = members
+            .into_iter()
+            .rev()
+            .find(|member| member.key == *segment)?
+            .value;
+    }
+    let Node::Object { members, .. } = node else {
+        return None;
+    };
+
+    let context = PatchContext {
+        original: source,
+        without_comments: &without_comments,
+        indent_unit: detect_indent_unit(source),
+    };
+    let mut edits = Vec::new();
+    let mut removed = Vec::new();
+    for (index, member) in members.iter().enumerate() {
+        if members[index + 1..]
+            .iter()
+            .any(|later| later.key == member.key)
+        {
+            edits.extend(context.remove_member(&members, index));
+            removed.push((
+                member.key.clone(),
+                SourcePosition::from_offset(source, member.key_start),
+            ));
+        }
+    }
+
+    Some((apply_edits(source, edits)?, removed))
+}
+
+fn apply_edits(original: &str, mut edits: Vec<TextEdit>) -> Option<String> {
+    edits.sort_by_key(|edit| edit.start);
+    if edits
+        .windows(2)
+        .any(|pair| pair[0].end > pair[1].start || pair[0].start == pair[1].start)
+    {
+        return None;
+    }
+
+    let mut patched = original.to_string();
+    for edit in edits.iter().rev() {
+        patched.replace_range(edit.start..edit.end, &edit.replacement);
+    }
+
+    Some(patched)
+}
+
+struct NormalizedJsonc {
+    text: String,
+    has_comments: bool,
+    has_trailing_commas: bool,
+}
+
+// Comments and trailing commas are blanked with spaces (newlines are kept) so byte
+// offsets and line/column positions in the normalized text match the original.
+fn normalize_jsonc(source: &str) -> NormalizedJsonc {
+    let (mut bytes, has_comments) = blank_comments(source);
+    let trailing_commas = trailing_comma_offsets(&bytes);
+    for offset in &trailing_commas {
+        bytes[*offset] = b' ';
+    }
+    let has_trailing_commas = !trailing_commas.is_empty();
+
+    NormalizedJsonc {
+        text: into_text(bytes, source),
+        has_comments,
+        has_trailing_commas,
+    }
+}
+
+fn blank_comments(source: &str) -> (Vec<u8>, bool) {
+    let mut bytes = source.as_bytes().to_vec();
+    let mut has_comments = false;
+    let mut index = 0;
+    let mut in_string = false;
+
+    while index < bytes.len() {
+        let byte = bytes[index];
+        if in_string {
+            match byte {
+                b'\\' => index += 1,
+                b'"' => in_string = false,
+                _ => {}
+            }
+            index += 1;
+            continue;
+        }
+
+        match (byte, bytes.get(index + 1).copied()) {
+            (b'"', _) => {
+                in_string = true;
+                index += 1;
+            }
+            (b'/', Some(b'/')) => {
+                has_comments = true;
+                while index < bytes.len() && bytes[index] != b'\n' {
+                    blank_byte(&mut bytes, index);
+                    index += 1;
+                }
+            }
+            (b'/', Some(b'*')) => {
+                has_comments = true;
+                let start = index;
+                index += 3;
+                while index < bytes.len() && !(bytes[index - 1] == b'*' && bytes[index] == b'/') {
+                    index += 1;
+                }
+                let end = (index + 1).min(bytes.len());
+                for position in start..end {
+                    blank_byte(&mut bytes, position);
+                }
+                index = end;
+            }
+            _ => index += 1,
+        }
+    }
+
+    (bytes, has_comments)
+}
+
+fn trailing_comma_offsets(bytes: &[u8]) -> Vec<usize> {
+    let mut offsets = Vec::new();
+    let mut index = 0;
+    let mut in_string = false;
+
+    while index < bytes.len() {
+        let byte = bytes[index];
+        if in_string {
+            match byte {
+                b'\\' => index += 1,
+                b'"' => in_string = false,
+                _ => {}
+            }
+            index += 1;
+            continue;
+        }
+
+        match byte {
+            b'"' => in_string = true,
+            b',' => {
+                let next = bytes[index + 1..]
+                    .iter()
+                    .find(|candidate| !candidate.is_ascii_whitespace());
+                if matches!(next, Some(b'}') | Some(b']')) {
+                    offsets.push(index);
+                }
+            }
+            _ => {}
+        }
+        index += 1;
+    }
+
+    offsets
+}
+
+// Only whole comment spans (including multi-byte characters) are blanked, so the
+// buffer stays valid UTF-8.
+fn into_text(bytes: Vec<u8>, source: &str) -> String {
+    String::from_utf8(bytes).unwrap_or_else(|_| source.to_string())
+}
+
+fn blank_byte(bytes: &mut [u8], index: usize) {
+    if !matches!(bytes[index], b'\n' | b'\r') {
+        bytes[index] = b' ';
+    }
+}
+
+#[derive(Debug)]
+enum Node {
+    Object {
+        open: usize,
+        close: usize,
+        members: Vec<Member>,
+    },
+    Opaque {
+        start: usize,
+        end: usize,
+    },
+}
+
+impl Node {
+    fn span(&self) -> (usize, usize) {
+        match self {
+            Node::Object { open, close, .. } => (*open, *close + 1),
+            Node::Opaque { start, end } => (*start, *end),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Member {
+    key: String,
+    key_start: usize,
+    value: Node,
+}
+
+struct Scanner<'a> {
+    text: &'a [u8],
+    position: usize,
+}
+
+impl Scanner<'_> {
+    fn skip_whitespace(&mut self) -> usize {
+        while self
+            .text
+            .get(self.position)
+            .is_some_and(|byte| byte.is_ascii_whitespace())
+        {
+            self.position += 1;
+        }
+        self.position
+    }
+
+    fn parse_value(&mut self) -> Option<Node> {
+        let start = self.skip_whitespace();
+        match self.text.get(start)? {
+            b'{' => self.parse_object(),
+            b'[' => {
+                self.skip_balanced(b'[', b']')?;
+                Some(Node::Opaque {
+                    start,
+                    end: self.position,
+                })
+            }
+            b'"' => {
+                self.skip_string()?;
+                Some(Node::Opaque {
+                    start,
+                    end: self.position,
+                })
+            }
+            _ => {
+                while self.text.get(self.position).is_some_and(|byte| {
+                    !byte.is_ascii_whitespace() && !matches!(byte, b',' | b'}' | b']')
+                }) {
+                    self.position += 1;
+                }
+                (self.position > start).then_some(Node::Opaque {
+                    start,
+                    end: self.position,
+                })
+            }
+        }
+    }
+
+    fn parse_object(&mut self) -> Option<Node> {
+        let open = self.position;
+        self.position += 1;
+        let mut members = Vec::new();
+
+        loop {
+            let index = self.skip_whitespace();
+            match self.text.get(index)? {
+                b'}' => {
+                    self.position += 1;
+                    return Some(Node::Object {
+                        open,
+                        close: index,
+                        members,
+                    });
+                }
+                b',' if !members.is_empty() => self.position += 1,
+                b'"' => {
+                    let key_start = index;
+                    self.skip_string()?;
+                    let key =
+                        serde_json::from_slice::<String>(&self.text[key_start..self.position])
+                            .ok()?;
+                    if self.text.get(self.skip_whitespace()) != Some(&b':') {
+                        return None;
+                    }
+                    self.position += 1;
+                    let value = self.parse_value()?;
+                    members.push(Member {
+                        key,
+                        key_start,
+                        value,
+                    });
+                }
+                _ => return None,
+            }
+        }
+    }
+
+    fn skip_string(&mut self) -> Option<()> {
+        self.position += 1;
+        loop {
+            match self.text.get(self.position)? {
+                b'\\' => self.position += 2,
+                b'"' => {
+                    self.position += 1;
+                    return Some(());
+                }
+                _ => self.position += 1,
+            }
+        }
+    }
+
+    fn skip_balanced(&mut self, open: u8, close: u8) -> Option<()> {
+        let mut depth = 0usize;
+        loop {
+            let byte = *self.text.get(self.position)?;
+            if byte == b'"' {
+                self.skip_string()?;
+                continue;
+            }
+            self.position += 1;
+            if byte == open {
+                depth += 1;
+            } else if byte == close {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(());
+                }
+            }
+        }
+    }
+}
+
+struct TextEdit {
+    start: usize,
+    end: usize,
+    replacement: String,
+}
+
+struct PatchContext<'a> {
+    original: &'a str,
+    without_comments: &'a str,
+    indent_unit: String,
+}
+
+impl PatchContext<'_> {
+    fn diff_node(
+        &self,
+        node: &Node,
+        current: &Value,
+        updated: &Value,
+        edits: &mut Vec<TextEdit>,
+    ) -> Option<()> {
+        if current == updated {
+            return Some(());
+        }
+
+        let (Node::Object { members, .. }, Some(current_map), Some(updated_map)) =
+            (node, current.as_object(), updated.as_object())
+        else {
+            let (start, end) = node.span();
+            edits.push(TextEdit {
+                start,
+                end,
+                replacement: self.render_value(updated, &self.line_indent(start))?,
+            });
+            return Some(());
+        };
+
+        let inserted = updated_map
+            .iter()
+            .filter(|(key, _)| !current_map.contains_key(*key))
+            .collect::<Vec<_>>();
+        let removes_any = members
+            .iter()
+            .any(|member| !updated_map.contains_key(&member.key));
+        // Mixed removals and insertions in one object (e.g. a renamed key) are
+        // rewritten as a whole to keep comma handling simple.
+        if removes_any && !inserted.is_empty() {
+            let (start, end) = node.span();
+            edits.push(TextEdit {
+                start,
+                end,
+                replacement: self.render_value(updated, &self.line_indent(start))?,
+            });
+            return Some(());
+        }
+
+        for (index, member) in members.iter().enumerate() {
+            match updated_map.get(&member.key) {
+                Some(updated_value) => {
+                    let current_value = current_map.get(&member.key)?;
+                    self.diff_node(&member.value, current_value, updated_value, edits)?;
+                }
+                None => edits.extend(self.remove_member(members, index)),
+            }
+        }
+
+        if !inserted.is_empty() {
+            edits.extend(self.insert_members(node, &inserted)?);
+        }
+
+        Some(())
+    }
+
+    fn remove_member(&self, members: &[Member], index: usize) -> Vec<TextEdit> {
+        let member = &members[index];
+        let (_, value_end) = member.value.span();
+        let mut start = member.key_start;
+        let mut end = value_end;
+
+        let bytes = self.without_comments.as_bytes();
+        let after_value = next_significant(bytes, value_end);
+        let has_following_comma = after_value.is_some_and(|position| bytes[position] == b',');
+        if let Some(comma) = after_value.filter(|_| has_following_comma) {
+            end = comma + 1;
+        }
+
+        let line_start = self.original[..start]
+            .rfind('\n')
+            .map_or(0, |index| index + 1);
+        if self.original[line_start..start].trim().is_empty() {
+            start = line_start;
+            let rest_of_line_end = self.original[end..]
+                .find('\n')
+                .map_or(self.original.len(), |offset| end + offset + 1);
+            // Same-line comments belong to the removed entry.
+            if self.without_comments[end..rest_of_line_end]
+                .trim()
+                .is_empty()
+            {
+                end = rest_of_line_end;
+            }
+        }
+
+        let mut edits = vec![TextEdit {
+            start,
+            end,
+            replacement: String::new(),
+        }];
+
+        let is_last = index + 1 == members.len();
+        if is_last && !has_following_comma && index > 0 {
+            let (_, previous_end) = members[index - 1].value.span();
+            if let Some(comma) =
+                next_significant(bytes, previous_end).filter(|position| bytes[*position] == b',')
+            {
+                edits.push(TextEdit {
+                    start: comma,
+                    end: comma + 1,
+                    replacement: String::new(),
+                });
+            }
+        }
+
+        edits
+    }
+
+    fn insert_members(&self, node: &Node, inserted: &[(&String, &Value)]) -> Option<Vec<TextEdit>> {
+        let Node::Object {
+            open,
+            close,
+            members,
+        } = node
+        else {
+            return None;
+        };
+
+        let member_indent = match members.first() {
+            Some(first) => self.line_indent(first.key_start),
+            None => format!("{}{}", self.line_indent(*open), self.indent_unit),
+        };
+
+        let mut rendered_members = Vec::with_capacity(inserted.len());
+        for (key, value) in inserted {
+            rendered_members.push(format!(
+                "{}{}: {}",
+                member_indent,
+                serde_json::to_string(key).ok()?,
+                self.render_value(value, &member_indent)?
+            ));
+        }
+        let body = rendered_members.join(",\n");
+
+        let Some(last) = members.last() else {
+            if self.original[*open + 1..*close].trim().is_empty() {
+                return Some(vec![TextEdit {
+                    start: *open + 1,
+                    end: *close,
+                    replacement: format!("\n{}\n{}", body, self.line_indent(*open)),
+                }]);
+            }
+
+            let insert_at = self.end_of_blank_line(*open + 1);
+            return Some(vec![TextEdit {
+                start: insert_at,
+                end: insert_at,
+                replacement: format!("\n{body}"),
+            }]);
+        };
+
+        let bytes = self.without_comments.as_bytes();
+        let (_, last_end) = last.value.span();
+        let trailing_comma =
+            next_significant(bytes, last_end).filter(|position| bytes[*position] == b',');
+        let insert_at = self.end_of_blank_line(trailing_comma.map_or(last_end, |comma| comma + 1));
+
+        let mut edits = Vec::new();
+        let replacement = match trailing_comma {
+            Some(_) => format!("\n{body},"),
+            None if insert_at == last_end => format!(",\n{body}"),
+            None => {
+                edits.push(TextEdit {
+                    start: last_end,
+                    end: last_end,
+                    replacement: ",".to_string(),
+                });
+                format!("\n{body}")
+            }
+        };
+        edits.push(TextEdit {
+            start: insert_at,
+            end: insert_at,
+            replacement,
+        });
+
+        Some(edits)
+    }
+
+    // Moves an insertion point past same-line comments so they stay attached to the
+    // entry they follow.
+    fn end_of_blank_line(&self, position: usize) -> usize {
+        let rest = &self.without_comments[position..];
+        let line_end = rest
+            .find('\n')
+            .map_or(self.without_comments.len(), |offset| position + offset);
+        if !self.without_comments[position..line_end].trim().is_empty() {
+            return position;
+        }
+
+        if self.original[..line_end].ends_with('\r') {
+            line_end - 1
+        } else {
+            line_end
+        }
+    }
+
+    fn render_value(&self, value: &Value, indent: &str) -> Option<String> {
+        let mut buffer = Vec::new();
+        let formatter = PrettyFormatter::with_indent(self.indent_unit.as_bytes());
+        let mut serializer = serde_json::Serializer::with_formatter(&mut buffer, formatter);
+        value.serialize(&mut serializer).ok()?;
+        let rendered = String::from_utf8(buffer).ok()?;
+
+        Some(rendered.replace('\n', &format!("\n{indent}")))
+    }
+
+    fn line_indent(&self, position: usize) -> String {
+        let line_start = self.original[..position]
+            .rfind('\n')
+            .map_or(0, |index| index + 1);
+        self.original[line_start..]
+            .chars()
+            .take_while(|character| *character == ' ' || *character == '\t')
+            .collect()
+    }
+}
+
+fn next_significant(bytes: &[u8], from: usize) -> Option<usize> {
+    (from..bytes.len()).find(|index| !bytes[*index].is_ascii_whitespace())
+}
+
+fn detect_indent_unit(source: &str) -> String {
+    source
+        .lines()
+        .map(|line| {
+            line.chars()
+                .take_while(|character| *character == ' ' || *character == '\t')
+                .collect::<String>()
+        })
+        .find(|indent| !indent.is_empty())
+        .unwrap_or_else(|| "  ".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::{
+        locate_jsonc_member, parse_jsonc, patch_jsonc, remove_duplicate_members,
+        strip_trailing_commas,
+    };
+    use crate::infra::parsers::SourcePosition;
+
+    const CURSOR_JSONC: &str = r#"{
+    // Servers managed by hand.
+    "mcpServers": {
+        /* local filesystem */
+        "filesystem": {
+            "command": "npx",
+            "args": ["-y", "@modelcontextprotocol/server-filesystem"], // pinned
+        },
+        "remote": { "url": "https://example.com/mcp/sse" }, // keep
+    },
+}
+"#;
+
+    #[test]
+    fn parse_jsonc_accepts_comments_and_trailing_commas() {
+        let document = parse_jsonc(CURSOR_JSONC).expect("jsonc should parse");
+
+        assert!(document.has_comments);
+        assert!(document.has_trailing_commas);
+        assert_eq!(
+            document.value["mcpServers"]["filesystem"]["command"],
+            json!("npx")
+        );
+    }
+
+    #[test]
+    fn parse_jsonc_keeps_comment_markers_inside_strings() {
+        let document = parse_jsonc(r#"{"url": "https://example.com/a//b", "note": "/* x */"}"#)
+            .expect("plain json should parse");
+
+        assert!(!document.has_comments);
+        assert_eq!(document.value["url"], json!("https://example.com/a//b"));
+        assert_eq!(document.value["note"], json!("/* x */"));
+    }
+
+    #[test]
+    fn parse_jsonc_reports_original_line_for_syntax_errors() {
+        let error = parse_jsonc("{\n  // comment\n  \"a\": 1 2\n}").expect_err("should fail");
+
+        assert_eq!(error.line(), 3);
+    }
+
+    #[test]
+    fn locate_jsonc_member_reports_key_position() {
+        let position = locate_jsonc_member(CURSOR_JSONC, &["mcpServers", "remote"]);
+
+        assert_eq!(position, Some(SourcePosition { line: 9, column: 9 }));
+        assert_eq!(
+            locate_jsonc_member(CURSOR_JSONC, &["mcpServers", "x"]),
+            None
+        );
+    }
+
+    #[test]
+    fn patch_jsonc_preserves_comments_when_adding_entry() {
+        let mut updated = parse_jsonc(CURSOR_JSONC).expect("jsonc should parse").value;
+        updated["mcpServers"]["github"] = json!({ "command": "gh-mcp", "args": [] });
+
+        let patched = patch_jsonc(CURSOR_JSONC, &updated).expect("patch should apply");
+
+        assert!(patched.contains("// Servers managed by hand."));
+        assert!(patched.contains("/* local filesystem */"));
+        assert!(patched.contains("// pinned"));
+        assert!(patched.contains("\"remote\": { \"url\": \"https://example.com/mcp/sse\" }, // keep\n        \"github\": {\n            \"args\": [],"));
+        assert_eq!(parse_jsonc(&patched).expect("reparse").value, updated);
+    }
+
+    #[test]
+    fn patch_jsonc_preserves_comments_when_updating_and_removing_entries() {
+        let mut updated = parse_jsonc(CURSOR_JSONC).expect("jsonc should parse").value;
+        updated["mcpServers"]["filesystem"]["command"] = json!("uvx");
+        let patched = patch_jsonc(CURSOR_JSONC, &updated).expect("update should apply");
+        assert!(patched.contains("\"command\": \"uvx\""));
+        assert!(patched.contains("// pinned"));
+
+        let mut removed = updated.clone();
+        removed["mcpServers"]
+            .as_object_mut()
+            .expect("section should be object")
+            .remove("remote");
+        let patched = patch_jsonc(&patched, &removed).expect("remove should apply");
+        assert!(!patched.contains("remote"));
+        assert!(patched.contains("/* local filesystem */"));
+        assert_eq!(parse_jsonc(&patched).expect("reparse").value, removed);
+    }
+
+    #[test]
+    fn patch_jsonc_removes_last_entry_without_leaving_dangling_comma() {
+        let source = "{\n  // top\n  \"mcpServers\": {\n    \"a\": {\"command\": \"x\"},\n    \"b\": {\"command\": \"y\"}\n  }\n}\n";
+        let updated = json!({ "mcpServers": { "a": { "command": "x" } } });
+
+        let patched = patch_jsonc(source, &updated).expect("patch should apply");
+
+        assert_eq!(
+            patched,
+            "{\n  // top\n  \"mcpServers\": {\n    \"a\": {\"command\": \"x\"}\n  }\n}\n"
+        );
+    }
+
+    #[test]
+    fn patch_jsonc_inserts_into_empty_object() {
+        let source = "{\n  // top\n  \"mcpServers\": {}\n}\n";
+        let updated = json!({ "mcpServers": { "a": { "url": "https://x" } } });
+
+        let patched = patch_jsonc(source, &updated).expect("patch should apply");
+
+        assert_eq!(
+            patched,
+            "{\n  // top\n  \"mcpServers\": {\n    \"a\": {\n      \"url\": \"https://x\"\n    }\n  }\n}\n"
+        );
+    }
+
+    #[test]
+    fn strip_trailing_commas_keeps_comments() {
+        let (stripped, positions) = strip_trailing_commas(CURSOR_JSONC);
+
+        assert_eq!(positions.len(), 3);
+        assert_eq!(
+            positions[0],
+            SourcePosition {
+                line: 7,
+                column: 70
+            }
+        );
+        assert!(stripped.contains("// pinned"));
+        assert!(!parse_jsonc(&stripped).expect("reparse").has_trailing_commas);
+    }
+
+    #[test]
+    fn remove_duplicate_members_keeps_last_occurrence() {
+        let source = "{\n  \"mcpServers\": {\n    \"a\": {\"command\": \"old\"}, // first\n    \"b\": {\"command\": \"b\"},\n    \"a\": {\"command\": \"new\"}\n  }\n}\n";
+
+        let (deduplicated, removed) =
+            remove_duplicate_members(source, &["mcpServers"]).expect("dedupe should apply");
+
+        assert_eq!(
+            removed,
+            vec![("a".to_string(), SourcePosition { line: 3, column: 5 })]
+        );
+        assert_eq!(
+            deduplicated,
+            "{\n  \"mcpServers\": {\n    \"b\": {\"command\": \"b\"},\n    \"a\": {\"command\": \"new\"}\n  }\n}\n"
+        );
+    }
+}
@@ -1,11 +1,19 @@
 mod client_config_parser;
 #[cfg(test)]
 mod fixture_tests;
+mod interpolation;
 mod json_parser;
+mod jsonc;
 mod registry;
 mod toml_parser;
 mod types;
 
 pub use client_config_parser::ClientConfigParser;
+pub use interpolation::{InterpolationSyntax, translate_interpolation};
+pub use jsonc::{
+    locate_jsonc_member, parse_jsonc, patch_jsonc, remove_duplicate_members, strip_trailing_commas,
+};
 pub use registry::ParserRegistry;
-pub use types::{ParseError, ParseOutcome, ParseWarning, ParsedClientConfig, ParsedMcpServer};
+pub use types::{
+    ParseError, ParseOutcome, ParseWarning, ParsedClientConfig, ParsedMcpServer, SourcePosition,
+};
@@ -29,6 +29,7 @@ impl ParserRegistry {
                         client_kind.as_str(),
                         parser.client_kind().as_str()
                     ),
+                    position: None,
                 }],
             };
         }
@@ -1,7 +1,8 @@
 use crate::domain::ClientKind;
 
 use super::{
-    ClientConfigParser, ParseError, ParseOutcome, ParseWarning, ParsedClientConfig, ParsedMcpServer,
+    ClientConfigParser, ParseError, ParseOutcome, ParseWarning, ParsedClientConfig,
+    ParsedMcpServer, SourcePosition, interpolation::interpolation_warnings,
 };
 
 pub struct TomlClientConfigParser {
@@ -28,6 +29,9 @@ impl ClientConfigParser for TomlClientConfigParser {
                     errors: vec![ParseError {
                         code: "PARSER_TOML_SYNTAX",
                         message: format!("Invalid TOML payload: {error}"),
+                        position: error
+                            .span()
+                            .map(|span| SourcePosition::from_offset(source, span.start)),
                     }],
                 };
             }
@@ -36,14 +40,16 @@ impl ClientConfigParser for TomlClientConfigParser {
         let mut warnings: Vec<ParseWarning> = Vec::new();
         let mut servers: Vec<ParsedMcpServer> = Vec::new();
 
-        let mcp_servers = parsed_table
-            .get("mcp_servers")
-            .or_else(|| parsed_table.get("mcpServers"));
+        let mcp_servers = ["mcp_servers", "mcpServers"]
+            .into_iter()
+            .find_map(|key| parsed_table.get(key).map(|section| (key, section)));
 
-        let Some(mcp_servers) = mcp_servers else {
+        let Some((section_key, mcp_servers)) = mcp_servers else {
             warnings.push(ParseWarning {
                 code: "PARSER_MCP_SECTION_MISSING",
                 message: "No MCP section (`mcp_servers` or `mcpServers`) was found.".to_string(),
+                server_name: None,
+                position: None,
             });
 
             return ParseOutcome::Success {
@@ -60,6 +66,8 @@ impl ClientConfigParser for TomlClientConfigParser {
             warnings.push(ParseWarning {
                 code: "PARSER_MCP_SECTION_INVALID",
                 message: "MCP section exists but is not a table map.".to_string(),
+                server_name: None,
+                position: None,
             });
 
             return ParseOutcome::Success {
@@ -79,6 +87,8 @@ impl ClientConfigParser for TomlClientConfigParser {
                     message: format!(
                         "Server `{server_name}` entry is not a table and was skipped."
                     ),
+                    server_name: Some(server_name.to_string()),
+                    position: locate_server_table(source, section_key, server_name),
                 });
                 continue;
             };
@@ -101,6 +111,8 @@ impl ClientConfigParser for TomlClientConfigParser {
                     message: format!(
                         "Server `{server_name}` has no supported transport fields (`command` or `url`)."
                     ),
+                    server_name: Some(server_name.to_string()),
+                    position: locate_server_table(source, section_key, server_name),
                 });
                 continue;
             };
@@ -125,6 +137,25 @@ impl ClientConfigParser for TomlClientConfigParser {
                 Vec::new()
             };
 
+            let string_values = |key: &str| {
+                server_table
+                    .get(key)
+                    .and_then(toml::Value::as_table)
+                    .into_iter()
+                    .flat_map(|table| table.values().filter_map(toml::Value::as_str))
+            };
+            warnings.extend(interpolation_warnings(
+                self.client_kind,
+                server_name,
+                command
+                    .into_iter()
+                    .chain(url)
+                    .chain(transport_args.iter().map(String::as_str))
+                    .chain(string_values("env"))
+                    .chain(string_values("http_headers")),
+                locate_server_table(source, section_key, server_name),
+            ));
+
             servers.push(ParsedMcpServer {
                 name: server_name.to_string(),
                 transport_kind: transport_kind.to_string(),
@@ -145,3 +176,28 @@ impl ClientConfigParser for TomlClientConfigParser {
         }
     }
 }
+
+fn locate_server_table(
+    source: &str,
+    section_key: &str,
+    server_name: &str,
+) -> Option<SourcePosition> {
+    let bare_header = format!("{section_key}.{server_name}");
+    let quoted_header = format!("{section_key}.\"{server_name}\"");
+    let mut offset = 0;
+
+    for line in source.split_inclusive('\n') {
+        let header = line
+            .trim_start()
+            .strip_prefix('[')
+            .and_then(|rest| rest.split(']').next())
+            .map(|header| header.replace(' ', ""));
+        if header.is_some_and(|header| header == bare_header || header == quoted_header) {
+            let indent = line.len() - line.trim_start().len();
+            return Some(SourcePosition::from_offset(source, offset + indent));
+        }
+        offset += line.len();
+    }
+
+    None
+}
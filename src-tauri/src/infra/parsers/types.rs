@@ -1,15 +1,36 @@
 use crate::domain::ClientKind;
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SourcePosition {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl SourcePosition {
+    pub fn from_offset(source: &str, offset: usize) -> Self {
+        let prefix = &source[..offset.min(source.len())];
+        let line_start = prefix.rfind('\n').map_or(0, |index| index + 1);
+
+        Self {
+            line: prefix.matches('\n').count() + 1,
+            column: prefix[line_start..].chars().count() + 1,
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ParseWarning {
     pub code: &'static str,
     pub message: String,
+    pub server_name: Option<String>,
+    pub position: Option<SourcePosition>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ParseError {
     pub code: &'static str,
     pub message: String,
+    pub position: Option<SourcePosition>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
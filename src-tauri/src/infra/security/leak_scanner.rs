@@ -0,0 +1,270 @@
+use std::{
+    collections::{BTreeMap, HashSet},
+    ops::Range,
+    sync::OnceLock,
+};
+
+use regex::Regex;
+
+use super::redaction::{key_value_regex, known_token_regex};
+
+pub const RULE_KNOWN_TOKEN_PREFIX: &str = "known_token_prefix";
+pub const RULE_SENSITIVE_KEY: &str = "sensitive_key";
+pub const RULE_HIGH_ENTROPY: &str = "high_entropy";
+
+const MIN_SECRET_VALUE_LEN: usize = 8;
+const MIN_ENTROPY_BITS_PER_CHAR: f64 = 3.5;
+const MAX_EXCERPT_CHARS: usize = 160;
+const REDACTED_VALUE: &str = "[REDACTED]";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LeakMatch {
+    pub line: usize,
+    pub column: usize,
+    pub rule: &'static str,
+    /// The matched line with the secret itself replaced.
+    pub excerpt: String,
+}
+
+/// Finds likely credentials line by line. Each span is reported once, under the first rule
+/// that matches it: known token prefixes, then sensitive key assignments, then long
+/// high-entropy strings. Excerpts redact every candidate on the line, not just their own.
+pub fn scan_for_leaks(content: &str) -> Vec<LeakMatch> {
+    scan(content).into_iter().map(|(found, _)| found).collect()
+}
+
+/// Like [`scan_for_leaks`], but skips secrets whose value already appears in `previous`, so
+/// rewriting a file does not report the credentials it already held.
+pub fn scan_for_new_leaks(previous: &str, content: &str) -> Vec<LeakMatch> {
+    let known = scan(previous)
+        .into_iter()
+        .map(|(_, secret)| secret)
+        .collect::<HashSet<_>>();
+    scan(content)
+        .into_iter()
+        .filter(|(_, secret)| !known.contains(secret))
+        .map(|(found, _)| found)
+        .collect()
+}
+
+/// Each match together with the secret it found, which never leaves this module.
+fn scan(content: &str) -> Vec<(LeakMatch, &str)> {
+    let mut matches = Vec::new();
+
+    for (index, line) in content.lines().enumerate() {
+        let candidates = line_candidates(line);
+        let mut claimed: Vec<(Range<usize>, &'static str)> = Vec::new();
+        for (range, rule) in &candidates {
+            if !claimed
+                .iter()
+                .any(|(other, _)| other.start < range.end && range.start < other.end)
+            {
+                claimed.push((range.clone(), rule));
+            }
+        }
+        if claimed.is_empty() {
+            continue;
+        }
+
+        let excerpt = excerpt(line, candidates.into_iter().map(|(range, _)| range));
+        matches.extend(claimed.into_iter().map(|(range, rule)| {
+            (
+                LeakMatch {
+                    line: index + 1,
+                    column: line[..range.start].chars().count() + 1,
+                    rule,
+                    excerpt: excerpt.clone(),
+                },
+                &line[range],
+            )
+        }));
+    }
+
+    matches
+}
+
+/// Every span on `line` that looks like a secret, in rule precedence order.
+fn line_candidates(line: &str) -> Vec<(Range<usize>, &'static str)> {
+    let mut candidates = Vec::new();
+    for found in known_token_regex().find_iter(line) {
+        candidates.push((found.range(), RULE_KNOWN_TOKEN_PREFIX));
+    }
+    for captures in key_value_regex().captures_iter(line) {
+        if let Some(value) = captures.get(2)
+            && is_literal_secret(value.as_str())
+        {
+            candidates.push((unquoted(line, value.range()), RULE_SENSITIVE_KEY));
+        }
+    }
+    for found in entropy_candidate_regex().find_iter(line) {
+        if is_high_entropy(found.as_str()) {
+            candidates.push((found.range(), RULE_HIGH_ENTROPY));
+        }
+    }
+    candidates
+}
+
+/// Narrows a captured value to its contents so excerpts keep the quotes.
+fn unquoted(line: &str, range: Range<usize>) -> Range<usize> {
+    let value = &line[range.clone()];
+    let leading = value.len() - value.trim_start_matches(['"', '\'']).len();
+    let trailing = value.len() - value.trim_end_matches(['"', '\'']).len();
+    if leading + trailing >= value.len() {
+        return range;
+    }
+    range.start + leading..range.end - trailing
+}
+
+/// Skips empty values, variable references, placeholders and masks such as `********`.
+fn is_literal_secret(value: &str) -> bool {
+    let value = value.trim_matches(|ch| ch == '"' || ch == '\'');
+    let value = value
+        .get(..7)
+        .filter(|prefix| prefix.eq_ignore_ascii_case("bearer "))
+        .map_or(value, |_| &value[7..])
+        .trim();
+
+    value.chars().count() >= MIN_SECRET_VALUE_LEN
+        && !value.contains("${")
+        && !value.starts_with(['$', '<', '{', '['])
+        && value
+            .chars()
+            .any(|ch| ch != value.chars().next().unwrap_or(ch))
+}
+
+fn is_high_entropy(candidate: &str) -> bool {
+    if !candidate.chars().any(|ch| ch.is_ascii_digit())
+        || !candidate.chars().any(|ch| ch.is_ascii_alphabetic())
+    {
+        return false;
+    }
+
+    let mut counts: BTreeMap<char, usize> = BTreeMap::new();
+    for ch in candidate.chars() {
+        *counts.entry(ch).or_default() += 1;
+    }
+    let length = candidate.chars().count() as f64;
+    let entropy = counts
+        .values()
+        .map(|count| {
+            let probability = *count as f64 / length;
+            -probability * probability.log2()
+        })
+        .sum::<f64>();
+
+    entropy >= MIN_ENTROPY_BITS_PER_CHAR
+}
+
+fn excerpt(line: &str, secrets: impl Iterator<Item = Range<usize>>) -> String {
+    let mut secrets = secrets.collect::<Vec<_>>();
+    secrets.sort_by_key(|range| range.start);
+
+    // Overlapping candidates merge into one redacted span.
+    let mut redacted = String::new();
+    let mut cursor = 0;
+    for range in secrets {
+        if range.end <= cursor {
+            continue;
+        }
+        if range.start >= cursor {
+            redacted.push_str(&line[cursor..range.start]);
+            redacted.push_str(REDACTED_VALUE);
+        }
+        cursor = range.end;
+    }
+    redacted.push_str(&line[cursor..]);
+
+    let trimmed = redacted.trim();
+    if trimmed.chars().count() <= MAX_EXCERPT_CHARS {
+        return trimmed.to_string();
+    }
+    format!(
+        "{}...",
+        trimmed.chars().take(MAX_EXCERPT_CHARS).collect::<String>()
+    )
+}
+
+fn entropy_candidate_regex() -> &'static Regex {
+    static ENTROPY_CANDIDATE_REGEX: OnceLock<Regex> = OnceLock::new();
+    ENTROPY_CANDIDATE_REGEX.get_or_init(|| {
+        Regex::new(r"[A-Za-z0-9+=_-]{20,}").expect("entropy candidate regex must compile")
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        RULE_HIGH_ENTROPY, RULE_KNOWN_TOKEN_PREFIX, RULE_SENSITIVE_KEY, scan_for_leaks,
+        scan_for_new_leaks,
+    };
+
+    #[test]
+    fn reports_each_secret_once_with_its_position_and_rule() {
+        let content = r#"{
+  "env": {
+    "GITHUB_TOKEN": "ghp_abcdefghijklmnop1234",
+    "DB_PASSWORD": "correct-horse-battery",
+    "SESSION": "q8Zr2Lw9XkP4vN7sT1mB6yH3",
+    "API_KEY": "${API_KEY}",
+    "LOG_LEVEL": "debug",
+    "PACKAGE": "@modelcontextprotocol/server-filesystem"
+  }
+}"#;
+
+        let matches = scan_for_leaks(content);
+
+        assert_eq!(
+            matches
+                .iter()
+                .map(|found| (found.line, found.rule))
+                .collect::<Vec<_>>(),
+            vec![
+                (3, RULE_KNOWN_TOKEN_PREFIX),
+                (4, RULE_SENSITIVE_KEY),
+                (5, RULE_HIGH_ENTROPY),
+            ]
+        );
+        assert_eq!(matches[0].column, 22);
+        assert_eq!(matches[0].excerpt, r#""GITHUB_TOKEN": "[REDACTED]","#);
+        assert!(!matches[1].excerpt.contains("correct-horse"));
+    }
+
+    #[test]
+    fn excerpts_redact_every_secret_on_a_shared_line() {
+        let content = r#"env = { GITHUB_TOKEN = "ghp_abcdefghijklmnop1234", DB_PASSWORD = "correct-horse-battery" }"#;
+
+        let matches = scan_for_leaks(content);
+
+        assert_eq!(
+            matches.iter().map(|found| found.rule).collect::<Vec<_>>(),
+            vec![RULE_KNOWN_TOKEN_PREFIX, RULE_SENSITIVE_KEY]
+        );
+        for found in &matches {
+            assert_eq!(
+                found.excerpt,
+                r#"env = { GITHUB_TOKEN = "[REDACTED]", DB_PASSWORD = "[REDACTED]" }"#
+            );
+        }
+    }
+
+    #[test]
+    fn new_leaks_skip_secrets_the_previous_content_already_held() {
+        let previous = r#"{ "env": { "GITHUB_TOKEN": "ghp_abcdefghijklmnop1234" } }"#;
+        let content = r#"{
+  "env": {
+    "GITHUB_TOKEN": "ghp_abcdefghijklmnop1234",
+    "DB_PASSWORD": "correct-horse-battery"
+  }
+}"#;
+
+        let matches = scan_for_new_leaks(previous, content);
+
+        assert_eq!(
+            matches
+                .iter()
+                .map(|found| (found.line, found.rule))
+                .collect::<Vec<_>>(),
+            vec![(4, RULE_SENSITIVE_KEY)]
+        );
+    }
+}
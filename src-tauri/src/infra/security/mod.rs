@@ -1 +1,4 @@
+pub mod leak_scanner;
 pub mod redaction;
+pub mod secret_store;
+pub mod token;
@@ -30,6 +30,11 @@ pub fn redact_sensitive_text(input: &str) -> String {
         .to_string()
 }
 
+/// Whether a config key (an `env` name or header) names a credential.
+pub fn is_sensitive_key(key: &str) -> bool {
+    sensitive_key_regex().is_match(key)
+}
+
 fn redact_value(value: &str) -> String {
     if value.to_ascii_lowercase().starts_with("bearer ") {
         return format!("Bearer {REDACTED_VALUE}");
@@ -47,7 +52,7 @@ fn redact_value(value: &str) -> String {
     REDACTED_VALUE.to_string()
 }
 
-fn key_value_regex() -> &'static Regex {
+pub(super) fn key_value_regex() -> &'static Regex {
     static KEY_VALUE_REGEX: OnceLock<Regex> = OnceLock::new();
     KEY_VALUE_REGEX.get_or_init(|| {
         let pattern = format!(
@@ -58,6 +63,14 @@ fn key_value_regex() -> &'static Regex {
     })
 }
 
+fn sensitive_key_regex() -> &'static Regex {
+    static SENSITIVE_KEY_REGEX: OnceLock<Regex> = OnceLock::new();
+    SENSITIVE_KEY_REGEX.get_or_init(|| {
+        Regex::new(&format!("(?i){SENSITIVE_KEYS_PATTERN}"))
+            .expect("sensitive key regex must compile")
+    })
+}
+
 fn bearer_regex() -> &'static Regex {
     static BEARER_REGEX: OnceLock<Regex> = OnceLock::new();
     BEARER_REGEX.get_or_init(|| {
@@ -65,7 +78,7 @@ fn bearer_regex() -> &'static Regex {
     })
 }
 
-fn known_token_regex() -> &'static Regex {
+pub(super) fn known_token_regex() -> &'static Regex {
     static KNOWN_TOKEN_REGEX: OnceLock<Regex> = OnceLock::new();
     KNOWN_TOKEN_REGEX.get_or_init(|| {
         Regex::new(
@@ -77,7 +90,7 @@ fn known_token_regex() -> &'static Regex {
 
 #[cfg(test)]
 mod tests {
-    use super::redact_sensitive_text;
+    use super::{is_sensitive_key, redact_sensitive_text};
 
     #[test]
     fn redacts_json_style_key_values() {
@@ -115,4 +128,12 @@ mod tests {
         let redacted = redact_sensitive_text(original);
         assert_eq!(redacted, original);
     }
+
+    #[test]
+    fn sensitive_keys_match_env_names_and_headers() {
+        assert!(is_sensitive_key("GITHUB_TOKEN"));
+        assert!(is_sensitive_key("OPENAI_API_KEY"));
+        assert!(is_sensitive_key("Authorization"));
+        assert!(!is_sensitive_key("LOG_LEVEL"));
+    }
 }
@@ -0,0 +1,270 @@
+use std::{
+    collections::BTreeMap,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use ring::{
+    aead::{Aad, CHACHA20_POLY1305, LessSafeKey, NONCE_LEN, Nonce, UnboundKey},
+    rand::{SecureRandom, SystemRandom},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::infra::{MutationFailure, SafeFileMutator, app_data_dir};
+
+const SECRETS_FILE: &str = "secrets.enc.json";
+const KEY_FILE: &str = "secrets.key";
+const KEY_LEN: usize = 32;
+const DOCUMENT_VERSION: u32 = 1;
+
+/// Backend holding values moved out of client configs, keyed by secret name.
+pub trait SecretStore: Send + Sync {
+    fn location(&self) -> String;
+    fn load(&self) -> io::Result<BTreeMap<String, String>>;
+    fn save(&self, secrets: &BTreeMap<String, String>) -> io::Result<()>;
+}
+
+/// Secrets sealed with ChaCha20-Poly1305 under a key file readable only by the owner.
+#[derive(Debug, Clone)]
+pub struct EncryptedFileSecretStore {
+    path: PathBuf,
+    key_path: PathBuf,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct EncryptedDocument {
+    version: u32,
+    algorithm: String,
+    nonce: String,
+    ciphertext: String,
+}
+
+impl EncryptedFileSecretStore {
+    pub fn in_directory(directory: impl AsRef<Path>) -> Self {
+        let directory = directory.as_ref();
+        Self {
+            path: directory.join(SECRETS_FILE),
+            key_path: directory.join(KEY_FILE),
+        }
+    }
+
+    pub fn default_location() -> Option<Self> {
+        app_data_dir().map(Self::in_directory)
+    }
+
+    fn read_key(&self) -> io::Result<Option<LessSafeKey>> {
+        let encoded = match fs::read_to_string(&self.key_path) {
+            Ok(encoded) => encoded,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(error) => return Err(error),
+        };
+        let bytes = decode_hex(encoded.trim())
+            .filter(|bytes| bytes.len() == KEY_LEN)
+            .ok_or_else(|| {
+                invalid_data(format!("'{}' is not a valid key.", self.key_path.display()))
+            })?;
+        sealing_key(&bytes).map(Some)
+    }
+
+    fn read_or_create_key(&self) -> io::Result<LessSafeKey> {
+        if let Some(key) = self.read_key()? {
+            return Ok(key);
+        }
+
+        let mut bytes = [0_u8; KEY_LEN];
+        SystemRandom::new()
+            .fill(&mut bytes)
+            .map_err(|_| io::Error::other("the system random source is unavailable"))?;
+        if let Some(parent) = self.key_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        write_owner_only(&self.key_path, encode_hex(&bytes).as_bytes())?;
+        sealing_key(&bytes)
+    }
+}
+
+impl SecretStore for EncryptedFileSecretStore {
+    fn location(&self) -> String {
+        self.path.display().to_string()
+    }
+
+    fn load(&self) -> io::Result<BTreeMap<String, String>> {
+        let content = match fs::read(&self.path) {
+            Ok(content) => content,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(BTreeMap::new()),
+            Err(error) => return Err(error),
+        };
+        let document: EncryptedDocument = serde_json::from_slice(&content)
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+        let key = self.read_key()?.ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::NotFound,
+                format!(
+                    "'{}' exists but its key '{}' is missing.",
+                    self.path.display(),
+                    self.key_path.display()
+                ),
+            )
+        })?;
+
+        let nonce = decode_hex(&document.nonce)
+            .and_then(|bytes| <[u8; NONCE_LEN]>::try_from(bytes).ok())
+            .ok_or_else(|| invalid_data("the secrets file has an invalid nonce."))?;
+        let mut sealed = decode_hex(&document.ciphertext)
+            .ok_or_else(|| invalid_data("the secrets file has invalid ciphertext."))?;
+        let plaintext = key
+            .open_in_place(
+                Nonce::assume_unique_for_key(nonce),
+                Aad::empty(),
+                &mut sealed,
+            )
+            .map_err(|_| invalid_data("the secrets file could not be decrypted with its key."))?;
+
+        serde_json::from_slice(plaintext)
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))
+    }
+
+    fn save(&self, secrets: &BTreeMap<String, String>) -> io::Result<()> {
+        let key = self.read_or_create_key()?;
+        let mut nonce = [0_u8; NONCE_LEN];
+        SystemRandom::new()
+            .fill(&mut nonce)
+            .map_err(|_| io::Error::other("the system random source is unavailable"))?;
+        let mut sealed = serde_json::to_vec(secrets).expect("secrets should serialize");
+        key.seal_in_place_append_tag(
+            Nonce::assume_unique_for_key(nonce),
+            Aad::empty(),
+            &mut sealed,
+        )
+        .map_err(|_| io::Error::other("failed to encrypt secrets"))?;
+
+        let mut content = serde_json::to_vec_pretty(&EncryptedDocument {
+            version: DOCUMENT_VERSION,
+            algorithm: "chacha20-poly1305".to_string(),
+            nonce: encode_hex(&nonce),
+            ciphertext: encode_hex(&sealed),
+        })
+        .expect("secrets document should serialize");
+        content.push(b'\n');
+
+        replace_file(&self.path, &content)
+    }
+}
+
+/// Unencrypted JSON map standing in for the encrypted store in tests.
+#[cfg(test)]
+#[derive(Debug, Clone)]
+pub struct PlainFileSecretStore {
+    path: PathBuf,
+}
+
+#[cfg(test)]
+impl PlainFileSecretStore {
+    pub fn at(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+}
+
+#[cfg(test)]
+impl SecretStore for PlainFileSecretStore {
+    fn location(&self) -> String {
+        self.path.display().to_string()
+    }
+
+    fn load(&self) -> io::Result<BTreeMap<String, String>> {
+        match fs::read(&self.path) {
+            Ok(content) => serde_json::from_slice(&content)
+                .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error)),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(BTreeMap::new()),
+            Err(error) => Err(error),
+        }
+    }
+
+    fn save(&self, secrets: &BTreeMap<String, String>) -> io::Result<()> {
+        let mut content = serde_json::to_vec_pretty(secrets).expect("secrets should serialize");
+        content.push(b'\n');
+        replace_file(&self.path, &content)
+    }
+}
+
+fn replace_file(path: &Path, content: &[u8]) -> io::Result<()> {
+    SafeFileMutator::new()
+        .replace_file(path, content)
+        .map(|_| ())
+        .map_err(|failure: MutationFailure| {
+            io::Error::new(
+                failure.io_kind.unwrap_or(io::ErrorKind::Other),
+                failure.message,
+            )
+        })
+}
+
+fn write_owner_only(path: &Path, content: &[u8]) -> io::Result<()> {
+    use std::io::Write;
+
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    options.open(path)?.write_all(content)
+}
+
+fn sealing_key(bytes: &[u8]) -> io::Result<LessSafeKey> {
+    UnboundKey::new(&CHACHA20_POLY1305, bytes)
+        .map(LessSafeKey::new)
+        .map_err(|_| invalid_data("the secrets key has the wrong length."))
+}
+
+fn invalid_data(message: impl Into<String>) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.into())
+}
+
+fn encode_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+fn decode_hex(value: &str) -> Option<Vec<u8>> {
+    if !value.len().is_multiple_of(2) || !value.is_ascii() {
+        return None;
+    }
+    (0..value.len())
+        .step_by(2)
+        .map(|index| u8::from_str_radix(&value[index..index + 2], 16).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::BTreeMap, fs};
+
+    use super::{EncryptedFileSecretStore, SecretStore};
+
+    #[test]
+    fn encrypted_store_round_trips_without_writing_plaintext() {
+        let temp_dir =
+            std::env::temp_dir().join(format!("ai-manager-secret-store-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&temp_dir);
+        let store = EncryptedFileSecretStore::in_directory(&temp_dir);
+        let secrets = BTreeMap::from([(
+            "AI_MANAGER_SECRET_GITHUB_TOKEN".to_string(),
+            "ghp_supersecretvalue".to_string(),
+        )]);
+
+        let empty = store.load().expect("missing store should load empty");
+        store.save(&secrets).expect("save should succeed");
+        let on_disk = fs::read_to_string(temp_dir.join("secrets.enc.json"))
+            .expect("secrets file should exist");
+        let loaded = store.load().expect("store should decrypt");
+        fs::write(temp_dir.join("secrets.key"), "00".repeat(32)).expect("should replace key");
+        let wrong_key = store.load();
+        let _ = fs::remove_dir_all(&temp_dir);
+
+        assert!(empty.is_empty());
+        assert!(!on_disk.contains("ghp_supersecretvalue"));
+        assert_eq!(loaded, secrets);
+        assert!(wrong_key.is_err());
+    }
+}
@@ -0,0 +1,51 @@
+use std::io;
+
+use ring::rand::{SecureRandom, SystemRandom};
+
+const TOKEN_BYTES: usize = 32;
+
+/// Returns a 256-bit hex token from the OS CSPRNG; fails rather than fall back to weaker
+/// randomness.
+pub fn generate_token() -> io::Result<String> {
+    let mut bytes = [0_u8; TOKEN_BYTES];
+    SystemRandom::new()
+        .fill(&mut bytes)
+        .map_err(|_| io::Error::other("the system random source is unavailable"))?;
+
+    Ok(bytes.iter().map(|byte| format!("{byte:02x}")).collect())
+}
+
+/// Compares tokens without short-circuiting on the first differing byte.
+pub fn tokens_match(expected: &str, candidate: &str) -> bool {
+    if expected.len() != candidate.len() {
+        return false;
+    }
+
+    expected
+        .bytes()
+        .zip(candidate.bytes())
+        .fold(0_u8, |diff, (left, right)| diff | (left ^ right))
+        == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{generate_token, tokens_match};
+
+    #[test]
+    fn generated_tokens_are_unique_hex() {
+        let first = generate_token().expect("token should generate");
+        let second = generate_token().expect("token should generate");
+
+        assert_eq!(first.len(), 64);
+        assert!(first.chars().all(|character| character.is_ascii_hexdigit()));
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    fn token_comparison_requires_exact_match() {
+        assert!(tokens_match("abc123", "abc123"));
+        assert!(!tokens_match("abc123", "abc124"));
+        assert!(!tokens_match("abc123", "abc12"));
+    }
+}
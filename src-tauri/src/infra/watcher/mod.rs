@@ -0,0 +1,3 @@
+mod poll_watcher;
+
+pub use poll_watcher::{PollWatcher, WatchTarget, WatchTiming};
@@ -0,0 +1,256 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs,
+    path::{Path, PathBuf},
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+    thread::{self, JoinHandle},
+    time::{Duration, Instant, SystemTime},
+};
+
+const MAX_RECURSIVE_DEPTH: usize = 4;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WatchTarget {
+    pub source_id: String,
+    pub path: PathBuf,
+    pub recursive: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WatchTiming {
+    pub poll_interval: Duration,
+    pub debounce: Duration,
+}
+
+impl Default for WatchTiming {
+    fn default() -> Self {
+        Self {
+            poll_interval: Duration::from_millis(500),
+            debounce: Duration::from_millis(300),
+        }
+    }
+}
+
+type Fingerprint = Vec<(PathBuf, Option<SystemTime>, u64)>;
+
+pub struct ChangeTracker {
+    targets: Vec<WatchTarget>,
+    fingerprints: Vec<Option<Fingerprint>>,
+    debounce: Duration,
+    pending: BTreeSet<String>,
+    last_change_at: Option<Instant>,
+}
+
+impl ChangeTracker {
+    pub fn new(targets: Vec<WatchTarget>, debounce: Duration) -> Self {
+        let fingerprints = targets.iter().map(fingerprint_target).collect();
+
+        Self {
+            targets,
+            fingerprints,
+            debounce,
+            pending: BTreeSet::new(),
+            last_change_at: None,
+        }
+    }
+
+    /// Returns the affected source ids once changes have settled for the debounce window.
+    pub fn poll(&mut self, now: Instant) -> Option<Vec<String>> {
+        for (target, previous) in self.targets.iter().zip(self.fingerprints.iter_mut()) {
+            let current = fingerprint_target(target);
+            if current != *previous {
+                *previous = current;
+                self.pending.insert(target.source_id.clone());
+                self.last_change_at = Some(now);
+            }
+        }
+
+        let settled = self
+            .last_change_at
+            .is_some_and(|changed_at| now.saturating_duration_since(changed_at) >= self.debounce);
+        if !settled || self.pending.is_empty() {
+            return None;
+        }
+
+        self.last_change_at = None;
+        Some(std::mem::take(&mut self.pending).into_iter().collect())
+    }
+}
+
+pub struct PollWatcher {
+    stop_requested: Arc<AtomicBool>,
+    worker: Option<JoinHandle<()>>,
+}
+
+impl PollWatcher {
+    pub fn spawn(
+        targets: Vec<WatchTarget>,
+        timing: WatchTiming,
+        on_change: impl Fn(Vec<String>) + Send + 'static,
+    ) -> Self {
+        let stop_requested = Arc::new(AtomicBool::new(false));
+        let worker_stop = Arc::clone(&stop_requested);
+
+        let worker = thread::spawn(move || {
+            let mut tracker = ChangeTracker::new(targets, timing.debounce);
+            while !worker_stop.load(Ordering::Relaxed) {
+                thread::sleep(timing.poll_interval);
+                if let Some(source_ids) = tracker.poll(Instant::now()) {
+                    on_change(source_ids);
+                }
+            }
+        });
+
+        Self {
+            stop_requested,
+            worker: Some(worker),
+        }
+    }
+
+    pub fn stop(mut self) {
+        self.shutdown();
+    }
+
+    fn shutdown(&mut self) {
+        self.stop_requested.store(true, Ordering::Relaxed);
+        if let Some(worker) = self.worker.take() {
+            let _ = worker.join();
+        }
+    }
+}
+
+impl Drop for PollWatcher {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
+
+fn fingerprint_target(target: &WatchTarget) -> Option<Fingerprint> {
+    let metadata = fs::metadata(&target.path).ok()?;
+    let mut entries = vec![(
+        target.path.clone(),
+        metadata.modified().ok(),
+        metadata.len(),
+    )];
+
+    if target.recursive && metadata.is_dir() {
+        let mut collected = BTreeMap::new();
+        collect_directory_entries(&target.path, 0, &mut collected);
+        entries.extend(
+            collected
+                .into_iter()
+                .map(|(path, (modified, len))| (path, modified, len)),
+        );
+    }
+
+    Some(entries)
+}
+
+fn collect_directory_entries(
+    directory: &Path,
+    depth: usize,
+    collected: &mut BTreeMap<PathBuf, (Option<SystemTime>, u64)>,
+) {
+    if depth >= MAX_RECURSIVE_DEPTH {
+        return;
+    }
+
+    let Ok(entries) = fs::read_dir(directory) else {
+        return;
+    };
+
+    for entry in entries.filter_map(Result::ok) {
+        let path = entry.path();
+        let Ok(metadata) = fs::metadata(&path) else {
+            continue;
+        };
+
+        collected.insert(path.clone(), (metadata.modified().ok(), metadata.len()));
+        if metadata.is_dir() {
+            collect_directory_entries(&path, depth + 1, collected);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        fs,
+        time::{Duration, Instant},
+    };
+
+    use super::{ChangeTracker, WatchTarget};
+
+    #[test]
+    fn tracker_reports_changed_sources_after_debounce_window() {
+        let root = std::env::temp_dir().join(format!(
+            "ai-manager-watcher-debounce-{}",
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(&root).expect("temp dir should be created");
+        let config_path = root.join("mcp.json");
+        fs::write(&config_path, "{}").expect("config should be written");
+
+        let mut tracker = ChangeTracker::new(
+            vec![
+                WatchTarget {
+                    source_id: "mcp::cursor::user".to_string(),
+                    path: config_path.clone(),
+                    recursive: false,
+                },
+                WatchTarget {
+                    source_id: "mcp::cursor::project".to_string(),
+                    path: root.join("missing.json"),
+                    recursive: false,
+                },
+            ],
+            Duration::from_millis(200),
+        );
+        let started = Instant::now();
+        assert_eq!(tracker.poll(started), None);
+
+        fs::write(&config_path, "{ \"mcpServers\": {} }").expect("config should be rewritten");
+        assert_eq!(tracker.poll(started + Duration::from_millis(10)), None);
+        assert_eq!(
+            tracker.poll(started + Duration::from_millis(300)),
+            Some(vec!["mcp::cursor::user".to_string()])
+        );
+        assert_eq!(tracker.poll(started + Duration::from_millis(600)), None);
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn recursive_target_detects_nested_file_creation() {
+        let root = std::env::temp_dir().join(format!(
+            "ai-manager-watcher-recursive-{}",
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("skill-a")).expect("skill dir should be created");
+
+        let mut tracker = ChangeTracker::new(
+            vec![WatchTarget {
+                source_id: "skill::user::root".to_string(),
+                path: root.clone(),
+                recursive: true,
+            }],
+            Duration::ZERO,
+        );
+        let started = Instant::now();
+        assert_eq!(tracker.poll(started), None);
+
+        fs::write(root.join("skill-a").join("SKILL.md"), "# Skill A\n")
+            .expect("manifest should be written");
+        assert_eq!(
+            tracker.poll(started),
+            Some(vec!["skill::user::root".to_string()])
+        );
+
+        let _ = fs::remove_dir_all(&root);
+    }
+}
@@ -0,0 +1,51 @@
+use tauri::{AppHandle, Manager, State};
+
+use crate::{
+    interface::contracts::{
+        command::{CommandEnvelope, CommandError, CommandMeta},
+        control::{ControlServerInfo, StartControlServerRequest, StopControlServerResponse},
+    },
+    interface::{control, state::AppState},
+};
+
+#[tauri::command]
+pub fn start_control_server(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    request: StartControlServerRequest,
+) -> CommandEnvelope<ControlServerInfo> {
+    let meta = CommandMeta::new(
+        state.next_operation_id("start_control_server"),
+        state.lifecycle_snapshot(),
+    );
+
+    if state.is_shutting_down() {
+        return CommandEnvelope::failure(CommandError::shutting_down(), meta);
+    }
+
+    state.replace_control_server(None);
+
+    match control::start_control_server(&request, move |auth, http_request| {
+        control::handle_request(&app.state::<AppState>(), auth, http_request)
+    }) {
+        Ok((server, info)) => {
+            state.replace_control_server(Some(server));
+            CommandEnvelope::success(info, meta)
+        }
+        Err(error) => CommandEnvelope::failure(error, meta),
+    }
+}
+
+#[tauri::command]
+pub fn stop_control_server(
+    state: State<'_, AppState>,
+) -> CommandEnvelope<StopControlServerResponse> {
+    let meta = CommandMeta::new(
+        state.next_operation_id("stop_control_server"),
+        state.lifecycle_snapshot(),
+    );
+
+    let stopped = state.replace_control_server(None);
+
+    CommandEnvelope::success(StopControlServerResponse { stopped }, meta)
+}
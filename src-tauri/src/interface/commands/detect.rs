@@ -1,12 +1,11 @@
 use tauri::State;
 
 use crate::{
-    application::AdapterService,
     interface::contracts::{
-        command::{CommandEnvelope, CommandError, CommandMeta},
+        command::CommandEnvelope,
         detect::{DetectClientsRequest, DetectClientsResponse},
     },
-    interface::state::AppState,
+    interface::{operations, state::AppState},
 };
 
 #[tauri::command]
@@ -14,16 +13,5 @@ pub fn detect_clients(
     state: State<'_, AppState>,
     request: DetectClientsRequest,
 ) -> CommandEnvelope<DetectClientsResponse> {
-    let meta = CommandMeta::new(
-        state.next_operation_id("detect"),
-        state.lifecycle_snapshot(),
-    );
-
-    if state.is_shutting_down() {
-        return CommandEnvelope::failure(CommandError::shutting_down(), meta);
-    }
-
-    let service = AdapterService::new(state.adapter_registry(), state.detector_registry());
-
-    CommandEnvelope::success(service.detect_clients(request).redact_sensitive(), meta)
+    operations::detect_clients(&state, request)
 }
@@ -1,11 +1,25 @@
+mod control;
 mod detect;
 mod list;
 mod mutate;
+mod probe;
+mod repair;
 mod replicate;
+mod secrets;
+mod security_audit;
 mod skill_discovery;
+mod undo;
+mod watch;
 
+pub use control::{start_control_server, stop_control_server};
 pub use detect::detect_clients;
 pub use list::list_resources;
 pub use mutate::mutate_resource;
+pub use probe::{mcp_tool_inventory, probe_mcp_server};
+pub use repair::{diagnose_config, repair_config};
 pub use replicate::replicate_resource;
+pub use secrets::externalize_secrets;
+pub use security_audit::security_audit;
 pub use skill_discovery::discover_skill_repository;
+pub use undo::undo_operation;
+pub use watch::{unwatch_sources, watch_sources};
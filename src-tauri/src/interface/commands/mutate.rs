@@ -1,12 +1,11 @@
 use tauri::State;
 
 use crate::{
-    application::AdapterService,
     interface::contracts::{
-        command::{CommandEnvelope, CommandError, CommandMeta},
+        command::CommandEnvelope,
         mutate::{MutateResourceRequest, MutateResourceResponse},
     },
-    interface::state::AppState,
+    interface::{operations, state::AppState},
 };
 
 #[tauri::command]
@@ -14,24 +13,5 @@ pub fn mutate_resource(
     state: State<'_, AppState>,
     request: MutateResourceRequest,
 ) -> CommandEnvelope<MutateResourceResponse> {
-    let meta = CommandMeta::new(
-        state.next_operation_id("mutate"),
-        state.lifecycle_snapshot(),
-    );
-
-    if state.is_shutting_down() {
-        return CommandEnvelope::failure(CommandError::shutting_down(), meta);
-    }
-
-    let service = AdapterService::new(state.adapter_registry(), state.detector_registry());
-
-    match service.mutate_resource(&request) {
-        Ok(response) if response.accepted => {
-            CommandEnvelope::success(response.redact_sensitive(), meta)
-        }
-        Ok(response) => {
-            CommandEnvelope::failure(CommandError::not_implemented(response.message), meta)
-        }
-        Err(error) => CommandEnvelope::failure(error, meta),
-    }
+    operations::mutate_resource(&state, request)
 }
@@ -0,0 +1,26 @@
+use tauri::State;
+
+use crate::{
+    interface::contracts::{
+        command::CommandEnvelope,
+        inventory::{McpToolInventoryRequest, McpToolInventoryResponse},
+        probe::{ProbeMcpServerRequest, ProbeMcpServerResponse},
+    },
+    interface::{operations, state::AppState},
+};
+
+#[tauri::command(async)]
+pub fn probe_mcp_server(
+    state: State<'_, AppState>,
+    request: ProbeMcpServerRequest,
+) -> CommandEnvelope<ProbeMcpServerResponse> {
+    operations::probe_mcp_server(&state, request)
+}
+
+#[tauri::command(async)]
+pub fn mcp_tool_inventory(
+    state: State<'_, AppState>,
+    request: McpToolInventoryRequest,
+) -> CommandEnvelope<McpToolInventoryResponse> {
+    operations::mcp_tool_inventory(&state, request)
+}
@@ -0,0 +1,28 @@
+use tauri::State;
+
+use crate::{
+    interface::contracts::{
+        command::CommandEnvelope,
+        repair::{
+            DiagnoseConfigRequest, DiagnoseConfigResponse, RepairConfigRequest,
+            RepairConfigResponse,
+        },
+    },
+    interface::{operations, state::AppState},
+};
+
+#[tauri::command]
+pub fn diagnose_config(
+    state: State<'_, AppState>,
+    request: DiagnoseConfigRequest,
+) -> CommandEnvelope<DiagnoseConfigResponse> {
+    operations::diagnose_config(&state, request)
+}
+
+#[tauri::command]
+pub fn repair_config(
+    state: State<'_, AppState>,
+    request: RepairConfigRequest,
+) -> CommandEnvelope<RepairConfigResponse> {
+    operations::repair_config(&state, request)
+}
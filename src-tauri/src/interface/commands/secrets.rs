@@ -0,0 +1,17 @@
+use tauri::State;
+
+use crate::{
+    interface::contracts::{
+        command::CommandEnvelope,
+        secrets::{ExternalizeSecretsRequest, ExternalizeSecretsResponse},
+    },
+    interface::{operations, state::AppState},
+};
+
+#[tauri::command]
+pub fn externalize_secrets(
+    state: State<'_, AppState>,
+    request: ExternalizeSecretsRequest,
+) -> CommandEnvelope<ExternalizeSecretsResponse> {
+    operations::externalize_secrets(&state, request)
+}
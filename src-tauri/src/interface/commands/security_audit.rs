@@ -0,0 +1,17 @@
+use tauri::State;
+
+use crate::{
+    interface::contracts::{
+        command::CommandEnvelope,
+        security_audit::{SecurityAuditRequest, SecurityAuditResponse},
+    },
+    interface::{operations, state::AppState},
+};
+
+#[tauri::command]
+pub fn security_audit(
+    state: State<'_, AppState>,
+    request: SecurityAuditRequest,
+) -> CommandEnvelope<SecurityAuditResponse> {
+    operations::security_audit(&state, request)
+}
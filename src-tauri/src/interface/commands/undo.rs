@@ -0,0 +1,17 @@
+use tauri::State;
+
+use crate::{
+    interface::contracts::{
+        command::CommandEnvelope,
+        undo::{UndoOperationRequest, UndoOperationResponse},
+    },
+    interface::{operations, state::AppState},
+};
+
+#[tauri::command]
+pub fn undo_operation(
+    state: State<'_, AppState>,
+    request: UndoOperationRequest,
+) -> CommandEnvelope<UndoOperationResponse> {
+    operations::undo_operation(&state, request)
+}
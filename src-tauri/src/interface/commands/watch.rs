@@ -0,0 +1,77 @@
+use tauri::{AppHandle, Emitter, State};
+
+use crate::{
+    application::AdapterService,
+    infra::{PollWatcher, WatchTiming},
+    interface::contracts::{
+        command::{CommandEnvelope, CommandError, CommandMeta},
+        watch::{
+            SOURCES_CHANGED_EVENT, SourcesChangedEvent, UnwatchSourcesResponse,
+            WatchSourcesRequest, WatchSourcesResponse, WatchedSource,
+        },
+    },
+    interface::state::{AppState, now_epoch_ms},
+};
+
+#[tauri::command]
+pub fn watch_sources(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    request: WatchSourcesRequest,
+) -> CommandEnvelope<WatchSourcesResponse> {
+    let meta = CommandMeta::new(
+        state.next_operation_id("watch_sources"),
+        state.lifecycle_snapshot(),
+    );
+
+    if state.is_shutting_down() {
+        return CommandEnvelope::failure(CommandError::shutting_down(), meta);
+    }
+
+    let service = AdapterService::new(state.adapter_registry(), state.detector_registry());
+
+    let (project_root, targets) = match service.watch_targets(&request) {
+        Ok(resolved) => resolved,
+        Err(error) => return CommandEnvelope::failure(error, meta),
+    };
+
+    let sources = targets
+        .iter()
+        .map(|target| WatchedSource {
+            source_id: target.source_id.clone(),
+            path: target.path.display().to_string(),
+            recursive: target.recursive,
+        })
+        .collect();
+
+    let watcher = PollWatcher::spawn(targets, WatchTiming::default(), move |source_ids| {
+        let _ = app.emit(
+            SOURCES_CHANGED_EVENT,
+            SourcesChangedEvent {
+                source_ids,
+                changed_at_epoch_ms: now_epoch_ms(),
+            },
+        );
+    });
+    state.replace_source_watcher(Some(watcher));
+
+    CommandEnvelope::success(
+        WatchSourcesResponse {
+            project_root,
+            sources,
+        },
+        meta,
+    )
+}
+
+#[tauri::command]
+pub fn unwatch_sources(state: State<'_, AppState>) -> CommandEnvelope<UnwatchSourcesResponse> {
+    let meta = CommandMeta::new(
+        state.next_operation_id("unwatch_sources"),
+        state.lifecycle_snapshot(),
+    );
+
+    let stopped = state.replace_source_watcher(None);
+
+    CommandEnvelope::success(UnwatchSourcesResponse { stopped }, meta)
+}
@@ -1,58 +1,215 @@
 use serde::{Deserialize, Serialize};
 
+use std::{io, path::Path};
+
 use super::common::LifecycleSnapshot;
-use crate::infra::security::redaction::redact_sensitive_text;
+use crate::infra::{MutationFailure, MutationStage, security::redaction::redact_sensitive_text};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum CommandErrorCode {
     ValidationError,
+    Conflict,
+    ParseError,
+    PermissionDenied,
+    IoError,
     NotImplemented,
     ShuttingDown,
     InternalError,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ErrorCategory {
+    Parse,
+    Permission,
+    Validation,
+    Conflict,
+    Io,
+    Unknown,
+}
+
+impl ErrorCategory {
+    pub const fn canonical_code(self) -> &'static str {
+        match self {
+            Self::Parse => "AM_PARSE_001",
+            Self::Permission => "AM_PERMISSION_001",
+            Self::Validation => "AM_VALIDATION_001",
+            Self::Conflict => "AM_CONFLICT_001",
+            Self::Io => "AM_IO_001",
+            Self::Unknown => "AM_UNKNOWN_001",
+        }
+    }
+
+    pub const fn suggested_actions(self) -> &'static [&'static str] {
+        match self {
+            Self::Parse => &["inspect_source_payload", "fix_syntax_and_retry"],
+            Self::Permission => &["check_file_permissions", "reconnect_with_required_scope"],
+            Self::Validation => &["review_validation_errors", "correct_input_and_retry"],
+            Self::Conflict => &["refresh_and_merge", "confirm_overwrite"],
+            Self::Io => &["verify_path_exists", "retry_after_io_recovery"],
+            Self::Unknown => &["retry", "open_diagnostic_logs"],
+        }
+    }
+
+    pub fn from_io_kind(kind: io::ErrorKind) -> Self {
+        if kind == io::ErrorKind::PermissionDenied {
+            Self::Permission
+        } else {
+            Self::Io
+        }
+    }
+}
+
+const FALLBACK_CANONICAL_CODE: &str = "AM_UNKNOWN_999";
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ErrorContext {
+    pub path: Option<String>,
+    pub source_id: Option<String>,
+    pub stage: Option<String>,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct CommandError {
     pub code: CommandErrorCode,
+    pub category: ErrorCategory,
+    pub canonical_code: String,
     pub message: String,
     pub recoverable: bool,
+    #[serde(default)]
+    pub context: Box<ErrorContext>,
+    #[serde(default)]
+    pub suggested_actions: Vec<String>,
 }
 
 impl CommandError {
-    pub fn validation(message: impl Into<String>) -> Self {
-        let message = message.into();
+    fn categorized(
+        code: CommandErrorCode,
+        category: ErrorCategory,
+        message: &str,
+        recoverable: bool,
+    ) -> Self {
         Self {
-            code: CommandErrorCode::ValidationError,
-            message: redact_sensitive_text(&message),
-            recoverable: true,
+            code,
+            category,
+            canonical_code: category.canonical_code().to_string(),
+            message: redact_sensitive_text(message),
+            recoverable,
+            context: Box::default(),
+            suggested_actions: category
+                .suggested_actions()
+                .iter()
+                .map(|action| action.to_string())
+                .collect(),
         }
     }
 
-    pub fn not_implemented(message: impl Into<String>) -> Self {
-        let message = message.into();
-        Self {
-            code: CommandErrorCode::NotImplemented,
-            message: redact_sensitive_text(&message),
-            recoverable: true,
+    pub fn validation(message: impl Into<String>) -> Self {
+        Self::categorized(
+            CommandErrorCode::ValidationError,
+            ErrorCategory::Validation,
+            &message.into(),
+            true,
+        )
+    }
+
+    pub fn conflict(message: impl Into<String>) -> Self {
+        Self::categorized(
+            CommandErrorCode::Conflict,
+            ErrorCategory::Conflict,
+            &message.into(),
+            true,
+        )
+    }
+
+    pub fn parse(message: impl Into<String>) -> Self {
+        Self::categorized(
+            CommandErrorCode::ParseError,
+            ErrorCategory::Parse,
+            &message.into(),
+            true,
+        )
+    }
+
+    /// Classifies filesystem failures as `permission` or `io` from the error kind.
+    pub fn io(message: impl Into<String>, kind: io::ErrorKind) -> Self {
+        match ErrorCategory::from_io_kind(kind) {
+            ErrorCategory::Permission => Self::categorized(
+                CommandErrorCode::PermissionDenied,
+                ErrorCategory::Permission,
+                &message.into(),
+                false,
+            ),
+            category => {
+                Self::categorized(CommandErrorCode::IoError, category, &message.into(), true)
+            }
         }
     }
 
+    pub fn not_implemented(message: impl Into<String>) -> Self {
+        Self::categorized(
+            CommandErrorCode::NotImplemented,
+            ErrorCategory::Validation,
+            &message.into(),
+            true,
+        )
+    }
+
     pub fn shutting_down() -> Self {
-        Self {
-            code: CommandErrorCode::ShuttingDown,
-            message: "Application is shutting down. Retry after restart.".to_string(),
-            recoverable: true,
-        }
+        Self::categorized(
+            CommandErrorCode::ShuttingDown,
+            ErrorCategory::Unknown,
+            "Application is shutting down. Retry after restart.",
+            true,
+        )
     }
 
     pub fn internal(message: impl Into<String>) -> Self {
-        let message = message.into();
-        Self {
-            code: CommandErrorCode::InternalError,
-            message: redact_sensitive_text(&message),
-            recoverable: false,
-        }
+        let mut error = Self::categorized(
+            CommandErrorCode::InternalError,
+            ErrorCategory::Unknown,
+            &message.into(),
+            false,
+        );
+        error.canonical_code = FALLBACK_CANONICAL_CODE.to_string();
+        error
+    }
+
+    pub fn from_mutation_failure(failure: &MutationFailure, path: &Path) -> Self {
+        let error = match (failure.stage, failure.io_kind) {
+            (MutationStage::RevisionCheck, None) => Self::conflict(&failure.message),
+            (stage, Some(kind)) => Self::io(
+                format!(
+                    "[stage={:?}] {} (rollback_succeeded={})",
+                    stage, failure.message, failure.rollback_succeeded
+                ),
+                kind,
+            ),
+            (stage, None) => Self::internal(format!(
+                "[stage={:?}] {} (rollback_succeeded={})",
+                stage, failure.message, failure.rollback_succeeded
+            )),
+        };
+
+        error
+            .with_path(path.display().to_string())
+            .with_stage(failure.stage.as_str())
+    }
+
+    pub fn with_path(mut self, path: impl Into<String>) -> Self {
+        self.context.path = Some(path.into());
+        self
+    }
+
+    pub fn with_source_id(mut self, source_id: impl Into<String>) -> Self {
+        self.context.source_id = Some(source_id.into());
+        self
+    }
+
+    pub fn with_stage(mut self, stage: impl Into<String>) -> Self {
+        self.context.stage = Some(stage.into());
+        self
     }
 }
 
@@ -101,7 +258,12 @@ impl<T> CommandEnvelope<T> {
 
 #[cfg(test)]
 mod tests {
-    use super::{CommandEnvelope, CommandError, CommandErrorCode, CommandMeta};
+    use std::{fs, io, path::Path};
+
+    use serde_json::Value;
+
+    use super::{CommandEnvelope, CommandError, CommandErrorCode, CommandMeta, ErrorCategory};
+    use crate::infra::{MutationFailure, MutationStage};
     use crate::interface::contracts::common::{LifecyclePhase, LifecycleSnapshot};
 
     fn meta(operation_id: &str) -> CommandMeta {
@@ -155,4 +317,146 @@ mod tests {
         let error = CommandError::validation("token=abc123");
         assert_eq!(error.message, "token=[REDACTED]");
     }
+
+    #[test]
+    fn internal_error_uses_fallback_canonical_code_and_actions() {
+        let error = CommandError::internal("registry misconfigured");
+
+        assert_eq!(error.category, ErrorCategory::Unknown);
+        assert_eq!(error.canonical_code, "AM_UNKNOWN_999");
+        assert_eq!(
+            error.suggested_actions,
+            vec!["retry", "open_diagnostic_logs"]
+        );
+    }
+
+    #[test]
+    fn io_errors_are_classified_by_kind() {
+        let permission = CommandError::io("cannot write", io::ErrorKind::PermissionDenied);
+        let missing = CommandError::io("cannot read", io::ErrorKind::NotFound);
+
+        assert_eq!(permission.code, CommandErrorCode::PermissionDenied);
+        assert_eq!(permission.canonical_code, "AM_PERMISSION_001");
+        assert!(!permission.recoverable);
+        assert_eq!(missing.code, CommandErrorCode::IoError);
+        assert_eq!(missing.category, ErrorCategory::Io);
+    }
+
+    #[test]
+    fn mutation_failure_carries_path_and_stage_context() {
+        let failure = MutationFailure {
+            stage: MutationStage::Write,
+            message: "Permission denied (os error 13)".to_string(),
+            rollback_succeeded: true,
+            io_kind: Some(io::ErrorKind::PermissionDenied),
+        };
+
+        let error = CommandError::from_mutation_failure(&failure, Path::new("/tmp/mcp.json"))
+            .with_source_id("mcp::cursor::user");
+
+        assert_eq!(error.category, ErrorCategory::Permission);
+        assert_eq!(error.context.path.as_deref(), Some("/tmp/mcp.json"));
+        assert_eq!(error.context.stage.as_deref(), Some("write"));
+        assert_eq!(
+            error.context.source_id.as_deref(),
+            Some("mcp::cursor::user")
+        );
+        assert!(error.message.contains("rollback_succeeded=true"));
+    }
+
+    #[test]
+    fn stale_revision_failure_maps_to_conflict() {
+        let failure = MutationFailure {
+            stage: MutationStage::RevisionCheck,
+            message: "'/tmp/mcp.json' changed since it was read".to_string(),
+            rollback_succeeded: false,
+            io_kind: None,
+        };
+
+        let error = CommandError::from_mutation_failure(&failure, Path::new("/tmp/mcp.json"));
+
+        assert_eq!(error.code, CommandErrorCode::Conflict);
+        assert_eq!(error.canonical_code, "AM_CONFLICT_001");
+    }
+
+    #[test]
+    fn categories_match_the_error_ui_contract() {
+        let spec_path = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("..")
+            .join("docs")
+            .join("spec")
+            .join("error-ui-contract.v1.json");
+        let spec: Value = serde_json::from_str(
+            &fs::read_to_string(&spec_path).expect("error UI contract should be readable"),
+        )
+        .expect("error UI contract should be valid JSON");
+        let representative = |category: ErrorCategory| match category {
+            ErrorCategory::Parse => CommandError::parse("x"),
+            ErrorCategory::Permission => CommandError::io("x", io::ErrorKind::PermissionDenied),
+            ErrorCategory::Validation => CommandError::validation("x"),
+            ErrorCategory::Conflict => CommandError::conflict("x"),
+            ErrorCategory::Io => CommandError::io("x", io::ErrorKind::NotFound),
+            ErrorCategory::Unknown => CommandError::shutting_down(),
+        };
+
+        let entries = spec["errorCategories"]
+            .as_array()
+            .expect("errorCategories should be an array");
+        let mut categories = Vec::new();
+        for entry in entries {
+            let category: ErrorCategory = serde_json::from_value(entry["category"].clone())
+                .expect("every contract category should exist in Rust");
+            let error = representative(category);
+
+            assert_eq!(
+                entry["canonicalCode"].as_str(),
+                Some(category.canonical_code()),
+                "{category:?}"
+            );
+            assert_eq!(
+                entry["suggestedActions"],
+                serde_json::json!(category.suggested_actions()),
+                "{category:?}"
+            );
+            assert_eq!(error.category, category);
+            assert_eq!(
+                entry["retryable"].as_bool(),
+                Some(error.recoverable),
+                "{category:?}"
+            );
+            for action in category.suggested_actions() {
+                assert!(
+                    spec["actionCatalog"].get(*action).is_some(),
+                    "{action} is missing from actionCatalog"
+                );
+            }
+            categories.push(category);
+        }
+        assert_eq!(
+            categories,
+            vec![
+                ErrorCategory::Parse,
+                ErrorCategory::Permission,
+                ErrorCategory::Validation,
+                ErrorCategory::Conflict,
+                ErrorCategory::Io,
+                ErrorCategory::Unknown,
+            ]
+        );
+
+        let policy = &spec["genericErrorPolicy"];
+        let fallback = CommandError::internal("x");
+        assert_eq!(
+            serde_json::to_value(fallback.category).expect("category should serialize"),
+            policy["fallbackCategory"]
+        );
+        assert_eq!(
+            policy["fallbackCode"].as_str(),
+            Some(fallback.canonical_code.as_str())
+        );
+        assert_eq!(
+            policy["fallbackSuggestedActions"],
+            serde_json::json!(fallback.suggested_actions)
+        );
+    }
 }
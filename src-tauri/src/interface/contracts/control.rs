@@ -0,0 +1,48 @@
+use serde::{Deserialize, Serialize};
+
+pub const CONTROL_PROTOCOL_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ControlTransport {
+    #[default]
+    Tcp,
+    Unix,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub struct StartControlServerRequest {
+    #[serde(default)]
+    pub transport: ControlTransport,
+    #[serde(default)]
+    pub port: Option<u16>,
+    #[serde(default)]
+    pub socket_path: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ControlServerInfo {
+    pub transport: ControlTransport,
+    pub address: String,
+    pub token: String,
+    pub discovery_path: Option<String>,
+    pub protocol_version: u32,
+}
+
+#[cfg(feature = "desktop")]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StopControlServerResponse {
+    pub stopped: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ControlHandshakeRequest {
+    pub token: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ControlHandshakeResponse {
+    pub session_token: String,
+    pub protocol_version: u32,
+    pub methods: Vec<String>,
+}
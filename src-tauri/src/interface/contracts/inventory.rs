@@ -0,0 +1,65 @@
+use serde::{Deserialize, Serialize};
+
+use super::common::ClientKind;
+use crate::infra::security::redaction::redact_sensitive_text;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct McpServerInventory {
+    pub resource_id: String,
+    pub client: ClientKind,
+    pub display_name: String,
+    pub transport_fingerprint: String,
+    pub protocol_version: Option<String>,
+    pub server_name: Option<String>,
+    pub server_version: Option<String>,
+    pub tool_names: Vec<String>,
+    pub prompt_names: Vec<String>,
+    pub resource_uris: Vec<String>,
+    pub probed_at_epoch_ms: u128,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct McpToolInventoryRequest {
+    #[serde(default)]
+    pub client: Option<ClientKind>,
+    #[serde(default)]
+    pub project_root: Option<String>,
+    #[serde(default)]
+    pub refresh: bool,
+    #[serde(default)]
+    pub timeout_ms: Option<u64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct McpServerInventoryEntry {
+    pub client: ClientKind,
+    pub resource_id: String,
+    pub display_name: String,
+    pub source_label: String,
+    pub enabled: bool,
+    pub inventory: Option<McpServerInventory>,
+    pub stale: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct McpToolCollision {
+    pub client: ClientKind,
+    pub tool_name: String,
+    pub resource_ids: Vec<String>,
+    pub display_names: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct McpToolInventoryResponse {
+    pub project_root: Option<String>,
+    pub servers: Vec<McpServerInventoryEntry>,
+    pub collisions: Vec<McpToolCollision>,
+    pub warning: Option<String>,
+}
+
+impl McpToolInventoryResponse {
+    pub fn redact_sensitive(mut self) -> Self {
+        self.warning = self.warning.map(|warning| redact_sensitive_text(&warning));
+        self
+    }
+}
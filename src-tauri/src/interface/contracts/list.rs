@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
 
+use super::command::ErrorCategory;
 use super::common::{ClientKind, ResourceKind, ResourceSourceScope};
 pub use crate::domain::ResourceRecord;
 use crate::infra::security::redaction::redact_sensitive_text;
@@ -27,6 +28,54 @@ pub struct ListResourcesRequest {
     pub scope_filter: Option<Vec<ResourceSourceScope>>,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DiagnosticSeverity {
+    Info,
+    Warning,
+    Error,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResourceDiagnostic {
+    pub client: ClientKind,
+    pub severity: DiagnosticSeverity,
+    pub code: String,
+    pub message: String,
+    pub source_id: Option<String>,
+    pub source_path: Option<String>,
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+    pub server_name: Option<String>,
+    #[serde(default)]
+    pub category: Option<ErrorCategory>,
+    #[serde(default)]
+    pub suggested_actions: Vec<String>,
+}
+
+impl ResourceDiagnostic {
+    pub fn with_category(mut self, category: ErrorCategory) -> Self {
+        self.category = Some(category);
+        self.suggested_actions = category
+            .suggested_actions()
+            .iter()
+            .map(|action| action.to_string())
+            .collect();
+        self
+    }
+
+    pub fn summary(&self) -> String {
+        format!("[{}:{}] {}", self.client.as_str(), self.code, self.message)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SourceRevision {
+    pub source_id: String,
+    pub source_path: String,
+    pub revision: Option<String>,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ListResourcesResponse {
     pub client: Option<ClientKind>,
@@ -35,11 +84,18 @@ pub struct ListResourcesResponse {
     pub view_mode: ResourceViewMode,
     pub items: Vec<ResourceRecord>,
     pub warning: Option<String>,
+    #[serde(default)]
+    pub diagnostics: Vec<ResourceDiagnostic>,
+    #[serde(default)]
+    pub source_revisions: Vec<SourceRevision>,
 }
 
 impl ListResourcesResponse {
     pub fn redact_sensitive(mut self) -> Self {
         self.warning = self.warning.map(|warning| redact_sensitive_text(&warning));
+        for diagnostic in &mut self.diagnostics {
+            diagnostic.message = redact_sensitive_text(&diagnostic.message);
+        }
         self
     }
 }
@@ -48,8 +104,11 @@ impl ListResourcesResponse {
 mod tests {
     use serde_json::json;
 
-    use super::{ListResourcesRequest, ListResourcesResponse, ResourceViewMode};
-    use crate::domain::ResourceKind;
+    use super::{
+        DiagnosticSeverity, ListResourcesRequest, ListResourcesResponse, ResourceDiagnostic,
+        ResourceViewMode,
+    };
+    use crate::domain::{ClientKind, ResourceKind};
 
     #[test]
     fn list_response_redacts_warning() {
@@ -60,12 +119,47 @@ mod tests {
             view_mode: ResourceViewMode::Effective,
             items: Vec::new(),
             warning: Some("api_key=abc123".to_string()),
+            diagnostics: Vec::new(),
+            source_revisions: Vec::new(),
         }
         .redact_sensitive();
 
         assert_eq!(response.warning, Some("api_key=[REDACTED]".to_string()));
     }
 
+    #[test]
+    fn list_response_redacts_diagnostic_messages() {
+        let response = ListResourcesResponse {
+            client: None,
+            resource_kind: ResourceKind::Mcp,
+            project_root: None,
+            view_mode: ResourceViewMode::Effective,
+            items: Vec::new(),
+            warning: None,
+            diagnostics: vec![ResourceDiagnostic {
+                client: ClientKind::Cursor,
+                severity: DiagnosticSeverity::Error,
+                code: "PARSER_JSON_SYNTAX".to_string(),
+                message: "near token=abc123".to_string(),
+                source_id: None,
+                source_path: Some("/tmp/mcp.json".to_string()),
+                line: Some(3),
+                column: Some(7),
+                server_name: None,
+                category: None,
+                suggested_actions: Vec::new(),
+            }],
+            source_revisions: Vec::new(),
+        }
+        .redact_sensitive();
+
+        assert_eq!(response.diagnostics[0].message, "near token=[REDACTED]");
+        assert_eq!(
+            serde_json::to_value(&response.diagnostics[0]).expect("diagnostic should serialize")["severity"],
+            json!("error")
+        );
+    }
+
     #[test]
     fn list_request_defaults_source_aware_fields() {
         let request: ListResourcesRequest =
@@ -1,7 +1,16 @@
 pub mod command;
 pub mod common;
+pub mod control;
 pub mod detect;
+pub mod inventory;
 pub mod list;
 pub mod mutate;
+pub mod probe;
+pub mod repair;
 pub mod replicate;
+pub mod secrets;
+pub mod security_audit;
 pub mod skill_discovery;
+pub mod undo;
+#[cfg(feature = "desktop")]
+pub mod watch;
@@ -17,6 +17,8 @@ pub struct MutateResourceRequest {
     pub target_source_id: Option<String>,
     #[serde(default)]
     pub payload: Option<Value>,
+    #[serde(default)]
+    pub expected_revision: Option<String>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -27,11 +29,14 @@ pub struct MutateResourceResponse {
     pub message: String,
     pub source_path: Option<String>,
     pub target_source_id: Option<String>,
+    /// Set when the write left likely secrets in a file Git would commit.
+    pub warning: Option<String>,
 }
 
 impl MutateResourceResponse {
     pub fn redact_sensitive(mut self) -> Self {
         self.message = redact_sensitive_text(&self.message);
+        self.warning = self.warning.map(|warning| redact_sensitive_text(&warning));
         self
     }
 }
@@ -52,6 +57,7 @@ mod tests {
             message: "token=abc123".to_string(),
             source_path: None,
             target_source_id: None,
+            warning: None,
         }
         .redact_sensitive();
 
@@ -71,6 +77,7 @@ mod tests {
 
         assert_eq!(request.project_root, None);
         assert_eq!(request.target_source_id, None);
+        assert_eq!(request.expected_revision, None);
         assert!(matches!(request.client, ClientKind::Codex));
         assert!(matches!(request.resource_kind, ResourceKind::Mcp));
     }
@@ -0,0 +1,74 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::common::ClientKind;
+use super::list::ResourceRecord;
+use crate::infra::security::redaction::redact_sensitive_text;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProbeMcpServerRequest {
+    pub resource: ResourceRecord,
+    #[serde(default)]
+    pub env: BTreeMap<String, String>,
+    #[serde(default)]
+    pub headers: BTreeMap<String, String>,
+    #[serde(default)]
+    pub timeout_ms: Option<u64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum McpProbeStatus {
+    Healthy,
+    SpawnFailed,
+    TimedOut,
+    HandshakeFailed,
+    DnsFailed,
+    ConnectFailed,
+    TlsFailed,
+    HttpError,
+    Unsupported,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum McpRemoteTransport {
+    StreamableHttp,
+    Sse,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProbeMcpServerResponse {
+    pub client: ClientKind,
+    pub target_id: String,
+    pub status: McpProbeStatus,
+    pub protocol_version: Option<String>,
+    pub server_name: Option<String>,
+    pub server_version: Option<String>,
+    pub tool_count: Option<usize>,
+    pub tool_names: Vec<String>,
+    #[serde(default)]
+    pub prompt_names: Vec<String>,
+    #[serde(default)]
+    pub resource_uris: Vec<String>,
+    #[serde(default)]
+    pub remote_transport: Option<McpRemoteTransport>,
+    #[serde(default)]
+    pub http_status: Option<u16>,
+    #[serde(default)]
+    pub resolved_addresses: Vec<String>,
+    pub elapsed_ms: u64,
+    pub stderr_tail: Vec<String>,
+    pub message: String,
+}
+
+impl ProbeMcpServerResponse {
+    pub fn redact_sensitive(mut self) -> Self {
+        self.message = redact_sensitive_text(&self.message);
+        for line in &mut self.stderr_tail {
+            *line = redact_sensitive_text(line);
+        }
+        self
+    }
+}
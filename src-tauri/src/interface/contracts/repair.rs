@@ -0,0 +1,136 @@
+use serde::{Deserialize, Serialize};
+
+use super::common::ClientKind;
+use crate::infra::security::redaction::redact_sensitive_text;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DiagnoseConfigRequest {
+    pub client: ClientKind,
+    #[serde(default)]
+    pub project_root: Option<String>,
+    #[serde(default)]
+    pub target_source_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConfigIssueKind {
+    Syntax,
+    TrailingComma,
+    DuplicateServerKey,
+    SectionNotObject,
+    ServerTransportMissing,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConfigIssue {
+    pub kind: ConfigIssueKind,
+    pub message: String,
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+    pub server_name: Option<String>,
+    pub fixable: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConfigRepairProposal {
+    pub source_id: String,
+    pub source_path: String,
+    pub issues: Vec<ConfigIssue>,
+    pub diff: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DiagnoseConfigResponse {
+    pub client: ClientKind,
+    pub project_root: Option<String>,
+    pub checked_sources: usize,
+    pub proposals: Vec<ConfigRepairProposal>,
+}
+
+impl DiagnoseConfigResponse {
+    pub fn redact_sensitive(mut self) -> Self {
+        for proposal in &mut self.proposals {
+            proposal.diff = redact_sensitive_text(&proposal.diff);
+            for issue in &mut proposal.issues {
+                issue.message = redact_sensitive_text(&issue.message);
+            }
+        }
+        self
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RepairConfigRequest {
+    pub client: ClientKind,
+    #[serde(default)]
+    pub project_root: Option<String>,
+    pub target_source_id: String,
+    pub accepted_diff: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RepairConfigResponse {
+    pub client: ClientKind,
+    pub source_id: String,
+    pub source_path: String,
+    pub repaired_issues: Vec<ConfigIssue>,
+    pub backup_path: Option<String>,
+    pub message: String,
+}
+
+impl RepairConfigResponse {
+    pub fn redact_sensitive(mut self) -> Self {
+        self.message = redact_sensitive_text(&self.message);
+        for issue in &mut self.repaired_issues {
+            issue.message = redact_sensitive_text(&issue.message);
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::{
+        ConfigIssue, ConfigIssueKind, ConfigRepairProposal, DiagnoseConfigRequest,
+        DiagnoseConfigResponse,
+    };
+    use crate::domain::ClientKind;
+
+    #[test]
+    fn diagnose_response_redacts_diff_and_issue_messages() {
+        let response = DiagnoseConfigResponse {
+            client: ClientKind::Cursor,
+            project_root: None,
+            checked_sources: 1,
+            proposals: vec![ConfigRepairProposal {
+                source_id: "mcp::cursor::user::/tmp/mcp.json::/mcpServers".to_string(),
+                source_path: "/tmp/mcp.json".to_string(),
+                issues: vec![ConfigIssue {
+                    kind: ConfigIssueKind::ServerTransportMissing,
+                    message: "token=abc123".to_string(),
+                    line: Some(2),
+                    column: Some(5),
+                    server_name: Some("broken".to_string()),
+                    fixable: true,
+                }],
+                diff: "-    \"env\": { \"API_KEY\": \"sk-abcdefghijklmnop\" }\n".to_string(),
+            }],
+        }
+        .redact_sensitive();
+
+        assert_eq!(response.proposals[0].issues[0].message, "token=[REDACTED]");
+        assert!(!response.proposals[0].diff.contains("sk-abcdefghijklmnop"));
+    }
+
+    #[test]
+    fn diagnose_request_defaults_optional_fields() {
+        let request: DiagnoseConfigRequest = serde_json::from_value(json!({ "client": "cursor" }))
+            .expect("request should deserialize");
+
+        assert_eq!(request.project_root, None);
+        assert_eq!(request.target_source_id, None);
+    }
+}
@@ -0,0 +1,53 @@
+use serde::{Deserialize, Serialize};
+
+use super::common::ClientKind;
+use crate::infra::security::redaction::redact_sensitive_text;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExternalizeSecretsRequest {
+    pub client: ClientKind,
+    #[serde(default)]
+    pub project_root: Option<String>,
+    #[serde(default)]
+    pub target_source_id: Option<String>,
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+/// How the rewritten config gets the value back at launch time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SecretReferenceKind {
+    /// The server command is wrapped by `ai-manager-cli run-with-secrets`.
+    Launcher,
+    /// The value is read from an environment variable the client expands.
+    EnvInterpolation,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExternalizedSecret {
+    pub source_id: String,
+    pub source_path: String,
+    pub server_name: String,
+    pub field: String,
+    pub secret_name: String,
+    pub reference: SecretReferenceKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExternalizeSecretsResponse {
+    pub client: ClientKind,
+    pub project_root: Option<String>,
+    pub dry_run: bool,
+    pub secrets: Vec<ExternalizedSecret>,
+    pub updated_sources: Vec<String>,
+    pub store_location: String,
+    pub message: String,
+}
+
+impl ExternalizeSecretsResponse {
+    pub fn redact_sensitive(mut self) -> Self {
+        self.message = redact_sensitive_text(&self.message);
+        self
+    }
+}
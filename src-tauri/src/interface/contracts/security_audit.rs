@@ -0,0 +1,60 @@
+use serde::{Deserialize, Serialize};
+
+use super::common::{ClientKind, ResourceKind, ResourceSourceScope};
+use crate::infra::security::redaction::redact_sensitive_text;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SecurityAuditRequest {
+    /// Every client when omitted.
+    #[serde(default)]
+    pub client: Option<ClientKind>,
+    #[serde(default)]
+    pub project_root: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditSeverity {
+    Low,
+    Medium,
+    High,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SecurityAuditFinding {
+    pub client: ClientKind,
+    pub resource_kind: ResourceKind,
+    pub source_scope: ResourceSourceScope,
+    pub file_path: String,
+    pub line: usize,
+    pub column: usize,
+    pub rule: String,
+    pub severity: AuditSeverity,
+    /// The offending line with the secret replaced by `[REDACTED]`.
+    pub excerpt: String,
+    pub committed_to_git: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SecurityAuditResponse {
+    pub project_root: Option<String>,
+    pub scanned_files: usize,
+    pub findings: Vec<SecurityAuditFinding>,
+    /// Files tracked by Git that contain findings.
+    pub high_risk_files: Vec<String>,
+    pub warnings: Vec<String>,
+    pub message: String,
+}
+
+impl SecurityAuditResponse {
+    pub fn redact_sensitive(mut self) -> Self {
+        self.message = redact_sensitive_text(&self.message);
+        for finding in &mut self.findings {
+            finding.excerpt = redact_sensitive_text(&finding.excerpt);
+        }
+        for warning in &mut self.warnings {
+            *warning = redact_sensitive_text(warning);
+        }
+        self
+    }
+}
@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+
+use super::common::{ClientKind, ResourceKind};
+use crate::infra::security::redaction::redact_sensitive_text;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UndoOperationRequest {
+    /// `entry_id` of a line in the operation log.
+    pub entry_id: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UndoOperationResponse {
+    pub entry_id: String,
+    /// Log entry for the undo itself, which can be undone in turn.
+    pub undo_entry_id: Option<String>,
+    pub client: ClientKind,
+    pub resource_kind: ResourceKind,
+    pub target_id: String,
+    pub restored_paths: Vec<String>,
+    pub message: String,
+}
+
+impl UndoOperationResponse {
+    pub fn redact_sensitive(mut self) -> Self {
+        self.message = redact_sensitive_text(&self.message);
+        self
+    }
+}
@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+
+pub const SOURCES_CHANGED_EVENT: &str = "sources-changed";
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WatchSourcesRequest {
+    #[serde(default)]
+    pub project_root: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WatchedSource {
+    pub source_id: String,
+    pub path: String,
+    pub recursive: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WatchSourcesResponse {
+    pub project_root: Option<String>,
+    pub sources: Vec<WatchedSource>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UnwatchSourcesResponse {
+    pub stopped: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SourcesChangedEvent {
+    pub source_ids: Vec<String>,
+    pub changed_at_epoch_ms: u128,
+}
@@ -0,0 +1,681 @@
+use std::{
+    collections::VecDeque,
+    fs,
+    io::{self, Write},
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
+
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use serde_json::{Value, json};
+
+use crate::{
+    infra::{
+        ControlEndpoint, ControlServer, HttpRequest, HttpResponse, app_data_dir,
+        security::token::{generate_token, tokens_match},
+    },
+    interface::{
+        contracts::{
+            command::{CommandEnvelope, CommandError, CommandMeta},
+            control::{
+                CONTROL_PROTOCOL_VERSION, ControlHandshakeRequest, ControlHandshakeResponse,
+                ControlServerInfo, ControlTransport, StartControlServerRequest,
+            },
+        },
+        operations,
+        state::AppState,
+    },
+};
+
+pub const CONTROL_RPC_PATH: &str = "/rpc";
+pub const CONTROL_METHODS: [&str; 13] = [
+    "handshake",
+    "detect_clients",
+    "list_resources",
+    "mutate_resource",
+    "replicate_resource",
+    "discover_skill_repository",
+    "probe_mcp_server",
+    "mcp_tool_inventory",
+    "diagnose_config",
+    "repair_config",
+    "externalize_secrets",
+    "security_audit",
+    "undo_operation",
+];
+
+const MAX_SESSIONS: usize = 32;
+const DISCOVERY_FILE: &str = "control-server.json";
+
+const PARSE_ERROR: i64 = -32700;
+const INVALID_REQUEST: i64 = -32600;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+const INTERNAL_ERROR: i64 = -32603;
+const UNAUTHORIZED: i64 = -32001;
+
+/// Launch token handed to whoever started the server, exchanged for session tokens.
+pub struct ControlAuth {
+    launch_token: String,
+    sessions: Mutex<VecDeque<String>>,
+}
+
+impl ControlAuth {
+    pub fn new() -> io::Result<Self> {
+        Ok(Self {
+            launch_token: generate_token()?,
+            sessions: Mutex::new(VecDeque::new()),
+        })
+    }
+
+    pub fn launch_token(&self) -> &str {
+        &self.launch_token
+    }
+
+    fn accepts_launch_token(&self, token: &str) -> bool {
+        tokens_match(&self.launch_token, token)
+    }
+
+    fn open_session(&self) -> io::Result<String> {
+        let session_token = generate_token()?;
+        let mut sessions = match self.sessions.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        if sessions.len() == MAX_SESSIONS {
+            sessions.pop_front();
+        }
+        sessions.push_back(session_token.clone());
+        Ok(session_token)
+    }
+
+    fn is_authorized(&self, bearer_token: Option<&str>) -> bool {
+        let Some(bearer_token) = bearer_token else {
+            return false;
+        };
+        let sessions = match self.sessions.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        sessions
+            .iter()
+            .any(|session| tokens_match(session, bearer_token))
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct RpcRequest {
+    jsonrpc: String,
+    #[serde(default)]
+    id: Value,
+    method: String,
+    #[serde(default)]
+    params: Option<Value>,
+}
+
+#[derive(Debug, Serialize)]
+struct RpcError {
+    code: i64,
+    message: String,
+}
+
+impl RpcError {
+    fn new(code: i64, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+        }
+    }
+}
+
+/// Binds the requested endpoint and publishes a discovery file for local tools.
+pub fn start_control_server(
+    request: &StartControlServerRequest,
+    handler: impl Fn(&ControlAuth, HttpRequest) -> HttpResponse + Send + 'static,
+) -> Result<(ControlServer, ControlServerInfo), CommandError> {
+    let endpoint = endpoint_for(request)?;
+    let data_dir = app_data_dir();
+    if let Some(directory) = data_dir.as_deref() {
+        remove_stale_discovery_file(directory)?;
+    }
+    let auth = Arc::new(ControlAuth::new().map_err(|error| {
+        CommandError::internal(format!("failed to create a control token: {error}"))
+    })?);
+    let token = auth.launch_token().to_string();
+
+    let mut server =
+        ControlServer::bind(&endpoint, move |http_request| handler(&auth, http_request)).map_err(
+            |error| {
+                CommandError::io(
+                    format!("failed to start control server: {error}"),
+                    error.kind(),
+                )
+            },
+        )?;
+
+    let mut info = ControlServerInfo {
+        transport: request.transport,
+        address: server.address().to_string(),
+        token,
+        discovery_path: None,
+        protocol_version: CONTROL_PROTOCOL_VERSION,
+    };
+    if let Some(discovery_path) = data_dir
+        .as_deref()
+        .and_then(|directory| write_discovery_file(directory, &info))
+    {
+        info.discovery_path = Some(discovery_path.display().to_string());
+        server.remove_on_stop(discovery_path);
+    }
+
+    Ok((server, info))
+}
+
+pub fn handle_request(state: &AppState, auth: &ControlAuth, request: HttpRequest) -> HttpResponse {
+    if request.path != CONTROL_RPC_PATH {
+        return rpc_failure(
+            404,
+            Value::Null,
+            RpcError::new(INVALID_REQUEST, "not found"),
+        );
+    }
+    if request.method != "POST" {
+        return rpc_failure(
+            405,
+            Value::Null,
+            RpcError::new(INVALID_REQUEST, "only POST is supported"),
+        );
+    }
+
+    let rpc_request: RpcRequest = match serde_json::from_slice(&request.body) {
+        Ok(rpc_request) => rpc_request,
+        Err(error) => {
+            return rpc_failure(
+                200,
+                Value::Null,
+                RpcError::new(PARSE_ERROR, error.to_string()),
+            );
+        }
+    };
+    if rpc_request.jsonrpc != "2.0" {
+        return rpc_failure(
+            200,
+            rpc_request.id,
+            RpcError::new(INVALID_REQUEST, "jsonrpc must be \"2.0\""),
+        );
+    }
+
+    let params = rpc_request
+        .params
+        .unwrap_or_else(|| Value::Object(Default::default()));
+
+    if rpc_request.method == "handshake" {
+        return match handshake(state, auth, params) {
+            Ok(result) => rpc_success(rpc_request.id, result),
+            Err(error) if error.code == UNAUTHORIZED => rpc_failure(401, rpc_request.id, error),
+            Err(error) => rpc_failure(200, rpc_request.id, error),
+        };
+    }
+
+    if !auth.is_authorized(request.bearer_token()) {
+        return rpc_failure(
+            401,
+            rpc_request.id,
+            RpcError::new(UNAUTHORIZED, "missing or invalid session token"),
+        );
+    }
+
+    match dispatch(state, &rpc_request.method, params) {
+        Ok(result) => rpc_success(rpc_request.id, result),
+        Err(error) => rpc_failure(200, rpc_request.id, error),
+    }
+}
+
+fn handshake(state: &AppState, auth: &ControlAuth, params: Value) -> Result<Value, RpcError> {
+    let request: ControlHandshakeRequest = serde_json::from_value(params)
+        .map_err(|error| RpcError::new(INVALID_PARAMS, error.to_string()))?;
+    if !auth.accepts_launch_token(&request.token) {
+        return Err(RpcError::new(UNAUTHORIZED, "invalid launch token"));
+    }
+
+    let meta = CommandMeta::new(
+        state.next_operation_id("control_handshake"),
+        state.lifecycle_snapshot(),
+    );
+    let envelope = if state.is_shutting_down() {
+        CommandEnvelope::failure(CommandError::shutting_down(), meta)
+    } else {
+        match auth.open_session() {
+            Ok(session_token) => CommandEnvelope::success(
+                ControlHandshakeResponse {
+                    session_token,
+                    protocol_version: CONTROL_PROTOCOL_VERSION,
+                    methods: CONTROL_METHODS
+                        .iter()
+                        .map(|method| method.to_string())
+                        .collect(),
+                },
+                meta,
+            ),
+            Err(error) => CommandEnvelope::failure(
+                CommandError::internal(format!("failed to create a session token: {error}")),
+                meta,
+            ),
+        }
+    };
+
+    to_result(envelope)
+}
+
+fn dispatch(state: &AppState, method: &str, params: Value) -> Result<Value, RpcError> {
+    match method {
+        "detect_clients" => invoke(params, |request| operations::detect_clients(state, request)),
+        "list_resources" => invoke(params, |request| operations::list_resources(state, request)),
+        "mutate_resource" => invoke(params, |request| {
+            operations::mutate_resource(state, request)
+        }),
+        "replicate_resource" => invoke(params, |request| {
+            operations::replicate_resource(state, request)
+        }),
+        "discover_skill_repository" => invoke(params, |request| {
+            operations::discover_skill_repository(state, request)
+        }),
+        "probe_mcp_server" => invoke(params, |request| {
+            operations::probe_mcp_server(state, request)
+        }),
+        "mcp_tool_inventory" => invoke(params, |request| {
+            operations::mcp_tool_inventory(state, request)
+        }),
+        "diagnose_config" => invoke(params, |request| {
+            operations::diagnose_config(state, request)
+        }),
+        "repair_config" => invoke(params, |request| operations::repair_config(state, request)),
+        "externalize_secrets" => invoke(params, |request| {
+            operations::externalize_secrets(state, request)
+        }),
+        "security_audit" => invoke(params, |request| operations::security_audit(state, request)),
+        "undo_operation" => invoke(params, |request| operations::undo_operation(state, request)),
+        _ => Err(RpcError::new(
+            METHOD_NOT_FOUND,
+            format!("unknown method '{method}'"),
+        )),
+    }
+}
+
+fn invoke<Req: DeserializeOwned, Resp: Serialize>(
+    params: Value,
+    operation: impl FnOnce(Req) -> CommandEnvelope<Resp>,
+) -> Result<Value, RpcError> {
+    let request = serde_json::from_value(params)
+        .map_err(|error| RpcError::new(INVALID_PARAMS, error.to_string()))?;
+
+    to_result(operation(request))
+}
+
+fn to_result<T: Serialize>(envelope: CommandEnvelope<T>) -> Result<Value, RpcError> {
+    serde_json::to_value(envelope).map_err(|error| RpcError::new(INTERNAL_ERROR, error.to_string()))
+}
+
+fn rpc_success(id: Value, result: Value) -> HttpResponse {
+    HttpResponse::json(
+        200,
+        json!({ "jsonrpc": "2.0", "id": id, "result": result }).to_string(),
+    )
+}
+
+fn rpc_failure(status: u16, id: Value, error: RpcError) -> HttpResponse {
+    HttpResponse::json(
+        status,
+        json!({ "jsonrpc": "2.0", "id": id, "error": error }).to_string(),
+    )
+}
+
+fn endpoint_for(request: &StartControlServerRequest) -> Result<ControlEndpoint, CommandError> {
+    match request.transport {
+        ControlTransport::Tcp => Ok(ControlEndpoint::Tcp {
+            port: request.port.unwrap_or(0),
+        }),
+        #[cfg(unix)]
+        ControlTransport::Unix => {
+            let path = request
+                .socket_path
+                .as_deref()
+                .filter(|path| !path.trim().is_empty())
+                .ok_or_else(|| {
+                    CommandError::validation("socket_path is required for the unix transport.")
+                })?;
+            Ok(ControlEndpoint::Unix {
+                path: PathBuf::from(path),
+            })
+        }
+        #[cfg(not(unix))]
+        ControlTransport::Unix => Err(CommandError::validation(
+            "The unix transport is not available on this platform.",
+        )),
+    }
+}
+
+/// Discovery file content; `pid` lets a later server tell whether the writer is still alive.
+#[derive(Debug, Serialize, Deserialize)]
+struct DiscoveryFile {
+    #[serde(flatten)]
+    info: ControlServerInfo,
+    pid: u32,
+}
+
+/// Replaces the discovery file with one for this server; the file is always created fresh so
+/// it never keeps looser permissions from an earlier writer.
+fn write_discovery_file(directory: &Path, info: &ControlServerInfo) -> Option<PathBuf> {
+    fs::create_dir_all(directory).ok()?;
+    let path = directory.join(DISCOVERY_FILE);
+    let content = serde_json::to_vec_pretty(&DiscoveryFile {
+        info: info.clone(),
+        pid: std::process::id(),
+    })
+    .ok()?;
+
+    match fs::remove_file(&path) {
+        Ok(()) => {}
+        Err(error) if error.kind() == io::ErrorKind::NotFound => {}
+        Err(_) => return None,
+    }
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    options.open(&path).ok()?.write_all(&content).ok()?;
+
+    Some(path)
+}
+
+/// Deletes a discovery file whose server was killed before it could clean up, along with
+/// its unix socket. Fails when the server that wrote the file is still running.
+fn remove_stale_discovery_file(directory: &Path) -> Result<(), CommandError> {
+    let path = directory.join(DISCOVERY_FILE);
+    let Some(stale) = fs::read(&path)
+        .ok()
+        .and_then(|content| serde_json::from_slice::<DiscoveryFile>(&content).ok())
+    else {
+        return Ok(());
+    };
+    if process_is_alive(stale.pid) {
+        return Err(CommandError::conflict(format!(
+            "Another control server (pid {}) is already running at '{}'. Stop it first.",
+            stale.pid, stale.info.address
+        ))
+        .with_path(path.display().to_string()));
+    }
+
+    #[cfg(unix)]
+    if let Some(socket_path) = stale.info.address.strip_prefix("unix:") {
+        use std::os::unix::fs::FileTypeExt;
+
+        if fs::symlink_metadata(socket_path).is_ok_and(|metadata| metadata.file_type().is_socket())
+        {
+            let _ = fs::remove_file(socket_path);
+        }
+    }
+    let _ = fs::remove_file(path);
+    Ok(())
+}
+
+#[cfg(unix)]
+fn process_is_alive(pid: u32) -> bool {
+    let Ok(pid) = libc::pid_t::try_from(pid) else {
+        return false;
+    };
+    // Signal 0 only checks the pid; EPERM means it exists under another user.
+    // SAFETY: `kill` with signal 0 sends nothing and has no memory effects.
+    let signalled = unsafe { libc::kill(pid, 0) } == 0;
+    signalled || std::io::Error::last_os_error().raw_os_error() == Some(libc::EPERM)
+}
+
+/// Without a portable liveness check, assume the writer may still be running.
+#[cfg(not(unix))]
+fn process_is_alive(_pid: u32) -> bool {
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use serde_json::{Value, json};
+
+    use super::{
+        ControlAuth, DISCOVERY_FILE, DiscoveryFile, handle_request, process_is_alive,
+        remove_stale_discovery_file, write_discovery_file,
+    };
+    use crate::{
+        infra::{HttpRequest, HttpResponse},
+        interface::{
+            contracts::{
+                command::CommandErrorCode,
+                control::{CONTROL_PROTOCOL_VERSION, ControlServerInfo, ControlTransport},
+            },
+            state::AppState,
+        },
+    };
+
+    fn post(body: Value, bearer_token: Option<&str>) -> HttpRequest {
+        let mut headers = BTreeMap::new();
+        if let Some(token) = bearer_token {
+            headers.insert("authorization".to_string(), format!("Bearer {token}"));
+        }
+        HttpRequest {
+            method: "POST".to_string(),
+            path: "/rpc".to_string(),
+            headers,
+            body: body.to_string().into_bytes(),
+        }
+    }
+
+    fn body(response: &HttpResponse) -> Value {
+        serde_json::from_str(&response.body).expect("response should be JSON")
+    }
+
+    fn handshake(state: &AppState, auth: &ControlAuth) -> String {
+        let response = handle_request(
+            state,
+            auth,
+            post(
+                json!({
+                    "jsonrpc": "2.0",
+                    "id": 1,
+                    "method": "handshake",
+                    "params": { "token": auth.launch_token() }
+                }),
+                None,
+            ),
+        );
+        assert_eq!(response.status, 200);
+        body(&response)["result"]["data"]["session_token"]
+            .as_str()
+            .expect("handshake should return a session token")
+            .to_string()
+    }
+
+    #[test]
+    fn calls_require_a_session_from_the_handshake() {
+        let state = AppState::new();
+        let auth = ControlAuth::new().expect("control auth should initialize");
+        let detect = json!({ "jsonrpc": "2.0", "id": 2, "method": "detect_clients" });
+
+        let rejected = handle_request(&state, &auth, post(detect.clone(), Some("guess")));
+        assert_eq!(rejected.status, 401);
+        assert_eq!(body(&rejected)["error"]["code"], -32001);
+
+        let session_token = handshake(&state, &auth);
+        let accepted = handle_request(&state, &auth, post(detect, Some(&session_token)));
+        let payload = body(&accepted);
+
+        assert_eq!(accepted.status, 200);
+        assert_eq!(payload["id"], 2);
+        assert_eq!(payload["result"]["ok"], true);
+        assert_eq!(payload["result"]["meta"]["operation_id"], "detect-2");
+    }
+
+    #[test]
+    fn handshake_rejects_wrong_launch_token() {
+        let state = AppState::new();
+        let auth = ControlAuth::new().expect("control auth should initialize");
+
+        let response = handle_request(
+            &state,
+            &auth,
+            post(
+                json!({
+                    "jsonrpc": "2.0",
+                    "id": 1,
+                    "method": "handshake",
+                    "params": { "token": "not-the-token" }
+                }),
+                None,
+            ),
+        );
+
+        assert_eq!(response.status, 401);
+    }
+
+    #[test]
+    fn shutdown_lifecycle_is_reported_through_envelopes() {
+        let state = AppState::new();
+        let auth = ControlAuth::new().expect("control auth should initialize");
+        let session_token = handshake(&state, &auth);
+        state.mark_shutdown_requested();
+
+        let response = handle_request(
+            &state,
+            &auth,
+            post(
+                json!({
+                    "jsonrpc": "2.0",
+                    "id": 3,
+                    "method": "list_resources",
+                    "params": { "resource_kind": "mcp" }
+                }),
+                Some(&session_token),
+            ),
+        );
+        let payload = body(&response);
+
+        assert_eq!(payload["result"]["ok"], false);
+        assert_eq!(payload["result"]["error"]["code"], "SHUTTING_DOWN");
+    }
+
+    #[test]
+    fn unknown_methods_and_bad_params_are_json_rpc_errors() {
+        let state = AppState::new();
+        let auth = ControlAuth::new().expect("control auth should initialize");
+        let session_token = handshake(&state, &auth);
+
+        let unknown = handle_request(
+            &state,
+            &auth,
+            post(
+                json!({ "jsonrpc": "2.0", "id": 4, "method": "format_disk" }),
+                Some(&session_token),
+            ),
+        );
+        assert_eq!(body(&unknown)["error"]["code"], -32601);
+
+        let invalid = handle_request(
+            &state,
+            &auth,
+            post(
+                json!({ "jsonrpc": "2.0", "id": 5, "method": "list_resources", "params": {} }),
+                Some(&session_token),
+            ),
+        );
+        assert_eq!(body(&invalid)["error"]["code"], -32602);
+    }
+
+    #[test]
+    fn discovery_liveness_check_recognizes_the_current_process() {
+        assert!(process_is_alive(std::process::id()));
+    }
+
+    fn discovery_info(address: &str) -> ControlServerInfo {
+        ControlServerInfo {
+            transport: ControlTransport::Tcp,
+            address: address.to_string(),
+            token: "launch-token".to_string(),
+            discovery_path: None,
+            protocol_version: CONTROL_PROTOCOL_VERSION,
+        }
+    }
+
+    fn write_discovery(directory: &std::path::Path, pid: u32) {
+        std::fs::create_dir_all(directory).expect("should create data dir");
+        let content = serde_json::to_vec(&DiscoveryFile {
+            info: discovery_info("127.0.0.1:4100"),
+            pid,
+        })
+        .expect("should serialize discovery file");
+        std::fs::write(directory.join(DISCOVERY_FILE), content).expect("should write discovery");
+    }
+
+    #[test]
+    fn discovery_file_of_a_running_server_blocks_a_second_server() {
+        let directory = std::env::temp_dir().join(format!(
+            "ai-manager-control-discovery-live-{}",
+            std::process::id()
+        ));
+        write_discovery(&directory, std::process::id());
+
+        let refused = remove_stale_discovery_file(&directory);
+        let kept = directory.join(DISCOVERY_FILE).exists();
+        let _ = std::fs::remove_dir_all(&directory);
+
+        let error = refused.expect_err("a live server's discovery file should be kept");
+        assert_eq!(error.code, CommandErrorCode::Conflict);
+        assert!(error.message.contains("127.0.0.1:4100"));
+        assert!(kept);
+    }
+
+    #[test]
+    fn stale_discovery_file_is_removed() {
+        let directory = std::env::temp_dir().join(format!(
+            "ai-manager-control-discovery-stale-{}",
+            std::process::id()
+        ));
+        // Not a valid pid, so the writer cannot be alive.
+        write_discovery(&directory, u32::MAX);
+
+        let removed = remove_stale_discovery_file(&directory);
+        let kept = directory.join(DISCOVERY_FILE).exists();
+        let _ = std::fs::remove_dir_all(&directory);
+
+        removed.expect("a stale discovery file should be removed");
+        assert!(!kept);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn rewritten_discovery_file_is_owner_only() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let directory = std::env::temp_dir().join(format!(
+            "ai-manager-control-discovery-mode-{}",
+            std::process::id()
+        ));
+        write_discovery(&directory, u32::MAX);
+        let existing = directory.join(DISCOVERY_FILE);
+        std::fs::set_permissions(&existing, std::fs::Permissions::from_mode(0o644))
+            .expect("should loosen permissions");
+
+        let written = write_discovery_file(&directory, &discovery_info("127.0.0.1:4200"));
+        let mode = std::fs::metadata(&existing)
+            .map(|metadata| metadata.permissions().mode() & 0o777)
+            .expect("discovery file should exist");
+        let content = std::fs::read_to_string(&existing).expect("should read discovery file");
+        let _ = std::fs::remove_dir_all(&directory);
+
+        assert_eq!(written, Some(existing));
+        assert_eq!(mode, 0o600);
+        assert!(content.contains("127.0.0.1:4200"));
+    }
+}
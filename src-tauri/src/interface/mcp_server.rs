@@ -0,0 +1,447 @@
+use std::io::{self, BufRead, Write};
+
+use serde::{Serialize, de::DeserializeOwned};
+use serde_json::{Map, Value, json};
+
+use crate::interface::{
+    contracts::{
+        command::{CommandEnvelope, CommandError, CommandMeta},
+        common::{ClientKind, ResourceKind},
+        mutate::{MutateResourceRequest, MutationAction},
+        replicate::ReplicateResourceRequest,
+    },
+    operations,
+    state::AppState,
+};
+
+pub const MCP_SERVER_SUBCOMMAND: &str = "mcp-server";
+pub const SELF_REGISTRATION_ID: &str = "ai-manager";
+
+const SUPPORTED_PROTOCOL_VERSIONS: [&str; 3] = ["2025-06-18", "2025-03-26", "2024-11-05"];
+const SERVER_NAME: &str = "ai-manager";
+
+const PARSE_ERROR: i64 = -32700;
+const INVALID_REQUEST: i64 = -32600;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+
+/// Serves AI Manager operations as MCP tools over newline-delimited JSON-RPC.
+pub struct McpServer<'a> {
+    state: &'a AppState,
+}
+
+impl<'a> McpServer<'a> {
+    pub fn new(state: &'a AppState) -> Self {
+        Self { state }
+    }
+
+    pub fn serve(&self, reader: impl BufRead, mut writer: impl Write) -> io::Result<()> {
+        for line in reader.lines() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            if let Some(response) = self.handle_message(&line) {
+                writeln!(writer, "{response}")?;
+                writer.flush()?;
+            }
+            if self.state.is_shutting_down() {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the serialized response, or `None` for notifications.
+    pub fn handle_message(&self, message: &str) -> Option<String> {
+        let message: Value = match serde_json::from_str(message) {
+            Ok(message) => message,
+            Err(error) => return Some(rpc_error(Value::Null, PARSE_ERROR, &error.to_string())),
+        };
+
+        let Some(method) = message.get("method").and_then(Value::as_str) else {
+            return Some(rpc_error(
+                message.get("id").cloned().unwrap_or(Value::Null),
+                INVALID_REQUEST,
+                "request is missing a method",
+            ));
+        };
+        let id = message.get("id").cloned()?;
+        let params = message.get("params").cloned().unwrap_or(Value::Null);
+
+        let result = match method {
+            "initialize" => Ok(initialize_result(&params)),
+            "ping" => Ok(json!({})),
+            "tools/list" => Ok(json!({ "tools": tool_definitions() })),
+            "tools/call" => self.call_tool(&params),
+            _ => Err((METHOD_NOT_FOUND, format!("unknown method '{method}'"))),
+        };
+
+        Some(match result {
+            Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }).to_string(),
+            Err((code, message)) => rpc_error(id, code, &message),
+        })
+    }
+
+    fn call_tool(&self, params: &Value) -> Result<Value, (i64, String)> {
+        let name = params.get("name").and_then(Value::as_str).ok_or_else(|| {
+            (
+                INVALID_PARAMS,
+                "tools/call requires a tool name".to_string(),
+            )
+        })?;
+        let arguments = params
+            .get("arguments")
+            .cloned()
+            .unwrap_or_else(|| Value::Object(Map::new()));
+        let confirmed = arguments.get("confirm").and_then(Value::as_bool) == Some(true);
+        let state = self.state;
+
+        match name {
+            "detect_clients" => Ok(self.invoke("detect", arguments, |request| {
+                operations::detect_clients(state, request)
+            })),
+            "list_resources" => Ok(self.invoke("list", arguments, |request| {
+                operations::list_resources(state, request)
+            })),
+            "mutate_resource" => Ok(self.invoke(
+                "mutate",
+                arguments,
+                |request: MutateResourceRequest| {
+                    let destructive = !matches!(request.action, MutationAction::Add);
+                    if destructive && !confirmed {
+                        return self.rejected(
+                            "mutate",
+                            format!(
+                                "'{}' on '{}' changes existing configuration; call again with \"confirm\": true.",
+                                request.action.as_str(),
+                                request.target_id
+                            ),
+                        );
+                    }
+                    operations::mutate_resource(state, request)
+                },
+            )),
+            "replicate_resource" => Ok(self.invoke(
+                "replicate",
+                arguments,
+                |request: ReplicateResourceRequest| {
+                    if request.overwrite && !confirmed {
+                        return self.rejected(
+                            "replicate",
+                            "Replicating with overwrite replaces the destination entry; call again with \"confirm\": true.".to_string(),
+                        );
+                    }
+                    operations::replicate_resource(state, request)
+                },
+            )),
+            _ => Err((INVALID_PARAMS, format!("unknown tool '{name}'"))),
+        }
+    }
+
+    fn invoke<Req: DeserializeOwned, Resp: Serialize>(
+        &self,
+        operation_name: &str,
+        arguments: Value,
+        operation: impl FnOnce(Req) -> CommandEnvelope<Resp>,
+    ) -> Value {
+        match serde_json::from_value(arguments) {
+            Ok(request) => tool_result(&operation(request)),
+            Err(error) => tool_result(
+                &self.rejected::<Resp>(operation_name, format!("Invalid tool arguments: {error}")),
+            ),
+        }
+    }
+
+    fn rejected<T>(&self, operation_name: &str, message: String) -> CommandEnvelope<T> {
+        let meta = CommandMeta::new(
+            self.state.next_operation_id(operation_name),
+            self.state.lifecycle_snapshot(),
+        );
+        CommandEnvelope::failure(CommandError::validation(message), meta)
+    }
+}
+
+/// Builds the mutation that adds AI Manager's own MCP server entry to a client config.
+pub fn self_registration_request(
+    client: ClientKind,
+    executable: &str,
+    project_root: Option<String>,
+    target_source_id: Option<String>,
+) -> MutateResourceRequest {
+    MutateResourceRequest {
+        client,
+        resource_kind: ResourceKind::Mcp,
+        action: MutationAction::Add,
+        target_id: SELF_REGISTRATION_ID.to_string(),
+        project_root,
+        target_source_id,
+        payload: Some(json!({
+            "transport": {
+                "command": executable,
+                "args": [MCP_SERVER_SUBCOMMAND]
+            }
+        })),
+        expected_revision: None,
+    }
+}
+
+fn initialize_result(params: &Value) -> Value {
+    let requested = params.get("protocolVersion").and_then(Value::as_str);
+    let protocol_version = requested
+        .filter(|version| SUPPORTED_PROTOCOL_VERSIONS.contains(version))
+        .unwrap_or(SUPPORTED_PROTOCOL_VERSIONS[0]);
+
+    json!({
+        "protocolVersion": protocol_version,
+        "capabilities": { "tools": {} },
+        "serverInfo": { "name": SERVER_NAME, "version": env!("CARGO_PKG_VERSION") }
+    })
+}
+
+fn tool_result<T: Serialize>(envelope: &CommandEnvelope<T>) -> Value {
+    let structured = serde_json::to_value(envelope).unwrap_or(Value::Null);
+    json!({
+        "content": [{
+            "type": "text",
+            "text": serde_json::to_string_pretty(&structured).unwrap_or_default()
+        }],
+        "structuredContent": structured,
+        "isError": !envelope.ok
+    })
+}
+
+fn rpc_error(id: Value, code: i64, message: &str) -> String {
+    json!({
+        "jsonrpc": "2.0",
+        "id": id,
+        "error": { "code": code, "message": message }
+    })
+    .to_string()
+}
+
+fn tool_definitions() -> Value {
+    let client = json!({ "type": "string", "enum": ["claude_code", "codex", "cursor"] });
+    let resource_kind = json!({ "type": "string", "enum": ["mcp", "skill", "subagent", "setting", "command", "instruction"] });
+
+    json!([
+        {
+            "name": "detect_clients",
+            "description": "Detect installed AI clients (Claude Code, Codex, Cursor) and their config paths.",
+            "inputSchema": {
+                "type": "object",
+                "properties": { "include_versions": { "type": "boolean" } }
+            }
+        },
+        {
+            "name": "list_resources",
+            "description": "List configured MCP servers, skills, subagents, Claude permission/hook settings, slash commands or instruction files (CLAUDE.md, AGENTS.md, Cursor rules), optionally filtered by client and project.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "resource_kind": resource_kind,
+                    "client": client,
+                    "enabled": { "type": "boolean" },
+                    "project_root": { "type": "string" },
+                    "view_mode": { "type": "string", "enum": ["effective", "all_sources"] }
+                },
+                "required": ["resource_kind"]
+            }
+        },
+        {
+            "name": "mutate_resource",
+            "description": "Add, update or remove a resource in a client config, or approve/deny a Claude Code project MCP server. Every action except add requires \"confirm\": true.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "client": client,
+                    "resource_kind": resource_kind,
+                    "action": { "type": "string", "enum": ["add", "update", "remove", "approve", "deny"] },
+                    "target_id": { "type": "string" },
+                    "project_root": { "type": "string" },
+                    "target_source_id": { "type": "string" },
+                    "payload": { "type": "object" },
+                    "expected_revision": { "type": "string" },
+                    "confirm": { "type": "boolean" }
+                },
+                "required": ["client", "resource_kind", "action", "target_id"]
+            }
+        },
+        {
+            "name": "replicate_resource",
+            "description": "Copy an MCP server or slash command from one client to another, or sync an instruction file between CLAUDE.md, AGENTS.md and Cursor rule formats. Overwriting an existing destination requires \"confirm\": true.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "resource_kind": resource_kind,
+                    "source_client": client,
+                    "source_target_id": { "type": "string" },
+                    "source_source_id": { "type": "string" },
+                    "source_project_root": { "type": "string" },
+                    "destination_client": client,
+                    "destination_target_id": { "type": "string" },
+                    "destination_source_id": { "type": "string" },
+                    "destination_project_root": { "type": "string" },
+                    "overwrite": { "type": "boolean" },
+                    "confirm": { "type": "boolean" }
+                },
+                "required": ["resource_kind", "source_client", "source_target_id", "source_source_id", "destination_client"]
+            }
+        }
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{Value, json};
+
+    use super::{McpServer, self_registration_request};
+    use crate::interface::{contracts::common::ClientKind, state::AppState};
+
+    fn call(server: &McpServer<'_>, message: Value) -> Value {
+        let response = server
+            .handle_message(&message.to_string())
+            .expect("request should produce a response");
+        serde_json::from_str(&response).expect("response should be JSON")
+    }
+
+    #[test]
+    fn initialize_and_tools_list_follow_mcp_handshake() {
+        let state = AppState::new();
+        let server = McpServer::new(&state);
+
+        let initialized = call(
+            &server,
+            json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "method": "initialize",
+                "params": { "protocolVersion": "2025-03-26", "capabilities": {} }
+            }),
+        );
+        assert_eq!(initialized["result"]["protocolVersion"], "2025-03-26");
+        assert_eq!(initialized["result"]["serverInfo"]["name"], "ai-manager");
+
+        assert_eq!(
+            server.handle_message(
+                &json!({ "jsonrpc": "2.0", "method": "notifications/initialized" }).to_string()
+            ),
+            None
+        );
+
+        let tools = call(
+            &server,
+            json!({ "jsonrpc": "2.0", "id": 2, "method": "tools/list" }),
+        );
+        let names: Vec<&str> = tools["result"]["tools"]
+            .as_array()
+            .expect("tools should be an array")
+            .iter()
+            .filter_map(|tool| tool["name"].as_str())
+            .collect();
+        assert_eq!(
+            names,
+            vec![
+                "detect_clients",
+                "list_resources",
+                "mutate_resource",
+                "replicate_resource"
+            ]
+        );
+    }
+
+    #[test]
+    fn detect_tool_returns_command_envelope_as_structured_content() {
+        let state = AppState::new();
+        let server = McpServer::new(&state);
+
+        let response = call(
+            &server,
+            json!({
+                "jsonrpc": "2.0",
+                "id": 3,
+                "method": "tools/call",
+                "params": { "name": "detect_clients", "arguments": {} }
+            }),
+        );
+
+        assert_eq!(response["result"]["isError"], false);
+        assert_eq!(response["result"]["structuredContent"]["ok"], true);
+        assert_eq!(
+            response["result"]["structuredContent"]["meta"]["operation_id"],
+            "detect-1"
+        );
+    }
+
+    #[test]
+    fn destructive_mutation_requires_confirmation() {
+        let state = AppState::new();
+        let server = McpServer::new(&state);
+
+        let response = call(
+            &server,
+            json!({
+                "jsonrpc": "2.0",
+                "id": 4,
+                "method": "tools/call",
+                "params": {
+                    "name": "mutate_resource",
+                    "arguments": {
+                        "client": "cursor",
+                        "resource_kind": "mcp",
+                        "action": "remove",
+                        "target_id": "filesystem"
+                    }
+                }
+            }),
+        );
+
+        assert_eq!(response["result"]["isError"], true);
+        let error = &response["result"]["structuredContent"]["error"];
+        assert_eq!(error["code"], "VALIDATION_ERROR");
+        assert!(
+            error["message"]
+                .as_str()
+                .is_some_and(|message| message.contains("\"confirm\": true"))
+        );
+    }
+
+    #[test]
+    fn unknown_tools_are_invalid_params() {
+        let state = AppState::new();
+        let server = McpServer::new(&state);
+
+        let response = call(
+            &server,
+            json!({
+                "jsonrpc": "2.0",
+                "id": 5,
+                "method": "tools/call",
+                "params": { "name": "format_disk" }
+            }),
+        );
+
+        assert_eq!(response["error"]["code"], -32602);
+    }
+
+    #[test]
+    fn self_registration_adds_stdio_entry_pointing_at_mcp_server_mode() {
+        let request = self_registration_request(
+            ClientKind::Cursor,
+            "/usr/local/bin/ai-manager-cli",
+            None,
+            None,
+        );
+
+        assert_eq!(request.target_id, "ai-manager");
+        assert_eq!(
+            request.payload,
+            Some(json!({
+                "transport": {
+                    "command": "/usr/local/bin/ai-manager-cli",
+                    "args": ["mcp-server"]
+                }
+            }))
+        );
+    }
+}
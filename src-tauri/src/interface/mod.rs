@@ -1,3 +1,7 @@
+#[cfg(feature = "desktop")]
 pub mod commands;
 pub mod contracts;
+pub mod control;
+pub mod mcp_server;
+pub mod operations;
 pub mod state;
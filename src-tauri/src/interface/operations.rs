@@ -0,0 +1,545 @@
+use crate::{
+    application::{
+        AdapterService, McpInventoryService, McpProbeService, OperationLogService,
+        OperationSubject, SkillRepositoryDiscoveryService,
+    },
+    domain::{ResourceKind, ResourceRecord},
+    infra::{LoggedOperationKind, RecordedWrite, record_writes},
+    interface::contracts::{
+        command::{CommandEnvelope, CommandError, CommandMeta},
+        detect::{DetectClientsRequest, DetectClientsResponse},
+        inventory::{McpToolInventoryRequest, McpToolInventoryResponse},
+        list::{ListResourcesRequest, ListResourcesResponse, ResourceViewMode},
+        mutate::{MutateResourceRequest, MutateResourceResponse},
+        probe::{ProbeMcpServerRequest, ProbeMcpServerResponse},
+        repair::{
+            DiagnoseConfigRequest, DiagnoseConfigResponse, RepairConfigRequest,
+            RepairConfigResponse,
+        },
+        replicate::{ReplicateResourceRequest, ReplicateResourceResponse},
+        secrets::{ExternalizeSecretsRequest, ExternalizeSecretsResponse},
+        security_audit::{SecurityAuditRequest, SecurityAuditResponse},
+        skill_discovery::{DiscoverSkillRepositoryRequest, DiscoverSkillRepositoryResponse},
+        undo::{UndoOperationRequest, UndoOperationResponse},
+    },
+    interface::state::{AppState, now_epoch_ms},
+};
+
+pub fn detect_clients(
+    state: &AppState,
+    request: DetectClientsRequest,
+) -> CommandEnvelope<DetectClientsResponse> {
+    let meta = CommandMeta::new(
+        state.next_operation_id("detect"),
+        state.lifecycle_snapshot(),
+    );
+
+    if state.is_shutting_down() {
+        return CommandEnvelope::failure(CommandError::shutting_down(), meta);
+    }
+
+    let service = AdapterService::new(state.adapter_registry(), state.detector_registry());
+
+    CommandEnvelope::success(service.detect_clients(request).redact_sensitive(), meta)
+}
+
+pub fn list_resources(
+    state: &AppState,
+    request: ListResourcesRequest,
+) -> CommandEnvelope<ListResourcesResponse> {
+    let meta = CommandMeta::new(state.next_operation_id("list"), state.lifecycle_snapshot());
+
+    if state.is_shutting_down() {
+        return CommandEnvelope::failure(CommandError::shutting_down(), meta);
+    }
+
+    let service = AdapterService::new(state.adapter_registry(), state.detector_registry());
+
+    match service.list_resources(request) {
+        Ok(response) => CommandEnvelope::success(response.redact_sensitive(), meta),
+        Err(error) => CommandEnvelope::failure(error, meta),
+    }
+}
+
+pub fn mutate_resource(
+    state: &AppState,
+    request: MutateResourceRequest,
+) -> CommandEnvelope<MutateResourceResponse> {
+    let meta = CommandMeta::new(
+        state.next_operation_id("mutate"),
+        state.lifecycle_snapshot(),
+    );
+
+    if state.is_shutting_down() {
+        return CommandEnvelope::failure(CommandError::shutting_down(), meta);
+    }
+
+    let service = AdapterService::new(state.adapter_registry(), state.detector_registry());
+
+    let (result, writes) = record_writes(|| service.mutate_resource(&request));
+
+    match result {
+        Ok(mut response) if response.accepted => {
+            let subject = OperationSubject {
+                client: request.client,
+                resource_kind: request.resource_kind,
+                target_id: response.target_id.clone(),
+                source_id: response.target_source_id.clone(),
+            };
+            if let Err(note) =
+                log_operation(&meta, LoggedOperationKind::Mutate, subject, None, &writes)
+            {
+                response.message.push_str(&note);
+            }
+            CommandEnvelope::success(response.redact_sensitive(), meta)
+        }
+        Ok(response) => {
+            CommandEnvelope::failure(CommandError::not_implemented(response.message), meta)
+        }
+        Err(error) => CommandEnvelope::failure(error, meta),
+    }
+}
+
+pub fn replicate_resource(
+    state: &AppState,
+    request: ReplicateResourceRequest,
+) -> CommandEnvelope<ReplicateResourceResponse> {
+    let meta = CommandMeta::new(
+        state.next_operation_id("replicate"),
+        state.lifecycle_snapshot(),
+    );
+
+    if state.is_shutting_down() {
+        return CommandEnvelope::failure(CommandError::shutting_down(), meta);
+    }
+
+    let service = AdapterService::new(state.adapter_registry(), state.detector_registry());
+
+    let (result, writes) = record_writes(|| service.replicate_resource(request));
+
+    match result {
+        Ok(mut response) if response.accepted => {
+            let subject = OperationSubject {
+                client: response.destination_client,
+                resource_kind: response.resource_kind,
+                target_id: response.destination_target_id.clone(),
+                source_id: Some(response.destination_source_id.clone()),
+            };
+            if let Err(note) = log_operation(
+                &meta,
+                LoggedOperationKind::Replicate,
+                subject,
+                None,
+                &writes,
+            ) {
+                response.message.push_str(&note);
+            }
+            CommandEnvelope::success(response.redact_sensitive(), meta)
+        }
+        Ok(response) => {
+            CommandEnvelope::failure(CommandError::not_implemented(response.message), meta)
+        }
+        Err(error) => CommandEnvelope::failure(error, meta),
+    }
+}
+
+pub fn discover_skill_repository(
+    state: &AppState,
+    request: DiscoverSkillRepositoryRequest,
+) -> CommandEnvelope<DiscoverSkillRepositoryResponse> {
+    let meta = CommandMeta::new(
+        state.next_operation_id("discover_skill_repo"),
+        state.lifecycle_snapshot(),
+    );
+
+    if state.is_shutting_down() {
+        return CommandEnvelope::failure(CommandError::shutting_down(), meta);
+    }
+
+    let service = SkillRepositoryDiscoveryService::new();
+    match service.discover(&request.github_repo_url) {
+        Ok(response) => CommandEnvelope::success(response, meta),
+        Err(error) => CommandEnvelope::failure(error, meta),
+    }
+}
+
+pub fn probe_mcp_server(
+    state: &AppState,
+    request: ProbeMcpServerRequest,
+) -> CommandEnvelope<ProbeMcpServerResponse> {
+    let meta = CommandMeta::new(
+        state.next_operation_id("probe_mcp_server"),
+        state.lifecycle_snapshot(),
+    );
+
+    if state.is_shutting_down() {
+        return CommandEnvelope::failure(CommandError::shutting_down(), meta);
+    }
+
+    match McpProbeService::new().probe(&request) {
+        Ok(response) => {
+            McpInventoryService::new(state.mcp_inventory()).record_probe(
+                &request.resource,
+                &response,
+                now_epoch_ms(),
+            );
+            CommandEnvelope::success(response.redact_sensitive(), meta)
+        }
+        Err(error) => CommandEnvelope::failure(error, meta),
+    }
+}
+
+pub fn mcp_tool_inventory(
+    state: &AppState,
+    request: McpToolInventoryRequest,
+) -> CommandEnvelope<McpToolInventoryResponse> {
+    let meta = CommandMeta::new(
+        state.next_operation_id("mcp_tool_inventory"),
+        state.lifecycle_snapshot(),
+    );
+
+    if state.is_shutting_down() {
+        return CommandEnvelope::failure(CommandError::shutting_down(), meta);
+    }
+
+    let service = AdapterService::new(state.adapter_registry(), state.detector_registry());
+    let listing = match service.list_resources(ListResourcesRequest {
+        client: request.client,
+        resource_kind: ResourceKind::Mcp,
+        enabled: None,
+        project_root: request.project_root,
+        view_mode: ResourceViewMode::Effective,
+        scope_filter: None,
+    }) {
+        Ok(listing) => listing,
+        Err(error) => return CommandEnvelope::failure(error, meta),
+    };
+
+    let inventory = McpInventoryService::new(state.mcp_inventory());
+    if request.refresh {
+        let prober = McpProbeService::new();
+        for resource in probe_targets(&listing.items) {
+            let probe_request = ProbeMcpServerRequest {
+                resource: resource.clone(),
+                env: Default::default(),
+                headers: Default::default(),
+                timeout_ms: request.timeout_ms,
+            };
+            if let Ok(response) = prober.probe(&probe_request) {
+                inventory.record_probe(resource, &response, now_epoch_ms());
+            }
+        }
+    }
+
+    let report = inventory.report(&listing.items, listing.project_root, listing.warning);
+    CommandEnvelope::success(report.redact_sensitive(), meta)
+}
+
+/// Enabled servers the refresh can reach, over stdio or a remote URL.
+fn probe_targets(items: &[ResourceRecord]) -> impl Iterator<Item = &ResourceRecord> {
+    items.iter().filter(|resource| {
+        resource.enabled
+            && (resource.transport_command.is_some() || resource.transport_url.is_some())
+    })
+}
+
+pub fn diagnose_config(
+    state: &AppState,
+    request: DiagnoseConfigRequest,
+) -> CommandEnvelope<DiagnoseConfigResponse> {
+    let meta = CommandMeta::new(
+        state.next_operation_id("diagnose_config"),
+        state.lifecycle_snapshot(),
+    );
+
+    if state.is_shutting_down() {
+        return CommandEnvelope::failure(CommandError::shutting_down(), meta);
+    }
+
+    let service = AdapterService::new(state.adapter_registry(), state.detector_registry());
+
+    match service.diagnose_config(request) {
+        Ok(response) => CommandEnvelope::success(response.redact_sensitive(), meta),
+        Err(error) => CommandEnvelope::failure(error, meta),
+    }
+}
+
+pub fn repair_config(
+    state: &AppState,
+    request: RepairConfigRequest,
+) -> CommandEnvelope<RepairConfigResponse> {
+    let meta = CommandMeta::new(
+        state.next_operation_id("repair_config"),
+        state.lifecycle_snapshot(),
+    );
+
+    if state.is_shutting_down() {
+        return CommandEnvelope::failure(CommandError::shutting_down(), meta);
+    }
+
+    let service = AdapterService::new(state.adapter_registry(), state.detector_registry());
+
+    let client = request.client;
+    let (result, writes) = record_writes(|| service.repair_config(request));
+
+    match result {
+        Ok(mut response) => {
+            let subject = OperationSubject {
+                client,
+                resource_kind: ResourceKind::Mcp,
+                target_id: response.source_path.clone(),
+                source_id: Some(response.source_id.clone()),
+            };
+            if let Err(note) =
+                log_operation(&meta, LoggedOperationKind::Repair, subject, None, &writes)
+            {
+                response.message.push_str(&note);
+            }
+            CommandEnvelope::success(response.redact_sensitive(), meta)
+        }
+        Err(error) => CommandEnvelope::failure(error, meta),
+    }
+}
+
+pub fn externalize_secrets(
+    state: &AppState,
+    request: ExternalizeSecretsRequest,
+) -> CommandEnvelope<ExternalizeSecretsResponse> {
+    let meta = CommandMeta::new(
+        state.next_operation_id("externalize_secrets"),
+        state.lifecycle_snapshot(),
+    );
+
+    if state.is_shutting_down() {
+        return CommandEnvelope::failure(CommandError::shutting_down(), meta);
+    }
+
+    let service = AdapterService::new(state.adapter_registry(), state.detector_registry());
+
+    let (result, writes) = record_writes(|| service.externalize_secrets(request));
+
+    match result {
+        Ok(mut response) => {
+            let mut server_names = response
+                .secrets
+                .iter()
+                .map(|secret| secret.server_name.as_str())
+                .collect::<Vec<_>>();
+            server_names.sort_unstable();
+            server_names.dedup();
+            let subject = OperationSubject {
+                client: response.client,
+                resource_kind: ResourceKind::Mcp,
+                target_id: server_names.join(", "),
+                source_id: match response.updated_sources.as_slice() {
+                    [source_id] => Some(source_id.clone()),
+                    _ => None,
+                },
+            };
+            if let Err(note) = log_operation(
+                &meta,
+                LoggedOperationKind::ExternalizeSecrets,
+                subject,
+                None,
+                &writes,
+            ) {
+                response.message.push_str(&note);
+            }
+            CommandEnvelope::success(response.redact_sensitive(), meta)
+        }
+        Err(error) => CommandEnvelope::failure(error, meta),
+    }
+}
+
+pub fn security_audit(
+    state: &AppState,
+    request: SecurityAuditRequest,
+) -> CommandEnvelope<SecurityAuditResponse> {
+    let meta = CommandMeta::new(
+        state.next_operation_id("security_audit"),
+        state.lifecycle_snapshot(),
+    );
+
+    if state.is_shutting_down() {
+        return CommandEnvelope::failure(CommandError::shutting_down(), meta);
+    }
+
+    let service = AdapterService::new(state.adapter_registry(), state.detector_registry());
+
+    match service.security_audit(request) {
+        Ok(response) => CommandEnvelope::success(response.redact_sensitive(), meta),
+        Err(error) => CommandEnvelope::failure(error, meta),
+    }
+}
+
+pub fn undo_operation(
+    state: &AppState,
+    request: UndoOperationRequest,
+) -> CommandEnvelope<UndoOperationResponse> {
+    let meta = CommandMeta::new(state.next_operation_id("undo"), state.lifecycle_snapshot());
+
+    if state.is_shutting_down() {
+        return CommandEnvelope::failure(CommandError::shutting_down(), meta);
+    }
+
+    let entry_id = request.entry_id.trim();
+    if entry_id.is_empty() {
+        return CommandEnvelope::failure(
+            CommandError::validation("entry_id must not be empty."),
+            meta,
+        );
+    }
+
+    let (result, writes) = record_writes(|| OperationLogService::new().undo(entry_id));
+    let undone = match result {
+        Ok(undone) => undone,
+        Err(error) => return CommandEnvelope::failure(error, meta),
+    };
+
+    let subject = OperationSubject {
+        client: undone.client,
+        resource_kind: undone.resource_kind,
+        target_id: undone.target_id.clone(),
+        source_id: undone.source_id.clone(),
+    };
+    let mut restored_paths = writes
+        .iter()
+        .map(|write| write.path.display().to_string())
+        .collect::<Vec<_>>();
+    restored_paths.dedup();
+    let mut message = format!(
+        "Reverted operation '{}' on '{}' for '{}' ({} file(s)).",
+        undone.entry_id,
+        undone.target_id,
+        undone.client.as_str(),
+        restored_paths.len()
+    );
+    let undo_entry_id = match log_operation(
+        &meta,
+        LoggedOperationKind::Undo,
+        subject,
+        Some(undone.entry_id.clone()),
+        &writes,
+    ) {
+        Ok(undo_entry_id) => undo_entry_id,
+        Err(note) => {
+            message.push_str(&note);
+            None
+        }
+    };
+
+    let response = UndoOperationResponse {
+        entry_id: undone.entry_id,
+        undo_entry_id,
+        client: undone.client,
+        resource_kind: undone.resource_kind,
+        target_id: undone.target_id,
+        restored_paths,
+        message,
+    };
+    CommandEnvelope::success(response.redact_sensitive(), meta)
+}
+
+/// Records file changes for `undo_operation`. A logging failure is reported in the message
+/// rather than failing an operation whose files were already written.
+fn log_operation(
+    meta: &CommandMeta,
+    operation: LoggedOperationKind,
+    subject: OperationSubject,
+    undoes: Option<String>,
+    writes: &[RecordedWrite],
+) -> Result<Option<String>, String> {
+    OperationLogService::new()
+        .record(&meta.operation_id, operation, subject, undoes, writes)
+        .map(|entry| entry.map(|entry| entry.entry_id))
+        .map_err(|error| format!(" The operation log could not be updated: {error}."))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{detect_clients, list_resources, probe_targets};
+    use crate::{
+        domain::{ClientKind, ResourceRecord, ResourceSourceScope},
+        interface::{
+            contracts::{
+                command::CommandErrorCode,
+                common::ResourceKind,
+                detect::DetectClientsRequest,
+                list::{ListResourcesRequest, ResourceViewMode},
+            },
+            state::AppState,
+        },
+    };
+
+    #[test]
+    fn operations_share_operation_ids_from_app_state() {
+        let state = AppState::new();
+
+        let envelope = detect_clients(&state, DetectClientsRequest::default());
+
+        assert!(envelope.ok);
+        assert_eq!(envelope.meta.operation_id, "detect-1");
+    }
+
+    #[test]
+    fn operations_fail_after_shutdown_is_requested() {
+        let state = AppState::new();
+        state.mark_shutdown_requested();
+
+        let envelope = list_resources(
+            &state,
+            ListResourcesRequest {
+                client: None,
+                resource_kind: ResourceKind::Mcp,
+                enabled: None,
+                project_root: None,
+                view_mode: ResourceViewMode::Effective,
+                scope_filter: None,
+            },
+        );
+
+        assert!(!envelope.ok);
+        assert_eq!(
+            envelope.error.map(|error| error.code),
+            Some(CommandErrorCode::ShuttingDown)
+        );
+    }
+
+    #[test]
+    fn tool_inventory_refresh_probes_stdio_and_remote_servers() {
+        let record =
+            |name: &str, enabled: bool, command: Option<&str>, url: Option<&str>| ResourceRecord {
+                id: format!("cursor::mcp::{name}"),
+                logical_id: format!("mcp::{name}"),
+                client: ClientKind::Cursor,
+                display_name: name.to_string(),
+                enabled,
+                transport_kind: Some(if url.is_some() { "http" } else { "stdio" }.to_string()),
+                transport_command: command.map(str::to_string),
+                transport_args: None,
+                transport_url: url.map(str::to_string),
+                source_path: None,
+                source_id: "mcp::cursor::user".to_string(),
+                source_scope: ResourceSourceScope::User,
+                source_label: "Personal".to_string(),
+                is_effective: true,
+                shadowed_by: None,
+                description: None,
+                install_kind: None,
+                manifest_content: None,
+                approval: None,
+            };
+        let items = vec![
+            record("local", true, Some("npx"), None),
+            record("remote", true, None, Some("https://mcp.example.com/mcp")),
+            record("disabled", false, None, Some("https://mcp.example.com/mcp")),
+            record("empty", true, None, None),
+        ];
+
+        let probed = probe_targets(&items)
+            .map(|resource| resource.display_name.as_str())
+            .collect::<Vec<_>>();
+
+        assert_eq!(probed, vec!["local", "remote"]);
+    }
+}
@@ -1,10 +1,14 @@
 use std::sync::{
-    RwLock,
+    Mutex, RwLock,
     atomic::{AtomicU64, Ordering},
 };
 
 use crate::infra::AdapterRegistry;
+use crate::infra::ControlServer;
 use crate::infra::DetectorRegistry;
+use crate::infra::McpInventoryStore;
+#[cfg(feature = "desktop")]
+use crate::infra::PollWatcher;
 use crate::interface::contracts::common::{LifecyclePhase, LifecycleSnapshot};
 
 use super::clock::now_epoch_ms;
@@ -14,6 +18,10 @@ pub struct AppState {
     operation_counter: AtomicU64,
     adapter_registry: AdapterRegistry,
     detector_registry: DetectorRegistry,
+    mcp_inventory: McpInventoryStore,
+    #[cfg(feature = "desktop")]
+    source_watcher: Mutex<Option<PollWatcher>>,
+    control_server: Mutex<Option<ControlServer>>,
 }
 
 impl AppState {
@@ -27,6 +35,10 @@ impl AppState {
             operation_counter: AtomicU64::new(0),
             adapter_registry: AdapterRegistry::with_default_adapters(),
             detector_registry: DetectorRegistry::with_default_detectors(),
+            mcp_inventory: McpInventoryStore::new(),
+            #[cfg(feature = "desktop")]
+            source_watcher: Mutex::new(None),
+            control_server: Mutex::new(None),
         }
     }
 
@@ -46,6 +58,9 @@ impl AppState {
 
             snapshot.phase = LifecyclePhase::ShuttingDown;
         });
+        #[cfg(feature = "desktop")]
+        self.replace_source_watcher(None);
+        self.replace_control_server(None);
     }
 
     pub fn next_operation_id(&self, command_name: &str) -> String {
@@ -61,6 +76,33 @@ impl AppState {
         &self.detector_registry
     }
 
+    pub fn mcp_inventory(&self) -> &McpInventoryStore {
+        &self.mcp_inventory
+    }
+
+    /// Swaps the active source watcher, stopping the previous one. Returns whether one was running.
+    #[cfg(feature = "desktop")]
+    pub fn replace_source_watcher(&self, watcher: Option<PollWatcher>) -> bool {
+        match swap_slot(&self.source_watcher, watcher) {
+            Some(previous) => {
+                previous.stop();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Swaps the active control server, stopping the previous one. Returns whether one was running.
+    pub fn replace_control_server(&self, server: Option<ControlServer>) -> bool {
+        match swap_slot(&self.control_server, server) {
+            Some(previous) => {
+                previous.stop();
+                true
+            }
+            None => false,
+        }
+    }
+
     fn with_lifecycle_read<T>(&self, accessor: impl FnOnce(&LifecycleSnapshot) -> T) -> T {
         match self.lifecycle.read() {
             Ok(guard) => accessor(&guard),
@@ -79,6 +121,13 @@ impl AppState {
     }
 }
 
+fn swap_slot<T>(slot: &Mutex<Option<T>>, value: Option<T>) -> Option<T> {
+    match slot.lock() {
+        Ok(mut guard) => std::mem::replace(&mut *guard, value),
+        Err(poisoned) => std::mem::replace(&mut *poisoned.into_inner(), value),
+    }
+}
+
 impl Default for AppState {
     fn default() -> Self {
         Self::new()
@@ -89,6 +138,9 @@ impl Default for AppState {
 mod tests {
     use super::AppState;
     use crate::domain::ClientKind;
+    use crate::infra::{ControlEndpoint, ControlServer, HttpResponse};
+    #[cfg(feature = "desktop")]
+    use crate::infra::{PollWatcher, WatchTiming};
 
     #[test]
     fn operation_id_counter_increments_per_command_prefix() {
@@ -165,4 +217,30 @@ mod tests {
             ]
         );
     }
+
+    #[cfg(feature = "desktop")]
+    #[test]
+    fn shutdown_stops_active_source_watcher() {
+        let state = AppState::new();
+        let watcher = PollWatcher::spawn(Vec::new(), WatchTiming::default(), |_| {});
+
+        assert!(!state.replace_source_watcher(Some(watcher)));
+        state.mark_shutdown_requested();
+
+        assert!(!state.replace_source_watcher(None));
+    }
+
+    #[test]
+    fn shutdown_stops_active_control_server() {
+        let state = AppState::new();
+        let server = ControlServer::bind(&ControlEndpoint::Tcp { port: 0 }, |_| {
+            HttpResponse::json(200, String::new())
+        })
+        .expect("server should bind");
+
+        assert!(!state.replace_control_server(Some(server)));
+        state.mark_shutdown_requested();
+
+        assert!(!state.replace_control_server(None));
+    }
 }
@@ -2,3 +2,4 @@ mod app_state;
 mod clock;
 
 pub use app_state::AppState;
+pub(crate) use clock::now_epoch_ms;
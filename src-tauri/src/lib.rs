@@ -1,35 +1,54 @@
 mod application;
+mod cli;
 mod domain;
 mod infra;
 mod interface;
 
 pub use application::{
-    ClientCapabilityService, McpSourceCatalogService, McpSourceDescriptor, McpSourceStorageKind,
+    ClientCapabilityService, ClientFeature, McpSourceCatalogService, McpSourceDescriptor,
+    McpSourceStorageKind,
 };
+pub use cli::run_cli;
 pub use domain::{
-    ClientCapabilities, ClientKind, ClientProfile, ResourceKind, ResourceScopeCapabilities,
-    ResourceSourceScope, profile_for_client,
+    ClientCapabilities, ClientKind, ClientProfile, ClientVersion, ResourceKind,
+    ResourceScopeCapabilities, ResourceSourceScope, profile_for_client,
 };
 
+#[cfg(feature = "desktop")]
 use interface::{
     commands::{
-        detect_clients, discover_skill_repository, list_resources, mutate_resource,
-        replicate_resource,
+        detect_clients, diagnose_config, discover_skill_repository, externalize_secrets,
+        list_resources, mcp_tool_inventory, mutate_resource, probe_mcp_server, repair_config,
+        replicate_resource, security_audit, start_control_server, stop_control_server,
+        undo_operation, unwatch_sources, watch_sources,
     },
     state::AppState,
 };
+#[cfg(feature = "desktop")]
 use tauri::Manager;
 
+#[cfg(feature = "desktop")]
 pub fn run() {
     tauri::Builder::default()
         .plugin(tauri_plugin_opener::init())
         .manage(AppState::new())
         .invoke_handler(tauri::generate_handler![
             detect_clients,
+            diagnose_config,
             discover_skill_repository,
+            externalize_secrets,
             list_resources,
+            mcp_tool_inventory,
             mutate_resource,
-            replicate_resource
+            probe_mcp_server,
+            repair_config,
+            replicate_resource,
+            security_audit,
+            start_control_server,
+            stop_control_server,
+            undo_operation,
+            unwatch_sources,
+            watch_sources
         ])
         .build(tauri::generate_context!())
         .expect("error while building tauri application")
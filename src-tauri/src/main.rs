@@ -2,5 +2,11 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
 fn main() {
+    // Configs rewritten by older versions point `run-with-secrets` at this binary.
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.first().map(String::as_str) == Some("run-with-secrets") {
+        std::process::exit(ai_manager_lib::run_cli(args));
+    }
+
     ai_manager_lib::run()
 }
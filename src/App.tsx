@@ -7,6 +7,7 @@ import { Button } from "./components/ui/button";
 import { ClientStatusCard } from "./features/clients/ClientStatusCard";
 import { formatClientLabel } from "./features/clients/client-labels";
 import { useClientDetections } from "./features/clients/useClientDetections";
+import { useSourceWatcher } from "./features/common/useSourceWatcher";
 import { McpManagerPanel } from "./features/mcp/McpManagerPanel";
 import { type AppRoute, NAVIGATION_ITEMS } from "./features/navigation";
 import { buildRouteOverview } from "./features/navigation/route-overview";
@@ -80,6 +81,7 @@ function App() {
     }),
     [projectRoot, resourceContextMode],
   );
+  useSourceWatcher(resourceContext.projectRoot);
 
   const featureContent = useMemo(
     () => renderRouteContent(activeRoute, resourceContext),
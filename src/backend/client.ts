@@ -1,18 +1,41 @@
 import { invoke } from "@tauri-apps/api/core";
+import { listen, type UnlistenFn } from "@tauri-apps/api/event";
 
 import type {
   CommandEnvelope,
+  ControlServerInfo,
   DetectClientsRequest,
   DetectClientsResponse,
+  DiagnoseConfigRequest,
+  DiagnoseConfigResponse,
   DiscoverSkillRepositoryRequest,
   DiscoverSkillRepositoryResponse,
+  ExternalizeSecretsRequest,
+  ExternalizeSecretsResponse,
   ListResourcesRequest,
   ListResourcesResponse,
+  McpToolInventoryRequest,
+  McpToolInventoryResponse,
   MutateResourceRequest,
   MutateResourceResponse,
+  ProbeMcpServerRequest,
+  ProbeMcpServerResponse,
+  RepairConfigRequest,
+  RepairConfigResponse,
   ReplicateResourceRequest,
   ReplicateResourceResponse,
+  SecurityAuditRequest,
+  SecurityAuditResponse,
+  SourcesChangedEvent,
+  StartControlServerRequest,
+  StopControlServerResponse,
+  UndoOperationRequest,
+  UndoOperationResponse,
+  UnwatchSourcesResponse,
+  WatchSourcesRequest,
+  WatchSourcesResponse,
 } from "./contracts";
+import { SOURCES_CHANGED_EVENT } from "./contracts";
 
 export async function detectClients(
   request: DetectClientsRequest,
@@ -20,26 +43,94 @@ export async function detectClients(
   return invoke("detect_clients", { request });
 }
 
+export async function diagnoseConfig(
+  request: DiagnoseConfigRequest,
+): Promise<CommandEnvelope<DiagnoseConfigResponse>> {
+  return invoke("diagnose_config", { request });
+}
+
 export async function discoverSkillRepository(
   request: DiscoverSkillRepositoryRequest,
 ): Promise<CommandEnvelope<DiscoverSkillRepositoryResponse>> {
   return invoke("discover_skill_repository", { request });
 }
 
+export async function externalizeSecrets(
+  request: ExternalizeSecretsRequest,
+): Promise<CommandEnvelope<ExternalizeSecretsResponse>> {
+  return invoke("externalize_secrets", { request });
+}
+
 export async function listResources(
   request: ListResourcesRequest,
 ): Promise<CommandEnvelope<ListResourcesResponse>> {
   return invoke("list_resources", { request });
 }
 
+export async function mcpToolInventory(
+  request: McpToolInventoryRequest,
+): Promise<CommandEnvelope<McpToolInventoryResponse>> {
+  return invoke("mcp_tool_inventory", { request });
+}
+
 export async function mutateResource(
   request: MutateResourceRequest,
 ): Promise<CommandEnvelope<MutateResourceResponse>> {
   return invoke("mutate_resource", { request });
 }
 
+export async function probeMcpServer(
+  request: ProbeMcpServerRequest,
+): Promise<CommandEnvelope<ProbeMcpServerResponse>> {
+  return invoke("probe_mcp_server", { request });
+}
+
+export async function repairConfig(
+  request: RepairConfigRequest,
+): Promise<CommandEnvelope<RepairConfigResponse>> {
+  return invoke("repair_config", { request });
+}
+
 export async function replicateResource(
   request: ReplicateResourceRequest,
 ): Promise<CommandEnvelope<ReplicateResourceResponse>> {
   return invoke("replicate_resource", { request });
 }
+
+export async function securityAudit(
+  request: SecurityAuditRequest,
+): Promise<CommandEnvelope<SecurityAuditResponse>> {
+  return invoke("security_audit", { request });
+}
+
+export async function startControlServer(
+  request: StartControlServerRequest,
+): Promise<CommandEnvelope<ControlServerInfo>> {
+  return invoke("start_control_server", { request });
+}
+
+export async function stopControlServer(): Promise<CommandEnvelope<StopControlServerResponse>> {
+  return invoke("stop_control_server");
+}
+
+export async function undoOperation(
+  request: UndoOperationRequest,
+): Promise<CommandEnvelope<UndoOperationResponse>> {
+  return invoke("undo_operation", { request });
+}
+
+export async function watchSources(
+  request: WatchSourcesRequest,
+): Promise<CommandEnvelope<WatchSourcesResponse>> {
+  return invoke("watch_sources", { request });
+}
+
+export async function unwatchSources(): Promise<CommandEnvelope<UnwatchSourcesResponse>> {
+  return invoke("unwatch_sources");
+}
+
+export async function onSourcesChanged(
+  handler: (event: SourcesChangedEvent) => void,
+): Promise<UnlistenFn> {
+  return listen<SourcesChangedEvent>(SOURCES_CHANGED_EVENT, (event) => handler(event.payload));
+}
@@ -1,6 +1,6 @@
 export type ClientKind = "claude_code" | "codex" | "cursor";
 
-export type ResourceKind = "mcp" | "skill" | "subagent";
+export type ResourceKind = "mcp" | "skill" | "subagent" | "setting" | "command" | "instruction";
 export type ResourceFamily = "generic" | "native";
 export type ResourceSourceScope = "user" | "project_shared" | "project_private";
 export type ResourceViewMode = "effective" | "all_sources";
@@ -27,6 +27,21 @@ export const RESOURCE_KIND_CATALOG: Record<ResourceKind, ResourceKindCatalogEntr
     label: "Subagent",
     managementModel: "client_native",
   },
+  setting: {
+    family: "native",
+    label: "Setting",
+    managementModel: "client_native",
+  },
+  command: {
+    family: "native",
+    label: "Command",
+    managementModel: "client_native",
+  },
+  instruction: {
+    family: "native",
+    label: "Instruction",
+    managementModel: "client_native",
+  },
 };
 
 export type LifecyclePhase = "running" | "shutting_down";
@@ -39,14 +54,30 @@ export interface LifecycleSnapshot {
 
 export type CommandErrorCode =
   | "VALIDATION_ERROR"
+  | "CONFLICT"
+  | "PARSE_ERROR"
+  | "PERMISSION_DENIED"
+  | "IO_ERROR"
   | "NOT_IMPLEMENTED"
   | "SHUTTING_DOWN"
   | "INTERNAL_ERROR";
 
+export type ErrorCategory = "parse" | "permission" | "validation" | "conflict" | "io" | "unknown";
+
+export interface ErrorContext {
+  path: string | null;
+  source_id: string | null;
+  stage: string | null;
+}
+
 export interface CommandError {
   code: CommandErrorCode;
+  category: ErrorCategory;
+  canonical_code: string;
   message: string;
   recoverable: boolean;
+  context: ErrorContext;
+  suggested_actions: string[];
 }
 
 export interface CommandMeta {
@@ -131,6 +162,31 @@ export interface ResourceRecord {
   description: string | null;
   install_kind: string | null;
   manifest_content: string | null;
+  approval: McpApprovalState | null;
+}
+
+export type McpApprovalState = "approved" | "denied" | "pending";
+
+export type DiagnosticSeverity = "info" | "warning" | "error";
+
+export interface ResourceDiagnostic {
+  client: ClientKind;
+  severity: DiagnosticSeverity;
+  code: string;
+  message: string;
+  source_id: string | null;
+  source_path: string | null;
+  line: number | null;
+  column: number | null;
+  server_name: string | null;
+  category: ErrorCategory | null;
+  suggested_actions: string[];
+}
+
+export interface SourceRevision {
+  source_id: string;
+  source_path: string;
+  revision: string | null;
 }
 
 export interface ListResourcesResponse {
@@ -140,9 +196,11 @@ export interface ListResourcesResponse {
   view_mode: ResourceViewMode;
   items: ResourceRecord[];
   warning: string | null;
+  diagnostics: ResourceDiagnostic[];
+  source_revisions: SourceRevision[];
 }
 
-export type MutationAction = "add" | "remove" | "update";
+export type MutationAction = "add" | "remove" | "update" | "approve" | "deny";
 
 export interface MutateResourceRequest {
   client: ClientKind;
@@ -152,6 +210,7 @@ export interface MutateResourceRequest {
   project_root?: string | null;
   target_source_id?: string | null;
   payload: Record<string, unknown> | null;
+  expected_revision?: string | null;
 }
 
 export interface MutateResourceResponse {
@@ -161,6 +220,7 @@ export interface MutateResourceResponse {
   message: string;
   source_path: string | null;
   target_source_id: string | null;
+  warning: string | null;
 }
 
 export interface ReplicateResourceRequest {
@@ -186,3 +246,256 @@ export interface ReplicateResourceResponse {
   destination_source_id: string;
   message: string;
 }
+
+export interface DiagnoseConfigRequest {
+  client: ClientKind;
+  project_root?: string | null;
+  target_source_id?: string | null;
+}
+
+export type ConfigIssueKind =
+  | "syntax"
+  | "trailing_comma"
+  | "duplicate_server_key"
+  | "section_not_object"
+  | "server_transport_missing";
+
+export interface ConfigIssue {
+  kind: ConfigIssueKind;
+  message: string;
+  line: number | null;
+  column: number | null;
+  server_name: string | null;
+  fixable: boolean;
+}
+
+export interface ConfigRepairProposal {
+  source_id: string;
+  source_path: string;
+  issues: ConfigIssue[];
+  diff: string;
+}
+
+export interface DiagnoseConfigResponse {
+  client: ClientKind;
+  project_root: string | null;
+  checked_sources: number;
+  proposals: ConfigRepairProposal[];
+}
+
+export interface RepairConfigRequest {
+  client: ClientKind;
+  project_root?: string | null;
+  target_source_id: string;
+  accepted_diff: string;
+}
+
+export interface RepairConfigResponse {
+  client: ClientKind;
+  source_id: string;
+  source_path: string;
+  repaired_issues: ConfigIssue[];
+  backup_path: string | null;
+  message: string;
+}
+
+export interface ExternalizeSecretsRequest {
+  client: ClientKind;
+  project_root?: string | null;
+  target_source_id?: string | null;
+  dry_run?: boolean;
+}
+
+export type SecretReferenceKind = "launcher" | "env_interpolation";
+
+export interface ExternalizedSecret {
+  source_id: string;
+  source_path: string;
+  server_name: string;
+  field: string;
+  secret_name: string;
+  reference: SecretReferenceKind;
+}
+
+export interface ExternalizeSecretsResponse {
+  client: ClientKind;
+  project_root: string | null;
+  dry_run: boolean;
+  secrets: ExternalizedSecret[];
+  updated_sources: string[];
+  store_location: string;
+  message: string;
+}
+
+export interface SecurityAuditRequest {
+  client?: ClientKind | null;
+  project_root?: string | null;
+}
+
+export type AuditSeverity = "low" | "medium" | "high";
+
+export interface SecurityAuditFinding {
+  client: ClientKind;
+  resource_kind: ResourceKind;
+  source_scope: ResourceSourceScope;
+  file_path: string;
+  line: number;
+  column: number;
+  rule: string;
+  severity: AuditSeverity;
+  excerpt: string;
+  committed_to_git: boolean;
+}
+
+export interface UndoOperationRequest {
+  entry_id: string;
+}
+
+export interface UndoOperationResponse {
+  entry_id: string;
+  undo_entry_id: string | null;
+  client: ClientKind;
+  resource_kind: ResourceKind;
+  target_id: string;
+  restored_paths: string[];
+  message: string;
+}
+
+export interface SecurityAuditResponse {
+  project_root: string | null;
+  scanned_files: number;
+  findings: SecurityAuditFinding[];
+  high_risk_files: string[];
+  warnings: string[];
+  message: string;
+}
+
+export const SOURCES_CHANGED_EVENT = "sources-changed";
+
+export interface WatchSourcesRequest {
+  project_root?: string | null;
+}
+
+export interface WatchedSource {
+  source_id: string;
+  path: string;
+  recursive: boolean;
+}
+
+export interface WatchSourcesResponse {
+  project_root: string | null;
+  sources: WatchedSource[];
+}
+
+export interface UnwatchSourcesResponse {
+  stopped: boolean;
+}
+
+export interface SourcesChangedEvent {
+  source_ids: string[];
+  changed_at_epoch_ms: number;
+}
+
+export type ControlTransport = "tcp" | "unix";
+
+export interface StartControlServerRequest {
+  transport?: ControlTransport;
+  port?: number | null;
+  socket_path?: string | null;
+}
+
+export interface ControlServerInfo {
+  transport: ControlTransport;
+  address: string;
+  token: string;
+  discovery_path: string | null;
+  protocol_version: number;
+}
+
+export interface StopControlServerResponse {
+  stopped: boolean;
+}
+
+export interface ProbeMcpServerRequest {
+  resource: ResourceRecord;
+  env?: Record<string, string>;
+  headers?: Record<string, string>;
+  timeout_ms?: number | null;
+}
+
+export type McpProbeStatus =
+  | "healthy"
+  | "spawn_failed"
+  | "timed_out"
+  | "handshake_failed"
+  | "dns_failed"
+  | "connect_failed"
+  | "tls_failed"
+  | "http_error"
+  | "unsupported";
+
+export type McpRemoteTransport = "streamable_http" | "sse";
+
+export interface ProbeMcpServerResponse {
+  client: ClientKind;
+  target_id: string;
+  status: McpProbeStatus;
+  protocol_version: string | null;
+  server_name: string | null;
+  server_version: string | null;
+  tool_count: number | null;
+  tool_names: string[];
+  prompt_names: string[];
+  resource_uris: string[];
+  remote_transport: McpRemoteTransport | null;
+  http_status: number | null;
+  resolved_addresses: string[];
+  elapsed_ms: number;
+  stderr_tail: string[];
+  message: string;
+}
+
+export interface McpServerInventory {
+  resource_id: string;
+  client: ClientKind;
+  display_name: string;
+  transport_fingerprint: string;
+  protocol_version: string | null;
+  server_name: string | null;
+  server_version: string | null;
+  tool_names: string[];
+  prompt_names: string[];
+  resource_uris: string[];
+  probed_at_epoch_ms: number;
+}
+
+export interface McpToolInventoryRequest {
+  client?: ClientKind | null;
+  project_root?: string | null;
+  refresh?: boolean;
+  timeout_ms?: number | null;
+}
+
+export interface McpServerInventoryEntry {
+  client: ClientKind;
+  resource_id: string;
+  display_name: string;
+  source_label: string;
+  enabled: boolean;
+  inventory: McpServerInventory | null;
+  stale: boolean;
+}
+
+export interface McpToolCollision {
+  client: ClientKind;
+  tool_name: string;
+  resource_ids: string[];
+  display_names: string[];
+}
+
+export interface McpToolInventoryResponse {
+  project_root: string | null;
+  servers: McpServerInventoryEntry[];
+  collisions: McpToolCollision[];
+  warning: string | null;
+}
@@ -6,8 +6,11 @@ const SOURCE_PATH_PATTERN = /Source:\s*([^\n.]+)\.?/i;
 
 export type UiErrorCode = CommandErrorCode | "RUNTIME_ERROR";
 
+const FALLBACK_CANONICAL_CODE = "AM_UNKNOWN_999";
+
 export interface ErrorDiagnostic {
   code: UiErrorCode;
+  canonicalCode: string;
   recoverable: boolean;
   message: string;
   backupPath: string | null;
@@ -16,24 +19,33 @@ export interface ErrorDiagnostic {
 }
 
 export function commandErrorToDiagnostic(error: CommandError): ErrorDiagnostic {
-  return buildDiagnostic(error.code, error.recoverable, error.message);
+  return buildDiagnostic(
+    error.code,
+    error.canonical_code ?? FALLBACK_CANONICAL_CODE,
+    error.recoverable,
+    error.message,
+    error.context?.path ?? null,
+  );
 }
 
 export function runtimeErrorToDiagnostic(message: string): ErrorDiagnostic {
-  return buildDiagnostic("RUNTIME_ERROR", true, message);
+  return buildDiagnostic("RUNTIME_ERROR", FALLBACK_CANONICAL_CODE, true, message, null);
 }
 
 function buildDiagnostic(
   code: UiErrorCode,
+  canonicalCode: string,
   recoverable: boolean,
   rawMessage: string,
+  contextPath: string | null,
 ): ErrorDiagnostic {
   const message = redactSensitiveText(rawMessage);
   const backupPath = extractPath(message, BACKUP_PATH_PATTERN);
-  const sourcePath = extractPath(message, SOURCE_PATH_PATTERN);
+  const sourcePath = extractPath(message, SOURCE_PATH_PATTERN) ?? contextPath;
 
   return {
     code,
+    canonicalCode,
     recoverable,
     message,
     backupPath,
@@ -59,6 +71,18 @@ function buildGuidance(
     case "VALIDATION_ERROR":
       guidance.push("Check required fields and retry the same action.");
       break;
+    case "CONFLICT":
+      guidance.push("The config changed outside the app. Refresh the list, then retry.");
+      break;
+    case "PARSE_ERROR":
+      guidance.push("Fix the config syntax (or run diagnose_config), then retry.");
+      break;
+    case "PERMISSION_DENIED":
+      guidance.push("Check read/write permissions for the config path, then retry.");
+      break;
+    case "IO_ERROR":
+      guidance.push("Verify the path exists and is reachable, then retry.");
+      break;
     case "NOT_IMPLEMENTED":
       guidance.push("This action is not supported for the selected client yet.");
       break;
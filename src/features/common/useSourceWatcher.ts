@@ -0,0 +1,39 @@
+import { useEffect } from "react";
+
+import { onSourcesChanged, unwatchSources, watchSources } from "../../backend/client";
+
+export function useSourceWatcher(projectRoot: string | null): void {
+  useEffect(() => {
+    void watchSources({ project_root: projectRoot }).catch(() => undefined);
+
+    return () => {
+      void unwatchSources().catch(() => undefined);
+    };
+  }, [projectRoot]);
+}
+
+export function useSourceChanges(sourceIdPrefix: string, onChange: () => void): void {
+  useEffect(() => {
+    let active = true;
+    let unlisten: (() => void) | null = null;
+
+    void onSourcesChanged((event) => {
+      if (event.source_ids.some((sourceId) => sourceId.startsWith(sourceIdPrefix))) {
+        onChange();
+      }
+    })
+      .then((stopListening) => {
+        if (active) {
+          unlisten = stopListening;
+        } else {
+          stopListening();
+        }
+      })
+      .catch(() => undefined);
+
+    return () => {
+      active = false;
+      unlisten?.();
+    };
+  }, [sourceIdPrefix, onChange]);
+}
@@ -96,10 +96,15 @@ export function McpManagerPanel({ contextMode, projectRoot }: McpManagerPanelPro
     pendingRemovalId,
     pendingUpdateId,
     pendingReplicationId,
+    pendingProbeId,
+    probeResults,
+    toolCollisions,
     addMcp,
     copyMcp,
     updateMcp,
     removeMcp,
+    setMcpApproval,
+    probeMcp,
     refresh,
     clearFeedback,
   } = useMcpManager({
@@ -286,6 +291,18 @@ export function McpManagerPanel({ contextMode, projectRoot }: McpManagerPanelPro
     setCopyOpen(true);
   }
 
+  async function handleSetApproval(resource: ResourceRecord, approve: boolean) {
+    dismissFeedback();
+    await setMcpApproval({
+      resourceId: resource.id,
+      targetId: resource.display_name,
+      projectRoot: effectiveProjectRoot,
+      targetSourceId: resource.source_id,
+      sourceLabel: resource.source_label,
+      approve,
+    });
+  }
+
   async function handleConfirmRemoval() {
     if (removalCandidate === null) {
       return;
@@ -429,6 +446,12 @@ export function McpManagerPanel({ contextMode, projectRoot }: McpManagerPanelPro
 
         <CardContent className="grid gap-3 p-5 pt-0">
           {warning ? <Alert variant="warning">{warning}</Alert> : null}
+          {toolCollisions.map((collision) => (
+            <Alert key={`${collision.client}:${collision.tool_name}`} variant="warning">
+              {collision.client}: tool "{collision.tool_name}" is exposed by{" "}
+              {collision.display_names.join(", ")}.
+            </Alert>
+          ))}
           {operationError ? (
             <ErrorRecoveryCallout
               title="MCP list operation failed"
@@ -445,10 +468,14 @@ export function McpManagerPanel({ contextMode, projectRoot }: McpManagerPanelPro
             pendingRemovalId={pendingRemovalId}
             pendingUpdateId={pendingUpdateId}
             pendingReplicationId={pendingReplicationId}
+            pendingProbeId={pendingProbeId}
+            probeResults={probeResults}
             onCopy={handleCopy}
             onPromote={handlePromote}
             onEdit={handleEdit}
             onRemove={handleRemove}
+            onSetApproval={handleSetApproval}
+            onProbe={probeMcp}
             emptyMessage={
               clientFilters.length === 0
                 ? "Select one or more clients to show MCP entries."
@@ -1,6 +1,11 @@
 import type { ReactNode } from "react";
 
-import type { ResourceRecord } from "../../backend/contracts";
+import type {
+  McpApprovalState,
+  McpProbeStatus,
+  ProbeMcpServerResponse,
+  ResourceRecord,
+} from "../../backend/contracts";
 import { Button } from "../../components/ui/button";
 import {
   Table,
@@ -18,10 +23,14 @@ interface McpResourceTableProps {
   pendingRemovalId: string | null;
   pendingUpdateId: string | null;
   pendingReplicationId: string | null;
+  pendingProbeId: string | null;
+  probeResults: Record<string, ProbeMcpServerResponse>;
   onCopy: (resource: ResourceRecord) => Promise<void>;
   onPromote: (resource: ResourceRecord) => Promise<void>;
   onEdit: (resource: ResourceRecord) => Promise<void>;
   onRemove: (resource: ResourceRecord) => Promise<void>;
+  onSetApproval: (resource: ResourceRecord, approve: boolean) => Promise<void>;
+  onProbe: (resource: ResourceRecord) => Promise<void>;
   emptyMessage?: string;
 }
 
@@ -29,6 +38,61 @@ function formatTransportKind(value: string | null): string {
   return value ?? "unknown";
 }
 
+const PROBE_STATUS_LABELS: Record<McpProbeStatus, string> = {
+  healthy: "Healthy",
+  spawn_failed: "Launch failed",
+  timed_out: "Timed out",
+  handshake_failed: "Handshake failed",
+  dns_failed: "DNS failed",
+  connect_failed: "Unreachable",
+  tls_failed: "TLS failed",
+  http_error: "HTTP error",
+  unsupported: "Not probed",
+};
+
+const APPROVAL_LABELS: Record<McpApprovalState, string> = {
+  approved: "Approved",
+  denied: "Denied",
+  pending: "Awaiting approval",
+};
+
+function ApproveIcon() {
+  return (
+    <svg viewBox="0 0 20 20" className="h-3.5 w-3.5" fill="none" aria-hidden="true">
+      <path
+        d="M4.5 10.5l3.5 3.5 7.5-8"
+        stroke="currentColor"
+        strokeWidth="1.5"
+        strokeLinecap="round"
+        strokeLinejoin="round"
+      />
+    </svg>
+  );
+}
+
+function DenyIcon() {
+  return (
+    <svg viewBox="0 0 20 20" className="h-3.5 w-3.5" fill="none" aria-hidden="true">
+      <circle cx="10" cy="10" r="6.5" stroke="currentColor" strokeWidth="1.5" />
+      <path d="M5.5 14.5l9-9" stroke="currentColor" strokeWidth="1.5" strokeLinecap="round" />
+    </svg>
+  );
+}
+
+function ProbeIcon() {
+  return (
+    <svg viewBox="0 0 20 20" className="h-3.5 w-3.5" fill="none" aria-hidden="true">
+      <path
+        d="M2.5 10h3l2-4.5 3 9 2-4.5h5"
+        stroke="currentColor"
+        strokeWidth="1.5"
+        strokeLinecap="round"
+        strokeLinejoin="round"
+      />
+    </svg>
+  );
+}
+
 function EditIcon() {
   return (
     <svg viewBox="0 0 20 20" className="h-3.5 w-3.5" fill="none" aria-hidden="true">
@@ -149,10 +213,14 @@ export function McpResourceTable({
   pendingRemovalId,
   pendingUpdateId,
   pendingReplicationId,
+  pendingProbeId,
+  probeResults,
   onCopy,
   onPromote,
   onEdit,
   onRemove,
+  onSetApproval,
+  onProbe,
   emptyMessage,
 }: McpResourceTableProps) {
   const resourcesById = new Map(resources.map((resource) => [resource.id, resource]));
@@ -177,7 +245,7 @@ export function McpResourceTable({
             <TableHead>Transport</TableHead>
             <TableHead>Status</TableHead>
             <TableHead>Source</TableHead>
-            <TableHead aria-label="actions" className="w-44" />
+            <TableHead aria-label="actions" className="w-52" />
           </TableRow>
         </TableHeader>
         <TableBody>
@@ -185,6 +253,10 @@ export function McpResourceTable({
             const removing = pendingRemovalId === resource.id;
             const updating = pendingUpdateId === resource.id;
             const replicating = pendingReplicationId === resource.id;
+            const probing = pendingProbeId === resource.id;
+            const probeResult = probeResults[resource.id] ?? null;
+            const canProbe =
+              resource.transport_command !== null || resource.transport_url !== null;
             const canPromote = canPromoteMcpResource(resource);
             const shadowingSource =
               resource.shadowed_by === null
@@ -236,6 +308,24 @@ export function McpResourceTable({
                       tone={resource.is_effective ? "success" : "warning"}
                       label={resource.is_effective ? "Effective" : "Shadowed"}
                     />
+                    {resource.approval ? (
+                      <StatusBadge
+                        tone={resource.approval === "approved" ? "success" : "warning"}
+                        label={APPROVAL_LABELS[resource.approval]}
+                      />
+                    ) : null}
+                    {probeResult ? (
+                      <span title={probeResult.message}>
+                        <StatusBadge
+                          tone={probeResult.status === "healthy" ? "success" : "warning"}
+                          label={
+                            probeResult.status === "healthy" && probeResult.tool_count !== null
+                              ? `${PROBE_STATUS_LABELS.healthy} · ${probeResult.tool_count} tools`
+                              : PROBE_STATUS_LABELS[probeResult.status]
+                          }
+                        />
+                      </span>
+                    ) : null}
                     {shadowingSource ? (
                       <span className="text-xs text-slate-500">
                         Hidden by {shadowingSource.source_label}
@@ -260,6 +350,19 @@ export function McpResourceTable({
                 </TableCell>
                 <TableCell>
                   <div className="flex items-center gap-2">
+                    {canProbe ? (
+                      <McpActionButton
+                        icon={<ProbeIcon />}
+                        label="Check server health"
+                        busyLabel="Checking..."
+                        busy={probing}
+                        disabled={probing}
+                        className="h-8 w-8 rounded-lg border border-slate-200 bg-slate-50 p-0 text-slate-700 hover:bg-slate-100 hover:text-slate-900"
+                        onClick={() => {
+                          void onProbe(resource);
+                        }}
+                      />
+                    ) : null}
                     {canPromote ? (
                       <McpActionButton
                         icon={<PromoteIcon />}
@@ -273,6 +376,23 @@ export function McpResourceTable({
                         }}
                       />
                     ) : null}
+                    {resource.approval ? (
+                      <McpActionButton
+                        icon={resource.approval === "approved" ? <DenyIcon /> : <ApproveIcon />}
+                        label={
+                          resource.approval === "approved"
+                            ? "Deny for this project"
+                            : "Approve for this project"
+                        }
+                        busyLabel="Saving approval..."
+                        busy={updating}
+                        disabled={updating || removing || replicating}
+                        className="h-8 w-8 rounded-lg border border-slate-200 bg-slate-50 p-0 text-slate-700 hover:bg-slate-100 hover:text-slate-900"
+                        onClick={() => {
+                          void onSetApproval(resource, resource.approval !== "approved");
+                        }}
+                      />
+                    ) : null}
                     <McpActionButton
                       icon={<CopyIcon />}
                       label="Copy to another client"
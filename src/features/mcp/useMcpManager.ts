@@ -1,9 +1,18 @@
 import { useCallback, useEffect, useMemo, useState } from "react";
 
-import { listResources, mutateResource, replicateResource } from "../../backend/client";
+import {
+  listResources,
+  mcpToolInventory,
+  mutateResource,
+  probeMcpServer,
+  replicateResource,
+} from "../../backend/client";
 import type {
   ClientKind,
   CommandEnvelope,
+  McpToolCollision,
+  MutateResourceResponse,
+  ProbeMcpServerResponse,
   ResourceRecord,
   ResourceViewMode,
 } from "../../backend/contracts";
@@ -13,6 +22,7 @@ import {
   type ErrorDiagnostic,
   runtimeErrorToDiagnostic,
 } from "../common/errorDiagnostics";
+import { useSourceChanges } from "../common/useSourceWatcher";
 import type { ResourceContextMode } from "../resources/resource-context";
 import { selectMcpResourcesForView, sortMcpResources } from "./mcp-list-view";
 
@@ -71,6 +81,15 @@ export interface RemoveMcpInput {
   sourceLabel: string;
 }
 
+export interface SetMcpApprovalInput {
+  resourceId: string;
+  targetId: string;
+  projectRoot: string | null;
+  targetSourceId: string;
+  sourceLabel: string;
+  approve: boolean;
+}
+
 interface UseMcpManagerParams {
   contextMode: ResourceContextMode;
   projectRoot: string | null;
@@ -96,11 +115,16 @@ interface UseMcpManagerResult {
   pendingRemovalId: string | null;
   pendingUpdateId: string | null;
   pendingReplicationId: string | null;
+  pendingProbeId: string | null;
+  probeResults: Record<string, ProbeMcpServerResponse>;
+  toolCollisions: McpToolCollision[];
   refresh: () => Promise<void>;
   addMcp: (input: AddMcpInput) => Promise<boolean>;
   updateMcp: (input: UpdateMcpInput) => Promise<boolean>;
   copyMcp: (input: CopyMcpInput) => Promise<boolean>;
   removeMcp: (input: RemoveMcpInput) => Promise<boolean>;
+  setMcpApproval: (input: SetMcpApprovalInput) => Promise<boolean>;
+  probeMcp: (resource: ResourceRecord) => Promise<void>;
   clearFeedback: () => void;
 }
 
@@ -114,6 +138,11 @@ function envelopeErrorDiagnostic(
   return runtimeErrorToDiagnostic(fallbackMessage);
 }
 
+function mutationFeedbackMessage(response: MutateResourceResponse): string {
+  const message = response.warning ? `${response.message} ${response.warning}` : response.message;
+  return redactSensitiveText(message);
+}
+
 export function useMcpManager({
   contextMode,
   projectRoot,
@@ -122,12 +151,16 @@ export function useMcpManager({
   const [phase, setPhase] = useState<LoadPhase>("idle");
   const [sourceAwareResources, setSourceAwareResources] = useState<ResourceRecord[]>([]);
   const [resolvedProjectRoot, setResolvedProjectRoot] = useState<string | null>(null);
+  const [sourceRevisions, setSourceRevisions] = useState<Record<string, string | null>>({});
   const [warning, setWarning] = useState<string | null>(null);
   const [operationError, setOperationError] = useState<ErrorDiagnostic | null>(null);
   const [feedback, setFeedback] = useState<MutationFeedback | null>(null);
   const [pendingRemovalId, setPendingRemovalId] = useState<string | null>(null);
   const [pendingUpdateId, setPendingUpdateId] = useState<string | null>(null);
   const [pendingReplicationId, setPendingReplicationId] = useState<string | null>(null);
+  const [pendingProbeId, setPendingProbeId] = useState<string | null>(null);
+  const [probeResults, setProbeResults] = useState<Record<string, ProbeMcpServerResponse>>({});
+  const [toolCollisions, setToolCollisions] = useState<McpToolCollision[]>([]);
   const resources = useMemo(
     () => selectMcpResourcesForView(sourceAwareResources, viewMode),
     [sourceAwareResources, viewMode],
@@ -159,6 +192,11 @@ export function useMcpManager({
       }
 
       setSourceAwareResources(sortMcpResources(envelope.data.items));
+      setSourceRevisions(
+        Object.fromEntries(
+          envelope.data.source_revisions.map((entry) => [entry.source_id, entry.revision]),
+        ),
+      );
       setResolvedProjectRoot(envelope.data.project_root);
       setWarning(redactNullableSensitiveText(envelope.data.warning));
       setOperationError(null);
@@ -176,6 +214,11 @@ export function useMcpManager({
     void refresh();
   }, [refresh]);
 
+  const refreshOnSourceChange = useCallback(() => {
+    void refresh();
+  }, [refresh]);
+  useSourceChanges("mcp::", refreshOnSourceChange);
+
   const addMcp = useCallback(
     async (input: AddMcpInput) => {
       const transport =
@@ -207,7 +250,7 @@ export function useMcpManager({
           return false;
         }
 
-        setFeedback({ kind: "success", message: redactSensitiveText(envelope.data.message) });
+        setFeedback({ kind: "success", message: mutationFeedbackMessage(envelope.data) });
         await refresh();
         return true;
       } catch (error) {
@@ -236,6 +279,7 @@ export function useMcpManager({
           project_root: input.projectRoot,
           target_source_id: input.targetSourceId,
           payload: null,
+          expected_revision: sourceRevisions[input.targetSourceId] ?? null,
         });
 
         if (!envelope.ok || envelope.data === null) {
@@ -247,7 +291,7 @@ export function useMcpManager({
           return false;
         }
 
-        setFeedback({ kind: "success", message: redactSensitiveText(envelope.data.message) });
+        setFeedback({ kind: "success", message: mutationFeedbackMessage(envelope.data) });
         await refresh();
         return true;
       } catch (error) {
@@ -263,6 +307,49 @@ export function useMcpManager({
         setPendingRemovalId(null);
       }
     },
+    [refresh, sourceRevisions],
+  );
+
+  const setMcpApproval = useCallback(
+    async (input: SetMcpApprovalInput) => {
+      setPendingUpdateId(input.resourceId);
+      try {
+        const envelope = await mutateResource({
+          client: "claude_code",
+          resource_kind: "mcp",
+          action: input.approve ? "approve" : "deny",
+          target_id: input.targetId,
+          project_root: input.projectRoot,
+          target_source_id: input.targetSourceId,
+          payload: null,
+          expected_revision: null,
+        });
+
+        if (!envelope.ok || envelope.data === null) {
+          const diagnostic = envelopeErrorDiagnostic(
+            envelope,
+            "Mutation command failed without an explicit error payload.",
+          );
+          setFeedback({ kind: "error", message: diagnostic.message, diagnostic });
+          return false;
+        }
+
+        setFeedback({ kind: "success", message: mutationFeedbackMessage(envelope.data) });
+        await refresh();
+        return true;
+      } catch (error) {
+        const message = error instanceof Error ? error.message : "Unknown approval runtime error.";
+        const diagnostic = runtimeErrorToDiagnostic(message);
+        setFeedback({
+          kind: "error",
+          message: `${input.sourceLabel}: ${diagnostic.message}`,
+          diagnostic,
+        });
+        return false;
+      } finally {
+        setPendingUpdateId(null);
+      }
+    },
     [refresh],
   );
 
@@ -286,6 +373,7 @@ export function useMcpManager({
             transport: payloadTransport,
             enabled: input.enabled,
           },
+          expected_revision: sourceRevisions[input.targetSourceId] ?? null,
         });
 
         if (!envelope.ok || envelope.data === null) {
@@ -297,7 +385,7 @@ export function useMcpManager({
           return false;
         }
 
-        setFeedback({ kind: "success", message: redactSensitiveText(envelope.data.message) });
+        setFeedback({ kind: "success", message: mutationFeedbackMessage(envelope.data) });
         await refresh();
         return true;
       } catch (error) {
@@ -313,7 +401,7 @@ export function useMcpManager({
         setPendingUpdateId(null);
       }
     },
-    [refresh],
+    [refresh, sourceRevisions],
   );
 
   const copyMcp = useCallback(
@@ -387,6 +475,56 @@ export function useMcpManager({
     [refresh],
   );
 
+  const loadToolCollisions = useCallback(async () => {
+    try {
+      const envelope = await mcpToolInventory({
+        client: null,
+        project_root: contextMode === "project" ? projectRoot : null,
+      });
+      if (envelope.ok && envelope.data !== null) {
+        setToolCollisions(envelope.data.collisions);
+      }
+    } catch {
+      setToolCollisions([]);
+    }
+  }, [contextMode, projectRoot]);
+
+  useEffect(() => {
+    if (sourceAwareResources.length > 0) {
+      void loadToolCollisions();
+    }
+  }, [sourceAwareResources, loadToolCollisions]);
+
+  const probeMcp = useCallback(async (resource: ResourceRecord) => {
+    setPendingProbeId(resource.id);
+    try {
+      const envelope = await probeMcpServer({ resource });
+
+      if (!envelope.ok || envelope.data === null) {
+        const diagnostic = envelopeErrorDiagnostic(
+          envelope,
+          "Probe command failed without an explicit error payload.",
+        );
+        setFeedback({ kind: "error", message: diagnostic.message, diagnostic });
+        return;
+      }
+
+      const result = envelope.data;
+      setProbeResults((current) => ({ ...current, [resource.id]: result }));
+      setFeedback({
+        kind: result.status === "healthy" ? "success" : "error",
+        message: redactSensitiveText(result.message),
+      });
+      await loadToolCollisions();
+    } catch (error) {
+      const message = error instanceof Error ? error.message : "Unknown probe runtime error.";
+      const diagnostic = runtimeErrorToDiagnostic(message);
+      setFeedback({ kind: "error", message: diagnostic.message, diagnostic });
+    } finally {
+      setPendingProbeId(null);
+    }
+  }, [loadToolCollisions]);
+
   return {
     phase,
     resources,
@@ -398,11 +536,16 @@ export function useMcpManager({
     pendingRemovalId,
     pendingUpdateId,
     pendingReplicationId,
+    pendingProbeId,
+    probeResults,
+    toolCollisions,
     refresh,
     addMcp,
     updateMcp,
     copyMcp,
     removeMcp,
+    setMcpApproval,
+    probeMcp,
     clearFeedback: () => setFeedback(null),
   };
 }
@@ -14,6 +14,7 @@ import {
   type ErrorDiagnostic,
   runtimeErrorToDiagnostic,
 } from "../common/errorDiagnostics";
+import { useSourceChanges } from "../common/useSourceWatcher";
 import { sortSkillResources } from "./skill-list-view";
 import { SKILL_CLIENTS } from "./skill-targets";
 
@@ -212,6 +213,11 @@ export function useSkillManager(): UseSkillManagerResult {
     void refresh();
   }, [refresh]);
 
+  const refreshOnSourceChange = useCallback(() => {
+    void refresh();
+  }, [refresh]);
+  useSourceChanges("skill::", refreshOnSourceChange);
+
   const addSkill = useCallback(
     async (input: AddSkillInput) => {
       try {
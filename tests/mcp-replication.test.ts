@@ -25,6 +25,7 @@ function makeResource(overrides: Partial<ResourceRecord>): ResourceRecord {
     description: null,
     install_kind: null,
     manifest_content: null,
+    approval: null,
     ...overrides,
   };
 }
@@ -96,6 +96,7 @@ test("destination matching prefers client plus destination scope and source id",
         description: null,
         install_kind: null,
         manifest_content: null,
+        approval: null,
       },
       target,
     ),
@@ -122,6 +123,7 @@ test("destination matching prefers client plus destination scope and source id",
         description: null,
         install_kind: null,
         manifest_content: null,
+        approval: null,
       },
       target,
     ),
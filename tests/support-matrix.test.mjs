@@ -20,7 +20,7 @@ function assertContiguousPriorities(candidates, fieldName) {
 }
 
 test("matrix includes exactly three target clients", () => {
-  assert.equal(matrix.version, "1.3.0");
+  assert.equal(matrix.version, "1.4.0");
   assert.equal(matrix.clients.length, expectedClientIds.length);
   assert.deepEqual(matrix.clients.map((client) => client.id).sort(), [...expectedClientIds].sort());
 });
@@ -42,6 +42,23 @@ test("each client has deterministic candidate priority order per kind", () => {
   }
 });
 
+test("desktop clients declare app install candidates for every OS", () => {
+  for (const client of matrix.clients) {
+    if (client.category !== "desktop") {
+      assert.deepEqual(client.appCandidates, [], `${client.id} is not a desktop client`);
+      continue;
+    }
+
+    assertContiguousPriorities(client.appCandidates, `${client.id}/appCandidates`);
+    for (const os of ["darwin", "linux", "win32"]) {
+      assert.ok(
+        client.appCandidates.some((candidate) => candidate.os.includes(os)),
+        `${client.id} must declare ${os} app candidates`,
+      );
+    }
+  }
+});
+
 test("every client includes happy_path and fallback candidates", () => {
   for (const client of matrix.clients) {
     const candidateRoles = new Set(client.configPathCandidates.map((candidate) => candidate.role));
@@ -65,7 +82,7 @@ test("detection requirements are actionable and include config readability", ()
 
 test("resource kinds expose staged scope support and precedence", () => {
   for (const client of matrix.clients) {
-    for (const kind of ["mcp", "skills", "subagents"]) {
+    for (const kind of ["mcp", "skills", "subagents", "settings", "commands", "instructions"]) {
       const support = client.resourceKinds[kind];
       assert.ok(Array.isArray(support.currentSourceScopes));
       assert.ok(Array.isArray(support.targetSourceScopes));
@@ -157,3 +174,53 @@ test("subagents are modeled as a dedicated Claude-native resource kind", () => {
     assert.deepEqual(byId.get(clientId).resourceKinds.subagents.effectivePrecedence, []);
   }
 });
+
+test("Claude settings are a native resource kind across all three scopes", () => {
+  const byId = new Map(matrix.clients.map((client) => [client.id, client]));
+  const claudeSettings = byId.get("claude_code").resourceKinds.settings;
+
+  assert.equal(claudeSettings.family, "native");
+  assert.deepEqual(claudeSettings.targetDestinationScopes, [
+    "user",
+    "project_shared",
+    "project_private",
+  ]);
+  assert.deepEqual(claudeSettings.effectivePrecedence, [
+    "project_private",
+    "project_shared",
+    "user",
+  ]);
+
+  for (const clientId of ["codex", "cursor"]) {
+    assert.deepEqual(byId.get(clientId).resourceKinds.settings.currentSourceScopes, []);
+    assert.equal(byId.get(clientId).resourceKinds.settings.projectScopeStatus, "not_applicable");
+  }
+});
+
+test("commands cover Claude project commands and Codex user prompts", () => {
+  const byId = new Map(matrix.clients.map((client) => [client.id, client]));
+
+  assert.deepEqual(byId.get("claude_code").resourceKinds.commands.targetDestinationScopes, [
+    "user",
+    "project_shared",
+  ]);
+  assert.deepEqual(byId.get("codex").resourceKinds.commands.currentSourceScopes, ["user"]);
+  assert.deepEqual(byId.get("cursor").resourceKinds.commands.currentSourceScopes, []);
+});
+
+test("instructions cover every client at its native file locations", () => {
+  const byId = new Map(matrix.clients.map((client) => [client.id, client]));
+
+  assert.deepEqual(byId.get("claude_code").resourceKinds.instructions.effectivePrecedence, [
+    "project_private",
+    "project_shared",
+    "user",
+  ]);
+  assert.deepEqual(byId.get("codex").resourceKinds.instructions.currentSourceScopes, [
+    "user",
+    "project_shared",
+  ]);
+  assert.deepEqual(byId.get("cursor").resourceKinds.instructions.currentSourceScopes, [
+    "project_shared",
+  ]);
+});